@@ -3,6 +3,7 @@ fn main() {
     build_apple_intelligence_bridge();
 
     generate_tray_translations();
+    generate_template_translations();
 
     tauri_build::build()
 }
@@ -91,6 +92,66 @@ fn generate_tray_translations() {
     );
 }
 
+/// Generate built-in meeting template names from frontend locale files.
+///
+/// Source of truth: the "builtinTemplates" section of src/i18n/locales/*/translation.json,
+/// keyed by each built-in template's `name_key`.
+fn generate_template_translations() {
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::Path;
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let locales_dir = Path::new("../src/i18n/locales");
+
+    // Collect each locale's template names
+    let mut translations: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+
+    for entry in fs::read_dir(locales_dir).unwrap().flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let lang = path.file_name().unwrap().to_str().unwrap().to_string();
+        let content = fs::read_to_string(path.join("translation.json")).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        if let Some(names) = parsed.get("builtinTemplates").and_then(|v| v.as_object()) {
+            let names = names
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect();
+            translations.insert(lang, names);
+        }
+    }
+
+    let mut out = String::from(
+        "// Auto-generated from src/i18n/locales/*/translation.json - do not edit\n\n",
+    );
+    out.push_str(
+        "pub static TEMPLATE_NAMES: Lazy<HashMap<&'static str, HashMap<&'static str, &'static str>>> =\n",
+    );
+    out.push_str("    Lazy::new(|| {\n");
+    out.push_str("        let mut m = HashMap::new();\n");
+
+    for (lang, names) in &translations {
+        out.push_str("        let mut names = HashMap::new();\n");
+        for (key, name) in names {
+            out.push_str(&format!(
+                "        names.insert(\"{}\", \"{}\");\n",
+                escape_string(key),
+                escape_string(name)
+            ));
+        }
+        out.push_str(&format!("        m.insert(\"{lang}\", names);\n"));
+    }
+
+    out.push_str("        m\n    });\n");
+
+    fs::write(Path::new(&out_dir).join("template_translations.rs"), out).unwrap();
+}
+
 fn camel_to_snake(s: &str) -> String {
     s.chars()
         .enumerate()
@@ -1,5 +1,6 @@
 use cpal::traits::{DeviceTrait, HostTrait};
 
+#[derive(Clone)]
 pub struct CpalDeviceInfo {
     pub index: String,
     pub name: String,
@@ -7,6 +8,23 @@ pub struct CpalDeviceInfo {
     pub device: cpal::Device,
 }
 
+/// Error returned when a recording needs a microphone but the system has no
+/// input device, or all of them are disabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoInputDevice;
+
+impl std::fmt::Display for NoInputDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No microphone was found. Connect or enable an input device in your system's \
+             sound settings, or use a template that records system audio only."
+        )
+    }
+}
+
+impl std::error::Error for NoInputDevice {}
+
 pub fn list_input_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::Error>> {
     let host = crate::audio_toolkit::get_cpal_host();
     let default_name = host.default_input_device().and_then(|d| d.name().ok());
@@ -29,6 +47,12 @@ pub fn list_input_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::E
     Ok(out)
 }
 
+/// Returns the input device `AudioRecorder::open(None)` records from.
+pub fn default_input_device() -> Option<CpalDeviceInfo> {
+    let devices = list_input_devices().ok()?;
+    devices.into_iter().find(|d| d.is_default)
+}
+
 pub fn list_output_devices() -> Result<Vec<CpalDeviceInfo>, Box<dyn std::error::Error>> {
     let host = crate::audio_toolkit::get_cpal_host();
     let default_name = host.default_output_device().and_then(|d| d.name().ok());
@@ -5,8 +5,10 @@ mod resampler;
 mod utils;
 mod visualizer;
 
-pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
+pub use device::{
+    default_input_device, list_input_devices, list_output_devices, CpalDeviceInfo, NoInputDevice,
+};
 pub use recorder::AudioRecorder;
 pub use resampler::FrameResampler;
-pub use utils::save_wav_file;
+pub use utils::{save_wav_file, save_wav_file_at_rate, validate_wav_samples};
 pub use visualizer::AudioVisualiser;
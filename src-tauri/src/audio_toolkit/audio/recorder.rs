@@ -30,6 +30,7 @@ pub struct AudioRecorder {
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     sample_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     error_cb: Option<Arc<dyn Fn(String) + Send + Sync + 'static>>,
+    keep_samples: bool,
 }
 
 impl AudioRecorder {
@@ -42,6 +43,7 @@ impl AudioRecorder {
             level_cb: None,
             sample_cb: None,
             error_cb: None,
+            keep_samples: true,
         })
     }
 
@@ -77,6 +79,13 @@ impl AudioRecorder {
         self
     }
 
+    /// Delivers samples only through the sample callback, so a recorder left
+    /// running doesn't accumulate them; `stop` then returns no samples.
+    pub fn without_sample_buffer(mut self) -> Self {
+        self.keep_samples = false;
+        self
+    }
+
     pub fn open(&mut self, device: Option<Device>) -> Result<(), Box<dyn std::error::Error>> {
         if self.worker_handle.is_some() {
             return Ok(()); // already open
@@ -101,6 +110,7 @@ impl AudioRecorder {
         let sample_cb = self.sample_cb.clone();
         // Move the optional error callback into the worker thread
         let error_cb = self.error_cb.clone();
+        let keep_samples = self.keep_samples;
 
         let worker = std::thread::spawn(move || {
             let config = AudioRecorder::get_preferred_config(&thread_device)
@@ -164,7 +174,15 @@ impl AudioRecorder {
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb, sample_cb);
+            run_consumer(
+                sample_rate,
+                vad,
+                sample_rx,
+                cmd_rx,
+                level_cb,
+                sample_cb,
+                keep_samples,
+            );
             // stream is dropped here, after run_consumer returns
         });
 
@@ -298,6 +316,7 @@ fn run_consumer(
     cmd_rx: mpsc::Receiver<Cmd>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     sample_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    keep_samples: bool,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
@@ -368,6 +387,9 @@ fn run_consumer(
         frame_resampler.push(&raw, &mut |frame: &[f32]| {
             handle_frame(frame, recording, &vad, &mut processed_samples, &sample_cb)
         });
+        if !keep_samples {
+            processed_samples.clear();
+        }
 
         // non-blocking check for a command
         while let Ok(cmd) = cmd_rx.try_recv() {
@@ -3,11 +3,51 @@ use hound::{WavSpec, WavWriter};
 use log::debug;
 use std::path::Path;
 
+/// Lowest sample rate accepted by `save_wav_file_at_rate`
+pub const MIN_WAV_SAMPLE_RATE: u32 = 8_000;
+/// Highest sample rate accepted by `save_wav_file_at_rate`
+pub const MAX_WAV_SAMPLE_RATE: u32 = 192_000;
+/// Longest buffer accepted by `save_wav_file_at_rate`, in seconds
+pub const MAX_WAV_DURATION_SECS: u64 = 4 * 60 * 60;
+
+/// Checks that a mono buffer is a sensible size to write as a WAV file.
+pub fn validate_wav_samples(sample_count: usize, sample_rate: u32) -> Result<()> {
+    if !(MIN_WAV_SAMPLE_RATE..=MAX_WAV_SAMPLE_RATE).contains(&sample_rate) {
+        return Err(anyhow::anyhow!(
+            "Sample rate {} Hz is outside the supported range ({}-{} Hz)",
+            sample_rate,
+            MIN_WAV_SAMPLE_RATE,
+            MAX_WAV_SAMPLE_RATE
+        ));
+    }
+    if sample_count == 0 {
+        return Err(anyhow::anyhow!("No samples to save"));
+    }
+    let max_samples = MAX_WAV_DURATION_SECS * sample_rate as u64;
+    if sample_count as u64 > max_samples {
+        return Err(anyhow::anyhow!(
+            "Buffer of {} samples is longer than the {} hour limit",
+            sample_count,
+            MAX_WAV_DURATION_SECS / 3600
+        ));
+    }
+    Ok(())
+}
+
 /// Save audio samples as a WAV file
 pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Result<()> {
+    save_wav_file_at_rate(file_path, samples, 16000).await
+}
+
+/// Save mono audio samples as a 16-bit WAV file at the given sample rate
+pub async fn save_wav_file_at_rate<P: AsRef<Path>>(
+    file_path: P,
+    samples: &[f32],
+    sample_rate: u32,
+) -> Result<()> {
     let spec = WavSpec {
         channels: 1,
-        sample_rate: 16000,
+        sample_rate,
         bits_per_sample: 16,
         sample_format: hound::SampleFormat::Int,
     };
@@ -24,3 +64,36 @@ pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Res
     debug!("Saved WAV file: {:?}", file_path.as_ref());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hound::WavReader;
+
+    #[test]
+    fn test_save_wav_file_at_rate_round_trips_samples() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("buffer.wav");
+        let samples = [0.0, 0.5, -0.5, 1.0, -1.0, 0.25];
+
+        validate_wav_samples(samples.len(), 22_050).unwrap();
+        tauri::async_runtime::block_on(save_wav_file_at_rate(&path, &samples, 22_050)).unwrap();
+
+        let mut reader = WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().channels, 1);
+        assert_eq!(reader.spec().sample_rate, 22_050);
+        let read: Vec<f32> = reader
+            .samples::<i16>()
+            .map(|s| s.unwrap() as f32 / i16::MAX as f32)
+            .collect();
+        assert_eq!(read.len(), samples.len());
+        for (written, read) in samples.iter().zip(&read) {
+            assert!((written - read).abs() < 1e-3, "{} vs {}", written, read);
+        }
+
+        assert!(validate_wav_samples(0, 16_000).is_err());
+        assert!(validate_wav_samples(100, 1_000).is_err());
+        let too_long = (MAX_WAV_DURATION_SECS * 16_000 + 1) as usize;
+        assert!(validate_wav_samples(too_long, 16_000).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Audio samples tagged with their format.
+//!
+//! Passing bare `Vec<f32>` around leaves the sample rate and channel layout
+//! implicit. `AudioBuffer` carries both, so code that needs a particular format
+//! (e.g. 16 kHz mono for transcription) converts explicitly instead of assuming.
+
+use std::time::Duration;
+
+use super::constants::WHISPER_SAMPLE_RATE;
+use super::system_audio::resample;
+
+/// Most channels a buffer may have; the WAV speaker mask defines 18 positions.
+pub const MAX_CHANNELS: u16 = 18;
+
+/// -3 dB, the gain applied to centre and surround channels when downmixing.
+const MINUS_3_DB: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// Per-channel gains for downmixing a frame of `channels` samples to mono.
+///
+/// Channel order follows the WAV convention (FL, FR, FC, LFE, BL, BR, SL, SR).
+fn downmix_weights(channels: usize) -> Vec<f32> {
+    // Mono is the average of the stereo downmix Lo/Ro
+    let front = 0.5;
+    let surround = 0.5 * MINUS_3_DB;
+    match channels {
+        // 5.1: FL FR FC LFE BL BR
+        6 => vec![front, front, MINUS_3_DB, 0.0, surround, surround],
+        // 7.1: FL FR FC LFE BL BR SL SR
+        8 => vec![
+            front, front, MINUS_3_DB, 0.0, surround, surround, surround, surround,
+        ],
+        _ => vec![1.0 / channels as f32; channels],
+    }
+}
+
+/// Checks that audio with `channels` channels can be downmixed.
+pub fn validate_channel_count(channels: u16) -> Result<(), String> {
+    match channels {
+        0 => Err("Audio has no channels".to_string()),
+        c if c > MAX_CHANNELS => Err(format!(
+            "Unsupported channel layout: {} channels (at most {} are supported)",
+            c, MAX_CHANNELS
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Interleaved f32 samples with their sample rate and channel count.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AudioBuffer {
+    /// Interleaved samples in [-1.0, 1.0]
+    pub samples: Vec<f32>,
+    /// Frames per second
+    pub sample_rate: u32,
+    /// Number of interleaved channels (at least 1)
+    pub channels: u16,
+}
+
+impl AudioBuffer {
+    pub fn new(samples: Vec<f32>, sample_rate: u32, channels: u16) -> Self {
+        Self {
+            samples,
+            sample_rate,
+            channels: channels.max(1),
+        }
+    }
+
+    /// Wraps samples that are already 16 kHz mono, the format used for transcription.
+    pub fn mono_16k(samples: Vec<f32>) -> Self {
+        Self::new(samples, WHISPER_SAMPLE_RATE, 1)
+    }
+
+    /// Number of samples across all channels.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Number of frames (samples per channel).
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+
+    /// Playback duration of the buffer.
+    pub fn duration(&self) -> Duration {
+        if self.sample_rate == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(self.frames() as f64 / self.sample_rate as f64)
+    }
+
+    pub fn is_mono_16k(&self) -> bool {
+        self.channels == 1 && self.sample_rate == WHISPER_SAMPLE_RATE
+    }
+
+    /// Downmixes to a single channel.
+    ///
+    /// 5.1 and 7.1 layouts use the ITU-R BS.775 coefficients (centre and surrounds
+    /// at -3 dB, LFE dropped); any other layout averages the channels. A trailing
+    /// partial frame is discarded.
+    pub fn to_mono(self) -> Self {
+        if self.channels <= 1 {
+            return self;
+        }
+        let channels = self.channels as usize;
+        let weights = downmix_weights(channels);
+        let samples = self
+            .samples
+            .chunks_exact(channels)
+            .map(|frame| {
+                let mixed: f32 = frame.iter().zip(&weights).map(|(s, w)| s * w).sum();
+                mixed.clamp(-1.0, 1.0)
+            })
+            .collect();
+        Self::new(samples, self.sample_rate, 1)
+    }
+
+    /// Resamples to `sample_rate`, downmixing to mono first.
+    pub fn to_mono_at(self, sample_rate: u32) -> Self {
+        let mono = self.to_mono();
+        if mono.sample_rate == sample_rate || mono.is_empty() {
+            return Self::new(mono.samples, sample_rate, 1);
+        }
+        let samples = resample(&mono.samples, mono.sample_rate, sample_rate);
+        Self::new(samples, sample_rate, 1)
+    }
+
+    /// Converts to 16 kHz mono for transcription. A no-op if already in that format.
+    pub fn to_mono_16k(self) -> Self {
+        if self.is_mono_16k() {
+            return self;
+        }
+        self.to_mono_at(WHISPER_SAMPLE_RATE)
+    }
+
+    /// Returns the raw samples, for APIs that still take `Vec<f32>`.
+    pub fn into_samples(self) -> Vec<f32> {
+        self.samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_and_frames_account_for_channels() {
+        let stereo = AudioBuffer::new(vec![0.0; 96_000], 48_000, 2);
+        assert_eq!(stereo.frames(), 48_000);
+        assert_eq!(stereo.duration(), Duration::from_secs(1));
+        assert!(!stereo.is_mono_16k());
+    }
+
+    #[test]
+    fn test_to_mono_16k_downmixes_and_resamples() {
+        let stereo = AudioBuffer::new(vec![0.5, -0.5, 1.0, 0.0].repeat(16_000), 32_000, 2);
+        let mono = stereo.to_mono_16k();
+        assert!(mono.is_mono_16k());
+        assert_eq!(mono.frames(), 16_000);
+        assert_eq!(mono.duration(), Duration::from_secs(1));
+
+        let already = AudioBuffer::mono_16k(vec![0.25; 10]);
+        assert_eq!(already.clone().to_mono_16k(), already);
+    }
+
+    #[test]
+    fn test_surround_downmix_drops_lfe_and_attenuates_centre() {
+        // One 5.1 frame with only the LFE, then one with only the centre
+        let frames = vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0];
+        let mono = AudioBuffer::new(frames, 48_000, 6).to_mono();
+        assert_eq!(mono.len(), 2);
+        assert_eq!(mono.samples[0], 0.0);
+        assert!((mono.samples[1] - 0.5 * MINUS_3_DB).abs() < 1e-6);
+
+        assert!(validate_channel_count(6).is_ok());
+        assert!(validate_channel_count(0).is_err());
+        assert!(validate_channel_count(MAX_CHANNELS + 1).is_err());
+    }
+}
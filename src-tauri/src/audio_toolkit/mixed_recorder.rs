@@ -2,14 +2,20 @@
 //!
 //! This module provides a unified recorder that combines:
 //! - Microphone input via cpal (AudioRecorder)
-//! - System audio via ScreenCaptureKit (SystemAudioRecorder)
+//! - System audio via ScreenCaptureKit on macOS or a PulseAudio/PipeWire monitor
+//!   source on Linux (SystemAudioRecorder)
 
+use rustfft::{num_complex::Complex32, FftPlanner};
+use std::collections::VecDeque;
 use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
-#[cfg(target_os = "macos")]
+use super::buffer::AudioBuffer;
+use super::constants::WHISPER_SAMPLE_RATE;
+#[cfg(any(target_os = "macos", target_os = "linux"))]
 use super::system_audio::SystemAudioRecorder;
+use super::system_audio::{resample, AdaptivePollInterval};
 use super::AudioRecorder;
 
 /// Configuration for audio source selection
@@ -17,7 +23,7 @@ use super::AudioRecorder;
 pub enum AudioSourceConfig {
     /// Only capture microphone input
     MicrophoneOnly,
-    /// Only capture system audio (requires macOS 13.0+)
+    /// Only capture system audio (requires macOS 13.0+, or Linux with a monitor source)
     SystemOnly,
     /// Capture both and mix them together
     Mixed,
@@ -29,17 +35,614 @@ impl Default for AudioSourceConfig {
     }
 }
 
+/// Short-term loudness the loudness mixer steers each source towards (~ -20 dBFS RMS)
+const LOUDNESS_TARGET_RMS: f32 = 0.1;
+/// Weight given to the newest block when updating the short-term loudness estimate
+const LOUDNESS_SMOOTHING: f32 = 0.1;
+/// Below this RMS a source is treated as silent and left unamplified
+const LOUDNESS_SILENCE_RMS: f32 = 1e-3;
+const LOUDNESS_MIN_GAIN: f32 = 0.1;
+const LOUDNESS_MAX_GAIN: f32 = 10.0;
+
+/// Mixer that balances microphone and system audio by short-term loudness.
+///
+/// Each source's loudness is tracked as an exponentially smoothed mean square,
+/// and an adaptive gain brings both to a comparable level before they are summed.
+/// This keeps a quiet speaker audible over loud system audio.
+pub struct LoudnessMixer {
+    mic_mean_square: f32,
+    sys_mean_square: f32,
+}
+
+impl LoudnessMixer {
+    pub fn new() -> Self {
+        Self {
+            mic_mean_square: 0.0,
+            sys_mean_square: 0.0,
+        }
+    }
+
+    fn block_mean_square(samples: &[f32]) -> Option<f32> {
+        if samples.is_empty() {
+            return None;
+        }
+        Some(samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32)
+    }
+
+    fn update(estimate: &mut f32, samples: &[f32]) {
+        if let Some(ms) = Self::block_mean_square(samples) {
+            *estimate = LOUDNESS_SMOOTHING * ms + (1.0 - LOUDNESS_SMOOTHING) * *estimate;
+        }
+    }
+
+    fn gain_for(mean_square: f32) -> f32 {
+        let rms = mean_square.sqrt();
+        if rms < LOUDNESS_SILENCE_RMS {
+            return 1.0;
+        }
+        (LOUDNESS_TARGET_RMS / rms).clamp(LOUDNESS_MIN_GAIN, LOUDNESS_MAX_GAIN)
+    }
+
+    /// Returns the current (mic, system) gains.
+    pub fn gains(&self) -> (f32, f32) {
+        (
+            Self::gain_for(self.mic_mean_square),
+            Self::gain_for(self.sys_mean_square),
+        )
+    }
+
+    /// Mixes a block of mic and system samples, padding the shorter with zeros.
+    pub fn mix(&mut self, mic_samples: &[f32], system_samples: &[f32]) -> Vec<f32> {
+        Self::update(&mut self.mic_mean_square, mic_samples);
+        Self::update(&mut self.sys_mean_square, system_samples);
+        let (mic_gain, sys_gain) = self.gains();
+
+        let mix_len = mic_samples.len().max(system_samples.len());
+        (0..mix_len)
+            .map(|i| {
+                let mic = mic_samples.get(i).copied().unwrap_or(0.0) * mic_gain;
+                let sys = system_samples.get(i).copied().unwrap_or(0.0) * sys_gain;
+                ((mic + sys) * 0.5).clamp(-1.0, 1.0)
+            })
+            .collect()
+    }
+}
+
+impl Default for LoudnessMixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Largest offset between mic and system audio that can be set or estimated, in milliseconds.
+pub const MAX_SYNC_OFFSET_MS: i32 = 500;
+/// Audio collected from each source to estimate the offset from (1s at 16kHz).
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+const SYNC_CALIBRATION_SAMPLES: usize = 16000;
+/// Lowest normalized cross-correlation accepted as the two sources sharing a sound.
+const SYNC_MIN_CORRELATION: f32 = 0.5;
+
+/// How microphone and system audio are lined up before mixing in Mixed mode.
+///
+/// The two captures buffer differently, so the same sound (e.g. a remote
+/// speaker heard both directly and through the speakers) can arrive at slightly
+/// different times and sound doubled once mixed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SyncSettings {
+    /// How much later the microphone picks up a sound than system audio
+    /// delivers it, in milliseconds. Positive delays system audio, negative
+    /// delays the microphone.
+    pub offset_ms: i32,
+    /// Estimate the offset from the first second of audio, replacing
+    /// `offset_ms` if the sources clearly share a sound
+    pub auto_calibrate: bool,
+}
+
+/// Converts a sync offset in milliseconds to 16kHz samples, within `MAX_SYNC_OFFSET_MS`.
+pub fn sync_offset_samples(offset_ms: i32) -> isize {
+    let offset_ms = offset_ms.clamp(-MAX_SYNC_OFFSET_MS, MAX_SYNC_OFFSET_MS) as isize;
+    offset_ms * WHISPER_SAMPLE_RATE as isize / 1000
+}
+
+/// Estimates how many samples later `mic` picks up the sound in `system`.
+///
+/// Cross-correlates the two signals at every lag up to `max_lag` in either
+/// direction (and at most half their length) and picks the lag with the
+/// highest normalized correlation. Positive means the microphone is behind.
+///
+/// The correlation at all lags comes from one FFT of each signal, so this is
+/// cheap enough to run on the mixer thread.
+///
+/// # Returns
+/// `None` if no lag correlates well enough, e.g. when the sources don't share any sound
+pub fn estimate_sync_offset(mic: &[f32], system: &[f32], max_lag: usize) -> Option<isize> {
+    let len = mic.len().min(system.len());
+    if len == 0 {
+        return None;
+    }
+    let max_lag = max_lag.min(len / 2) as isize;
+    let dots = cross_correlation(&mic[..len], &system[..len]);
+
+    // Energy of the first i samples of each signal, to normalize each lag by
+    // the parts that overlap at it
+    let prefix_energy = |samples: &[f32]| -> Vec<f64> {
+        std::iter::once(0.0)
+            .chain(samples.iter().scan(0.0f64, |sum, s| {
+                *sum += (*s as f64) * (*s as f64);
+                Some(*sum)
+            }))
+            .collect()
+    };
+    let mic_prefix = prefix_energy(&mic[..len]);
+    let sys_prefix = prefix_energy(&system[..len]);
+
+    let mut best: Option<(isize, f32)> = None;
+    for lag in -max_lag..=max_lag {
+        // Pair mic[i] with system[i - lag]
+        let shift = lag.unsigned_abs();
+        let (mic_energy, sys_energy, dot) = if lag >= 0 {
+            (
+                mic_prefix[len] - mic_prefix[shift],
+                sys_prefix[len - shift],
+                dots[shift],
+            )
+        } else {
+            (
+                mic_prefix[len - shift],
+                sys_prefix[len] - sys_prefix[shift],
+                dots[dots.len() - shift],
+            )
+        };
+        let norm = (mic_energy.max(0.0) * sys_energy.max(0.0)).sqrt() as f32;
+        if norm <= f32::EPSILON {
+            continue;
+        }
+
+        let score = dot / norm;
+        if best.map_or(true, |(_, best_score)| score > best_score) {
+            best = Some((lag, score));
+        }
+    }
+
+    best.filter(|(_, score)| *score >= SYNC_MIN_CORRELATION)
+        .map(|(lag, _)| lag)
+}
+
+/// Sum of `mic[i + k] * system[i]` for every lag `k`, computed with FFTs.
+///
+/// Entry `k` holds lag `k` for `k >= 0`; negative lags wrap around to the end,
+/// with lag `-k` at index `result.len() - k`.
+fn cross_correlation(mic: &[f32], system: &[f32]) -> Vec<f32> {
+    // Zero padding to twice the length keeps the circular correlation from wrapping
+    let size = (mic.len() + system.len()).next_power_of_two();
+    let mut planner = FftPlanner::<f32>::new();
+    let forward = planner.plan_fft_forward(size);
+    let inverse = planner.plan_fft_inverse(size);
+
+    let spectrum = |samples: &[f32]| -> Vec<Complex32> {
+        let mut buffer: Vec<Complex32> = samples
+            .iter()
+            .map(|s| Complex32::new(*s, 0.0))
+            .chain(std::iter::repeat(Complex32::new(0.0, 0.0)))
+            .take(size)
+            .collect();
+        forward.process(&mut buffer);
+        buffer
+    };
+    let mic_spectrum = spectrum(mic);
+    let sys_spectrum = spectrum(system);
+
+    let mut product: Vec<Complex32> = mic_spectrum
+        .iter()
+        .zip(&sys_spectrum)
+        .map(|(m, s)| m * s.conj())
+        .collect();
+    inverse.process(&mut product);
+    product.iter().map(|c| c.re / size as f32).collect()
+}
+
+/// Delays a stream by a fixed number of samples without changing its block sizes.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+struct DelayLine {
+    queue: VecDeque<f32>,
+}
+
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+impl DelayLine {
+    fn new(delay: usize) -> Self {
+        Self {
+            queue: std::iter::repeat(0.0).take(delay).collect(),
+        }
+    }
+
+    /// Replaces `block` with as many samples, taken from `delay` samples earlier.
+    fn process(&mut self, block: &mut Vec<f32>) {
+        let len = block.len();
+        self.queue.extend(block.drain(..));
+        block.extend(self.queue.drain(..len));
+    }
+
+    /// Returns the samples still held back.
+    fn flush(&mut self) -> Vec<f32> {
+        self.queue.drain(..).collect()
+    }
+}
+
+/// Applies `SyncSettings` to the mic and system streams as they are mixed.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+struct StreamAligner {
+    mic_delay: Option<DelayLine>,
+    sys_delay: Option<DelayLine>,
+    /// Samples collected for auto-calibration, until it has run
+    calibration: Option<(Vec<f32>, Vec<f32>)>,
+}
+
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+impl StreamAligner {
+    fn new(sync: SyncSettings) -> Self {
+        let offset = sync_offset_samples(sync.offset_ms);
+        Self {
+            mic_delay: (offset < 0).then(|| DelayLine::new(offset.unsigned_abs())),
+            sys_delay: (offset > 0).then(|| DelayLine::new(offset.unsigned_abs())),
+            calibration: sync.auto_calibrate.then(|| (Vec::new(), Vec::new())),
+        }
+    }
+
+    /// Delays whichever stream is ahead, in place. While auto-calibrating, the
+    /// blocks are also collected until there is enough audio to estimate from.
+    fn align(&mut self, mic: &mut Vec<f32>, sys: &mut Vec<f32>) {
+        if let Some((mic_window, sys_window)) = self.calibration.as_mut() {
+            let mic_room = SYNC_CALIBRATION_SAMPLES.saturating_sub(mic_window.len());
+            let sys_room = SYNC_CALIBRATION_SAMPLES.saturating_sub(sys_window.len());
+            mic_window.extend(mic.iter().take(mic_room));
+            sys_window.extend(sys.iter().take(sys_room));
+
+            if mic_window.len() == SYNC_CALIBRATION_SAMPLES
+                && sys_window.len() == SYNC_CALIBRATION_SAMPLES
+            {
+                let max_lag = sync_offset_samples(MAX_SYNC_OFFSET_MS).unsigned_abs();
+                match estimate_sync_offset(mic_window, sys_window, max_lag) {
+                    Some(offset) => {
+                        log::info!(
+                            "Estimated mic/system audio offset: {}ms",
+                            offset * 1000 / WHISPER_SAMPLE_RATE as isize
+                        );
+                        self.set_offset(offset, mic, sys);
+                    }
+                    None => log::debug!("Mic and system audio too different to estimate offset"),
+                }
+                self.calibration = None;
+            }
+        }
+
+        if let Some(delay) = self.mic_delay.as_mut() {
+            delay.process(mic);
+        }
+        if let Some(delay) = self.sys_delay.as_mut() {
+            delay.process(sys);
+        }
+    }
+
+    /// Switches to a new offset. Samples the old delays still held are put in
+    /// front of the current blocks so none are lost.
+    fn set_offset(&mut self, offset: isize, mic: &mut Vec<f32>, sys: &mut Vec<f32>) {
+        if let Some(mut delay) = self.mic_delay.take() {
+            mic.splice(0..0, delay.flush());
+        }
+        if let Some(mut delay) = self.sys_delay.take() {
+            sys.splice(0..0, delay.flush());
+        }
+        self.mic_delay = (offset < 0).then(|| DelayLine::new(offset.unsigned_abs()));
+        self.sys_delay = (offset > 0).then(|| DelayLine::new(offset.unsigned_abs()));
+    }
+
+    /// Appends the samples the delays still hold, at the end of the recording.
+    fn flush(&mut self, mic: &mut Vec<f32>, sys: &mut Vec<f32>) {
+        if let Some(delay) = self.mic_delay.as_mut() {
+            mic.extend(delay.flush());
+        }
+        if let Some(delay) = self.sys_delay.as_mut() {
+            sys.extend(delay.flush());
+        }
+    }
+}
+
+/// Largest chunk size, in frames, that can be requested for the sample callback (1s at 16kHz).
+pub const MAX_SAMPLE_CHUNK_FRAMES: usize = 16000;
+
+/// Regroups incoming sample blocks of arbitrary size into chunks of exactly `frames` samples.
+///
+/// Used so the sample callback sees a steady chunk size regardless of the
+/// device's native period. Leftover samples are held until the chunk is full
+/// or [`SampleChunker::flush`] is called.
+pub struct SampleChunker {
+    frames: usize,
+    pending: Vec<f32>,
+}
+
+impl SampleChunker {
+    pub fn new(frames: usize) -> Self {
+        let frames = frames.clamp(1, MAX_SAMPLE_CHUNK_FRAMES);
+        Self {
+            frames,
+            pending: Vec::with_capacity(frames),
+        }
+    }
+
+    /// Buffers `samples` and returns every chunk that is now complete.
+    pub fn push(&mut self, samples: &[f32]) -> Vec<Vec<f32>> {
+        self.pending.extend_from_slice(samples);
+        let complete = self.pending.len() / self.frames * self.frames;
+        if complete == 0 {
+            return Vec::new();
+        }
+        let rest = self.pending.split_off(complete);
+        let full = std::mem::replace(&mut self.pending, rest);
+        full.chunks(self.frames).map(<[f32]>::to_vec).collect()
+    }
+
+    /// Returns the final partial chunk, if any samples are still buffered.
+    pub fn flush(&mut self) -> Option<Vec<f32>> {
+        (!self.pending.is_empty()).then(|| std::mem::take(&mut self.pending))
+    }
+}
+
+/// How long the mixer thread waits for new samples between mixing passes while audio flows.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+const MIXER_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Longest wait between passes during silence; bounds the added latency when audio resumes.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+const MIXER_MAX_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Mixes whatever mic and system samples are buffered, then clears the buffers.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+fn mix_buffered(
+    mic_buffer: &mut Vec<f32>,
+    sys_buffer: &mut Vec<f32>,
+    loudness_mixer: Option<&mut LoudnessMixer>,
+) -> Vec<f32> {
+    let mixed = match loudness_mixer {
+        Some(mixer) => mixer.mix(mic_buffer, sys_buffer),
+        None => {
+            let mix_len = mic_buffer.len().max(sys_buffer.len());
+            let mut mixed = Vec::with_capacity(mix_len);
+
+            for i in 0..mix_len {
+                let mic = mic_buffer.get(i).copied().unwrap_or(0.0);
+                let sys = sys_buffer.get(i).copied().unwrap_or(0.0);
+                // Mix with equal weight, clamp to [-1, 1]
+                mixed.push(((mic + sys) * 0.5).clamp(-1.0, 1.0));
+            }
+            mixed
+        }
+    };
+
+    mic_buffer.clear();
+    sys_buffer.clear();
+    mixed
+}
+
+/// How far one stream may run ahead of the other before its extra audio is
+/// mixed against silence (0.5s at 16kHz), e.g. while system audio is quiet and
+/// delivers nothing.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+const MAX_MIX_WAIT_SAMPLES: usize = WHISPER_SAMPLE_RATE as usize / 2;
+
+/// Mic and system audio waiting to be mixed, at 16kHz.
+///
+/// The two streams arrive in blocks of different sizes and at different
+/// times, so sample `i` of one block isn't the same moment as sample `i` of
+/// the other. Only the stretch both streams cover is mixed; the rest waits for
+/// the other stream to catch up.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+struct MixQueue {
+    mic_rate: u32,
+    sys_rate: u32,
+    mic: Vec<f32>,
+    sys: Vec<f32>,
+}
+
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+impl MixQueue {
+    fn new(mic_rate: u32, sys_rate: u32) -> Self {
+        Self {
+            mic_rate,
+            sys_rate,
+            mic: Vec::new(),
+            sys: Vec::new(),
+        }
+    }
+
+    /// Resamples newly arrived blocks of each stream to 16kHz.
+    fn to_mix_rate(&self, mic: Vec<f32>, sys: Vec<f32>) -> (Vec<f32>, Vec<f32>) {
+        (
+            resample(&mic, self.mic_rate, WHISPER_SAMPLE_RATE),
+            resample(&sys, self.sys_rate, WHISPER_SAMPLE_RATE),
+        )
+    }
+
+    /// Queues blocks already at 16kHz.
+    fn push(&mut self, mic: Vec<f32>, sys: Vec<f32>) {
+        self.mic.extend(mic);
+        self.sys.extend(sys);
+    }
+
+    /// Mixes the stretch both streams cover and keeps the rest queued. Audio
+    /// more than `MAX_MIX_WAIT_SAMPLES` ahead of the other stream is mixed
+    /// against silence.
+    fn mix_ready(&mut self, loudness_mixer: Option<&mut LoudnessMixer>) -> Vec<f32> {
+        let longest = self.mic.len().max(self.sys.len());
+        let ready = self
+            .mic
+            .len()
+            .min(self.sys.len())
+            .max(longest.saturating_sub(MAX_MIX_WAIT_SAMPLES));
+        if ready == 0 {
+            return Vec::new();
+        }
+
+        let mut mic: Vec<f32> = self.mic.drain(..ready.min(self.mic.len())).collect();
+        let mut sys: Vec<f32> = self.sys.drain(..ready.min(self.sys.len())).collect();
+        mix_buffered(&mut mic, &mut sys, loudness_mixer)
+    }
+
+    /// Mixes everything queued, padding the shorter stream with silence.
+    fn mix_all(&mut self, loudness_mixer: Option<&mut LoudnessMixer>) -> Vec<f32> {
+        mix_buffered(&mut self.mic, &mut self.sys, loudness_mixer)
+    }
+
+    /// Takes the queued mic audio unmixed, discarding any system audio.
+    fn take_mic(&mut self) -> Vec<f32> {
+        self.sys.clear();
+        std::mem::take(&mut self.mic)
+    }
+}
+
+/// Body of the Mixed-mode mixer thread.
+///
+/// Mixes mic and system samples as they arrive, lined up according to `sync`,
+/// and passes them to `output`. Only time-aligned stretches are mixed, see `MixQueue`.
+/// If the system stream reports an error on `sys_errors`, what was captured so
+/// far is mixed, `on_system_dropped` is called with the error, and the rest of
+/// the recording is the microphone alone.
+/// Returns as soon as `shutdown` receives a message or its sender is dropped,
+/// after draining any samples still queued so the end of the recording is kept.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+#[allow(clippy::too_many_arguments)]
+fn run_mixer<F, D>(
+    mic_rx: mpsc::Receiver<Vec<f32>>,
+    sys_rx: mpsc::Receiver<Vec<f32>>,
+    sys_errors: mpsc::Receiver<String>,
+    shutdown: mpsc::Receiver<()>,
+    normalize_loudness: bool,
+    sync: SyncSettings,
+    mut on_system_dropped: D,
+    mut output: F,
+) where
+    F: FnMut(Vec<f32>),
+    D: FnMut(&str),
+{
+    // Both capture paths deliver 16kHz audio
+    let mut queue = MixQueue::new(WHISPER_SAMPLE_RATE, WHISPER_SAMPLE_RATE);
+    let mut loudness_mixer = normalize_loudness.then(LoudnessMixer::new);
+    let mut aligner = StreamAligner::new(sync);
+    let mut poll = AdaptivePollInterval::new(MIXER_POLL_INTERVAL, MIXER_MAX_POLL_INTERVAL);
+    let mut system_dropped = false;
+
+    loop {
+        let stopping = match shutdown.recv_timeout(poll.current()) {
+            Err(mpsc::RecvTimeoutError::Timeout) => false,
+            Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => true,
+        };
+
+        let mic_new: Vec<f32> = mic_rx.try_iter().flatten().collect();
+        let sys_new: Vec<f32> = sys_rx.try_iter().flatten().collect();
+
+        // Back off while nothing arrives so long quiet stretches don't spin the CPU
+        poll.record(!mic_new.is_empty() || !sys_new.is_empty());
+
+        let (mut mic_new, mut sys_new) = queue.to_mix_rate(mic_new, sys_new);
+        let mut system_error = None;
+        let mixed = if system_dropped {
+            // Anything the failed stream still delivers is discarded
+            queue.push(mic_new, Vec::new());
+            queue.take_mic()
+        } else {
+            system_error = sys_errors.try_recv().ok();
+            // Delays apply to new audio only, so queued samples aren't delayed twice
+            aligner.align(&mut mic_new, &mut sys_new);
+            let finishing = stopping || system_error.is_some();
+            if finishing {
+                aligner.flush(&mut mic_new, &mut sys_new);
+            }
+            queue.push(mic_new, sys_new);
+            if finishing {
+                queue.mix_all(loudness_mixer.as_mut())
+            } else {
+                queue.mix_ready(loudness_mixer.as_mut())
+            }
+        };
+
+        if !mixed.is_empty() {
+            output(mixed);
+        }
+
+        if let Some(error) = system_error {
+            log::warn!(
+                "System audio capture failed, continuing microphone-only: {}",
+                error
+            );
+            system_dropped = true;
+            on_system_dropped(&error);
+        }
+
+        if stopping {
+            return;
+        }
+    }
+}
+
+/// Body of the SystemOnly forwarding thread.
+///
+/// Passes system samples to `output` as they arrive. Returns as soon as
+/// `shutdown` receives a message or its sender is dropped, after forwarding
+/// whatever is still queued.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+fn run_system_forwarder<F>(
+    sys_rx: mpsc::Receiver<Vec<f32>>,
+    shutdown: mpsc::Receiver<()>,
+    mut output: F,
+) where
+    F: FnMut(Vec<f32>),
+{
+    let mut poll = AdaptivePollInterval::new(MIXER_POLL_INTERVAL, MIXER_MAX_POLL_INTERVAL);
+
+    loop {
+        let stopping = match shutdown.recv_timeout(poll.current()) {
+            Err(mpsc::RecvTimeoutError::Timeout) => false,
+            Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => true,
+        };
+
+        let samples: Vec<f32> = sys_rx.try_iter().flatten().collect();
+        poll.record(!samples.is_empty());
+        if !samples.is_empty() {
+            output(samples);
+        }
+
+        if stopping {
+            return;
+        }
+    }
+}
+
 /// Mixed audio recorder that can capture mic, system, or both
 pub struct MixedAudioRecorder {
     config: AudioSourceConfig,
     mic_recorder: Option<AudioRecorder>,
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     system_recorder: Option<SystemAudioRecorder>,
     mixed_samples: Arc<Mutex<Vec<f32>>>,
     sample_callback: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
     error_callback: Option<Arc<dyn Fn(String) + Send + Sync + 'static>>,
+    /// Called when system capture fails part way through a Mixed recording
+    #[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+    system_dropped_callback: Option<Arc<dyn Fn(String) + Send + Sync + 'static>>,
     is_recording: Arc<Mutex<bool>>,
     mixer_handle: Option<thread::JoinHandle<()>>,
+    /// Wakes the mixer thread on stop; dropping it has the same effect
+    mixer_shutdown: Option<mpsc::Sender<()>>,
+    normalize_loudness: bool,
+    /// Lining up of mic and system audio in Mixed mode
+    sync: SyncSettings,
+    /// Leave this app's own sound output out of the system audio capture
+    exclude_own_audio: bool,
+    /// Fixed chunk size for the sample callback, or None for whatever the source delivers
+    sample_chunk_frames: Option<usize>,
+    /// Chunker feeding the sample callback while recording, flushed on stop
+    sample_chunker: Option<Arc<Mutex<SampleChunker>>>,
+    /// Microphone to record from, or None for the default input device
+    input_device: Option<cpal::Device>,
 }
 
 impl MixedAudioRecorder {
@@ -48,16 +651,80 @@ impl MixedAudioRecorder {
         Ok(Self {
             config,
             mic_recorder: None,
-            #[cfg(target_os = "macos")]
+            #[cfg(any(target_os = "macos", target_os = "linux"))]
             system_recorder: None,
             mixed_samples: Arc::new(Mutex::new(Vec::new())),
             sample_callback: None,
             error_callback: None,
+            system_dropped_callback: None,
             is_recording: Arc::new(Mutex::new(false)),
             mixer_handle: None,
+            mixer_shutdown: None,
+            normalize_loudness: false,
+            sync: SyncSettings::default(),
+            exclude_own_audio: false,
+            sample_chunk_frames: None,
+            sample_chunker: None,
+            input_device: None,
         })
     }
 
+    /// Enables loudness-normalized mixing in Mixed mode instead of a plain average
+    pub fn with_loudness_normalization(mut self, enabled: bool) -> Self {
+        self.normalize_loudness = enabled;
+        self
+    }
+
+    /// Sets how mic and system audio are lined up in Mixed mode. By default
+    /// neither is delayed.
+    pub fn with_sync(mut self, sync: SyncSettings) -> Self {
+        self.sync = sync;
+        self
+    }
+
+    /// Leaves sounds played by this app out of the system audio in SystemOnly and
+    /// Mixed modes. Off by default, so the app's own output is recorded too.
+    pub fn with_own_audio_excluded(mut self, excluded: bool) -> Self {
+        self.exclude_own_audio = excluded;
+        self
+    }
+
+    /// Records the microphone from `device` in MicrophoneOnly and Mixed modes.
+    /// `None` uses the system's default input device.
+    pub fn with_input_device(mut self, device: Option<cpal::Device>) -> Self {
+        self.input_device = device;
+        self
+    }
+
+    /// Delivers samples to the sample callback in chunks of exactly `frames`
+    /// samples (the last chunk before stop may be shorter). `None` or 0 keeps the
+    /// source's own block sizes.
+    pub fn with_sample_chunk_frames(mut self, frames: Option<usize>) -> Self {
+        self.sample_chunk_frames = frames.filter(|&f| f > 0);
+        self
+    }
+
+    /// Returns the sample callback to hand to the recorders, wrapped in a
+    /// chunker when a fixed chunk size was requested.
+    fn chunked_sample_callback(&mut self) -> Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>> {
+        let cb = self.sample_callback.clone()?;
+        let Some(frames) = self.sample_chunk_frames else {
+            return Some(cb);
+        };
+
+        let chunker = Arc::new(Mutex::new(SampleChunker::new(frames)));
+        self.sample_chunker = Some(chunker.clone());
+        Some(Arc::new(move |samples: Vec<f32>| {
+            let chunks = chunker
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .push(&samples);
+            for chunk in chunks {
+                cb(chunk);
+            }
+        }))
+    }
+
     /// Sets a callback for receiving mixed audio samples
     pub fn with_sample_callback<F>(mut self, cb: F) -> Self
     where
@@ -76,14 +743,24 @@ impl MixedAudioRecorder {
         self
     }
 
+    /// Sets a callback for system audio capture failing part way through a
+    /// Mixed recording, which then continues with the microphone alone
+    pub fn with_system_dropped_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        self.system_dropped_callback = Some(Arc::new(cb));
+        self
+    }
+
     /// Starts recording from the configured audio sources
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if *self.is_recording.lock().unwrap_or_else(|p| p.into_inner()) {
             return Ok(());
         }
 
-        let sample_callback = self.sample_callback.clone();
+        let sample_callback = self.chunked_sample_callback();
         let error_callback = self.error_callback.clone();
         let mixed_samples = self.mixed_samples.clone();
 
@@ -106,27 +783,44 @@ impl MixedAudioRecorder {
                         err_cb(error);
                     });
                 }
-                recorder.open(None)?;
+                recorder.open(self.input_device.clone())?;
                 recorder.start()?;
                 self.mic_recorder = Some(recorder);
             }
             AudioSourceConfig::SystemOnly => {
                 // Just use system audio recorder
-                let mut system_recorder = SystemAudioRecorder::new()?;
+                let mut system_recorder =
+                    SystemAudioRecorder::new()?.with_own_audio_excluded(self.exclude_own_audio);
                 system_recorder.start()?;
-                self.system_recorder = Some(system_recorder);
+                let sys_rx = system_recorder
+                    .take_sample_receiver()
+                    .ok_or("System audio capture did not provide a sample stream")?;
 
-                // Start mixer thread to receive and forward system samples
-                let is_recording = self.is_recording.clone();
-                *is_recording.lock().unwrap_or_else(|p| p.into_inner()) = true;
+                // Forward system samples from a thread; stop() shuts it down and joins it
+                let samples_clone = mixed_samples.clone();
+                let callback = sample_callback.clone();
+                let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
+
+                let handle = thread::spawn(move || {
+                    run_system_forwarder(sys_rx, shutdown_rx, |samples| {
+                        samples_clone
+                            .lock()
+                            .unwrap_or_else(|p| p.into_inner())
+                            .extend_from_slice(&samples);
+                        if let Some(ref cb) = callback {
+                            cb(samples);
+                        }
+                    });
+                });
 
-                // We need to poll the system recorder for samples
-                // Since we can't move system_recorder into thread, we'll handle differently
+                self.mixer_shutdown = Some(shutdown_tx);
+                self.mixer_handle = Some(handle);
+                self.system_recorder = Some(system_recorder);
             }
             AudioSourceConfig::Mixed => {
                 // Start both recorders
                 let (mic_tx, mic_rx) = mpsc::channel::<Vec<f32>>();
-                let (_sys_tx, sys_rx) = mpsc::channel::<Vec<f32>>();
+                let (sys_error_tx, sys_error_rx) = mpsc::channel::<String>();
 
                 // Mic recorder
                 let mut mic_recorder = AudioRecorder::new()?;
@@ -141,61 +835,56 @@ impl MixedAudioRecorder {
                         err_cb(error);
                     });
                 }
-                mic_recorder.open(None)?;
+                mic_recorder.open(self.input_device.clone())?;
                 mic_recorder.start()?;
                 self.mic_recorder = Some(mic_recorder);
 
-                // System recorder
-                let mut system_recorder = SystemAudioRecorder::new()?;
+                // System recorder; its errors reach the mixer so recording can go on mic-only
+                let mut system_recorder = SystemAudioRecorder::new()?
+                    .with_own_audio_excluded(self.exclude_own_audio)
+                    .with_error_callback(move |error| {
+                        let _ = sys_error_tx.send(error);
+                    });
                 system_recorder.start()?;
+                let sys_rx = system_recorder
+                    .take_sample_receiver()
+                    .ok_or("System audio capture did not provide a sample stream")?;
 
                 // Start mixer thread
-                let is_recording = self.is_recording.clone();
                 let samples_clone = mixed_samples.clone();
                 let callback = sample_callback.clone();
+                let dropped_callback = self.system_dropped_callback.clone();
+                let normalize_loudness = self.normalize_loudness;
+                let sync = self.sync;
 
-                let handle = thread::spawn(move || {
-                    let mut mic_buffer: Vec<f32> = Vec::new();
-                    let mut sys_buffer: Vec<f32> = Vec::new();
-
-                    while *is_recording.lock().unwrap_or_else(|p| p.into_inner()) {
-                        // Collect mic samples
-                        while let Ok(samples) = mic_rx.try_recv() {
-                            mic_buffer.extend(samples);
-                        }
-
-                        // Collect system samples
-                        while let Ok(samples) = sys_rx.try_recv() {
-                            sys_buffer.extend(samples);
-                        }
-
-                        // Mix available samples
-                        if !mic_buffer.is_empty() || !sys_buffer.is_empty() {
-                            let mix_len = mic_buffer.len().max(sys_buffer.len());
-                            let mut mixed = Vec::with_capacity(mix_len);
+                let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
 
-                            for i in 0..mix_len {
-                                let mic = mic_buffer.get(i).copied().unwrap_or(0.0);
-                                let sys = sys_buffer.get(i).copied().unwrap_or(0.0);
-                                // Mix with equal weight, clamp to [-1, 1]
-                                mixed.push(((mic + sys) * 0.5).clamp(-1.0, 1.0));
+                let handle = thread::spawn(move || {
+                    run_mixer(
+                        mic_rx,
+                        sys_rx,
+                        sys_error_rx,
+                        shutdown_rx,
+                        normalize_loudness,
+                        sync,
+                        |error| {
+                            if let Some(ref cb) = dropped_callback {
+                                cb(error.to_string());
                             }
-
-                            if !mixed.is_empty() {
-                                samples_clone.lock().unwrap_or_else(|p| p.into_inner()).extend_from_slice(&mixed);
-                                if let Some(ref cb) = callback {
-                                    cb(mixed);
-                                }
+                        },
+                        |mixed| {
+                            samples_clone
+                                .lock()
+                                .unwrap_or_else(|p| p.into_inner())
+                                .extend_from_slice(&mixed);
+                            if let Some(ref cb) = callback {
+                                cb(mixed);
                             }
-
-                            mic_buffer.clear();
-                            sys_buffer.clear();
-                        }
-
-                        thread::sleep(Duration::from_millis(10));
-                    }
+                        },
+                    );
                 });
 
+                self.mixer_shutdown = Some(shutdown_tx);
                 self.mixer_handle = Some(handle);
                 self.system_recorder = Some(system_recorder);
             }
@@ -206,17 +895,17 @@ impl MixedAudioRecorder {
         Ok(())
     }
 
-    /// Non-macOS stub
-    #[cfg(not(target_os = "macos"))]
+    /// Stub for platforms without system audio capture
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     pub fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if matches!(
             self.config,
             AudioSourceConfig::SystemOnly | AudioSourceConfig::Mixed
         ) {
-            return Err("System audio capture is only supported on macOS".into());
+            return Err("System audio capture is only supported on macOS and Linux".into());
         }
 
-        let sample_callback = self.sample_callback.clone();
+        let sample_callback = self.chunked_sample_callback();
         let error_callback = self.error_callback.clone();
         let mixed_samples = self.mixed_samples.clone();
 
@@ -236,15 +925,15 @@ impl MixedAudioRecorder {
                 err_cb(error);
             });
         }
-        recorder.open(None)?;
+        recorder.open(self.input_device.clone())?;
         recorder.start()?;
         self.mic_recorder = Some(recorder);
         *self.is_recording.lock().unwrap_or_else(|p| p.into_inner()) = true;
         Ok(())
     }
 
-    /// Stops recording and returns all collected samples
-    pub fn stop(&mut self) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+    /// Stops recording and returns all collected samples (16kHz mono)
+    pub fn stop(&mut self) -> Result<AudioBuffer, Box<dyn std::error::Error>> {
         *self.is_recording.lock().unwrap_or_else(|p| p.into_inner()) = false;
 
         // Stop mic recorder
@@ -253,22 +942,35 @@ impl MixedAudioRecorder {
         }
 
         // Stop system recorder
-        #[cfg(target_os = "macos")]
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
         if let Some(ref mut system_recorder) = self.system_recorder {
             let _ = system_recorder.stop();
         }
 
+        // Wake the mixer only after the recorders stopped, so it drains their final samples
+        if let Some(shutdown) = self.mixer_shutdown.take() {
+            let _ = shutdown.send(());
+        }
+
         // Wait for mixer thread
         if let Some(handle) = self.mixer_handle.take() {
             let _ = handle.join();
         }
 
+        // Hand the callback the last partial chunk so no samples are lost
+        if let Some(chunker) = self.sample_chunker.take() {
+            let rest = chunker.lock().unwrap_or_else(|p| p.into_inner()).flush();
+            if let (Some(rest), Some(cb)) = (rest, &self.sample_callback) {
+                cb(rest);
+            }
+        }
+
         let samples = std::mem::take(&mut *self.mixed_samples.lock().unwrap_or_else(|p| p.into_inner()));
         log::info!(
             "MixedAudioRecorder stopped, collected {} samples",
             samples.len()
         );
-        Ok(samples)
+        Ok(AudioBuffer::mono_16k(samples))
     }
 
     /// Closes the recorder and releases resources
@@ -280,7 +982,7 @@ impl MixedAudioRecorder {
         }
         self.mic_recorder = None;
 
-        #[cfg(target_os = "macos")]
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
         {
             self.system_recorder = None;
         }
@@ -299,3 +1001,294 @@ impl Drop for MixedAudioRecorder {
         let _ = self.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio_toolkit::mix_audio;
+
+    fn sine(amplitude: f32, freq: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * freq * i as f32 / 16000.0).sin())
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn test_loudness_mixer_boosts_quiet_mic_over_loud_system() {
+        let mut mixer = LoudnessMixer::new();
+        let mic = sine(0.02, 220.0, 160);
+        let sys = sine(0.8, 440.0, 160);
+
+        // Warm up the short-term loudness estimates
+        for _ in 0..100 {
+            mixer.mix(&mic, &sys);
+        }
+
+        let (mic_gain, sys_gain) = mixer.gains();
+        assert!(mic_gain > 1.0, "mic should be boosted, got {}", mic_gain);
+        assert!(sys_gain < 1.0, "system should be attenuated, got {}", sys_gain);
+
+        // The mic's contribution to the output is louder than with a plain average
+        let normalized = mixer.mix(&mic, &vec![0.0; mic.len()]);
+        let plain = mix_audio(&mic, &vec![0.0; mic.len()]);
+        assert!(rms(&normalized) > rms(&plain));
+    }
+
+    #[test]
+    fn test_mixer_drains_final_samples_on_prompt_stop() {
+        let (mic_tx, mic_rx) = mpsc::channel::<Vec<f32>>();
+        let (sys_tx, sys_rx) = mpsc::channel::<Vec<f32>>();
+        let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
+
+        // Final buffers are still queued when stop is signalled
+        mic_tx.send(vec![0.5; 160]).unwrap();
+        sys_tx.send(vec![0.5; 160]).unwrap();
+        mic_tx.send(vec![0.25; 80]).unwrap();
+        shutdown_tx.send(()).unwrap();
+
+        let started = std::time::Instant::now();
+        let handle = thread::spawn(move || {
+            let mut out = Vec::new();
+            let (_sys_error_tx, sys_error_rx) = mpsc::channel::<String>();
+            run_mixer(
+                mic_rx,
+                sys_rx,
+                sys_error_rx,
+                shutdown_rx,
+                false,
+                SyncSettings::default(),
+                |_| {},
+                |mixed| out.extend(mixed),
+            );
+            out
+        });
+        let out = handle.join().unwrap();
+
+        // Stop wakes the mixer immediately instead of after a poll interval
+        assert!(started.elapsed() < MIXER_POLL_INTERVAL * 50);
+        assert_eq!(out.len(), 240);
+        assert!((out[0] - 0.5).abs() < 1e-6);
+        assert!((out[239] - 0.125).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_loudness_mixer_leaves_silence_unamplified() {
+        let mut mixer = LoudnessMixer::new();
+        let silence = vec![0.0; 160];
+        for _ in 0..10 {
+            let out = mixer.mix(&silence, &silence);
+            assert!(out.iter().all(|s| *s == 0.0));
+        }
+        assert_eq!(mixer.gains(), (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_sample_chunker_delivers_requested_chunk_size() {
+        let mut chunker = SampleChunker::new(480);
+        let mut delivered: Vec<Vec<f32>> = Vec::new();
+
+        // Irregular device periods: 100, 512, 1000, 7 samples
+        let mut next = 0.0;
+        for len in [100, 512, 1000, 7] {
+            let block: Vec<f32> = (0..len)
+                .map(|_| {
+                    next += 1.0;
+                    next
+                })
+                .collect();
+            delivered.extend(chunker.push(&block));
+        }
+
+        assert_eq!(delivered.len(), 3);
+        assert!(delivered.iter().all(|chunk| chunk.len() == 480));
+
+        // The remainder comes out on flush, with ordering preserved throughout
+        let rest = chunker.flush().unwrap();
+        assert_eq!(rest.len(), 1619 - 3 * 480);
+        assert!(chunker.flush().is_none());
+
+        let all: Vec<f32> = delivered.into_iter().flatten().chain(rest).collect();
+        assert!(all.iter().enumerate().all(|(i, s)| *s == (i + 1) as f32));
+    }
+
+    #[test]
+    fn test_estimated_sync_offset_matches_injected_delay() {
+        // Deterministic noise, so only the true lag correlates strongly
+        let mut seed: u32 = 12345;
+        let system: Vec<f32> = (0..16000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (seed >> 16) as f32 / 32768.0 - 1.0
+            })
+            .collect();
+
+        // The microphone hears the same sound 40ms (640 samples) later, a bit quieter
+        let delay = 640;
+        let mic: Vec<f32> = std::iter::repeat(0.0)
+            .take(delay)
+            .chain(system.iter().map(|s| s * 0.6))
+            .take(system.len())
+            .collect();
+
+        let estimated = estimate_sync_offset(&mic, &system, 800).unwrap();
+        assert!(
+            (estimated - delay as isize).abs() <= 2,
+            "estimated {} samples, expected about {}",
+            estimated,
+            delay
+        );
+        assert_eq!(estimate_sync_offset(&system, &mic, 800), Some(-estimated));
+
+        // Unrelated or silent audio gives no estimate
+        let tone = sine(0.5, 440.0, 16000);
+        assert_eq!(estimate_sync_offset(&tone, &system, 800), None);
+        assert_eq!(estimate_sync_offset(&vec![0.0; 16000], &tone, 800), None);
+
+        // Delaying system audio by the estimate lines the two up block by block
+        let offset_ms = (estimated * 1000 / WHISPER_SAMPLE_RATE as isize) as i32;
+        let mut aligner = StreamAligner::new(SyncSettings {
+            offset_ms,
+            auto_calibrate: false,
+        });
+        let (mut mic_out, mut sys_out) = (Vec::new(), Vec::new());
+        for (mic_block, sys_block) in mic.chunks(1000).zip(system.chunks(1000)) {
+            let (mut mic_block, mut sys_block) = (mic_block.to_vec(), sys_block.to_vec());
+            aligner.align(&mut mic_block, &mut sys_block);
+            assert_eq!(mic_block.len(), sys_block.len());
+            mic_out.extend(mic_block);
+            sys_out.extend(sys_block);
+        }
+        assert!(mic_out
+            .iter()
+            .zip(&sys_out)
+            .skip(delay)
+            .all(|(m, s)| (m - s * 0.6).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_mixer_continues_with_mic_after_system_stream_fails() {
+        let (mic_tx, mic_rx) = mpsc::channel::<Vec<f32>>();
+        let (sys_tx, sys_rx) = mpsc::channel::<Vec<f32>>();
+        let (sys_error_tx, sys_error_rx) = mpsc::channel::<String>();
+        let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
+        let (out_tx, out_rx) = mpsc::channel::<Vec<f32>>();
+        let (dropped_tx, dropped_rx) = mpsc::channel::<String>();
+
+        // Both sources deliver, then the system stream errors
+        mic_tx.send(vec![0.5; 160]).unwrap();
+        sys_tx.send(vec![0.5; 160]).unwrap();
+        sys_error_tx
+            .send("display configuration changed".to_string())
+            .unwrap();
+
+        let handle = thread::spawn(move || {
+            run_mixer(
+                mic_rx,
+                sys_rx,
+                sys_error_rx,
+                shutdown_rx,
+                false,
+                SyncSettings::default(),
+                |error| dropped_tx.send(error.to_string()).unwrap(),
+                |mixed| out_tx.send(mixed).unwrap(),
+            );
+        });
+
+        // Audio captured before the failure is still mixed
+        let timeout = Duration::from_secs(5);
+        let before = out_rx.recv_timeout(timeout).unwrap();
+        assert_eq!(before.len(), 160);
+        assert!(before.iter().all(|s| (s - 0.5).abs() < 1e-6));
+        assert_eq!(
+            dropped_rx.recv_timeout(timeout).unwrap(),
+            "display configuration changed"
+        );
+
+        // Afterwards the microphone is recorded on its own, at full level
+        mic_tx.send(vec![0.4; 100]).unwrap();
+        sys_tx.send(vec![0.9; 100]).unwrap();
+        mic_tx.send(vec![0.3; 50]).unwrap();
+        shutdown_tx.send(()).unwrap();
+        handle.join().unwrap();
+
+        let after: Vec<f32> = out_rx.try_iter().flatten().collect();
+        assert_eq!(after.len(), 150);
+        assert!(after[..100].iter().all(|s| (s - 0.4).abs() < 1e-6));
+        assert!(after[100..].iter().all(|s| (s - 0.3).abs() < 1e-6));
+        assert!(dropped_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_system_forwarder_forwards_samples_and_stops_on_shutdown() {
+        let (sys_tx, sys_rx) = mpsc::channel::<Vec<f32>>();
+        let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
+        let (out_tx, out_rx) = mpsc::channel::<Vec<f32>>();
+
+        let handle = thread::spawn(move || {
+            run_system_forwarder(sys_rx, shutdown_rx, |samples| {
+                out_tx.send(samples).unwrap();
+            });
+        });
+
+        sys_tx.send(vec![0.5; 160]).unwrap();
+        let first = out_rx
+            .recv_timeout(std::time::Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(first, vec![0.5; 160]);
+
+        // Samples queued when stop is signalled are still forwarded
+        sys_tx.send(vec![0.25; 80]).unwrap();
+        shutdown_tx.send(()).unwrap();
+        handle.join().unwrap();
+        let rest: Vec<f32> = out_rx.try_iter().flatten().collect();
+        assert_eq!(rest, vec![0.25; 80]);
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    fn test_system_only_forwarding_thread_starts_and_is_joined_on_stop() {
+        let mut recorder = MixedAudioRecorder::new(AudioSourceConfig::SystemOnly)
+            .unwrap()
+            .with_sample_callback(|_| {});
+        // Capture needs screen recording permission, which test machines may lack
+        if recorder.start().is_err() {
+            return;
+        }
+        assert!(recorder.mixer_handle.is_some());
+        assert!(recorder.is_recording());
+
+        recorder.stop().unwrap();
+        assert!(recorder.mixer_handle.is_none());
+        assert!(recorder.mixer_shutdown.is_none());
+        assert!(!recorder.is_recording());
+    }
+
+    #[test]
+    fn test_mix_queue_mixes_only_time_aligned_audio_across_rates() {
+        // Mic delivers 8kHz, system audio 16kHz
+        let mut queue = MixQueue::new(8000, 16000);
+
+        // 10ms of mic and 20ms of system audio: only the first 10ms line up
+        let (mic, sys) = queue.to_mix_rate(vec![0.4; 80], vec![0.2; 320]);
+        assert_eq!(mic.len(), 160);
+        queue.push(mic, sys);
+        let mixed = queue.mix_ready(None);
+        assert_eq!(mixed.len(), 160);
+        assert!(mixed.iter().all(|s| (s - 0.3).abs() < 1e-6));
+
+        // The system audio left over is mixed once the mic catches up
+        let (mic, sys) = queue.to_mix_rate(vec![0.4; 80], Vec::new());
+        queue.push(mic, sys);
+        assert_eq!(queue.mix_ready(None).len(), 160);
+        assert!(queue.mix_all(None).is_empty());
+
+        // A stream far ahead of a silent one isn't held back indefinitely
+        queue.push(Vec::new(), vec![0.2; 16000]);
+        assert_eq!(queue.mix_ready(None).len(), 16000 - MAX_MIX_WAIT_SAMPLES);
+        assert_eq!(queue.mix_all(None).len(), MAX_MIX_WAIT_SAMPLES);
+    }
+}
@@ -1,19 +1,34 @@
 pub mod audio;
+pub mod buffer;
 pub mod constants;
 pub mod mixed_recorder;
+pub mod permissions;
+pub mod preprocess;
 pub mod system_audio;
 pub mod text;
+pub mod trim;
 pub mod utils;
 pub mod vad;
 
 pub use audio::{
-    list_input_devices, list_output_devices, save_wav_file, AudioRecorder, CpalDeviceInfo,
+    default_input_device, list_input_devices, list_output_devices, save_wav_file,
+    save_wav_file_at_rate, validate_wav_samples, AudioRecorder, CpalDeviceInfo, NoInputDevice,
 };
-pub use mixed_recorder::{AudioSourceConfig, MixedAudioRecorder};
+pub use buffer::{validate_channel_count, AudioBuffer};
+pub use mixed_recorder::{
+    estimate_sync_offset, AudioSourceConfig, LoudnessMixer, MixedAudioRecorder, SampleChunker,
+    SyncSettings, MAX_SAMPLE_CHUNK_FRAMES, MAX_SYNC_OFFSET_MS,
+};
+pub use permissions::{
+    has_microphone_permission, microphone_permission, request_microphone_permission,
+    MicrophonePermission, MicrophonePermissionError,
+};
+pub use preprocess::{apply_preprocessing, PreprocessOp};
 pub use system_audio::{
-    has_screen_recording_permission, mix_audio, request_screen_recording_permission, AudioSource,
-    SystemAudioRecorder,
+    has_screen_recording_permission, mix_audio, mix_buffers, request_screen_recording_permission,
+    system_audio_supported, AdaptivePollInterval, AudioSource, SystemAudioRecorder,
 };
 pub use text::apply_custom_words;
+pub use trim::TrimMap;
 pub use utils::get_cpal_host;
 pub use vad::{SileroVad, VoiceActivityDetector};
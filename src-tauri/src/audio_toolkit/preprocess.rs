@@ -0,0 +1,276 @@
+//! Offline audio preprocessing applied to 16 kHz mono samples before transcription.
+//!
+//! Steps are described by a name plus numeric parameters (as stored on meeting
+//! templates) and parsed into [`PreprocessOp`]s, which can then be applied in order.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::trim::{keep_regions, padded_regions, TrimMap};
+
+/// Frame length used by frame-based steps (noise gate, silence trimming).
+const FRAME_MS: usize = 10;
+const FRAME_SAMPLES: usize = WHISPER_SAMPLE_RATE as usize * FRAME_MS / 1000;
+
+const DEFAULT_TARGET_PEAK: f32 = 0.9;
+const DEFAULT_GATE_THRESHOLD: f32 = 0.01;
+const DEFAULT_TRIM_THRESHOLD: f32 = 0.01;
+const DEFAULT_TRIM_PADDING_MS: f32 = 200.0;
+const MAX_TRIM_PADDING_MS: f32 = 5000.0;
+
+/// Names of all supported preprocessing steps.
+pub const PREPROCESS_STEP_NAMES: &[&str] = &["normalize", "noise_gate", "trim_silence"];
+
+/// A single, validated preprocessing operation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PreprocessOp {
+    /// Scale so the loudest sample reaches `target_peak`
+    Normalize { target_peak: f32 },
+    /// Silence 10 ms frames whose RMS is below `threshold`
+    NoiseGate { threshold: f32 },
+    /// Drop frames whose RMS is below `threshold`, keeping `padding_ms` before and
+    /// after each stretch of speech
+    TrimSilence { threshold: f32, padding_ms: u32 },
+}
+
+impl PreprocessOp {
+    /// Parses and validates a step from its name and parameters.
+    ///
+    /// Missing parameters take their defaults; unknown names, unknown parameters,
+    /// and out-of-range values are rejected.
+    pub fn parse(name: &str, params: &HashMap<String, f32>) -> Result<Self, String> {
+        let allowed: &[&str] = match name {
+            "normalize" => &["target_peak"],
+            "noise_gate" => &["threshold"],
+            "trim_silence" => &["threshold", "padding_ms"],
+            _ => {
+                return Err(format!(
+                    "Unknown preprocessing step '{}' (expected one of: {})",
+                    name,
+                    PREPROCESS_STEP_NAMES.join(", ")
+                ))
+            }
+        };
+
+        if let Some(unknown) = params.keys().find(|k| !allowed.contains(&k.as_str())) {
+            return Err(format!(
+                "Unknown parameter '{}' for preprocessing step '{}'",
+                unknown, name
+            ));
+        }
+
+        let param = |key: &str, default: f32| -> Result<f32, String> {
+            let value = params.get(key).copied().unwrap_or(default);
+            if value.is_finite() {
+                Ok(value)
+            } else {
+                Err(format!("Parameter '{}' of step '{}' must be a number", key, name))
+            }
+        };
+
+        match name {
+            "normalize" => {
+                let target_peak = param("target_peak", DEFAULT_TARGET_PEAK)?;
+                if target_peak <= 0.0 || target_peak > 1.0 {
+                    return Err("normalize.target_peak must be in (0, 1]".to_string());
+                }
+                Ok(PreprocessOp::Normalize { target_peak })
+            }
+            "noise_gate" => {
+                let threshold = param("threshold", DEFAULT_GATE_THRESHOLD)?;
+                if !(0.0..1.0).contains(&threshold) {
+                    return Err("noise_gate.threshold must be in [0, 1)".to_string());
+                }
+                Ok(PreprocessOp::NoiseGate { threshold })
+            }
+            _ => {
+                let threshold = param("threshold", DEFAULT_TRIM_THRESHOLD)?;
+                if !(0.0..1.0).contains(&threshold) {
+                    return Err("trim_silence.threshold must be in [0, 1)".to_string());
+                }
+                let padding_ms = param("padding_ms", DEFAULT_TRIM_PADDING_MS)?;
+                if !(0.0..=MAX_TRIM_PADDING_MS).contains(&padding_ms) {
+                    return Err(format!(
+                        "trim_silence.padding_ms must be between 0 and {}",
+                        MAX_TRIM_PADDING_MS
+                    ));
+                }
+                Ok(PreprocessOp::TrimSilence {
+                    threshold,
+                    padding_ms: padding_ms as u32,
+                })
+            }
+        }
+    }
+
+    /// Applies this operation to 16 kHz mono samples.
+    ///
+    /// # Returns
+    /// The processed samples and where they came from in the input; only
+    /// silence trimming drops audio
+    pub fn apply(&self, samples: Vec<f32>) -> (Vec<f32>, TrimMap) {
+        match *self {
+            PreprocessOp::Normalize { target_peak } => {
+                (normalize_peak(samples, target_peak), TrimMap::default())
+            }
+            PreprocessOp::NoiseGate { threshold } => {
+                (noise_gate(samples, threshold), TrimMap::default())
+            }
+            PreprocessOp::TrimSilence {
+                threshold,
+                padding_ms,
+            } => trim_silence(samples, threshold, padding_ms),
+        }
+    }
+}
+
+/// Applies each operation in order.
+///
+/// # Returns
+/// The processed samples and the map of times in them back onto `samples`
+pub fn apply_preprocessing(samples: Vec<f32>, ops: &[PreprocessOp]) -> (Vec<f32>, TrimMap) {
+    ops.iter()
+        .fold((samples, TrimMap::default()), |(samples, map), op| {
+            let (samples, trim) = op.apply(samples);
+            (samples, map.then(trim))
+        })
+}
+
+fn frame_rms(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return 0.0;
+    }
+    (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt()
+}
+
+fn normalize_peak(mut samples: Vec<f32>, target_peak: f32) -> Vec<f32> {
+    let peak = samples.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+    if peak > 0.0 {
+        let gain = target_peak / peak;
+        samples.iter_mut().for_each(|s| *s *= gain);
+    }
+    samples
+}
+
+fn noise_gate(mut samples: Vec<f32>, threshold: f32) -> Vec<f32> {
+    for frame in samples.chunks_mut(FRAME_SAMPLES) {
+        if frame_rms(frame) < threshold {
+            frame.iter_mut().for_each(|s| *s = 0.0);
+        }
+    }
+    samples
+}
+
+/// Sample ranges of frames whose RMS is at least `threshold`, each widened by
+/// `padding` samples on both sides and merged where they touch.
+fn padded_speech_regions(samples: &[f32], threshold: f32, padding: usize) -> Vec<Range<usize>> {
+    let loud_frames = samples
+        .chunks(FRAME_SAMPLES)
+        .enumerate()
+        .filter(|(_, frame)| frame_rms(frame) >= threshold)
+        .map(|(i, frame)| i * FRAME_SAMPLES..i * FRAME_SAMPLES + frame.len());
+    padded_regions(loud_frames, padding, samples.len())
+}
+
+fn trim_silence(samples: Vec<f32>, threshold: f32, padding_ms: u32) -> (Vec<f32>, TrimMap) {
+    let padding = WHISPER_SAMPLE_RATE as usize * padding_ms as usize / 1000;
+    let regions = padded_speech_regions(&samples, threshold, padding);
+
+    // Nothing above the threshold: leave the audio alone rather than drop it all
+    if regions.is_empty() {
+        return (samples, TrimMap::default());
+    }
+    keep_regions(&samples, &regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, f32)]) -> HashMap<String, f32> {
+        pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_normalize_gate_trim_chain() {
+        // 100ms silence, 100ms faint hiss, 200ms tone at 0.25, 100ms faint hiss, 100ms silence
+        let mut samples = vec![0.0f32; 1600];
+        samples.extend((0..1600).map(|i| if i % 2 == 0 { 0.002 } else { -0.002 }));
+        samples.extend((0..3200).map(|i| if i % 2 == 0 { 0.25 } else { -0.25 }));
+        samples.extend((0..1600).map(|i| if i % 2 == 0 { 0.002 } else { -0.002 }));
+        samples.extend(vec![0.0f32; 1600]);
+
+        let ops = vec![
+            PreprocessOp::parse("normalize", &params(&[("target_peak", 0.5)])).unwrap(),
+            PreprocessOp::parse("noise_gate", &params(&[("threshold", 0.01)])).unwrap(),
+            PreprocessOp::parse("trim_silence", &params(&[("padding_ms", 50.0)])).unwrap(),
+        ];
+
+        let (out, _) = apply_preprocessing(samples, &ops);
+
+        // Tone plus 50ms padding on each side
+        assert_eq!(out.len(), 3200 + 2 * 800);
+
+        // Normalization doubled the tone to the 0.5 target peak
+        assert!(out.iter().any(|s| (s.abs() - 0.5).abs() < 1e-6));
+
+        // Hiss was normalized to 0.004 RMS, below the gate, so padding is silent
+        assert!(out[..800].iter().all(|s| *s == 0.0));
+        assert!(out[out.len() - 800..].iter().all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_steps() {
+        assert!(PreprocessOp::parse("resample_to_mars", &HashMap::new()).is_err());
+        assert!(PreprocessOp::parse("normalize", &params(&[("target_peak", 2.0)])).is_err());
+        assert!(PreprocessOp::parse("noise_gate", &params(&[("gain", 1.0)])).is_err());
+        assert!(PreprocessOp::parse("trim_silence", &params(&[("padding_ms", -1.0)])).is_err());
+
+        assert_eq!(
+            PreprocessOp::parse("trim_silence", &HashMap::new()).unwrap(),
+            PreprocessOp::TrimSilence {
+                threshold: DEFAULT_TRIM_THRESHOLD,
+                padding_ms: 200
+            }
+        );
+    }
+
+    #[test]
+    fn test_padding_merges_close_speech_regions() {
+        let tone = |ms: usize| -> Vec<f32> {
+            (0..ms * 16)
+                .map(|i| if i % 2 == 0 { 0.25 } else { -0.25 })
+                .collect()
+        };
+        // 1s silence, 200ms speech, 300ms pause, 200ms speech, 1s silence
+        let mut samples = vec![0.0f32; 16000];
+        samples.extend(tone(200));
+        samples.extend(vec![0.0f32; 4800]);
+        samples.extend(tone(200));
+        samples.extend(vec![0.0f32; 16000]);
+
+        // 200ms of padding on each side covers the 300ms pause, so the two
+        // regions become one and the pause is kept exactly once
+        let regions = padded_speech_regions(&samples, 0.01, 3200);
+        assert_eq!(regions, vec![16000 - 3200..16000 + 11200 + 3200]);
+        let (merged, _) = trim_silence(samples.clone(), 0.01, 200);
+        assert_eq!(merged.len(), 3200 + 11200 + 3200);
+
+        // With 50ms of padding the pause is cut down to the two paddings around it
+        let regions = padded_speech_regions(&samples, 0.01, 800);
+        assert_eq!(regions.len(), 2);
+        let (separate, map) = trim_silence(samples.clone(), 0.01, 50);
+        assert_eq!(separate.len(), 2 * (800 + 3200 + 800));
+
+        // Times in the trimmed audio map back past the cut-out silence: the
+        // second tone starts 5600 samples into the trimmed audio, 1.5s into the original
+        let second_tone = 5600.0 / WHISPER_SAMPLE_RATE as f32;
+        assert!((map.original_secs(0.0) - 0.95).abs() < 1e-3);
+        assert!((map.original_secs(second_tone) - 1.5).abs() < 1e-3);
+
+        // Less silence is removed when padding keeps more around speech
+        let removed = |out: &[f32]| samples.len() - out.len();
+        assert!(removed(&merged) < removed(&separate));
+    }
+}
@@ -1,14 +1,22 @@
-//! System audio capture using ScreenCaptureKit (macOS 13.0+)
+//! System audio capture using ScreenCaptureKit (macOS 13.0+) or the monitor
+//! source of PulseAudio/PipeWire (Linux)
 //!
 //! This module provides system audio capture functionality for Meeting Mode,
 //! allowing capture of audio from all applications (YouTube, Zoom, etc.)
 //! in addition to microphone input.
+//!
+//! On Linux the monitor of the default output is read through `parec`, which
+//! ships with PulseAudio and works against PipeWire's Pulse server too.
 
 use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
 
 #[cfg(target_os = "macos")]
 use screencapturekit::prelude::*;
+#[cfg(target_os = "macos")]
+use screencapturekit::stream::delegate_trait::SCStreamDelegateTrait;
 
+use super::buffer::AudioBuffer;
 use super::constants;
 
 /// Audio source configuration for meeting recording
@@ -32,10 +40,12 @@ impl Default for AudioSource {
 ///
 /// On macOS 13.0+, ScreenCaptureKit requires screen recording permission
 /// to capture system audio. This function checks if the permission is granted.
+/// Linux has no such permission; there it checks that a monitor source of the
+/// sound server can be reached instead.
 ///
 /// # Returns
-/// - `true` if permission is granted or on non-macOS platforms
-/// - `false` if permission is denied or not yet requested
+/// - `true` if permission is granted, or on Linux if a monitor source is available
+/// - `false` if permission is denied or not yet requested, and on other platforms
 #[cfg(target_os = "macos")]
 pub fn has_screen_recording_permission() -> bool {
     // Try to get shareable content - this will fail if permission is not granted
@@ -45,9 +55,107 @@ pub fn has_screen_recording_permission() -> bool {
     }
 }
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(target_os = "linux")]
+pub fn has_screen_recording_permission() -> bool {
+    monitor_source_accessible()
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
 pub fn has_screen_recording_permission() -> bool {
-    false // System audio capture not supported on non-macOS
+    false // System audio capture not supported on this platform
+}
+
+/// PulseAudio name for the monitor of the default output, which carries
+/// everything the system plays. PipeWire's Pulse server resolves it too.
+#[cfg(target_os = "linux")]
+const DEFAULT_MONITOR_SOURCE: &str = "@DEFAULT_MONITOR@";
+
+/// Samples read from `parec` at a time: 100ms at 16kHz.
+#[cfg(target_os = "linux")]
+const MONITOR_BLOCK_SAMPLES: usize = constants::WHISPER_SAMPLE_RATE as usize / 10;
+
+/// Whether `pactl list short sources` output includes a monitor source.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn lists_monitor_source(sources: &str) -> bool {
+    sources.lines().any(|line| {
+        line.split_whitespace()
+            .nth(1)
+            .map_or(false, |name| name.ends_with(".monitor"))
+    })
+}
+
+/// Decodes raw little-endian f32 samples; a trailing partial sample is dropped.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn decode_f32le(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+/// Checks that the sound server lists a monitor source to capture from.
+#[cfg(target_os = "linux")]
+fn monitor_source_accessible() -> bool {
+    match std::process::Command::new("pactl")
+        .args(["list", "short", "sources"])
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            lists_monitor_source(&String::from_utf8_lossy(&output.stdout))
+        }
+        _ => false,
+    }
+}
+
+/// Checks that `parec`, used to read the monitor source, is installed.
+#[cfg(target_os = "linux")]
+fn parec_available() -> bool {
+    std::process::Command::new("parec")
+        .arg("--version")
+        .output()
+        .map_or(false, |output| output.status.success())
+}
+
+/// Minimum macOS major version with ScreenCaptureKit audio capture.
+const MIN_MACOS_MAJOR_FOR_SYSTEM_AUDIO: u32 = 13;
+
+/// Parses the major component of a version string such as "14.2.1".
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_major_version(version: &str) -> Option<u32> {
+    version.trim().split('.').next()?.parse().ok()
+}
+
+/// Checks whether this platform can capture system audio at all.
+///
+/// Unlike `has_screen_recording_permission`, this does not touch ScreenCaptureKit,
+/// so it never prompts the user and does not depend on the permission state.
+///
+/// # Returns
+/// - `true` on macOS 13.0+ (ScreenCaptureKit audio capture)
+/// - `true` on Linux when `parec` is installed and a monitor source is available
+/// - `false` on older macOS and on other platforms, which have no system audio backend yet
+#[cfg(target_os = "macos")]
+pub fn system_audio_supported() -> bool {
+    let output = match std::process::Command::new("sw_vers")
+        .arg("-productVersion")
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        _ => return false,
+    };
+
+    parse_major_version(&String::from_utf8_lossy(&output.stdout))
+        .map_or(false, |major| major >= MIN_MACOS_MAJOR_FOR_SYSTEM_AUDIO)
+}
+
+#[cfg(target_os = "linux")]
+pub fn system_audio_supported() -> bool {
+    parec_available() && monitor_source_accessible()
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+pub fn system_audio_supported() -> bool {
+    false // System audio capture not supported on this platform
 }
 
 /// Requests screen recording permission by attempting to access ScreenCaptureKit.
@@ -71,9 +179,15 @@ pub fn request_screen_recording_permission() -> Result<bool, Box<dyn std::error:
     }
 }
 
-#[cfg(not(target_os = "macos"))]
+/// Linux needs no permission; reports whether a monitor source is available.
+#[cfg(target_os = "linux")]
 pub fn request_screen_recording_permission() -> Result<bool, Box<dyn std::error::Error>> {
-    Err("System audio capture is only supported on macOS".into())
+    Ok(monitor_source_accessible())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+pub fn request_screen_recording_permission() -> Result<bool, Box<dyn std::error::Error>> {
+    Err("System audio capture is only supported on macOS and Linux".into())
 }
 
 /// Handler for receiving system audio samples from ScreenCaptureKit
@@ -111,12 +225,29 @@ impl SCStreamOutputTrait for SystemAudioHandler {
     }
 }
 
+/// Reports a ScreenCaptureKit stream that stops because of an error, e.g.
+/// when the captured display is reconfigured.
+#[cfg(target_os = "macos")]
+struct StreamErrorDelegate {
+    callback: Arc<dyn Fn(String) + Send + Sync + 'static>,
+}
+
+#[cfg(target_os = "macos")]
+impl SCStreamDelegateTrait for StreamErrorDelegate {
+    fn did_stop_with_error(&self, error: SCError) {
+        log::warn!("System audio stream stopped with an error: {:?}", error);
+        (self.callback)(format!("{:?}", error));
+    }
+}
+
 /// System audio recorder using ScreenCaptureKit
 #[cfg(target_os = "macos")]
 pub struct SystemAudioRecorder {
     stream: Option<SCStream>,
     sample_rx: Option<mpsc::Receiver<Vec<f32>>>,
     is_recording: Arc<Mutex<bool>>,
+    exclude_own_audio: bool,
+    error_callback: Option<Arc<dyn Fn(String) + Send + Sync + 'static>>,
 }
 
 #[cfg(target_os = "macos")]
@@ -127,9 +258,29 @@ impl SystemAudioRecorder {
             stream: None,
             sample_rx: None,
             is_recording: Arc::new(Mutex::new(false)),
+            exclude_own_audio: false,
+            error_callback: None,
         })
     }
 
+    /// Leaves sounds played by this app (notification dings, playback previews)
+    /// out of the capture. By default they are recorded like any other app's
+    /// audio. Takes effect on the next `start`.
+    pub fn with_own_audio_excluded(mut self, excluded: bool) -> Self {
+        self.exclude_own_audio = excluded;
+        self
+    }
+
+    /// Sets a callback for a capture stream that stops because of an error
+    /// after it started. Takes effect on the next `start`.
+    pub fn with_error_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        self.error_callback = Some(Arc::new(cb));
+        self
+    }
+
     /// Starts capturing system audio
     ///
     /// This captures all audio output from the system (apps, browser, etc.)
@@ -160,7 +311,7 @@ impl SystemAudioRecorder {
             .with_width(1) // Minimal video (required for audio capture)
             .with_height(1)
             .with_captures_audio(true)
-            .with_excludes_current_process_audio(false) // Include our app's audio if any
+            .with_excludes_current_process_audio(self.exclude_own_audio)
             .with_sample_rate(constants::WHISPER_SAMPLE_RATE as i32) // 16kHz for Whisper
             .with_channel_count(1); // Mono for Whisper
 
@@ -168,7 +319,16 @@ impl SystemAudioRecorder {
         let (sample_tx, sample_rx) = mpsc::channel();
 
         // Create and configure stream
-        let mut stream = SCStream::new(&filter, &config);
+        let mut stream = match &self.error_callback {
+            Some(callback) => SCStream::new_with_delegate(
+                &filter,
+                &config,
+                StreamErrorDelegate {
+                    callback: callback.clone(),
+                },
+            ),
+            None => SCStream::new(&filter, &config),
+        };
 
         // Add audio output handler
         let handler = SystemAudioHandler { sample_tx };
@@ -224,6 +384,12 @@ impl SystemAudioRecorder {
     pub fn recv_samples(&self) -> Option<Vec<f32>> {
         self.sample_rx.as_ref()?.recv().ok()
     }
+
+    /// Takes the receiver of captured samples, so another thread can consume
+    /// them directly. `try_recv_samples` and `recv_samples` return None afterwards.
+    pub fn take_sample_receiver(&mut self) -> Option<mpsc::Receiver<Vec<f32>>> {
+        self.sample_rx.take()
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -233,18 +399,190 @@ impl Drop for SystemAudioRecorder {
     }
 }
 
-/// Stub implementation for non-macOS platforms
-#[cfg(not(target_os = "macos"))]
+/// System audio recorder reading the default output's monitor source with `parec`
+#[cfg(target_os = "linux")]
+pub struct SystemAudioRecorder {
+    child: Option<std::process::Child>,
+    reader_handle: Option<std::thread::JoinHandle<()>>,
+    sample_rx: Option<mpsc::Receiver<Vec<f32>>>,
+    is_recording: Arc<Mutex<bool>>,
+    exclude_own_audio: bool,
+    error_callback: Option<Arc<dyn Fn(String) + Send + Sync + 'static>>,
+}
+
+#[cfg(target_os = "linux")]
+impl SystemAudioRecorder {
+    /// Creates a new SystemAudioRecorder
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            child: None,
+            reader_handle: None,
+            sample_rx: None,
+            is_recording: Arc::new(Mutex::new(false)),
+            exclude_own_audio: false,
+            error_callback: None,
+        })
+    }
+
+    /// Asks to leave this app's own sounds out of the capture. The monitor
+    /// source carries the whole output mix, so on Linux this can't be honored;
+    /// `start` logs a warning and records them anyway.
+    pub fn with_own_audio_excluded(mut self, excluded: bool) -> Self {
+        self.exclude_own_audio = excluded;
+        self
+    }
+
+    /// Sets a callback for a capture that stops because of an error after it
+    /// started, e.g. the sound server restarting. Takes effect on the next `start`.
+    pub fn with_error_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        self.error_callback = Some(Arc::new(cb));
+        self
+    }
+
+    /// Starts capturing system audio as 16kHz mono samples
+    pub fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Read;
+        use std::process::{Command, Stdio};
+
+        if *self.is_recording.lock().unwrap_or_else(|p| p.into_inner()) {
+            return Ok(()); // Already recording
+        }
+
+        if !monitor_source_accessible() {
+            return Err("No PulseAudio/PipeWire monitor source to capture from".into());
+        }
+        if self.exclude_own_audio {
+            log::warn!("Can't exclude this app's own audio on Linux; it will be recorded");
+        }
+
+        let mut child = Command::new("parec")
+            .arg(format!("--device={}", DEFAULT_MONITOR_SOURCE))
+            .arg("--format=float32le")
+            .arg(format!("--rate={}", constants::WHISPER_SAMPLE_RATE))
+            .arg("--channels=1")
+            .arg("--raw")
+            .arg("--latency-msec=100")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to start parec for system audio capture: {}", e))?;
+        let mut stdout = child
+            .stdout
+            .take()
+            .ok_or("parec did not provide an output stream")?;
+
+        let (sample_tx, sample_rx) = mpsc::channel();
+        let is_recording = self.is_recording.clone();
+        let error_callback = self.error_callback.clone();
+        *is_recording.lock().unwrap_or_else(|p| p.into_inner()) = true;
+
+        let handle = std::thread::spawn(move || {
+            let mut block = vec![0u8; MONITOR_BLOCK_SAMPLES * 4];
+            loop {
+                if let Err(e) = stdout.read_exact(&mut block) {
+                    // stop() kills parec; anything else means capture was lost
+                    if *is_recording.lock().unwrap_or_else(|p| p.into_inner()) {
+                        log::error!("System audio capture stopped unexpectedly: {}", e);
+                        if let Some(callback) = &error_callback {
+                            callback(format!("System audio capture stopped: {}", e));
+                        }
+                    }
+                    break;
+                }
+                if sample_tx.send(decode_f32le(&block)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.child = Some(child);
+        self.reader_handle = Some(handle);
+        self.sample_rx = Some(sample_rx);
+
+        log::info!("System audio capture started from the default monitor source");
+        Ok(())
+    }
+
+    /// Stops capturing system audio
+    pub fn stop(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if !*self.is_recording.lock().unwrap_or_else(|p| p.into_inner()) {
+            return Ok(()); // Not recording
+        }
+        *self.is_recording.lock().unwrap_or_else(|p| p.into_inner()) = false;
+
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        if let Some(handle) = self.reader_handle.take() {
+            let _ = handle.join();
+        }
+
+        self.sample_rx = None;
+
+        log::info!("System audio capture stopped");
+        Ok(())
+    }
+
+    /// Returns whether the recorder is currently capturing
+    pub fn is_recording(&self) -> bool {
+        *self.is_recording.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    /// Tries to receive available audio samples (non-blocking)
+    ///
+    /// Returns None if no samples are available
+    pub fn try_recv_samples(&self) -> Option<Vec<f32>> {
+        self.sample_rx.as_ref()?.try_recv().ok()
+    }
+
+    /// Receives audio samples (blocking)
+    ///
+    /// Returns None if the channel is closed
+    pub fn recv_samples(&self) -> Option<Vec<f32>> {
+        self.sample_rx.as_ref()?.recv().ok()
+    }
+
+    /// Takes the receiver of captured samples, so another thread can consume
+    /// them directly. `try_recv_samples` and `recv_samples` return None afterwards.
+    pub fn take_sample_receiver(&mut self) -> Option<mpsc::Receiver<Vec<f32>>> {
+        self.sample_rx.take()
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for SystemAudioRecorder {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+/// Stub implementation for platforms without system audio capture
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
 pub struct SystemAudioRecorder;
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
 impl SystemAudioRecorder {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        Err("System audio capture is only supported on macOS".into())
+        Err("System audio capture is only supported on macOS and Linux".into())
+    }
+
+    pub fn with_own_audio_excluded(self, _excluded: bool) -> Self {
+        self
+    }
+
+    pub fn with_error_callback<F>(self, _cb: F) -> Self
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        self
     }
 
     pub fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        Err("System audio capture is only supported on macOS".into())
+        Err("System audio capture is only supported on macOS and Linux".into())
     }
 
     pub fn stop(&mut self) -> Result<(), Box<dyn std::error::Error>> {
@@ -262,10 +600,64 @@ impl SystemAudioRecorder {
     pub fn recv_samples(&self) -> Option<Vec<f32>> {
         None
     }
+
+    pub fn take_sample_receiver(&mut self) -> Option<mpsc::Receiver<Vec<f32>>> {
+        None
+    }
+}
+
+/// Poll interval for draining audio channels that backs off while no samples
+/// arrive (e.g., a silent system) and snaps back as soon as they do.
+///
+/// The interval doubles after each empty poll up to `max`, so latency once audio
+/// resumes is bounded by `max`.
+#[derive(Clone, Debug)]
+pub struct AdaptivePollInterval {
+    min: Duration,
+    max: Duration,
+    current: Duration,
+}
+
+impl AdaptivePollInterval {
+    pub fn new(min: Duration, max: Duration) -> Self {
+        Self {
+            min,
+            max: max.max(min),
+            current: min,
+        }
+    }
+
+    /// How long to wait before the next poll.
+    pub fn current(&self) -> Duration {
+        self.current
+    }
+
+    /// Records whether the last poll produced samples and returns the next interval.
+    pub fn record(&mut self, got_samples: bool) -> Duration {
+        self.current = if got_samples {
+            self.min
+        } else {
+            (self.current * 2).min(self.max)
+        };
+        self.current
+    }
 }
 
 /// Mixes two audio buffers together
 ///
+/// Both are brought to mono at the microphone's sample rate first, so sources
+/// captured in different formats line up. If buffers have different lengths,
+/// the shorter one is padded with zeros
+pub fn mix_buffers(mic: &AudioBuffer, system: &AudioBuffer) -> AudioBuffer {
+    let sample_rate = mic.sample_rate;
+    let mic = mic.clone().to_mono_at(sample_rate);
+    let system = system.clone().to_mono_at(sample_rate);
+    AudioBuffer::new(mix_audio(&mic.samples, &system.samples), sample_rate, 1)
+}
+
+/// Mixes two mono sample slices that share a sample rate
+///
+/// Thin adapter over raw samples for callers that already know both formats match.
 /// If buffers have different lengths, the shorter one is padded with zeros
 pub fn mix_audio(mic_samples: &[f32], system_samples: &[f32]) -> Vec<f32> {
     let max_len = mic_samples.len().max(system_samples.len());
@@ -285,14 +677,18 @@ pub fn mix_audio(mic_samples: &[f32], system_samples: &[f32]) -> Vec<f32> {
 
 /// Resamples audio from one sample rate to another
 ///
-/// Uses linear interpolation for simplicity
+/// Uses linear interpolation for simplicity. Empty input gives empty output,
+/// and a single sample is repeated for the resampled length.
 pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
-    if from_rate == to_rate {
+    if from_rate == to_rate || samples.is_empty() {
         return samples.to_vec();
     }
 
     let ratio = to_rate as f64 / from_rate as f64;
     let new_len = (samples.len() as f64 * ratio).ceil() as usize;
+    if samples.len() == 1 {
+        return vec![samples[0]; new_len];
+    }
     let mut resampled = Vec::with_capacity(new_len);
 
     for i in 0..new_len {
@@ -330,6 +726,17 @@ mod tests {
         assert!((mixed[2] - 0.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_mix_buffers_aligns_sample_rates() {
+        let mic = AudioBuffer::mono_16k(vec![0.5; 16]);
+        let sys = AudioBuffer::new(vec![0.5; 16], 8000, 2);
+        let mixed = mix_buffers(&mic, &sys);
+        assert_eq!(mixed.sample_rate, 16000);
+        assert_eq!(mixed.channels, 1);
+        assert_eq!(mixed.len(), 16);
+        assert!(mixed.samples.iter().all(|s| (s - 0.5).abs() < 0.001));
+    }
+
     #[test]
     fn test_mix_audio_different_lengths() {
         let mic = vec![0.5, -0.5];
@@ -338,6 +745,25 @@ mod tests {
         assert_eq!(mixed.len(), 4);
     }
 
+    #[test]
+    fn test_adaptive_poll_backs_off_when_silent_and_resets_on_samples() {
+        let min = Duration::from_millis(10);
+        let max = Duration::from_millis(80);
+        let mut poll = AdaptivePollInterval::new(min, max);
+        assert_eq!(poll.current(), min);
+
+        // Silent stretch: interval grows, but never past the cap
+        let backoff: Vec<Duration> = (0..5).map(|_| poll.record(false)).collect();
+        assert_eq!(
+            backoff,
+            [20, 40, 80, 80, 80].map(Duration::from_millis).to_vec()
+        );
+
+        // Audio resumes: back to the fastest interval immediately
+        assert_eq!(poll.record(true), min);
+        assert_eq!(poll.record(false), Duration::from_millis(20));
+    }
+
     #[test]
     fn test_resample_same_rate() {
         let samples = vec![1.0, 2.0, 3.0];
@@ -345,10 +771,58 @@ mod tests {
         assert_eq!(resampled, samples);
     }
 
+    #[test]
+    fn test_parse_major_version() {
+        assert_eq!(parse_major_version("14.2.1\n"), Some(14));
+        assert_eq!(parse_major_version("13.0"), Some(13));
+        assert_eq!(parse_major_version(""), None);
+        assert_eq!(parse_major_version("beta"), None);
+    }
+
+    #[test]
+    fn test_lists_monitor_source() {
+        let sources = "0\talsa_output.analog-stereo.monitor\tPipeWire\tSUSPENDED\n\
+                       1\talsa_input.analog-stereo\tPipeWire\tRUNNING\n";
+        assert!(lists_monitor_source(sources));
+        assert!(!lists_monitor_source(
+            "1\talsa_input.analog-stereo\tPipeWire\tRUNNING\n"
+        ));
+        assert!(!lists_monitor_source(""));
+    }
+
+    #[test]
+    fn test_decode_f32le_drops_partial_sample() {
+        let mut bytes: Vec<u8> = [0.5f32, -0.25, 1.0]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        bytes.extend([0x00, 0x01]);
+        assert_eq!(decode_f32le(&bytes), vec![0.5, -0.25, 1.0]);
+    }
+
     #[test]
     fn test_resample_upsample() {
         let samples = vec![0.0, 1.0];
         let resampled = resample(&samples, 8000, 16000);
         assert!(resampled.len() >= 3); // Should at least double
     }
+
+    #[test]
+    fn test_resample_empty_input() {
+        assert!(resample(&[], 48000, 16000).is_empty());
+        assert!(resample(&[], 8000, 16000).is_empty());
+    }
+
+    #[test]
+    fn test_resample_single_sample_is_repeated() {
+        assert_eq!(resample(&[0.5], 8000, 16000), vec![0.5, 0.5]);
+        assert_eq!(resample(&[0.5], 48000, 16000), vec![0.5]);
+    }
+
+    #[test]
+    fn test_resample_downsample() {
+        let samples: Vec<f32> = (0..8).map(|i| i as f32).collect();
+        let resampled = resample(&samples, 16000, 8000);
+        assert_eq!(resampled, vec![0.0, 2.0, 4.0, 6.0]);
+    }
 }
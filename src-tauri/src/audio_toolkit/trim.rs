@@ -0,0 +1,102 @@
+//! Keeping only some regions of 16 kHz mono audio, and mapping times in the
+//! kept audio back onto the original.
+//!
+//! Shared by every step that drops audio before transcription (silence
+//! trimming in preprocessing, VAD trimming), so segment times can always be
+//! moved back onto the recording.
+
+use std::ops::Range;
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+
+/// Widens each frame of speech by `padding` samples on both sides, clamped to
+/// `len`, and merges regions whose padding touches or overlaps.
+///
+/// `speech_frames` must be in order. The gap between two close stretches of
+/// speech is then kept once rather than cut or repeated.
+pub fn padded_regions<I>(speech_frames: I, padding: usize, len: usize) -> Vec<Range<usize>>
+where
+    I: IntoIterator<Item = Range<usize>>,
+{
+    let mut regions: Vec<Range<usize>> = Vec::new();
+    for frame in speech_frames {
+        let start = frame.start.saturating_sub(padding);
+        let end = (frame.end + padding).min(len);
+        match regions.last_mut() {
+            Some(last) if start <= last.end => last.end = last.end.max(end),
+            _ => regions.push(start..end),
+        }
+    }
+    regions
+}
+
+/// A run of kept samples, at its position in the original and the trimmed audio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct KeptRegion {
+    original_start: usize,
+    trimmed_start: usize,
+    len: usize,
+}
+
+/// Where the pieces of trimmed audio came from in the original.
+///
+/// Several trims of the same audio are chained with [`TrimMap::then`]; the
+/// default map leaves times unchanged.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrimMap {
+    /// Kept regions of each trim, in the order the trims were applied
+    trims: Vec<Vec<KeptRegion>>,
+}
+
+impl TrimMap {
+    /// Returns true if no audio was dropped.
+    pub fn is_identity(&self) -> bool {
+        self.trims.is_empty()
+    }
+
+    /// Chains a trim applied to the output of this one.
+    pub fn then(mut self, later: TrimMap) -> TrimMap {
+        self.trims.extend(later.trims);
+        self
+    }
+
+    /// Maps a time in the trimmed audio back to the original, in seconds.
+    pub fn original_secs(&self, secs: f32) -> f32 {
+        if self.is_identity() {
+            return secs;
+        }
+        let mut position = (secs.max(0.0) * WHISPER_SAMPLE_RATE as f32) as usize;
+        for regions in self.trims.iter().rev() {
+            if let Some(region) = regions
+                .iter()
+                .rev()
+                .find(|region| region.trimmed_start <= position)
+            {
+                position =
+                    region.original_start + (position - region.trimmed_start).min(region.len);
+            }
+        }
+        position as f32 / WHISPER_SAMPLE_RATE as f32
+    }
+}
+
+/// Keeps only `regions` of `samples`, in order.
+///
+/// # Returns
+/// The kept audio and the map back onto `samples`
+pub fn keep_regions(samples: &[f32], regions: &[Range<usize>]) -> (Vec<f32>, TrimMap) {
+    let mut kept = Vec::with_capacity(regions.iter().map(|region| region.len()).sum());
+    let mut kept_regions = Vec::with_capacity(regions.len());
+    for region in regions {
+        kept_regions.push(KeptRegion {
+            original_start: region.start,
+            trimmed_start: kept.len(),
+            len: region.len(),
+        });
+        kept.extend_from_slice(&samples[region.clone()]);
+    }
+    let map = TrimMap {
+        trims: vec![kept_regions],
+    };
+    (kept, map)
+}
@@ -1,10 +1,11 @@
 use crate::audio_feedback;
-use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
+use crate::audio_toolkit::audio::{list_input_devices, list_output_devices, CpalDeviceInfo};
 use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
 use crate::settings::{get_settings, write_settings};
 use log::warn;
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 
@@ -39,6 +40,16 @@ pub struct AudioDevice {
     pub is_default: bool,
 }
 
+impl From<&CpalDeviceInfo> for AudioDevice {
+    fn from(device: &CpalDeviceInfo) -> Self {
+        Self {
+            index: device.index.clone(),
+            name: device.name.clone(),
+            is_default: device.is_default,
+        }
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), String> {
@@ -200,3 +211,54 @@ pub fn is_recording(app: AppHandle) -> bool {
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     audio_manager.is_recording()
 }
+
+/// Returns whether this platform can capture system audio, independent of
+/// permission state. Used to decide whether to offer system-audio sources.
+#[tauri::command]
+#[specta::specta]
+pub fn system_audio_supported() -> bool {
+    crate::audio_toolkit::system_audio_supported()
+}
+
+/// Returns whether the app may record from the microphone. Always true outside macOS.
+#[tauri::command]
+#[specta::specta]
+pub fn has_microphone_permission() -> bool {
+    crate::audio_toolkit::has_microphone_permission()
+}
+
+/// Asks for microphone access, showing the macOS prompt if the user hasn't been
+/// asked yet. Returns true only if access is already granted.
+#[tauri::command]
+#[specta::specta]
+pub fn request_microphone_permission() -> bool {
+    crate::audio_toolkit::request_microphone_permission()
+}
+
+/// Writes an in-memory mono buffer to `dest` as a 16-bit WAV file.
+///
+/// Rejects empty or overlong buffers, unsupported sample rates, and
+/// destinations whose folder doesn't exist or can't be written.
+#[tauri::command]
+#[specta::specta]
+pub async fn save_samples_as_wav(
+    samples: Vec<f32>,
+    sample_rate: u32,
+    dest: String,
+) -> Result<(), String> {
+    crate::audio_toolkit::validate_wav_samples(samples.len(), sample_rate)
+        .map_err(|e| e.to_string())?;
+
+    let dest = PathBuf::from(dest);
+    if dest.is_dir() {
+        return Err(format!("Destination {:?} is a folder, not a file", dest));
+    }
+    match dest.parent() {
+        Some(parent) if parent.as_os_str().is_empty() || parent.is_dir() => {}
+        _ => return Err(format!("Destination folder for {:?} does not exist", dest)),
+    }
+
+    crate::audio_toolkit::save_wav_file_at_rate(&dest, &samples, sample_rate)
+        .await
+        .map_err(|e| format!("Failed to write WAV to {:?}: {}", dest, e))
+}
@@ -1,66 +1,23 @@
+use crate::commands::audio::AudioDevice;
 use crate::managers::meeting::{
-    AudioSourceType, MeetingSession, MeetingSessionManager, MeetingStatus,
+    find_template, recording_options, render_title, require_template, resolve_audio_source,
+    resolve_meeting_config, AudioSourceType, CancelOutcome, DayGroup, ExportFormat,
+    ExportFormatInfo, FileJobKind, FileJobStatus, IntegrityReport, MeetingSearchResult,
+    MeetingSession, MeetingSessionManager, MeetingStatus, RecordingOptions, ResetSummary,
+    ResolvedMeetingConfig, RetryComparison, RetryKeepPolicy, Segment, SessionDetail, SessionLite,
+    SessionPatch, SuspendSummary, TextDirection, ToggleAction, TranscriptionQueueSnapshot,
+    MEETING_CATEGORIES, MEETING_DB_EXPECTED_VERSION,
 };
+use crate::managers::meeting_logger::ErrorEntry;
 use crate::settings::get_settings;
 use log::{debug, info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashMap;
 use std::path::{Component, Path};
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager};
 
-/// Maximum transcript size in bytes (1MB) to prevent OOM and LLM context overflow
-const MAX_TRANSCRIPT_SIZE: u64 = 1024 * 1024;
-
-/// Interpolates a title template with current date/time placeholders.
-///
-/// Supported placeholders:
-/// - `{date}` - Replaced with current date in YYYY-MM-DD format
-/// - `{time}` - Replaced with current time in HH:MM format
-///
-/// # Arguments
-/// * `template` - The title template string
-///
-/// # Returns
-/// The interpolated title string
-fn interpolate_title_template(template: &str) -> String {
-    let now = chrono::Local::now();
-    template
-        .replace("{date}", &now.format("%Y-%m-%d").to_string())
-        .replace("{time}", &now.format("%H:%M").to_string())
-}
-
-/// Builds the default summary prompt for meetings without a custom template.
-///
-/// This is the standard prompt used when no template-specific prompt is configured.
-///
-/// # Arguments
-/// * `transcript` - The meeting transcript to summarize
-///
-/// # Returns
-/// The formatted prompt string ready for LLM consumption
-fn build_default_summary_prompt(transcript: &str) -> String {
-    format!(
-        r#"Please summarize this meeting transcript concisely. Structure your response with:
-
-## Key Points
-- Main topics and discussions
-
-## Action Items
-- Tasks assigned with owners (if mentioned)
-
-## Decisions Made
-- Important decisions reached
-
-## Next Steps
-- Follow-up actions needed
-
-Transcript:
-{}
-
-Provide a clear, professional summary in markdown format."#,
-        transcript
-    )
-}
-
 /// Validates that a relative path is safe and doesn't escape the base directory.
 /// Prevents path traversal attacks (e.g., "../../../etc/passwd").
 ///
@@ -124,24 +81,6 @@ fn validate_safe_path(base_dir: &Path, relative_path: &str) -> Result<std::path:
     Ok(full_path)
 }
 
-/// Validates a path for writing. Same as validate_safe_path but with additional
-/// checks to ensure the target directory exists and is writable.
-fn validate_safe_write_path(
-    base_dir: &Path,
-    relative_path: &str,
-) -> Result<std::path::PathBuf, String> {
-    let full_path = validate_safe_path(base_dir, relative_path)?;
-
-    // Ensure parent directory exists for write operations
-    if let Some(parent) = full_path.parent() {
-        if !parent.exists() {
-            return Err(format!("Parent directory does not exist: {:?}", parent));
-        }
-    }
-
-    Ok(full_path)
-}
-
 /// Starts a new meeting session recording.
 ///
 /// This command:
@@ -154,7 +93,14 @@ fn validate_safe_write_path(
 /// # Arguments
 /// * `audio_source` - The audio source configuration (microphone_only, system_only, or mixed)
 ///                    If None and template_id is provided, uses template's audio_source
-/// * `template_id` - Optional ID of a meeting template to use for this session
+/// * `template_id` - Optional ID of a meeting template to use for this session.
+///                   If None, the active template (if any) is used
+/// * `idempotency_key` - Optional key identifying this request. A retry with the
+///                       same key within a minute returns the session the first
+///                       request created instead of starting another one
+/// * `device_name` - Name of the microphone to record from, as listed by
+///                   `list_meeting_input_devices`. If None or not connected,
+///                   the default input device is used
 ///
 /// # Returns
 /// * `Ok(MeetingSession)` - The newly created and active session
@@ -165,50 +111,91 @@ pub fn start_meeting_session(
     app: AppHandle,
     audio_source: Option<AudioSourceType>,
     template_id: Option<String>,
+    idempotency_key: Option<String>,
+    device_name: Option<String>,
 ) -> Result<MeetingSession, String> {
     info!(
-        "start_meeting_session command called with template_id: {:?}, audio_source: {:?}",
-        template_id, audio_source
+        "start_meeting_session command called with template_id: {:?}, audio_source: {:?}, device: {:?}",
+        template_id, audio_source, device_name
     );
 
-    // Load template if template_id is provided
-    let template = if let Some(tid) = template_id.as_ref() {
-        let settings = get_settings(&app);
-        settings
-            .meeting_templates
-            .iter()
-            .find(|t| &t.id == tid)
-            .cloned()
-    } else {
-        None
-    };
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager.start_with_idempotency_key(idempotency_key.as_deref(), || {
+        start_new_meeting_session(&app, audio_source, template_id, device_name)
+    })
+}
+
+/// Starts a meeting session from a specific template.
+///
+/// Records with the template's audio source, titles the session from its
+/// `title_template`, and stores the template id on the session, like
+/// `start_meeting_session` with that template. Unlike it, an unknown id is an
+/// error rather than falling back to no template.
+///
+/// # Arguments
+/// * `template_id` - ID of the meeting template to start from
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The newly created session with Recording status
+/// * `Err(String)` - If the template doesn't exist or recording can't start
+#[tauri::command]
+#[specta::specta]
+pub fn start_meeting_from_template(
+    app: AppHandle,
+    template_id: String,
+) -> Result<MeetingSession, String> {
+    info!(
+        "start_meeting_from_template command called with template_id: {}",
+        template_id
+    );
+
+    require_template(&get_settings(&app), &template_id)?;
+    start_new_meeting_session(&app, None, Some(template_id), None)
+}
+
+/// Creates and starts a session for `start_meeting_session`, applying the template.
+fn start_new_meeting_session(
+    app: &AppHandle,
+    audio_source: Option<AudioSourceType>,
+    template_id: Option<String>,
+    device_name: Option<String>,
+) -> Result<MeetingSession, String> {
+    // Load template if template_id is provided, otherwise fall back to the active template
+    let settings = get_settings(app);
+    let template = find_template(&settings, template_id.as_deref());
 
     // Determine audio source: use explicit parameter, then template, then default
-    let source = audio_source.or_else(|| {
-        template.as_ref().and_then(|t| {
-            match t.audio_source.as_str() {
-                "microphone_only" => Some(AudioSourceType::MicrophoneOnly),
-                "system_only" => Some(AudioSourceType::SystemOnly),
-                "mixed" => Some(AudioSourceType::Mixed),
-                _ => None,
-            }
-        })
-    }).unwrap_or_default();
+    let source = resolve_audio_source(audio_source, template.as_ref());
 
     debug!("Using audio source: {:?}", source);
 
+    let options = RecordingOptions {
+        input_device: device_name,
+        ..recording_options(template.as_ref())
+    };
+
     let manager = app.state::<Arc<MeetingSessionManager>>();
+
+    // Rendered before the recording starts, so nothing here can fail once it runs
+    let generated_title = template.as_ref().map(|template| {
+        let number = match manager.next_template_number(&template.id) {
+            Ok(number) => Some(number),
+            Err(e) => {
+                warn!("Failed to number template session, leaving it out: {}", e);
+                None
+            }
+        };
+        render_title(template, chrono::Local::now(), number)
+    });
+
     let mut session = manager
-        .start_recording(source)
+        .start_recording_with_options(source, options)
         .map_err(|e| format!("Failed to start meeting session: {}", e))?;
 
     // Apply template settings if available
-    if let Some(template) = template {
+    if let (Some(template), Some(generated_title)) = (template, generated_title) {
         debug!("Applying template '{}' to session {}", template.name, session.id);
 
-        // Generate title from template
-        let generated_title = interpolate_title_template(&template.title_template);
-
         // Update session title (this will update in database)
         manager
             .update_session_title(&session.id, &generated_title)
@@ -234,29 +221,167 @@ pub fn start_meeting_session(
     Ok(session)
 }
 
+/// Starts a meeting recording if none is active, or stops the current one.
+///
+/// For binding one hotkey to start/stop. Starting uses the template's audio
+/// source like `start_meeting_session`; stopping follows the auto-transcribe
+/// setting.
+///
+/// # Arguments
+/// * `template_id` - Template for a recording this call starts; if None, the
+///   active template (if any) is used
+///
+/// # Returns
+/// * `Ok(ToggleAction)` - `started` or `stopped`
+/// * `Err(String)` - If starting or stopping fails
+#[tauri::command]
+#[specta::specta]
+pub fn toggle_meeting_recording(
+    app: AppHandle,
+    template_id: Option<String>,
+) -> Result<ToggleAction, String> {
+    info!(
+        "toggle_meeting_recording command called with template_id: {:?}",
+        template_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .toggle_recording(|| {
+            start_new_meeting_session(&app, None, template_id, None)
+                .map(|_| ())
+                .map_err(|e| anyhow::anyhow!(e))
+        })
+        .map_err(|e| format!("Failed to toggle meeting recording: {}", e))
+}
+
+/// Resolves the configuration a `start_meeting_session` with the same inputs
+/// would record with, without recording.
+///
+/// Used for the confirmation shown before recording starts.
+///
+/// # Arguments
+/// * `template_id` - Optional template; if None, the active template (if any) is used
+///
+/// # Returns
+/// * `Ok(ResolvedMeetingConfig)` - Source, device, audio format, language,
+///   preprocessing, and summary behavior the recording would use
+#[tauri::command]
+#[specta::specta]
+pub fn preview_recording_config(
+    app: AppHandle,
+    template_id: Option<String>,
+) -> Result<ResolvedMeetingConfig, String> {
+    debug!(
+        "preview_recording_config command called with template_id: {:?}",
+        template_id
+    );
+
+    let settings = get_settings(&app);
+    let device = crate::audio_toolkit::default_input_device().map(|d| AudioDevice::from(&d));
+    Ok(resolve_meeting_config(
+        &settings,
+        None,
+        template_id.as_deref(),
+        device,
+    ))
+}
+
 /// Stops the current meeting session recording.
 ///
 /// This command:
-/// 1. Validates current session is in Recording state
+/// 1. Validates current session is in Recording or Paused state
 /// 2. Stops audio capture
 /// 3. Finalizes WAV file
-/// 4. Updates session status to Processing
-/// 5. Spawns background transcription task
+/// 4. Updates session status to Processing, or Recorded when not transcribing
+/// 5. Spawns background transcription task, unless auto-transcribe is off
+///
+/// # Arguments
+/// * `auto_transcribe` - Overrides the auto-transcribe setting for this stop
 ///
 /// # Returns
 /// * `Ok(String)` - The relative path to the audio file (e.g., "{session-id}/audio.wav")
 /// * `Err(String)` - If no recording is active or stopping fails
 #[tauri::command]
 #[specta::specta]
-pub fn stop_meeting_session(app: AppHandle) -> Result<String, String> {
-    info!("stop_meeting_session command called");
+pub fn stop_meeting_session(
+    app: AppHandle,
+    auto_transcribe: Option<bool>,
+) -> Result<String, String> {
+    info!(
+        "stop_meeting_session command called (auto_transcribe: {:?})",
+        auto_transcribe
+    );
 
     let manager = app.state::<Arc<MeetingSessionManager>>();
     manager
-        .stop_recording()
+        .stop_recording_with_options(auto_transcribe)
         .map_err(|e| format!("Failed to stop meeting session: {}", e))
 }
 
+/// Pauses the current meeting recording.
+///
+/// Audio captured while paused is dropped, so it isn't part of the recording
+/// or its duration.
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The session, now Paused
+/// * `Err(String)` - If no recording is active
+#[tauri::command]
+#[specta::specta]
+pub fn pause_meeting_recording(app: AppHandle) -> Result<MeetingSession, String> {
+    info!("pause_meeting_recording command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .pause_recording()
+        .map_err(|e| format!("Failed to pause meeting recording: {}", e))
+}
+
+/// Resumes a paused meeting recording into the same audio file.
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The session, Recording again
+/// * `Err(String)` - If no recording is paused
+#[tauri::command]
+#[specta::specta]
+pub fn resume_meeting_recording(app: AppHandle) -> Result<MeetingSession, String> {
+    info!("resume_meeting_recording command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .resume_recording()
+        .map_err(|e| format!("Failed to resume meeting recording: {}", e))
+}
+
+/// Starts transcribing a session that was stopped without auto-transcribe.
+///
+/// # Arguments
+/// * `session_id` - The unique ID of a session in Recorded status
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The session, now in Processing
+/// * `Err(String)` - If the session is not found or not in Recorded status
+#[tauri::command]
+#[specta::specta]
+pub fn transcribe_meeting_session(
+    app: AppHandle,
+    session_id: String,
+) -> Result<MeetingSession, String> {
+    info!(
+        "transcribe_meeting_session command called for session: {}",
+        session_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    let session = manager
+        .transcribe_recorded_session(&session_id)
+        .map_err(|e| format!("Failed to start transcription: {}", e))?;
+
+    let _ = app.emit("meeting_processing", &session);
+    Ok(session)
+}
+
 /// Gets the current meeting status.
 ///
 /// Returns the status of the currently active session, if any.
@@ -303,6 +428,39 @@ pub fn get_current_meeting(app: AppHandle) -> Result<Option<MeetingSession>, Str
         .map_err(|e| format!("Failed to get current meeting: {}", e))
 }
 
+/// Gets the input device the active meeting is recording from.
+///
+/// # Returns
+/// * `Ok(Some(AudioDevice))` - The device opened when recording started
+/// * `Ok(None)` - If nothing is recording, or only system audio is captured
+#[tauri::command]
+#[specta::specta]
+pub fn get_active_recording_device(app: AppHandle) -> Result<Option<AudioDevice>, String> {
+    debug!("get_active_recording_device command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    Ok(manager.active_recording_device())
+}
+
+/// Lists the microphones a meeting can record from, for the device picker.
+///
+/// Pass a device's `name` to `start_meeting_session` as `device_name` to
+/// record from it.
+///
+/// # Returns
+/// * `Ok(Vec<AudioDevice>)` - The connected input devices, with the system
+///   default marked
+/// * `Err(String)` - If the devices can't be listed
+#[tauri::command]
+#[specta::specta]
+pub fn list_meeting_input_devices() -> Result<Vec<AudioDevice>, String> {
+    debug!("list_meeting_input_devices command called");
+
+    let devices = crate::audio_toolkit::list_input_devices()
+        .map_err(|e| format!("Failed to list input devices: {}", e))?;
+    Ok(devices.iter().map(AudioDevice::from).collect())
+}
+
 /// Updates the title of a meeting session.
 ///
 /// Updates the title in the database. The title can be edited at any time
@@ -340,22 +498,73 @@ pub fn update_meeting_title(
         .map_err(|e| format!("Failed to update meeting title: {}", e))
 }
 
+/// Updates title, notes, category, and starred state of a session in one call.
+///
+/// Present fields are validated like their single-field commands and written
+/// together in one transaction; absent fields are left untouched. Emits a
+/// single `meeting_session_updated` event with the updated session.
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the session to update
+/// * `patch` - The fields to change; an empty `category` or `notes` clears it
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The session with the changes applied
+/// * `Err(String)` - If a field is invalid, the session is not found, or the update fails
+#[tauri::command]
+#[specta::specta]
+pub fn update_session_fields(
+    app: AppHandle,
+    session_id: String,
+    patch: SessionPatch,
+) -> Result<MeetingSession, String> {
+    info!(
+        "update_session_fields command called for session {}: {:?}",
+        session_id, patch
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+
+    if patch.is_empty() {
+        return manager
+            .get_session(&session_id)
+            .map_err(|e| format!("Failed to get meeting session: {}", e))?
+            .ok_or_else(|| format!("Session not found: {}", session_id));
+    }
+
+    let session = manager
+        .update_session_fields(&session_id, &patch)
+        .map_err(|e| format!("Failed to update meeting session: {}", e))?;
+
+    if let Err(e) = app.emit("meeting_session_updated", &session) {
+        warn!("Failed to emit meeting_session_updated event: {}", e);
+    }
+
+    Ok(session)
+}
+
 /// Retries transcription for a failed meeting session.
 ///
 /// This command:
 /// 1. Validates the session exists and is in Failed status
 /// 2. Updates status to Processing
-/// 3. Spawns background transcription task
+/// 3. Queues background transcription
 ///
 /// # Arguments
 /// * `session_id` - The unique ID of the session to retry
+/// * `keep_policy` - Which transcript to keep if the session already has one;
+///   defaults to overwriting it
 ///
 /// # Returns
 /// * `Ok(())` - If retry was initiated successfully
 /// * `Err(String)` - If session not found, not in Failed status, or retry fails
 #[tauri::command]
 #[specta::specta]
-pub fn retry_transcription(app: AppHandle, session_id: String) -> Result<(), String> {
+pub fn retry_transcription(
+    app: AppHandle,
+    session_id: String,
+    keep_policy: Option<RetryKeepPolicy>,
+) -> Result<(), String> {
     info!(
         "retry_transcription command called for session: {}",
         session_id
@@ -386,74 +595,134 @@ pub fn retry_transcription(app: AppHandle, session_id: String) -> Result<(), Str
     let audio_path = manager
         .retry_transcription_for_session(&session_id)
         .map_err(|e| format!("Failed to prepare retry: {}", e))?;
+    debug!("Retrying transcription for audio: {}", audio_path);
 
     // Emit processing event
     let _ = app.emit("meeting_processing", &session);
 
-    // Spawn background transcription task
-    let manager_clone = Arc::clone(&manager);
-    let session_id_clone = session_id.clone();
-    let audio_path_clone = audio_path.clone();
-    let app_clone = app.clone();
-
-    std::thread::spawn(move || {
-        match manager_clone.process_transcription(&audio_path_clone) {
-            Ok(transcript) => {
-                // Save transcript and update status to Completed
-                if let Err(e) = manager_clone.save_transcript(&session_id_clone, &transcript) {
-                    // Failed to save transcript
-                    let error_msg = format!("Failed to save transcript: {}", e);
-                    let _ = manager_clone.update_session_status_with_error(
-                        &session_id_clone,
-                        MeetingStatus::Failed,
-                        &error_msg,
-                    );
-
-                    // Update in-memory state
-                    manager_clone.set_session_error(&session_id_clone, &error_msg);
-
-                    // Emit failed event
-                    if let Some(updated_session) =
-                        manager_clone.get_session(&session_id_clone).ok().flatten()
-                    {
-                        let _ = app_clone.emit("meeting_failed", &updated_session);
-                    }
-                } else {
-                    // Success - emit completed event
-                    if let Some(updated_session) =
-                        manager_clone.get_session(&session_id_clone).ok().flatten()
-                    {
-                        let _ = app_clone.emit("meeting_completed", &updated_session);
-                    }
-                }
-            }
-            Err(e) => {
-                // Transcription failed
-                let error_msg = format!("Transcription failed: {}", e);
-                let _ = manager_clone.update_session_status_with_error(
-                    &session_id_clone,
-                    MeetingStatus::Failed,
-                    &error_msg,
-                );
-
-                // Update in-memory state
-                manager_clone.set_session_error(&session_id_clone, &error_msg);
-
-                // Emit failed event
-                if let Some(updated_session) =
-                    manager_clone.get_session(&session_id_clone).ok().flatten()
-                {
-                    let _ = app_clone.emit("meeting_failed", &updated_session);
-                }
-            }
-        }
-    });
+    if let Some(policy) = keep_policy {
+        manager.set_retry_keep_policy(&session_id, policy);
+    }
+
+    // Queue background transcription
+    manager.enqueue_transcription(&session_id);
 
     info!("Retry transcription initiated for session: {}", session_id);
 
     Ok(())
 }
 
+/// Returns the stored and the new transcript of a session's last retry with a
+/// keep policy, for showing a diff of the two.
+///
+/// # Arguments
+/// * `session_id` - The session that was retried
+///
+/// # Returns
+/// * `Ok(Some(RetryComparison))` - Both transcripts and which one was kept
+/// * `Ok(None)` - If the session had no such retry since the app started
+#[tauri::command]
+#[specta::specta]
+pub fn get_retry_comparison(
+    app: AppHandle,
+    session_id: String,
+) -> Result<Option<RetryComparison>, String> {
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    Ok(manager.get_retry_comparison(&session_id))
+}
+
+/// Queues a finished session to re-run the full processing pipeline with current settings.
+///
+/// Unlike `retry_transcription`, this is meant for sessions that already have
+/// results: the transcript is regenerated with the current model and custom
+/// words, and the summary is regenerated with the current template if the
+/// session had one. Existing outputs are overwritten, so `confirm` must be true.
+/// Progress is reported through the usual queue and lifecycle events.
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the session to reprocess
+/// * `confirm` - Must be true to acknowledge that outputs will be overwritten
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The session, now queued as Processing
+/// * `Err(String)` - If not confirmed, the session is not Completed/Failed,
+///   has no audio, or is already queued
+#[tauri::command]
+#[specta::specta]
+pub fn reprocess_session(
+    app: AppHandle,
+    session_id: String,
+    confirm: bool,
+) -> Result<MeetingSession, String> {
+    info!(
+        "reprocess_session command called for session: {} (confirm: {})",
+        session_id, confirm
+    );
+
+    if !confirm {
+        return Err(
+            "Reprocessing overwrites the existing transcript and summary; pass confirm=true to proceed"
+                .to_string(),
+        );
+    }
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    let session = manager
+        .reprocess_session(&session_id)
+        .map_err(|e| format!("Failed to reprocess session: {}", e))?;
+
+    let _ = app.emit("meeting_processing", &session);
+    Ok(session)
+}
+
+/// Cancels a transcription that is queued but has not started yet.
+///
+/// The session is removed from the queue and marked Failed with a
+/// "cancelled" message, so it can be retried later.
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the queued session
+///
+/// # Returns
+/// * `Ok(())` - If the session was removed from the queue
+/// * `Err(String)` - If the session is already being transcribed or is not queued
+#[tauri::command]
+#[specta::specta]
+pub fn cancel_queued_transcription(app: AppHandle, session_id: String) -> Result<(), String> {
+    info!(
+        "cancel_queued_transcription command called for session: {}",
+        session_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    let outcome = manager
+        .cancel_queued_transcription(&session_id)
+        .map_err(|e| format!("Failed to cancel queued transcription: {}", e))?;
+
+    match outcome {
+        CancelOutcome::Removed => Ok(()),
+        CancelOutcome::Running => Err(format!(
+            "Transcription for session {} is already running and can no longer be dequeued; cancel the running transcription instead",
+            session_id
+        )),
+        CancelOutcome::NotQueued => Err(format!(
+            "Session {} is not waiting in the transcription queue",
+            session_id
+        )),
+    }
+}
+
+/// Gets the sessions currently queued for transcription.
+///
+/// # Returns
+/// The running session (if any) and the pending sessions in order
+#[tauri::command]
+#[specta::specta]
+pub fn get_transcription_queue(app: AppHandle) -> TranscriptionQueueSnapshot {
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager.get_transcription_queue()
+}
+
 /// Gets the transcript text content for a completed meeting session.
 ///
 /// Reads the transcript file from disk and returns its content.
@@ -504,295 +773,904 @@ pub fn get_meeting_transcript(
     Ok(Some(content))
 }
 
-/// Lists all meeting sessions.
-///
-/// Returns all meeting sessions from the database, ordered by creation time
-/// (newest first).
+/// Gets the timed segments of a session's transcript, for jumping to a moment
+/// in the audio.
 ///
 /// # Returns
-/// * `Ok(Vec<MeetingSession>)` - All meeting sessions
-/// * `Err(String)` - If database query fails
+/// * `Ok(Vec<Segment>)` - The segments in order; empty if the session has no
+///   timed transcript
+/// * `Err(String)` - If the session doesn't exist or the segments can't be read
 #[tauri::command]
 #[specta::specta]
-pub fn list_meeting_sessions(app: AppHandle) -> Result<Vec<MeetingSession>, String> {
-    info!("list_meeting_sessions command called");
-
-    let manager = app.state::<Arc<MeetingSessionManager>>();
+pub fn get_transcript_segments(app: AppHandle, session_id: String) -> Result<Vec<Segment>, String> {
+    debug!(
+        "get_transcript_segments command called for session: {}",
+        session_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_transcript_segments(&session_id)
+        .map_err(|e| format!("Failed to get transcript segments: {}", e))
+}
+
+/// Gets only a session's id, title, status, and error message.
+///
+/// Meant for polling while a transcription runs; use `get_meeting_detail`
+/// for the full record.
+///
+/// # Returns
+/// * `Ok(Some(SessionLite))` - The session's status fields
+/// * `Ok(None)` - If the session doesn't exist
+/// * `Err(String)` - If the lookup fails
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_status_lite(
+    app: AppHandle,
+    session_id: String,
+) -> Result<Option<SessionLite>, String> {
+    debug!("get_meeting_status_lite command called for session: {}", session_id);
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_session_lite(&session_id)
+        .map_err(|e| format!("Failed to get meeting status: {}", e))
+}
+
+/// Gets a session with its audio path, transcript, summary, and stats in one call.
+///
+/// Used by the meeting detail view to avoid several round-trips. Parts that
+/// don't exist yet are returned as null.
+///
+/// # Returns
+/// * `Ok(Some(SessionDetail))` - The session detail
+/// * `Ok(None)` - If the session doesn't exist
+/// * `Err(String)` - If the lookup fails
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_detail(
+    app: AppHandle,
+    session_id: String,
+) -> Result<Option<SessionDetail>, String> {
+    info!("get_meeting_detail command called for session: {}", session_id);
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_session_detail(&session_id)
+        .map_err(|e| format!("Failed to get meeting detail: {}", e))
+}
+
+/// Estimates how many more minutes can be recorded with the free disk space left,
+/// so the UI can warn during long meetings. Cheap enough to poll while recording.
+///
+/// # Returns
+/// * `Ok(u64)` - Whole minutes of recording that still fit on disk
+/// * `Err(String)` - If the free space can't be determined
+#[tauri::command]
+#[specta::specta]
+pub fn get_remaining_recording_minutes(app: AppHandle) -> Result<u64, String> {
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .remaining_recording_minutes()
+        .map_err(|e| format!("Failed to estimate remaining recording time: {}", e))
+}
+
+/// Gets the fraction of a session's recording that is speech, so the UI can
+/// flag recordings that are mostly silence.
+///
+/// # Returns
+/// * `Ok(f32)` - Speech ratio from 0.0 (no speech) to 1.0 (all speech)
+/// * `Err(String)` - If the session has no audio or the analysis fails
+#[tauri::command]
+#[specta::specta]
+pub async fn get_speech_ratio(app: AppHandle, session_id: String) -> Result<f32, String> {
+    debug!("get_speech_ratio command called for session: {}", session_id);
+
+    // Loading the VAD and scanning a long recording takes a while
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    tokio::task::spawn_blocking(move || manager.get_speech_ratio(&session_id))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| format!("Failed to compute speech ratio: {}", e))
+}
+
+/// Gets the text direction of a session's transcript so the UI can render
+/// right-to-left languages correctly.
+///
+/// # Returns
+/// * `Ok(Some(TextDirection))` - "ltr" or "rtl"
+/// * `Ok(None)` - If the direction can't be determined yet
+/// * `Err(String)` - If the session is not found or the lookup fails
+#[tauri::command]
+#[specta::specta]
+pub fn get_transcript_direction(
+    app: AppHandle,
+    session_id: String,
+) -> Result<Option<TextDirection>, String> {
+    debug!(
+        "get_transcript_direction command called for session: {}",
+        session_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .transcript_direction(&session_id)
+        .map_err(|e| format!("Failed to get transcript direction: {}", e))
+}
+
+/// Dismisses a session's error message while keeping its status (e.g., Failed).
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the session
+///
+/// # Returns
+/// * `Ok(())` - If the error message was cleared
+/// * `Err(String)` - If the session doesn't exist or the update fails
+#[tauri::command]
+#[specta::specta]
+pub fn clear_meeting_error(app: AppHandle, session_id: String) -> Result<(), String> {
+    info!("clear_meeting_error command called for session: {}", session_id);
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .clear_session_error(&session_id)
+        .map_err(|e| format!("Failed to clear meeting error: {}", e))
+}
+
+/// Stops all in-flight meeting work, for use on user logout or screen lock.
+///
+/// Stops and finalizes an active recording (marked Interrupted so it can be
+/// recovered), and cancels any stop awaiting resume and all queued transcriptions
+/// (marked Failed so they can be retried). Safe to call when nothing is active.
+///
+/// # Returns
+/// * `Ok(SuspendSummary)` - What was interrupted, cancelled, or left running
+#[tauri::command]
+#[specta::specta]
+pub fn suspend_meetings(app: AppHandle) -> Result<SuspendSummary, String> {
+    info!("suspend_meetings command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    let summary = manager.suspend();
+
+    if !summary.is_noop() {
+        if let Err(e) = app.emit("meetings_suspended", &summary) {
+            warn!("Failed to emit meetings_suspended event: {}", e);
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Repairs a session whose status is inconsistent with its files, e.g. stuck in
+/// Processing with no transcription running, or Completed without a transcript.
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the session to repair
+///
+/// # Returns
+/// * `Ok(MeetingSession)` - The session with its repaired status
+/// * `Err(String)` - If the session doesn't exist, is recording, or the update fails
+#[tauri::command]
+#[specta::specta]
+pub fn repair_meeting_session(
+    app: AppHandle,
+    session_id: String,
+) -> Result<MeetingSession, String> {
+    info!("repair_meeting_session command called for session: {}", session_id);
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .refresh_session_status_from_files(&session_id)
+        .map_err(|e| format!("Failed to repair meeting session: {}", e))
+}
+
+/// Recalculates meeting durations from their audio files, fixing sessions
+/// whose duration is missing or wrong.
+///
+/// # Returns
+/// * `Ok(u32)` - How many sessions had their duration corrected
+/// * `Err(String)` - If the database can't be read or updated
+#[tauri::command]
+#[specta::specta]
+pub async fn recompute_meeting_durations(app: AppHandle) -> Result<u32, String> {
+    info!("recompute_meeting_durations command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    tokio::task::spawn_blocking(move || manager.recompute_durations())
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| format!("Failed to recompute meeting durations: {}", e))
+}
+
+/// Lists all meeting sessions.
+///
+/// Returns all meeting sessions from the database, ordered by creation time
+/// (newest first).
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - All meeting sessions
+/// * `Err(String)` - If database query fails
+#[tauri::command]
+#[specta::specta]
+pub fn list_meeting_sessions(app: AppHandle) -> Result<Vec<MeetingSession>, String> {
+    info!("list_meeting_sessions command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
     manager
         .list_sessions()
         .map_err(|e| format!("Failed to list meeting sessions: {}", e))
 }
 
-/// Gets the path to the meetings directory.
+/// Returns the most recent meeting errors and warnings, newest first.
 ///
-/// # Returns
-/// * `Ok(String)` - The absolute path to the meetings directory
-/// * `Err(String)` - If getting the path fails
+/// Entries are kept in memory only, so the support panel can show recent
+/// problems without reading log files. At most `MEETING_ERROR_LOG_CAPACITY`
+/// entries are retained.
+///
+/// # Arguments
+/// * `n` - Maximum number of entries to return
 #[tauri::command]
 #[specta::specta]
-pub fn get_meetings_directory(app: AppHandle) -> Result<String, String> {
-    info!("get_meetings_directory command called");
+pub fn get_recent_meeting_errors(app: AppHandle, n: u32) -> Result<Vec<ErrorEntry>, String> {
+    debug!("get_recent_meeting_errors command called: n={}", n);
 
     let manager = app.state::<Arc<MeetingSessionManager>>();
-    Ok(manager.get_meetings_dir().to_string_lossy().to_string())
+    Ok(manager.recent_errors(n as usize))
 }
 
-/// Deletes a meeting session and its associated files.
+/// Counts meeting sessions per status.
 ///
-/// This command:
-/// 1. Validates the session exists
-/// 2. Deletes the session folder (audio, transcript files)
-/// 3. Removes the session from the database
+/// Every status is included, with zero where no session has it, so the filter
+/// bar can render a badge for each one.
+///
+/// # Returns
+/// * `Ok(HashMap<MeetingStatus, u64>)` - Session count for each status
+/// * `Err(String)` - If database query fails
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_status_counts(app: AppHandle) -> Result<HashMap<MeetingStatus, u64>, String> {
+    info!("get_meeting_status_counts command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_status_counts()
+        .map_err(|e| format!("Failed to count meeting sessions: {}", e))
+}
+
+/// Sets or clears the color category of a meeting session.
 ///
 /// # Arguments
-/// * `session_id` - The unique ID of the session to delete
+/// * `session_id` - The unique ID of the session
+/// * `category` - One of `MEETING_CATEGORIES` (e.g., "blue"), or `None` to clear it
 ///
 /// # Returns
-/// * `Ok(())` - If the session was deleted successfully
-/// * `Err(String)` - If session not found or deletion fails
+/// * `Ok(())` - If the category was updated
+/// * `Err(String)` - If the category is not allowed or the update fails
 #[tauri::command]
 #[specta::specta]
-pub fn delete_meeting_session(app: AppHandle, session_id: String) -> Result<(), String> {
+pub fn set_meeting_category(
+    app: AppHandle,
+    session_id: String,
+    category: Option<String>,
+) -> Result<(), String> {
     info!(
-        "delete_meeting_session command called for session: {}",
-        session_id
+        "set_meeting_category command called for session {}: {:?}",
+        session_id, category
     );
 
     let manager = app.state::<Arc<MeetingSessionManager>>();
     manager
-        .delete_session(&session_id)
-        .map_err(|e| format!("Failed to delete meeting session: {}", e))
+        .set_session_category(&session_id, category.as_deref())
+        .map_err(|e| format!("Failed to set meeting category: {}", e))
 }
 
-/// Generates an AI summary for a meeting session.
+/// Lists meeting sessions with the given color category, newest first.
 ///
-/// This command:
-/// 1. Validates the session exists and has a transcript
-/// 2. Reads the transcript content
-/// 3. Sends it to the configured LLM provider for summarization
-/// 4. Saves the summary to a markdown file
-/// 5. Updates the session with the summary path
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - Sessions in the category
+/// * `Err(String)` - If the category is not allowed or the query fails
+#[tauri::command]
+#[specta::specta]
+pub fn list_meetings_by_category(
+    app: AppHandle,
+    category: String,
+) -> Result<Vec<MeetingSession>, String> {
+    info!("list_meetings_by_category command called: {}", category);
+
+    if !MEETING_CATEGORIES.contains(&category.as_str()) {
+        return Err(format!(
+            "Invalid category '{}' (expected one of: {})",
+            category,
+            MEETING_CATEGORIES.join(", ")
+        ));
+    }
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .list_sessions_by_category(&category)
+        .map_err(|e| format!("Failed to list meeting sessions: {}", e))
+}
+
+/// Lists all meeting sessions grouped by the local calendar day they started on.
+///
+/// # Returns
+/// * `Ok(Vec<DayGroup>)` - Day groups, newest day first, each with its sessions newest first
+/// * `Err(String)` - If database query fails
+#[tauri::command]
+#[specta::specta]
+pub fn list_meetings_by_day(app: AppHandle) -> Result<Vec<DayGroup>, String> {
+    info!("list_meetings_by_day command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .list_sessions_grouped_by_day()
+        .map_err(|e| format!("Failed to list meeting sessions: {}", e))
+}
+
+/// Looks up a meeting session by its audio file path.
 ///
 /// # Arguments
-/// * `session_id` - The unique ID of the session to summarize
+/// * `audio_path` - Audio path relative to the meetings directory (e.g., "{session-id}/audio.wav")
 ///
 /// # Returns
-/// * `Ok(String)` - The generated summary text
-/// * `Err(String)` - If session not found, no transcript, or LLM call fails
+/// * `Ok(Some(MeetingSession))` - The session owning the audio file
+/// * `Ok(None)` - If no session references this path
+/// * `Err(String)` - If database query fails
 #[tauri::command]
 #[specta::specta]
-pub async fn generate_meeting_summary(
+pub fn get_session_by_audio_path(
     app: AppHandle,
-    session_id: String,
-) -> Result<String, String> {
+    audio_path: String,
+) -> Result<Option<MeetingSession>, String> {
     info!(
-        "generate_meeting_summary command called for session: {}",
-        session_id
+        "get_session_by_audio_path command called for path: {}",
+        audio_path
     );
 
     let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_session_by_audio_path(&audio_path)
+        .map_err(|e| format!("Failed to look up session by audio path: {}", e))
+}
 
-    // Get session from database
-    let session = manager
-        .get_session(&session_id)
-        .map_err(|e| format!("Failed to get session: {}", e))?
-        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+/// Checks the meetings database and files for problems, for diagnostics.
+///
+/// Reports database corruption, missing or unreadable files, orphan session
+/// folders, and sessions stuck in a status that can't be right. Changes nothing.
+///
+/// # Returns
+/// * `Ok(IntegrityReport)` - The problems found
+/// * `Err(String)` - If the check could not run
+#[tauri::command]
+#[specta::specta]
+pub fn check_meeting_integrity(app: AppHandle) -> Result<IntegrityReport, String> {
+    info!("check_meeting_integrity command called");
 
-    // Check if transcript exists
-    let transcript_path = session
-        .transcript_path
-        .ok_or_else(|| "No transcript available for this session".to_string())?;
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .run_integrity_check()
+        .map_err(|e| format!("Failed to check meeting integrity: {}", e))
+}
 
-    // Read transcript content with path validation
-    let meetings_dir = manager.get_meetings_dir();
-    let full_transcript_path = validate_safe_path(&meetings_dir, &transcript_path)?;
+/// Lists Completed sessions whose transcript is missing.
+///
+/// These sessions show as done but have nothing to display; the UI offers to
+/// transcribe them again.
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - Affected sessions, newest first
+/// * `Err(String)` - If the database query fails
+#[tauri::command]
+#[specta::specta]
+pub fn list_sessions_missing_transcript(app: AppHandle) -> Result<Vec<MeetingSession>, String> {
+    debug!("list_sessions_missing_transcript command called");
 
-    if !full_transcript_path.exists() {
-        return Err("Transcript file not found".to_string());
-    }
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .list_sessions_missing_transcript()
+        .map_err(|e| format!("Failed to list sessions missing a transcript: {}", e))
+}
+
+/// Re-applies the custom words to the transcripts of all completed sessions.
+///
+/// Meant for after the custom-words list changes. Transcripts are rewritten
+/// only when the correction changes them, and `meeting_transcript_updated` is
+/// emitted for each one.
+///
+/// # Returns
+/// * `Ok(u32)` - How many transcripts were changed
+/// * `Err(String)` - If the sessions can't be listed
+#[tauri::command]
+#[specta::specta]
+pub async fn reapply_custom_words_all(app: AppHandle) -> Result<u32, String> {
+    info!("reapply_custom_words_all command called");
 
-    // Check file size before reading to prevent OOM
-    let metadata = std::fs::metadata(&full_transcript_path)
-        .map_err(|e| format!("Failed to get transcript metadata: {}", e))?;
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    // Reads and rewrites every transcript, so keep it off the async runtime
+    tokio::task::spawn_blocking(move || manager.reapply_custom_words_all())
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| format!("Failed to re-apply custom words: {}", e))
+}
 
-    if metadata.len() > MAX_TRANSCRIPT_SIZE {
-        return Err(format!(
-            "Transcript too large ({} bytes). Maximum allowed: {} bytes",
-            metadata.len(),
-            MAX_TRANSCRIPT_SIZE
-        ));
+/// Searches the transcripts of all sessions for `query`.
+///
+/// Sessions match when their transcript contains every word of the query; the
+/// last word also matches as a prefix, so results can follow the typing.
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSearchResult>)` - Matching sessions with a snippet of the
+///   transcript, best match first
+/// * `Err(String)` - If the search index can't be queried
+#[tauri::command]
+#[specta::specta]
+pub async fn search_meetings(
+    app: AppHandle,
+    query: String,
+) -> Result<Vec<MeetingSearchResult>, String> {
+    debug!("search_meetings command called: {:?}", query);
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .search_meetings(&query)
+        .map_err(|e| format!("Failed to search meetings: {}", e))
+}
+
+/// Rebuilds the transcript search index from the transcript files on disk.
+///
+/// # Returns
+/// * `Ok(u32)` - How many transcripts were indexed
+/// * `Err(String)` - If the index can't be rebuilt
+#[tauri::command]
+#[specta::specta]
+pub async fn reindex_meeting_transcripts(app: AppHandle) -> Result<u32, String> {
+    info!("reindex_meeting_transcripts command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    // Reads every transcript, so keep it off the async runtime
+    tokio::task::spawn_blocking(move || manager.reindex_transcripts())
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| format!("Failed to reindex transcripts: {}", e))
+}
+
+/// Deletes all meeting sessions, their files, and their database rows.
+///
+/// This is the "start fresh" option in the settings danger zone. It only runs
+/// when `confirm` is true, and refuses while a recording or transcription is
+/// active. Emits `meetings_reset` with the summary on success.
+///
+/// # Returns
+/// * `Ok(ResetSummary)` - How much was removed
+/// * `Err(String)` - If not confirmed, something is active, or deleting fails
+#[tauri::command]
+#[specta::specta]
+pub fn reset_all_meetings(app: AppHandle, confirm: bool) -> Result<ResetSummary, String> {
+    info!("reset_all_meetings command called: confirm={}", confirm);
+
+    if !confirm {
+        return Err("Resetting deletes all meetings permanently; confirm to proceed".to_string());
     }
 
-    // Read transcript using blocking task to avoid blocking async runtime
-    let transcript_path_clone = full_transcript_path.clone();
-    let transcript =
-        tokio::task::spawn_blocking(move || std::fs::read_to_string(&transcript_path_clone))
-            .await
-            .map_err(|e| format!("Task join error: {}", e))?
-            .map_err(|e| format!("Failed to read transcript: {}", e))?;
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    let summary = manager
+        .reset_all()
+        .map_err(|e| format!("Failed to reset meetings: {}", e))?;
 
-    if transcript.trim().is_empty() {
-        return Err("Transcript is empty".to_string());
+    if let Err(e) = app.emit("meetings_reset", &summary) {
+        warn!("Failed to emit meetings_reset event: {}", e);
     }
 
-    // Get settings for LLM configuration
-    let settings = get_settings(&app);
+    Ok(summary)
+}
 
-    // Get active provider
-    let provider = settings
-        .active_post_process_provider()
-        .cloned()
-        .ok_or_else(|| {
-            "No LLM provider configured. Please set up a provider in Settings.".to_string()
-        })?;
-
-    let model = settings
-        .post_process_models
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
-
-    // Fall back to provider's default model if none configured
-    let model = if model.trim().is_empty() {
-        provider.default_model.clone().unwrap_or_default()
-    } else {
-        model
-    };
+/// Exports metadata for all meeting sessions as a CSV file in the background.
+///
+/// # Arguments
+/// * `dest` - Absolute path of the CSV file to write
+///
+/// # Returns
+/// * `Ok(String)` - The job id; on completion its result is the number of sessions exported
+/// * `Err(String)` - If the destination is empty
+#[tauri::command]
+#[specta::specta]
+pub fn export_meetings_csv(app: AppHandle, dest: String) -> Result<String, String> {
+    info!("export_meetings_csv command called: dest={}", dest);
 
-    if model.trim().is_empty() {
-        return Err(format!(
-            "No model configured for provider '{}'. Please configure in Settings.",
-            provider.label
-        ));
+    if dest.trim().is_empty() {
+        return Err("Export destination cannot be empty".to_string());
     }
 
-    let api_key = settings
-        .post_process_api_keys
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    let worker = manager.clone();
+    Ok(manager.submit_file_job(FileJobKind::Export, move || {
+        worker
+            .export_sessions_csv(std::path::PathBuf::from(dest))
+            .map(|count| count.to_string())
+    }))
+}
 
-    // Validate API key is set — but only if the provider requires one
-    if provider.requires_api_key && api_key.trim().is_empty() {
-        return Err(format!(
-            "No API key configured for provider '{}'. Please set your API key in Settings.",
-            provider.label
-        ));
-    }
+/// Computes a stable content hash (SHA-256, lowercase hex) of a session's audio
+/// in the background.
+///
+/// Used to detect duplicate imports and to verify exported copies. The hash is
+/// computed on first request and cached.
+///
+/// # Arguments
+/// * `session_id` - The session whose audio to hash
+///
+/// # Returns
+/// * `Ok(String)` - The job id; on completion its result is the hash of the audio file
+/// * `Err(String)` - Never; a missing or unreadable file fails the job instead
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_audio_hash(app: AppHandle, session_id: String) -> Result<String, String> {
+    debug!(
+        "get_meeting_audio_hash command called for session: {}",
+        session_id
+    );
 
-    // Build summary prompt - use template-specific prompt if available
-    let summary_prompt = if let Some(template_id) = &session.template_id {
-        // Find the template to get its custom summary prompt
-        let template = settings
-            .meeting_templates
-            .iter()
-            .find(|t| &t.id == template_id);
-
-        if let Some(template) = template {
-            if let Some(ref custom_prompt) = template.summary_prompt_template {
-                debug!(
-                    "Using template-specific summary prompt for template '{}'",
-                    template.name
-                );
-                // Replace {} placeholder with transcript
-                custom_prompt.replace("{}", &transcript)
-            } else {
-                // Template exists but has no custom prompt, use default
-                build_default_summary_prompt(&transcript)
-            }
-        } else {
-            // Template ID exists but template not found (may have been deleted)
-            warn!(
-                "Template '{}' not found, using default summary prompt",
-                template_id
-            );
-            build_default_summary_prompt(&transcript)
-        }
-    } else {
-        // No template associated with this session, use default
-        build_default_summary_prompt(&transcript)
-    };
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    let worker = manager.clone();
+    Ok(manager.submit_file_job(FileJobKind::AudioHash, move || {
+        worker.get_audio_hash(&session_id)
+    }))
+}
 
+/// Returns the status of a background export or hashing job.
+///
+/// The same status is also sent with each `file_job_updated` event.
+///
+/// # Arguments
+/// * `job_id` - The id returned when the job was submitted
+///
+/// # Returns
+/// * `Ok(FileJobStatus)` - The job's state and, once finished, its result or error
+/// * `Err(String)` - If the job id is unknown
+#[tauri::command]
+#[specta::specta]
+pub fn get_job_status(app: AppHandle, job_id: String) -> Result<FileJobStatus, String> {
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_job_status(&job_id)
+        .ok_or_else(|| format!("Job not found: {}", job_id))
+}
+
+/// Returns where playback of a session's audio was last left.
+///
+/// # Arguments
+/// * `session_id` - The session being played
+///
+/// # Returns
+/// * `Ok(u64)` - The position in milliseconds, 0 if never saved
+/// * `Err(String)` - If the session is not found
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_play_position(app: AppHandle, session_id: String) -> Result<u64, String> {
     debug!(
-        "Generating summary with provider '{}' (model: {})",
-        provider.id, model
+        "get_meeting_play_position command called for session: {}",
+        session_id
     );
 
-    // Auto-setup for Ollama: start server + pull model if needed
-    if provider.id == "ollama" || provider.id == "lmstudio" {
-        let status = crate::ollama::check_ollama_status().await;
-        match status.status {
-            crate::ollama::OllamaStatus::NotInstalled => {
-                return Err(format!(
-                    "Ollama is not installed. Please download from: {}",
-                    crate::ollama::get_ollama_install_url()
-                ));
-            }
-            crate::ollama::OllamaStatus::Installed => {
-                // Auto-start
-                info!("Ollama not running, starting automatically...");
-                let _ = app.emit("meeting_summary_status", "Starting Ollama server...");
-                crate::ollama::start_ollama().await.map_err(|e| {
-                    format!("Failed to auto-start Ollama: {}. Please start it manually.", e)
-                })?;
-            }
-            crate::ollama::OllamaStatus::Running => {
-                debug!("Ollama is already running");
-            }
-        }
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .get_play_position(&session_id)
+        .map_err(|e| format!("Failed to get play position: {}", e))
+}
 
-        // Check if the model is available, if not — auto-pull
-        if provider.id == "ollama" {
-            let models = crate::ollama::check_ollama_status().await;
-            let model_available = models.models.iter().any(|m| {
-                m.name == model || m.name.starts_with(&format!("{}:", model))
-            });
-
-            if !model_available {
-                info!("Model '{}' not found locally, pulling...", model);
-                let _ = app.emit("meeting_summary_status", &format!("Downloading model {}...", model));
-                crate::ollama::pull_ollama_model(app.clone(), model.clone())
-                    .await
-                    .map_err(|e| format!("Failed to download model '{}': {}", model, e))?;
-            }
-        }
+/// Saves where playback of a session's audio was left, as the user scrubs.
+///
+/// # Arguments
+/// * `session_id` - The session being played
+/// * `position_ms` - The playback position in milliseconds
+///
+/// # Returns
+/// * `Ok(())` - If the position was saved
+/// * `Err(String)` - If the session is not found
+#[tauri::command]
+#[specta::specta]
+pub fn set_meeting_play_position(
+    app: AppHandle,
+    session_id: String,
+    position_ms: u64,
+) -> Result<(), String> {
+    debug!(
+        "set_meeting_play_position command called for session: {} ({}ms)",
+        session_id, position_ms
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .set_play_position(&session_id, position_ms)
+        .map_err(|e| format!("Failed to set play position: {}", e))
+}
+
+/// Reads a byte range of a session's audio file.
+///
+/// Lets a frontend without filesystem access download or stream the audio in
+/// pieces. Each call returns at most 8 MiB.
+///
+/// # Arguments
+/// * `session_id` - The session whose audio to read
+/// * `offset` - Byte offset into the audio file
+/// * `len` - Number of bytes to read
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The requested bytes
+/// * `Err(String)` - If the session has no audio or the range is outside the file
+#[tauri::command]
+#[specta::specta]
+pub async fn get_meeting_audio_bytes(
+    app: AppHandle,
+    session_id: String,
+    offset: u64,
+    len: u64,
+) -> Result<Vec<u8>, String> {
+    debug!(
+        "get_meeting_audio_bytes command called for session: {} ({}+{})",
+        session_id, offset, len
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    tokio::task::spawn_blocking(move || manager.read_audio_bytes(&session_id, offset, len))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| format!("Failed to read meeting audio: {}", e))
+}
+
+/// Exports a session's timed transcript as an SRT subtitle file in the background.
+///
+/// Cues are streamed to the file as they are written, so even very long
+/// meetings export without holding the whole file in memory.
+///
+/// # Arguments
+/// * `session_id` - The session whose transcript to export
+/// * `dest` - Absolute path of the SRT file to write
+///
+/// # Returns
+/// * `Ok(String)` - The job id; on completion its result is the number of cues written
+/// * `Err(String)` - If the destination is empty
+#[tauri::command]
+#[specta::specta]
+pub fn export_meeting_srt(
+    app: AppHandle,
+    session_id: String,
+    dest: String,
+) -> Result<String, String> {
+    info!(
+        "export_meeting_srt command called for session: {}, dest={}",
+        session_id, dest
+    );
+
+    if dest.trim().is_empty() {
+        return Err("Export destination cannot be empty".to_string());
     }
 
-    // Call LLM API
-    let summary =
-        crate::llm_client::send_chat_completion(&provider, api_key, &model, summary_prompt)
-            .await
-            .map_err(|e| format!("LLM API call failed: {}", e))?
-            .ok_or_else(|| "LLM returned empty response".to_string())?;
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    let worker = manager.clone();
+    Ok(manager.submit_file_job(FileJobKind::SrtExport, move || {
+        worker
+            .export_srt_to_path(&session_id, Path::new(&dest))
+            .map(|cues| cues.to_string())
+    }))
+}
 
-    // Save summary to file with path validation
-    let summary_filename = format!("{}/summary.md", session_id);
-    let summary_path = validate_safe_write_path(&meetings_dir, &summary_filename)?;
+/// Lists the formats meetings can be exported in, for the export dialog.
+///
+/// # Returns
+/// Each format's display name, file extension, and whether it needs stored
+/// transcript segments
+#[tauri::command]
+#[specta::specta]
+pub fn list_export_formats() -> Vec<ExportFormatInfo> {
+    crate::managers::meeting::list_export_formats()
+}
 
-    // Write using blocking task to avoid blocking async runtime
-    let summary_clone = summary.clone();
-    tokio::task::spawn_blocking(move || std::fs::write(&summary_path, &summary_clone))
+/// Exports meeting sessions in the given format in the background.
+///
+/// # Arguments
+/// * `format` - One of the formats returned by `list_export_formats`
+/// * `session_ids` - Sessions to include; `None` includes every session. SRT
+///   needs exactly one.
+/// * `dest` - Absolute path of the file to write
+///
+/// # Returns
+/// * `Ok(String)` - The job id; on completion its result is the number of sessions exported
+/// * `Err(String)` - If the destination is empty
+#[tauri::command]
+#[specta::specta]
+pub fn export_meetings(
+    app: AppHandle,
+    format: ExportFormat,
+    session_ids: Option<Vec<String>>,
+    dest: String,
+) -> Result<String, String> {
+    info!("export_meetings command called: format={:?}, dest={}", format, dest);
+
+    if dest.trim().is_empty() {
+        return Err("Export destination cannot be empty".to_string());
+    }
+
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    let worker = manager.clone();
+    Ok(manager.submit_file_job(FileJobKind::Export, move || {
+        worker
+            .export_sessions(
+                format,
+                session_ids.as_deref(),
+                std::path::PathBuf::from(dest),
+            )
+            .map(|count| count.to_string())
+    }))
+}
+
+/// Exports sessions with their transcript and summary text as one JSON
+/// bundle, for importing into another instance.
+///
+/// # Arguments
+/// * `ids` - Sessions to include; `None` includes every session
+///
+/// # Returns
+/// * `Ok(String)` - The JSON bundle
+/// * `Err(String)` - If a session is not found or the export fails
+#[tauri::command]
+#[specta::specta]
+pub async fn export_sessions_json(
+    app: AppHandle,
+    ids: Option<Vec<String>>,
+) -> Result<String, String> {
+    info!(
+        "export_sessions_json command called for {} session(s)",
+        ids.as_ref()
+            .map_or("all".to_string(), |ids| ids.len().to_string())
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    tokio::task::spawn_blocking(move || manager.export_sessions_json(ids))
         .await
         .map_err(|e| format!("Task join error: {}", e))?
-        .map_err(|e| format!("Failed to save summary: {}", e))?;
+        .map_err(|e| format!("Failed to export sessions: {}", e))
+}
 
-    // Update database with summary path
-    manager
-        .update_session_summary_path(&session_id, &summary_filename)
-        .map_err(|e| format!("Failed to update session: {}", e))?;
+/// Imports the sessions of a bundle written by `export_sessions_json`.
+///
+/// Each session is created under a new id. Audio isn't part of a bundle, so
+/// sessions that weren't Completed are imported as Failed.
+///
+/// # Arguments
+/// * `json` - The bundle's contents
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - The imported sessions
+/// * `Err(String)` - If the bundle is invalid or from a newer version of the app
+#[tauri::command]
+#[specta::specta]
+pub async fn import_sessions_json(
+    app: AppHandle,
+    json: String,
+) -> Result<Vec<MeetingSession>, String> {
+    info!("import_sessions_json command called ({} bytes)", json.len());
 
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    tokio::task::spawn_blocking(move || manager.import_sessions_json(&json))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+        .map_err(|e| format!("Failed to import sessions: {}", e))
+}
+
+/// Splits a meeting's recording at the given times into new sessions.
+///
+/// # Arguments
+/// * `session_id` - The meeting to split
+/// * `split_points_ms` - Where to split, in milliseconds, ascending
+/// * `distribute_transcript` - Give each part the transcript segments that start in it
+/// * `delete_original` - Delete the original meeting once the parts exist
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - The new sessions, in recording order
+/// * `Err(String)` - If the meeting is busy, has no audio, or a split point is invalid
+#[tauri::command]
+#[specta::specta]
+pub async fn split_meeting_session(
+    app: AppHandle,
+    session_id: String,
+    split_points_ms: Vec<u64>,
+    distribute_transcript: bool,
+    delete_original: bool,
+) -> Result<Vec<MeetingSession>, String> {
     info!(
-        "Summary generated and saved for session {}: {} bytes",
-        session_id,
-        summary.len()
+        "split_meeting_session command called for {} at {:?}ms",
+        session_id, split_points_ms
     );
 
-    // Emit event for frontend
-    if let Some(updated_session) = manager.get_session(&session_id).ok().flatten() {
-        let _ = app.emit("meeting_summary_generated", &updated_session);
-    }
+    let manager = app.state::<Arc<MeetingSessionManager>>().inner().clone();
+    tokio::task::spawn_blocking(move || {
+        manager.split_session(
+            &session_id,
+            split_points_ms,
+            distribute_transcript,
+            delete_original,
+        )
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+    .map_err(|e| format!("Failed to split meeting: {}", e))
+}
 
-    Ok(summary)
+/// Gets the path to the meetings directory.
+///
+/// # Returns
+/// * `Ok(String)` - The absolute path to the meetings directory
+/// * `Err(String)` - If getting the path fails
+#[tauri::command]
+#[specta::specta]
+pub fn get_meetings_directory(app: AppHandle) -> Result<String, String> {
+    info!("get_meetings_directory command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    Ok(manager.get_meetings_dir().to_string_lossy().to_string())
+}
+
+/// Deletes a meeting session and its associated files.
+///
+/// This command:
+/// 1. Validates the session exists and isn't recording or processing
+/// 2. Deletes the session folder (audio, transcript files), if present
+/// 3. Removes the session from the database
+/// 4. Emits `meeting_deleted` with the session id
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the session to delete
+///
+/// # Returns
+/// * `Ok(())` - If the session was deleted successfully
+/// * `Err(String)` - If session not found, recording or processing, or deletion fails
+#[tauri::command]
+#[specta::specta]
+pub fn delete_meeting_session(app: AppHandle, session_id: String) -> Result<(), String> {
+    info!(
+        "delete_meeting_session command called for session: {}",
+        session_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .delete_session(&session_id)
+        .map_err(|e| format!("Failed to delete meeting session: {}", e))
+}
+
+/// Generates an AI summary for a meeting session.
+///
+/// This command:
+/// 1. Validates the session exists and has a transcript
+/// 2. Reads the transcript content
+/// 3. Sends it to the configured LLM provider for summarization
+/// 4. Saves the summary to a markdown file
+/// 5. Updates the session with the summary path
+///
+/// # Arguments
+/// * `session_id` - The unique ID of the session to summarize
+///
+/// # Returns
+/// * `Ok(String)` - The generated summary text
+/// * `Err(String)` - If session not found, no transcript, or LLM call fails
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_meeting_summary(
+    app: AppHandle,
+    session_id: String,
+) -> Result<String, String> {
+    info!(
+        "generate_meeting_summary command called for session: {}",
+        session_id
+    );
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    manager
+        .generate_summary(&session_id)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Gets the summary text content for a meeting session.
@@ -841,3 +1719,38 @@ pub fn get_meeting_summary(app: AppHandle, session_id: String) -> Result<Option<
 
     Ok(Some(content))
 }
+
+/// Schema version information for the meetings database.
+#[derive(Serialize, Type)]
+pub struct MeetingDbVersion {
+    /// Version currently stored in the database (`PRAGMA user_version`)
+    pub version: i32,
+    /// Version this build of the app migrates to
+    pub expected_version: i32,
+    /// True if the database is behind the expected version
+    pub upgrade_pending: bool,
+}
+
+/// Gets the schema/migration version of the meetings database.
+///
+/// Useful for diagnostics to confirm a user's database is on the latest migration.
+///
+/// # Returns
+/// * `Ok(MeetingDbVersion)` - Current and expected schema versions
+/// * `Err(String)` - If the database cannot be read
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_db_version(app: AppHandle) -> Result<MeetingDbVersion, String> {
+    info!("get_meeting_db_version command called");
+
+    let manager = app.state::<Arc<MeetingSessionManager>>();
+    let version = manager
+        .get_db_version()
+        .map_err(|e| format!("Failed to read meeting database version: {}", e))?;
+
+    Ok(MeetingDbVersion {
+        version,
+        expected_version: MEETING_DB_EXPECTED_VERSION,
+        upgrade_pending: version < MEETING_DB_EXPECTED_VERSION,
+    })
+}
@@ -12,6 +12,17 @@ pub async fn get_available_models(
     Ok(model_manager.get_available_models())
 }
 
+/// Lists the transcription models that are installed and can be used for meetings.
+///
+/// Returns an empty list when nothing is installed so the UI can prompt a download.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_available_models(
+    model_manager: State<'_, Arc<ModelManager>>,
+) -> Result<Vec<ModelInfo>, String> {
+    Ok(model_manager.get_installed_models())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_model_info(
@@ -1,26 +1,272 @@
-use crate::settings::{get_settings, write_settings, MeetingTemplate};
+use crate::audio_toolkit::PreprocessOp;
+use crate::settings::{
+    get_settings, write_settings, LLMPrompt, MeetingTemplate, PostAction, PreprocessingStep,
+    SummaryStrategy,
+};
+use crate::template_i18n::{template_display_name, template_language};
 use log::debug;
+use serde::Deserialize;
+use specta::Type;
 use tauri::AppHandle;
 
+/// Maximum number of steps in a template's preprocessing chain
+const MAX_PREPROCESSING_STEPS: usize = 8;
+
+/// Serialized `AudioSourceType` values a template may use
+const AUDIO_SOURCES: [&str; 3] = ["microphone_only", "system_only", "mixed"];
+
+/// Settings for a new template, as sent by `create_meeting_template`.
+///
+/// Absent optional fields get the same defaults as a template stored before
+/// they existed.
+#[derive(Clone, Debug, Deserialize, Type)]
+pub struct MeetingTemplateInput {
+    pub name: String,
+    pub icon: String,
+    pub title_template: String,
+    pub audio_source: String,
+    #[serde(default)]
+    pub prompt_id: Option<String>,
+    #[serde(default)]
+    pub summary_prompt_template: Option<String>,
+    #[serde(default)]
+    pub normalize_loudness: Option<bool>,
+    #[serde(default)]
+    pub exclude_app_audio: Option<bool>,
+    #[serde(default)]
+    pub preprocessing: Option<Vec<PreprocessingStep>>,
+    #[serde(default)]
+    pub auto_title_from_transcript: Option<bool>,
+    #[serde(default)]
+    pub fail_on_no_speech: Option<bool>,
+    #[serde(default)]
+    pub retain_audio: Option<bool>,
+    #[serde(default)]
+    pub retain_transcript: Option<bool>,
+    #[serde(default)]
+    pub format_paragraphs: Option<bool>,
+    #[serde(default)]
+    pub paragraph_target_chars: Option<u32>,
+    #[serde(default)]
+    pub summary_strategy: Option<SummaryStrategy>,
+    #[serde(default)]
+    pub summary_token_budget: Option<u32>,
+    #[serde(default)]
+    pub post_actions: Option<Vec<PostAction>>,
+    #[serde(default)]
+    pub live_captions: Option<bool>,
+    #[serde(default)]
+    pub sync_offset_ms: Option<i32>,
+    #[serde(default)]
+    pub auto_sync_offset: Option<bool>,
+}
+
+/// Fields to change on a template in one `update_meeting_template` call.
+///
+/// Absent fields are left as they are.
+#[derive(Clone, Debug, Default, Deserialize, Type)]
+pub struct MeetingTemplatePatch {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub title_template: Option<String>,
+    #[serde(default)]
+    pub audio_source: Option<String>,
+    #[serde(default)]
+    pub prompt_id: Option<String>,
+    #[serde(default)]
+    pub summary_prompt_template: Option<String>,
+    #[serde(default)]
+    pub normalize_loudness: Option<bool>,
+    #[serde(default)]
+    pub exclude_app_audio: Option<bool>,
+    #[serde(default)]
+    pub preprocessing: Option<Vec<PreprocessingStep>>,
+    #[serde(default)]
+    pub auto_title_from_transcript: Option<bool>,
+    #[serde(default)]
+    pub fail_on_no_speech: Option<bool>,
+    #[serde(default)]
+    pub retain_audio: Option<bool>,
+    #[serde(default)]
+    pub retain_transcript: Option<bool>,
+    #[serde(default)]
+    pub format_paragraphs: Option<bool>,
+    #[serde(default)]
+    pub paragraph_target_chars: Option<u32>,
+    #[serde(default)]
+    pub summary_strategy: Option<SummaryStrategy>,
+    #[serde(default)]
+    pub summary_token_budget: Option<u32>,
+    #[serde(default)]
+    pub post_actions: Option<Vec<PostAction>>,
+    #[serde(default)]
+    pub live_captions: Option<bool>,
+    #[serde(default)]
+    pub sync_offset_ms: Option<i32>,
+    #[serde(default)]
+    pub auto_sync_offset: Option<bool>,
+}
+
+/// Validates a preprocessing chain: known step names and sane parameters.
+fn validate_preprocessing(steps: &[PreprocessingStep]) -> Result<(), String> {
+    if steps.len() > MAX_PREPROCESSING_STEPS {
+        return Err(format!(
+            "Preprocessing chain is too long (max {} steps)",
+            MAX_PREPROCESSING_STEPS
+        ));
+    }
+
+    for (index, step) in steps.iter().enumerate() {
+        PreprocessOp::parse(&step.name, &step.params)
+            .map_err(|e| format!("Invalid preprocessing step {}: {}", index + 1, e))?;
+    }
+
+    Ok(())
+}
+
+/// Validates a template's paragraph target length.
+fn validate_paragraph_target(chars: u32) -> Result<(), String> {
+    let min = crate::managers::meeting::MIN_PARAGRAPH_TARGET_CHARS;
+    if chars < min {
+        return Err(format!(
+            "Paragraph length must be at least {} characters",
+            min
+        ));
+    }
+    Ok(())
+}
+
+/// Validates a template's summary token budget.
+fn validate_summary_token_budget(tokens: u32) -> Result<(), String> {
+    let min = crate::managers::meeting::MIN_SUMMARY_TOKEN_BUDGET;
+    if tokens < min {
+        return Err(format!(
+            "Summary token budget must be at least {} tokens",
+            min
+        ));
+    }
+    Ok(())
+}
+
+/// Validates a template's post-completion actions: each action at most once.
+fn validate_post_actions(actions: &[PostAction]) -> Result<(), String> {
+    for (index, action) in actions.iter().enumerate() {
+        if actions[..index].contains(action) {
+            return Err(format!("Post action {:?} is listed more than once", action));
+        }
+    }
+    Ok(())
+}
+
+/// Validates a template's mic/system audio offset.
+fn validate_sync_offset(offset_ms: i32) -> Result<(), String> {
+    let max = crate::audio_toolkit::MAX_SYNC_OFFSET_MS;
+    if offset_ms.abs() > max {
+        return Err(format!(
+            "Audio sync offset must be between -{} and {} ms",
+            max, max
+        ));
+    }
+    Ok(())
+}
+
+/// Lists problems with a stored template that would make meetings using it
+/// misbehave, such as a summary prompt that no longer exists.
+fn template_issues(template: &MeetingTemplate, prompts: &[LLMPrompt]) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    if let Some(prompt_id) = template.prompt_id.as_ref() {
+        if !prompts.iter().any(|p| &p.id == prompt_id) {
+            issues.push(format!("Prompt '{}' no longer exists", prompt_id));
+        }
+    }
+
+    if !AUDIO_SOURCES.contains(&template.audio_source.as_str()) {
+        issues.push(format!("Invalid audio_source: {}", template.audio_source));
+    }
+
+    if let Some(spt) = template.summary_prompt_template.as_ref() {
+        if !spt.is_empty() && !spt.contains("{}") {
+            issues.push(
+                "summary_prompt_template must contain '{}' placeholder for transcript".to_string(),
+            );
+        }
+        if spt.len() > 10000 {
+            issues.push("summary_prompt_template is too long (max 10000 characters)".to_string());
+        }
+    }
+
+    let checks = [
+        validate_preprocessing(&template.preprocessing),
+        validate_paragraph_target(template.paragraph_target_chars),
+        validate_summary_token_budget(template.summary_token_budget),
+        validate_post_actions(&template.post_actions),
+        validate_sync_offset(template.sync_offset_ms),
+    ];
+    issues.extend(checks.into_iter().filter_map(Result::err));
+
+    issues
+}
+
+/// Replaces a built-in template's stored English name with its name in `locale`.
+fn localize(mut template: MeetingTemplate, locale: &str) -> MeetingTemplate {
+    template.name = template_display_name(&template, locale);
+    template
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn list_meeting_templates(app: AppHandle) -> Result<Vec<MeetingTemplate>, String> {
     debug!("list_meeting_templates command called");
     let settings = get_settings(&app);
-    Ok(settings.meeting_templates)
+    let locale = settings.app_language.clone();
+    Ok(settings
+        .meeting_templates
+        .into_iter()
+        .map(|template| localize(template, &locale))
+        .collect())
+}
+
+/// Returns the language code built-in template names are shown in: the app
+/// language if translations exist for it, otherwise "en".
+#[tauri::command]
+#[specta::specta]
+pub fn get_template_locale(app: AppHandle) -> String {
+    template_language(&get_settings(&app).app_language).to_string()
 }
 
 #[tauri::command]
 #[specta::specta]
 pub fn create_meeting_template(
     app: AppHandle,
-    name: String,
-    icon: String,
-    title_template: String,
-    audio_source: String,
-    prompt_id: Option<String>,
-    summary_prompt_template: Option<String>,
+    template: MeetingTemplateInput,
 ) -> Result<MeetingTemplate, String> {
+    let MeetingTemplateInput {
+        name,
+        icon,
+        title_template,
+        audio_source,
+        prompt_id,
+        summary_prompt_template,
+        normalize_loudness,
+        exclude_app_audio,
+        preprocessing,
+        auto_title_from_transcript,
+        fail_on_no_speech,
+        retain_audio,
+        retain_transcript,
+        format_paragraphs,
+        paragraph_target_chars,
+        summary_strategy,
+        summary_token_budget,
+        post_actions,
+        live_captions,
+        sync_offset_ms,
+        auto_sync_offset,
+    } = template;
     debug!("create_meeting_template command called: name={}", name);
 
     // Validation
@@ -33,7 +279,7 @@ pub fn create_meeting_template(
     }
 
     // Validate audio_source
-    if !["microphone_only", "system_only", "mixed"].contains(&audio_source.as_str()) {
+    if !AUDIO_SOURCES.contains(&audio_source.as_str()) {
         return Err(format!("Invalid audio_source: {}", audio_source));
     }
 
@@ -47,13 +293,30 @@ pub fn create_meeting_template(
         }
     }
 
+    let preprocessing = preprocessing.unwrap_or_default();
+    validate_preprocessing(&preprocessing)?;
+
+    let paragraph_target_chars =
+        paragraph_target_chars.unwrap_or(crate::managers::meeting::DEFAULT_PARAGRAPH_TARGET_CHARS);
+    validate_paragraph_target(paragraph_target_chars)?;
+
+    let summary_token_budget =
+        summary_token_budget.unwrap_or(crate::managers::meeting::DEFAULT_SUMMARY_TOKEN_BUDGET);
+    validate_summary_token_budget(summary_token_budget)?;
+
+    let post_actions = post_actions.unwrap_or_default();
+    validate_post_actions(&post_actions)?;
+
+    let sync_offset_ms = sync_offset_ms.unwrap_or(0);
+    validate_sync_offset(sync_offset_ms)?;
+
     let mut settings = get_settings(&app);
 
-    // Check for duplicate names
+    // Check for duplicate names, as the user sees them
     if settings
         .meeting_templates
         .iter()
-        .any(|t| t.name == name.trim())
+        .any(|t| template_display_name(t, &settings.app_language) == name.trim())
     {
         return Err(format!("Template with name '{}' already exists", name.trim()));
     }
@@ -62,11 +325,27 @@ pub fn create_meeting_template(
     let new_template = MeetingTemplate {
         id: uuid::Uuid::new_v4().to_string(),
         name: name.trim().to_string(),
+        name_key: None,
         icon,
         title_template,
         audio_source,
         prompt_id,
         summary_prompt_template,
+        normalize_loudness: normalize_loudness.unwrap_or(false),
+        exclude_app_audio: exclude_app_audio.unwrap_or(false),
+        preprocessing,
+        auto_title_from_transcript: auto_title_from_transcript.unwrap_or(false),
+        fail_on_no_speech: fail_on_no_speech.unwrap_or(false),
+        retain_audio: retain_audio.unwrap_or(true),
+        retain_transcript: retain_transcript.unwrap_or(true),
+        format_paragraphs: format_paragraphs.unwrap_or(false),
+        paragraph_target_chars,
+        summary_strategy: summary_strategy.unwrap_or_default(),
+        summary_token_budget,
+        post_actions,
+        live_captions: live_captions.unwrap_or(false),
+        sync_offset_ms,
+        auto_sync_offset: auto_sync_offset.unwrap_or(false),
         created_at: chrono::Utc::now().timestamp(),
         updated_at: chrono::Utc::now().timestamp(),
     };
@@ -83,16 +362,35 @@ pub fn create_meeting_template(
 pub fn update_meeting_template(
     app: AppHandle,
     id: String,
-    name: Option<String>,
-    icon: Option<String>,
-    title_template: Option<String>,
-    audio_source: Option<String>,
-    prompt_id: Option<String>,
-    summary_prompt_template: Option<String>,
+    patch: MeetingTemplatePatch,
 ) -> Result<MeetingTemplate, String> {
+    let MeetingTemplatePatch {
+        name,
+        icon,
+        title_template,
+        audio_source,
+        prompt_id,
+        summary_prompt_template,
+        normalize_loudness,
+        exclude_app_audio,
+        preprocessing,
+        auto_title_from_transcript,
+        fail_on_no_speech,
+        retain_audio,
+        retain_transcript,
+        format_paragraphs,
+        paragraph_target_chars,
+        summary_strategy,
+        summary_token_budget,
+        post_actions,
+        live_captions,
+        sync_offset_ms,
+        auto_sync_offset,
+    } = patch;
     debug!("update_meeting_template command called: id={}", id);
 
     let mut settings = get_settings(&app);
+    let locale = settings.app_language.clone();
 
     // Find template
     let template = settings
@@ -109,7 +407,11 @@ pub fn update_meeting_template(
         if n.len() > 50 {
             return Err("Template name must be 50 characters or less".to_string());
         }
-        template.name = n.trim().to_string();
+        // Renaming a built-in pins the literal name instead of the translated one
+        if n.trim() != template_display_name(template, &locale) {
+            template.name = n.trim().to_string();
+            template.name_key = None;
+        }
     }
 
     if let Some(i) = icon {
@@ -121,7 +423,7 @@ pub fn update_meeting_template(
     }
 
     if let Some(as_val) = audio_source {
-        if !["microphone_only", "system_only", "mixed"].contains(&as_val.as_str()) {
+        if !AUDIO_SOURCES.contains(&as_val.as_str()) {
             return Err(format!("Invalid audio_source: {}", as_val));
         }
         template.audio_source = as_val;
@@ -145,13 +447,99 @@ pub fn update_meeting_template(
         template.summary_prompt_template = summary_prompt_template;
     }
 
+    if let Some(nl) = normalize_loudness {
+        template.normalize_loudness = nl;
+    }
+
+    if let Some(exclude) = exclude_app_audio {
+        template.exclude_app_audio = exclude;
+    }
+
+    if let Some(steps) = preprocessing {
+        validate_preprocessing(&steps)?;
+        template.preprocessing = steps;
+    }
+
+    if let Some(auto_title) = auto_title_from_transcript {
+        template.auto_title_from_transcript = auto_title;
+    }
+
+    if let Some(fail) = fail_on_no_speech {
+        template.fail_on_no_speech = fail;
+    }
+
+    if let Some(retain) = retain_audio {
+        template.retain_audio = retain;
+    }
+
+    if let Some(retain) = retain_transcript {
+        template.retain_transcript = retain;
+    }
+
+    if let Some(enabled) = format_paragraphs {
+        template.format_paragraphs = enabled;
+    }
+
+    if let Some(chars) = paragraph_target_chars {
+        validate_paragraph_target(chars)?;
+        template.paragraph_target_chars = chars;
+    }
+
+    if let Some(strategy) = summary_strategy {
+        template.summary_strategy = strategy;
+    }
+
+    if let Some(tokens) = summary_token_budget {
+        validate_summary_token_budget(tokens)?;
+        template.summary_token_budget = tokens;
+    }
+
+    if let Some(actions) = post_actions {
+        validate_post_actions(&actions)?;
+        template.post_actions = actions;
+    }
+
+    if let Some(live) = live_captions {
+        template.live_captions = live;
+    }
+
+    if let Some(offset) = sync_offset_ms {
+        validate_sync_offset(offset)?;
+        template.sync_offset_ms = offset;
+    }
+
+    if let Some(auto) = auto_sync_offset {
+        template.auto_sync_offset = auto;
+    }
+
     template.updated_at = chrono::Utc::now().timestamp();
 
     let updated_template = template.clone();
 
     write_settings(&app, settings);
     debug!("Template updated successfully: {}", id);
-    Ok(updated_template)
+    Ok(localize(updated_template, &locale))
+}
+
+/// Checks a template for problems without modifying it, so the template
+/// editor can show warnings.
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - One message per problem; empty if the template is fine
+/// * `Err(String)` - If no template has the given id
+#[tauri::command]
+#[specta::specta]
+pub fn validate_template(app: AppHandle, id: String) -> Result<Vec<String>, String> {
+    debug!("validate_template command called: id={}", id);
+
+    let settings = get_settings(&app);
+    let template = settings
+        .meeting_templates
+        .iter()
+        .find(|t| t.id == id)
+        .ok_or_else(|| format!("Template with id '{}' not found", id))?;
+
+    Ok(template_issues(template, &settings.post_process_prompts))
 }
 
 #[tauri::command]
@@ -174,7 +562,78 @@ pub fn delete_meeting_template(app: AppHandle, id: String) -> Result<(), String>
         return Err(format!("Template with id '{}' not found", id));
     }
 
+    // Disarm the template if it was the active one
+    if settings.active_meeting_template_id.as_deref() == Some(id.as_str()) {
+        settings.active_meeting_template_id = None;
+    }
+
     write_settings(&app, settings);
     debug!("Template deleted successfully: {}", id);
     Ok(())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_active_template(app: AppHandle) -> Result<Option<MeetingTemplate>, String> {
+    debug!("get_active_template command called");
+
+    let mut settings = get_settings(&app);
+    if let Some(active_id) = settings.clear_missing_active_template() {
+        debug!("Active template '{}' not found, clearing", active_id);
+        write_settings(&app, settings);
+        return Ok(None);
+    }
+
+    Ok(settings
+        .active_meeting_template()
+        .cloned()
+        .map(|template| localize(template, &settings.app_language)))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_active_template(
+    app: AppHandle,
+    id: Option<String>,
+) -> Result<Option<MeetingTemplate>, String> {
+    debug!("set_active_template command called: id={:?}", id);
+
+    let mut settings = get_settings(&app);
+    let template = settings.set_active_meeting_template(id)?;
+
+    let locale = settings.app_language.clone();
+    write_settings(&app, settings);
+    Ok(template.map(|template| localize(template, &locale)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    #[test]
+    fn test_template_with_deleted_prompt_is_flagged() {
+        let settings = get_default_settings();
+        let mut template = settings.meeting_templates[0].clone();
+        assert!(template_issues(&template, &settings.post_process_prompts).is_empty());
+
+        template.prompt_id = Some("deleted-prompt".to_string());
+        template.audio_source = "speakers".to_string();
+        template.sync_offset_ms = 2000;
+        let issues = template_issues(&template, &settings.post_process_prompts);
+        assert_eq!(
+            issues,
+            vec![
+                "Prompt 'deleted-prompt' no longer exists".to_string(),
+                "Invalid audio_source: speakers".to_string(),
+                "Audio sync offset must be between -500 and 500 ms".to_string(),
+            ]
+        );
+
+        // An existing prompt is fine
+        template.prompt_id = settings.post_process_prompts.first().map(|p| p.id.clone());
+        template.audio_source = "mixed".to_string();
+        template.sync_offset_ms = -120;
+        assert!(template_issues(&template, &settings.post_process_prompts).is_empty());
+    }
+}
@@ -14,6 +14,7 @@ mod overlay;
 mod settings;
 mod shortcut;
 mod signal_handle;
+mod template_i18n;
 mod tray;
 mod tray_i18n;
 mod utils;
@@ -153,9 +154,19 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.manage(history_manager.clone());
     app_handle.manage(meeting_manager.clone());
 
+    // Pick up transcriptions that were still queued when the app last closed
+    if let Err(e) = meeting_manager.resume_transcription_queue() {
+        log::error!("Failed to resume queued meeting transcriptions: {}", e);
+    }
+
     // Check for interrupted meeting sessions from previous runs
-    if let Err(e) = meeting_manager.check_interrupted_sessions() {
-        log::error!("Failed to check for interrupted meeting sessions: {}", e);
+    match meeting_manager.check_interrupted_sessions() {
+        Ok(sessions) => {
+            if settings::get_settings(app_handle).meeting_auto_retry_interrupted {
+                meeting_manager.auto_retry_interrupted_sessions(&sessions);
+            }
+        }
+        Err(e) => log::error!("Failed to check for interrupted meeting sessions: {}", e),
     }
 
     // Initialize the shortcuts
@@ -287,6 +298,19 @@ pub fn run() {
         shortcut::resume_binding,
         shortcut::change_mute_while_recording_setting,
         shortcut::change_append_trailing_space_setting,
+        shortcut::change_mirror_latest_transcript_setting,
+        shortcut::change_meeting_audio_archival_setting,
+        shortcut::change_meeting_resume_grace_setting,
+        shortcut::change_meeting_sample_chunk_frames_setting,
+        shortcut::change_meeting_pre_roll_ms_setting,
+        shortcut::change_meeting_auto_retry_interrupted_setting,
+        shortcut::change_meeting_auto_transcribe_setting,
+        shortcut::change_meeting_auto_summarize_setting,
+        shortcut::change_meeting_apply_custom_words_setting,
+        shortcut::change_meeting_live_chunk_secs_setting,
+        shortcut::change_meeting_vad_trim_setting,
+        shortcut::change_meeting_vad_trim_threshold_setting,
+        shortcut::change_meeting_file_job_concurrency_setting,
         shortcut::change_app_language_setting,
         shortcut::change_update_checks_setting,
         trigger_update_check,
@@ -300,6 +324,7 @@ pub fn run() {
         commands::open_log_dir,
         commands::open_app_data_dir,
         commands::models::get_available_models,
+        commands::models::list_available_models,
         commands::models::get_model_info,
         commands::models::download_model,
         commands::models::delete_model,
@@ -324,6 +349,10 @@ pub fn run() {
         commands::audio::set_clamshell_microphone,
         commands::audio::get_clamshell_microphone,
         commands::audio::is_recording,
+        commands::audio::system_audio_supported,
+        commands::audio::has_microphone_permission,
+        commands::audio::request_microphone_permission,
+        commands::audio::save_samples_as_wav,
         commands::transcription::set_model_unload_timeout,
         commands::transcription::get_model_load_status,
         commands::transcription::unload_model_manually,
@@ -334,21 +363,73 @@ pub fn run() {
         commands::history::update_history_limit,
         commands::history::update_recording_retention_period,
         commands::meeting::start_meeting_session,
+        commands::meeting::start_meeting_from_template,
+        commands::meeting::preview_recording_config,
         commands::meeting::stop_meeting_session,
+        commands::meeting::pause_meeting_recording,
+        commands::meeting::resume_meeting_recording,
+        commands::meeting::toggle_meeting_recording,
+        commands::meeting::transcribe_meeting_session,
         commands::meeting::get_meeting_status,
         commands::meeting::get_current_meeting,
+        commands::meeting::get_active_recording_device,
+        commands::meeting::list_meeting_input_devices,
         commands::meeting::update_meeting_title,
+        commands::meeting::update_session_fields,
         commands::meeting::retry_transcription,
+        commands::meeting::get_retry_comparison,
+        commands::meeting::cancel_queued_transcription,
+        commands::meeting::reprocess_session,
+        commands::meeting::get_transcription_queue,
         commands::meeting::get_meeting_transcript,
+        commands::meeting::get_transcript_segments,
+        commands::meeting::get_transcript_direction,
+        commands::meeting::get_speech_ratio,
+        commands::meeting::get_meeting_detail,
+        commands::meeting::get_meeting_status_lite,
+        commands::meeting::get_remaining_recording_minutes,
         commands::meeting::list_meeting_sessions,
+        commands::meeting::get_meeting_status_counts,
+        commands::meeting::get_recent_meeting_errors,
+        commands::meeting::clear_meeting_error,
+        commands::meeting::repair_meeting_session,
+        commands::meeting::recompute_meeting_durations,
+        commands::meeting::suspend_meetings,
+        commands::meeting::get_session_by_audio_path,
+        commands::meeting::export_meetings_csv,
+        commands::meeting::export_meeting_srt,
+        commands::meeting::get_meeting_audio_hash,
+        commands::meeting::get_job_status,
+        commands::meeting::get_meeting_audio_bytes,
+        commands::meeting::get_meeting_play_position,
+        commands::meeting::set_meeting_play_position,
+        commands::meeting::list_export_formats,
+        commands::meeting::export_meetings,
+        commands::meeting::export_sessions_json,
+        commands::meeting::import_sessions_json,
+        commands::meeting::split_meeting_session,
+        commands::meeting::check_meeting_integrity,
+        commands::meeting::list_sessions_missing_transcript,
+        commands::meeting::reapply_custom_words_all,
+        commands::meeting::search_meetings,
+        commands::meeting::reindex_meeting_transcripts,
+        commands::meeting::reset_all_meetings,
+        commands::meeting::set_meeting_category,
+        commands::meeting::list_meetings_by_category,
+        commands::meeting::list_meetings_by_day,
         commands::meeting::get_meetings_directory,
         commands::meeting::delete_meeting_session,
         commands::meeting::generate_meeting_summary,
         commands::meeting::get_meeting_summary,
+        commands::meeting::get_meeting_db_version,
         commands::templates::list_meeting_templates,
+        commands::templates::get_template_locale,
         commands::templates::create_meeting_template,
         commands::templates::update_meeting_template,
+        commands::templates::validate_template,
         commands::templates::delete_meeting_template,
+        commands::templates::get_active_template,
+        commands::templates::set_active_template,
         helpers::clamshell::is_laptop,
         ollama::check_ollama_status,
         ollama::start_ollama,
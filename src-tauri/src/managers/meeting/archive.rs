@@ -0,0 +1,139 @@
+//! Archival copies of meeting audio.
+//!
+//! After transcription the recording can be compressed to 16-bit mono FLAC at
+//! its own sample rate. FLAC is lossless, so for the usual 16 kHz/16-bit
+//! recordings the archive is as good as the original for listening back and
+//! re-transcribing, at roughly half the size or less. Depending on settings the
+//! archive is kept alongside the original or replaces it; the original is only
+//! deleted once the archive has been decoded back and checked.
+
+use anyhow::Result;
+use claxon::FlacReader;
+use flacenc::component::BitRepr;
+use flacenc::error::Verify;
+use log::info;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::audio_cache::{cache_path_for, read_audio};
+use super::db::update_session_archive;
+use super::files::write_atomic;
+
+/// File name of the archive copy inside a session folder.
+pub(crate) const ARCHIVE_FILENAME: &str = "audio_archive.flac";
+
+/// Format identifier stored in the `archive_format` column.
+pub(crate) const ARCHIVE_FORMAT: &str = "flac_pcm16_mono";
+
+/// Writes a 16-bit mono FLAC copy of `source_wav`, at the source's sample
+/// rate, to `archive_path`.
+///
+/// # Returns
+/// The number of frames written
+pub(crate) fn write_archive(source_wav: &Path, archive_path: &Path) -> Result<u64> {
+    let audio = read_audio(source_wav)?.to_mono();
+    let samples: Vec<i32> = audio
+        .samples
+        .iter()
+        .map(|sample| (sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i32)
+        .collect();
+
+    let config = flacenc::config::Encoder::default()
+        .into_verified()
+        .map_err(|(_, e)| anyhow::anyhow!("Invalid FLAC encoder settings: {:?}", e))?;
+    let source =
+        flacenc::source::MemSource::from_samples(&samples, 1, 16, audio.sample_rate as usize);
+    let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+        .map_err(|e| anyhow::anyhow!("Failed to encode {:?} as FLAC: {:?}", source_wav, e))?;
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    stream
+        .write(&mut sink)
+        .map_err(|e| anyhow::anyhow!("Failed to write FLAC stream: {:?}", e))?;
+
+    write_atomic(archive_path, sink.as_slice())?;
+    Ok(samples.len() as u64)
+}
+
+/// Checks that the archive decodes as 16-bit mono audio with `frames` frames.
+pub(crate) fn verify_archive(archive_path: &Path, frames: u64) -> Result<()> {
+    let mut reader = FlacReader::open(archive_path)
+        .map_err(|e| anyhow::anyhow!("Archive {:?} is unreadable: {}", archive_path, e))?;
+    let info = reader.streaminfo();
+    if info.channels != 1 || info.bits_per_sample != 16 {
+        return Err(anyhow::anyhow!(
+            "Archive {:?} has {} channel(s) at {}-bit, expected mono 16-bit",
+            archive_path,
+            info.channels,
+            info.bits_per_sample
+        ));
+    }
+
+    // Decoding every frame also checks each frame's checksum
+    let mut decoded = 0u64;
+    for sample in reader.samples() {
+        sample.map_err(|e| anyhow::anyhow!("Archive {:?} is corrupt: {}", archive_path, e))?;
+        decoded += 1;
+    }
+    if decoded != frames {
+        return Err(anyhow::anyhow!(
+            "Archive {:?} holds {} frames, expected {}",
+            archive_path,
+            decoded,
+            frames
+        ));
+    }
+    Ok(())
+}
+
+/// Archives a session's audio and records the archive in the database.
+///
+/// # Arguments
+/// * `db_path` - Path to the meetings database
+/// * `meetings_dir` - Root meetings directory
+/// * `session_id` - The session to archive
+/// * `audio_rel_path` - The session's current audio path, relative to `meetings_dir`
+/// * `replace_original` - Delete the original WAV and point `audio_path` at the archive
+///
+/// # Returns
+/// The archive path relative to `meetings_dir`. If the archive can't be
+/// written or doesn't verify, it is removed and the original is left as it was.
+pub(crate) fn archive_session_audio(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    session_id: &str,
+    audio_rel_path: &str,
+    replace_original: bool,
+) -> Result<String> {
+    let source = meetings_dir.join(audio_rel_path);
+    let archive_rel_path = format!("{}/{}", session_id, ARCHIVE_FILENAME);
+    let archive_full_path = meetings_dir.join(&archive_rel_path);
+
+    let written = write_archive(&source, &archive_full_path)
+        .and_then(|frames| verify_archive(&archive_full_path, frames));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&archive_full_path);
+        return Err(e);
+    }
+
+    update_session_archive(
+        db_path,
+        session_id,
+        &archive_rel_path,
+        ARCHIVE_FORMAT,
+        replace_original,
+    )?;
+
+    if replace_original {
+        fs::remove_file(&source)?;
+        let _ = fs::remove_file(cache_path_for(&source));
+    }
+
+    info!(
+        "Archived audio for session {} to {} (original {})",
+        session_id,
+        archive_rel_path,
+        if replace_original { "removed" } else { "kept" }
+    );
+
+    Ok(archive_rel_path)
+}
@@ -0,0 +1,244 @@
+//! On-disk cache of meeting audio converted to 16 kHz mono samples.
+//!
+//! Transcribing a session (retry, reprocess, ...) needs the WAV decoded and
+//! resampled to the STT sample rate. The converted buffer is stored next to the
+//! WAV as `audio16k.bin` and reused until the WAV's size or mtime changes.
+//! Samples are stored as 16-bit PCM, which keeps the cache no larger than a
+//! 16 kHz WAV, and the cache is removed once the session is Completed.
+
+use anyhow::Result;
+use claxon::FlacReader;
+use hound::{SampleFormat, WavReader};
+use log::{debug, warn};
+use std::fs;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use super::files::open_shared_read;
+use crate::audio_toolkit::{validate_channel_count, AudioBuffer};
+
+/// File name of the converted sample cache inside a session folder.
+pub(crate) const CONVERTED_AUDIO_FILENAME: &str = "audio16k.bin";
+
+const CACHE_MAGIC: &[u8; 4] = b"M16S";
+/// magic + source length (u64) + mtime seconds (u64) + mtime nanos (u32)
+const CACHE_HEADER_LEN: usize = 4 + 8 + 8 + 4;
+
+/// Identifies a particular version of the source WAV.
+#[derive(Debug, PartialEq, Eq)]
+struct SourceStamp {
+    len: u64,
+    modified_secs: u64,
+    modified_nanos: u32,
+}
+
+impl SourceStamp {
+    fn of(path: &Path) -> Result<Self> {
+        let metadata = fs::metadata(path)?;
+        let modified = metadata.modified()?.duration_since(UNIX_EPOCH)?;
+        Ok(Self {
+            len: metadata.len(),
+            modified_secs: modified.as_secs(),
+            modified_nanos: modified.subsec_nanos(),
+        })
+    }
+}
+
+/// Samples ready for transcription, plus whether they came from the cache.
+pub(crate) struct ConvertedAudio {
+    pub audio: AudioBuffer,
+    pub from_cache: bool,
+}
+
+/// Returns the cache path used for the given WAV file.
+pub(crate) fn cache_path_for(wav_path: &Path) -> PathBuf {
+    wav_path.with_file_name(CONVERTED_AUDIO_FILENAME)
+}
+
+/// Loads the WAV at `wav_path` as 16 kHz mono f32 samples, reusing the cached
+/// conversion when it is still valid and refreshing it otherwise.
+///
+/// Failing to write the cache is not an error; the converted samples are still returned.
+pub(crate) fn load_16k_samples(wav_path: &Path) -> Result<ConvertedAudio> {
+    let stamp = SourceStamp::of(wav_path)?;
+    let cache_path = cache_path_for(wav_path);
+
+    if let Some(samples) = read_cache(&cache_path, &stamp) {
+        debug!(
+            "Using cached 16kHz samples for {:?} ({} samples)",
+            wav_path,
+            samples.len()
+        );
+        return Ok(ConvertedAudio {
+            audio: AudioBuffer::mono_16k(samples),
+            from_cache: true,
+        });
+    }
+
+    // Quantized the same way whether or not the cache is used, so both give equal samples
+    let quantized: Vec<i16> = convert_wav_to_16k(wav_path)?
+        .samples
+        .iter()
+        .map(|&sample| to_i16(sample))
+        .collect();
+
+    if let Err(e) = write_cache(&cache_path, &stamp, &quantized) {
+        warn!("Failed to write converted audio cache {:?}: {}", cache_path, e);
+    }
+
+    Ok(ConvertedAudio {
+        audio: AudioBuffer::mono_16k(quantized.into_iter().map(from_i16).collect()),
+        from_cache: false,
+    })
+}
+
+/// Removes the converted audio cache from a session folder, if there is one.
+pub(crate) fn remove_converted_audio(session_dir: &Path) -> Result<()> {
+    match fs::remove_file(session_dir.join(CONVERTED_AUDIO_FILENAME)) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn to_i16(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16
+}
+
+fn from_i16(sample: i16) -> f32 {
+    sample as f32 / i16::MAX as f32
+}
+
+/// Decodes a WAV file, or a FLAC archive, and converts it to 16 kHz mono f32 samples.
+///
+/// Multichannel audio (e.g. 5.1) is downmixed; layouts with more channels than
+/// a WAV file can describe are rejected.
+pub(crate) fn convert_wav_to_16k(wav_path: &Path) -> Result<AudioBuffer> {
+    Ok(read_audio(wav_path)?.to_mono_16k())
+}
+
+/// Decodes a recording into interleaved f32 samples at its own rate.
+///
+/// Files ending in `.flac` (archived recordings) are decoded as FLAC, anything
+/// else as WAV.
+pub(crate) fn read_audio(audio_path: &Path) -> Result<AudioBuffer> {
+    if is_flac(audio_path) {
+        read_flac(audio_path)
+    } else {
+        read_wav(audio_path)
+    }
+}
+
+/// Whether `path` names a FLAC file, such as an archived recording.
+pub(crate) fn is_flac(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("flac"))
+}
+
+fn read_flac(flac_path: &Path) -> Result<AudioBuffer> {
+    let file = open_shared_read(flac_path)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?}: {}", flac_path, e))?;
+    let mut reader = FlacReader::new(BufReader::new(file))
+        .map_err(|e| anyhow::anyhow!("Failed to read audio file {:?}: {}", flac_path, e))?;
+
+    let info = reader.streaminfo();
+    let channels = u16::try_from(info.channels)
+        .map_err(|_| anyhow::anyhow!("Audio file {:?} has too many channels", flac_path))?;
+    validate_channel_count(channels)
+        .map_err(|e| anyhow::anyhow!("Cannot read audio file {:?}: {}", flac_path, e))?;
+
+    let scale = ((1i64 << (info.bits_per_sample - 1)) - 1) as f32;
+    let interleaved = reader
+        .samples()
+        .map(|sample| sample.map(|sample| sample as f32 / scale))
+        .collect::<Result<Vec<f32>, _>>()
+        .map_err(|e| anyhow::anyhow!("Failed to decode audio file {:?}: {}", flac_path, e))?;
+
+    Ok(AudioBuffer::new(interleaved, info.sample_rate, channels))
+}
+
+fn read_wav(wav_path: &Path) -> Result<AudioBuffer> {
+    // Shared so playback or export reading the file at the same time doesn't block it
+    let file = open_shared_read(wav_path)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?}: {}", wav_path, e))?;
+    let reader = WavReader::new(BufReader::new(file))
+        .map_err(|e| anyhow::anyhow!("Failed to read audio file {:?}: {}", wav_path, e))?;
+
+    let spec = reader.spec();
+    validate_channel_count(spec.channels)
+        .map_err(|e| anyhow::anyhow!("Cannot read audio file {:?}: {}", wav_path, e))?;
+
+    let interleaved: Vec<f32> = match (spec.sample_format, spec.bits_per_sample) {
+        (SampleFormat::Int, 8) => reader
+            .into_samples::<i8>()
+            .filter_map(Result::ok)
+            .map(|sample| sample as f32 / i8::MAX as f32)
+            .collect(),
+        (SampleFormat::Int, 16) => reader
+            .into_samples::<i16>()
+            .filter_map(Result::ok)
+            .map(|sample| sample as f32 / i16::MAX as f32)
+            .collect(),
+        (SampleFormat::Float, 32) => reader.into_samples::<f32>().filter_map(Result::ok).collect(),
+        (format, bits) => {
+            return Err(anyhow::anyhow!(
+                "Unsupported audio format: {:?} {}-bit",
+                format,
+                bits
+            ));
+        }
+    };
+
+    Ok(AudioBuffer::new(
+        interleaved,
+        spec.sample_rate,
+        spec.channels,
+    ))
+}
+
+fn read_cache(cache_path: &Path, stamp: &SourceStamp) -> Option<Vec<f32>> {
+    let bytes = fs::read(cache_path).ok()?;
+    if bytes.len() < CACHE_HEADER_LEN || &bytes[0..4] != CACHE_MAGIC {
+        return None;
+    }
+
+    let cached = SourceStamp {
+        len: u64::from_le_bytes(bytes[4..12].try_into().ok()?),
+        modified_secs: u64::from_le_bytes(bytes[12..20].try_into().ok()?),
+        modified_nanos: u32::from_le_bytes(bytes[20..24].try_into().ok()?),
+    };
+    if &cached != stamp {
+        debug!("Converted audio cache {:?} is stale", cache_path);
+        return None;
+    }
+
+    let body = &bytes[CACHE_HEADER_LEN..];
+    if body.len() % 2 != 0 {
+        return None;
+    }
+
+    Some(
+        body.chunks_exact(2)
+            .map(|b| from_i16(i16::from_le_bytes([b[0], b[1]])))
+            .collect(),
+    )
+}
+
+fn write_cache(cache_path: &Path, stamp: &SourceStamp, samples: &[i16]) -> Result<()> {
+    let mut bytes = Vec::with_capacity(CACHE_HEADER_LEN + samples.len() * 2);
+    bytes.extend_from_slice(CACHE_MAGIC);
+    bytes.extend_from_slice(&stamp.len.to_le_bytes());
+    bytes.extend_from_slice(&stamp.modified_secs.to_le_bytes());
+    bytes.extend_from_slice(&stamp.modified_nanos.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    // Write to a temp file first so a crash never leaves a truncated cache behind
+    let tmp_path = cache_path.with_extension("bin.tmp");
+    fs::write(&tmp_path, &bytes)?;
+    fs::rename(&tmp_path, cache_path)?;
+    Ok(())
+}
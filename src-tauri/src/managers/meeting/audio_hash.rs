@@ -0,0 +1,33 @@
+//! Content hashes of session audio for spotting duplicates and corrupted copies.
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Bytes read from the file at a time while hashing.
+const HASH_READ_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Returns the SHA-256 of the file's bytes as lowercase hex.
+///
+/// The file is read in chunks, so hashing a long recording doesn't load it
+/// into memory.
+pub(crate) fn hash_file(path: &Path) -> Result<String> {
+    let mut file =
+        File::open(path).map_err(|e| anyhow::anyhow!("Failed to open {:?}: {}", path, e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; HASH_READ_CHUNK_BYTES];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
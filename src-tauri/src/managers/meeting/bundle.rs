@@ -0,0 +1,181 @@
+//! Shareable JSON bundles of sessions, for moving meetings to another instance.
+//!
+//! A bundle holds the session rows with their transcript and summary text
+//! inline. Audio is not included, so imported sessions have transcripts and
+//! summaries but nothing to play back or re-transcribe.
+
+use anyhow::Result;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+use super::db::{get_session, insert_imported_session, list_sessions};
+use super::files::write_atomic;
+use super::models::{MeetingSession, MeetingStatus};
+use super::search::index_transcript;
+
+/// Version of the bundle layout written by `export_sessions_bundle`.
+///
+/// Bump when a change would make older instances misread a bundle.
+pub(crate) const SESSION_BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// Error message given to imported sessions that weren't Completed, since
+/// without audio they can't be processed again.
+const IMPORTED_WITHOUT_AUDIO: &str = "Imported without audio";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionBundle {
+    schema_version: u32,
+    sessions: Vec<BundledSession>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundledSession {
+    session: MeetingSession,
+    #[serde(default)]
+    transcript: Option<String>,
+    #[serde(default)]
+    summary: Option<String>,
+}
+
+/// Reads an optional session file, logging rather than failing if it is gone.
+fn read_session_file(meetings_dir: &Path, rel_path: Option<&str>) -> Option<String> {
+    let rel_path = rel_path?;
+    match fs::read_to_string(meetings_dir.join(rel_path)) {
+        Ok(text) => Some(text),
+        Err(e) => {
+            warn!("Leaving {} out of the session bundle: {}", rel_path, e);
+            None
+        }
+    }
+}
+
+/// Serializes sessions with their transcript and summary text into one JSON document.
+///
+/// # Arguments
+/// * `ids` - Sessions to include; `None` includes every session
+///
+/// # Returns
+/// * `Err` - If one of `ids` doesn't exist
+pub(crate) fn export_sessions_bundle(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    ids: Option<&[String]>,
+) -> Result<String> {
+    let sessions = match ids {
+        Some(ids) => ids
+            .iter()
+            .map(|id| {
+                get_session(db_path, id)?
+                    .ok_or_else(|| anyhow::anyhow!("Session not found: {}", id))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => list_sessions(db_path)?,
+    };
+
+    let bundle = SessionBundle {
+        schema_version: SESSION_BUNDLE_SCHEMA_VERSION,
+        sessions: sessions
+            .into_iter()
+            .map(|session| BundledSession {
+                transcript: read_session_file(meetings_dir, session.transcript_path.as_deref()),
+                summary: read_session_file(meetings_dir, session.summary_path.as_deref()),
+                session,
+            })
+            .collect(),
+    };
+    Ok(serde_json::to_string_pretty(&bundle)?)
+}
+
+/// Recreates the sessions of a bundle written by `export_sessions_bundle`.
+///
+/// Each session gets a new id and folder, so importing the same bundle twice
+/// gives two copies rather than a conflict. Sessions that weren't Completed
+/// are imported as Failed, as there is no audio to finish them with.
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - The imported sessions, with their new ids
+/// * `Err` - If the JSON isn't a bundle, was written by a newer version, or
+///   writing a session fails
+pub(crate) fn import_sessions_bundle(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    json: &str,
+) -> Result<Vec<MeetingSession>> {
+    // Check the version before the layout, so newer bundles get a clear error
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let schema_version = value
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("Not a meeting sessions bundle: missing schema_version"))?;
+    if schema_version > SESSION_BUNDLE_SCHEMA_VERSION as u64 {
+        return Err(anyhow::anyhow!(
+            "Bundle was written by a newer version (schema {}, this version reads up to {})",
+            schema_version,
+            SESSION_BUNDLE_SCHEMA_VERSION
+        ));
+    }
+    let bundle: SessionBundle = serde_json::from_value(value)?;
+
+    let mut imported = Vec::with_capacity(bundle.sessions.len());
+    for bundled in bundle.sessions {
+        let original_id = bundled.session.id.clone();
+        let session = import_session(db_path, meetings_dir, bundled)?;
+        info!("Imported session {} as {}", original_id, session.id);
+        imported.push(session);
+    }
+    Ok(imported)
+}
+
+/// Writes one bundled session under a new id, removing its folder again if
+/// the session can't be stored.
+fn import_session(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    bundled: BundledSession,
+) -> Result<MeetingSession> {
+    let id = Uuid::new_v4().to_string();
+    let session_dir = meetings_dir.join(&id);
+    fs::create_dir_all(&session_dir)?;
+
+    let result: Result<MeetingSession> = (|| {
+        let mut session = MeetingSession {
+            id: id.clone(),
+            audio_path: None,
+            transcript_path: None,
+            summary_path: None,
+            archive_path: None,
+            archive_format: None,
+            ..bundled.session
+        };
+        if let Some(transcript) = &bundled.transcript {
+            write_atomic(&session_dir.join("transcript.txt"), transcript.as_bytes())?;
+            session.transcript_path = Some(format!("{}/transcript.txt", id));
+        }
+        if let Some(summary) = bundled.summary {
+            write_atomic(&session_dir.join("summary.md"), summary.as_bytes())?;
+            session.summary_path = Some(format!("{}/summary.md", id));
+        }
+        if session.status != MeetingStatus::Completed {
+            session.status = MeetingStatus::Failed;
+            session.error_message = Some(IMPORTED_WITHOUT_AUDIO.to_string());
+        }
+
+        insert_imported_session(db_path, &session)?;
+        if let Some(transcript) = &bundled.transcript {
+            if let Err(e) = index_transcript(db_path, &id, transcript) {
+                warn!(
+                    "Failed to index transcript of imported session {}: {}",
+                    id, e
+                );
+            }
+        }
+        Ok(session)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&session_dir);
+    }
+    result
+}
@@ -0,0 +1,141 @@
+//! Custom-word correction of meeting transcripts.
+//!
+//! New transcripts and live captions get the custom words applied, unless
+//! `meeting_apply_custom_words` is off. Re-running the correction over stored
+//! transcripts brings older ones in line after the word list changes.
+
+use anyhow::Result;
+use log::warn;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::db::list_sessions_with_status;
+use super::files::write_atomic;
+use super::models::{MeetingSession, MeetingStatus};
+use super::search::index_transcript;
+use super::transcript::{Segment, TranscriptionResult};
+use crate::audio_toolkit::apply_custom_words;
+use crate::settings::AppSettings;
+
+/// The custom words applied to new meeting transcripts.
+#[derive(Clone, Debug)]
+pub(crate) struct CustomWords {
+    words: Vec<String>,
+    threshold: f64,
+}
+
+impl CustomWords {
+    /// Returns None when `meeting_apply_custom_words` is off or there are no custom words.
+    pub fn from_settings(settings: &AppSettings) -> Option<Self> {
+        if !settings.meeting_apply_custom_words || settings.custom_words.is_empty() {
+            return None;
+        }
+        Some(Self {
+            words: settings.custom_words.clone(),
+            threshold: settings.word_correction_threshold,
+        })
+    }
+
+    pub fn correct_text(&self, text: &str) -> String {
+        correct_transcript(text, &self.words, self.threshold)
+    }
+
+    pub fn correct(&self, transcription: &TranscriptionResult) -> TranscriptionResult {
+        correct_transcription(transcription, &self.words, self.threshold)
+    }
+}
+
+/// Applies the custom words to `transcript` one line at a time.
+///
+/// `apply_custom_words` joins words with single spaces, so line breaks are
+/// kept by correcting each line separately, and a line whose words didn't
+/// change keeps its original spacing.
+fn correct_transcript(transcript: &str, custom_words: &[String], threshold: f64) -> String {
+    transcript
+        .split('\n')
+        .map(|line| {
+            let corrected = apply_custom_words(line, custom_words, threshold);
+            let original: Vec<&str> = line.split_whitespace().collect();
+            if corrected.split_whitespace().eq(original.iter().copied()) {
+                line.to_string()
+            } else {
+                corrected
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Applies the custom words to a new transcription's text and segments.
+pub(crate) fn correct_transcription(
+    transcription: &TranscriptionResult,
+    custom_words: &[String],
+    threshold: f64,
+) -> TranscriptionResult {
+    TranscriptionResult {
+        text: correct_transcript(&transcription.text, custom_words, threshold),
+        segments: transcription
+            .segments
+            .iter()
+            .map(|segment| Segment {
+                text: correct_transcript(&segment.text, custom_words, threshold),
+                ..segment.clone()
+            })
+            .collect(),
+        ..transcription.clone()
+    }
+}
+
+/// Re-applies the custom words to the transcript of every Completed session.
+///
+/// Transcripts are only rewritten when the correction changed them. A
+/// transcript that can't be read or written is logged and skipped.
+///
+/// # Returns
+/// The sessions whose transcript was rewritten
+pub(crate) fn reapply_custom_words(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    custom_words: &[String],
+    threshold: f64,
+) -> Result<Vec<MeetingSession>> {
+    if custom_words.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut changed = Vec::new();
+    for session in list_sessions_with_status(db_path, &MeetingStatus::Completed)? {
+        let rel_path = match session.transcript_path.as_ref() {
+            Some(rel_path) => rel_path,
+            None => continue,
+        };
+        let path = meetings_dir.join(rel_path);
+        let transcript = match fs::read_to_string(&path) {
+            Ok(transcript) => transcript,
+            Err(e) => {
+                warn!("Skipping custom words for session {}: {}", session.id, e);
+                continue;
+            }
+        };
+
+        let corrected = correct_transcript(&transcript, custom_words, threshold);
+        if corrected == transcript {
+            continue;
+        }
+        if let Err(e) = write_atomic(&path, corrected.as_bytes()) {
+            warn!(
+                "Failed to rewrite transcript of session {}: {}",
+                session.id, e
+            );
+            continue;
+        }
+        if let Err(e) = index_transcript(db_path, &session.id, &corrected) {
+            warn!(
+                "Failed to index transcript of session {}: {}",
+                session.id, e
+            );
+        }
+        changed.push(session);
+    }
+    Ok(changed)
+}
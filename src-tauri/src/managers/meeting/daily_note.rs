@@ -0,0 +1,60 @@
+//! Appending finished meetings to a combined note per day.
+//!
+//! Users who keep a daily log can have each completed meeting's transcript or
+//! summary appended to `daily/{YYYY-MM-DD}.md` under the meetings root, under
+//! a heading with the meeting's title and start time.
+
+use anyhow::Result;
+use chrono::{DateTime, TimeZone};
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::models::MeetingSession;
+
+/// Folder under the meetings root holding one Markdown note per day.
+pub(crate) const DAILY_NOTES_DIR: &str = "daily";
+
+/// Held while appending, so entries from sessions completing at the same time
+/// don't interleave.
+static DAILY_NOTE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Appends `body` to the note for the day `session` started on, in `tz`.
+///
+/// The entry is written with a single append under a lock, so concurrent
+/// completions end up one after the other rather than mixed together.
+///
+/// # Returns
+/// The path of the daily note
+pub(crate) fn append_to_daily_note<Tz>(
+    meetings_dir: &Path,
+    session: &MeetingSession,
+    body: &str,
+    tz: &Tz,
+) -> Result<PathBuf>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    let started = DateTime::from_timestamp(session.created_at, 0)
+        .ok_or_else(|| anyhow::anyhow!("Invalid session timestamp: {}", session.created_at))?
+        .with_timezone(tz);
+
+    let dir = meetings_dir.join(DAILY_NOTES_DIR);
+    let path = dir.join(format!("{}.md", started.format("%Y-%m-%d")));
+    let entry = format!(
+        "## {} ({})\n\n{}\n\n",
+        session.title,
+        started.format("%H:%M"),
+        body.trim()
+    );
+
+    let _guard = DAILY_NOTE_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+    fs::create_dir_all(&dir)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    file.write_all(entry.as_bytes())?;
+    file.sync_data()?;
+    Ok(path)
+}
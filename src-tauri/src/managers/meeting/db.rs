@@ -5,9 +5,10 @@ use anyhow::Result;
 use log::{debug, info};
 use rusqlite::{params, Connection, OptionalExtension};
 use rusqlite_migration::{Migrations, M};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-use super::models::{AudioSourceType, MeetingSession, MeetingStatus};
+use super::models::{AudioSourceType, MeetingSession, MeetingStatus, SessionLite, SessionPatch};
 
 /// Database migrations for meeting sessions.
 /// Each migration is applied in order. The library tracks which migrations
@@ -37,8 +38,64 @@ static MIGRATIONS: &[M] = &[
     M::up(
         "ALTER TABLE meeting_sessions ADD COLUMN template_id TEXT;",
     ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN archive_path TEXT;
+         ALTER TABLE meeting_sessions ADD COLUMN archive_format TEXT;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN category TEXT;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN sample_rate INTEGER;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN notes TEXT;
+         ALTER TABLE meeting_sessions ADD COLUMN starred INTEGER NOT NULL DEFAULT 0;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN audio_hash TEXT;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN last_play_position_ms INTEGER NOT NULL DEFAULT 0;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN system_audio_partial INTEGER NOT NULL DEFAULT 0;",
+    ),
+    M::up(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS meeting_transcripts_fts USING fts5(
+            session_id UNINDEXED,
+            content,
+            tokenize = 'unicode61 remove_diacritics 2'
+        );",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN status_note TEXT;",
+    ),
+    M::up(
+        "ALTER TABLE meeting_sessions ADD COLUMN transcript_removed INTEGER NOT NULL DEFAULT 0;",
+    ),
+    M::up(
+        "CREATE TABLE IF NOT EXISTS template_counters (
+            template_id TEXT PRIMARY KEY,
+            last_number INTEGER NOT NULL
+        );
+        INSERT INTO template_counters (template_id, last_number)
+            SELECT template_id, COUNT(*) FROM meeting_sessions
+            WHERE template_id IS NOT NULL GROUP BY template_id;",
+    ),
 ];
 
+/// Schema version the meetings database is at once all migrations are applied;
+/// each migration bumps `user_version` by one.
+pub(crate) const MEETING_DB_EXPECTED_VERSION: i32 = MIGRATIONS.len() as i32;
+
+/// Reads the current schema version (`PRAGMA user_version`) of the meetings database.
+pub(crate) fn get_db_version(db_path: &PathBuf) -> Result<i32> {
+    let conn = get_connection(db_path)?;
+    let version: i32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    Ok(version)
+}
+
 /// Initialize the meeting sessions database and run any pending migrations.
 ///
 /// This function opens (or creates) the database at the specified path and
@@ -95,6 +152,8 @@ pub(crate) fn status_to_string(status: &MeetingStatus) -> String {
     match status {
         MeetingStatus::Idle => "idle".to_string(),
         MeetingStatus::Recording => "recording".to_string(),
+        MeetingStatus::Paused => "paused".to_string(),
+        MeetingStatus::Recorded => "recorded".to_string(),
         MeetingStatus::Processing => "processing".to_string(),
         MeetingStatus::Completed => "completed".to_string(),
         MeetingStatus::Failed => "failed".to_string(),
@@ -105,6 +164,8 @@ pub(crate) fn status_to_string(status: &MeetingStatus) -> String {
 pub(crate) fn string_to_status(s: &str) -> MeetingStatus {
     match s {
         "recording" => MeetingStatus::Recording,
+        "paused" => MeetingStatus::Paused,
+        "recorded" => MeetingStatus::Recorded,
         "processing" => MeetingStatus::Processing,
         "completed" => MeetingStatus::Completed,
         "failed" => MeetingStatus::Failed,
@@ -145,6 +206,15 @@ pub(crate) fn row_to_session(row: &rusqlite::Row) -> rusqlite::Result<MeetingSes
         audio_source: string_to_audio_source(&audio_source_str),
         summary_path: row.get(9)?,
         template_id: row.get(10)?,
+        archive_path: row.get(11)?,
+        archive_format: row.get(12)?,
+        category: row.get(13)?,
+        sample_rate: row.get(14)?,
+        notes: row.get(15)?,
+        starred: row.get(16)?,
+        system_audio_partial: row.get(17)?,
+        status_note: row.get(18)?,
+        transcript_removed: row.get(19)?,
     })
 }
 
@@ -171,11 +241,71 @@ pub(crate) fn insert_session(db_path: &PathBuf, session: &MeetingSession) -> Res
     Ok(())
 }
 
+/// Creates a session record with every stored field, for sessions imported
+/// from another instance or split off an existing one.
+pub(crate) fn insert_imported_session(db_path: &PathBuf, session: &MeetingSession) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    conn.execute(
+        "INSERT INTO meeting_sessions (id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
+        params![
+            session.id,
+            session.title,
+            session.created_at,
+            session.duration,
+            status_to_string(&session.status),
+            session.audio_path,
+            session.transcript_path,
+            session.error_message,
+            audio_source_to_string(&session.audio_source),
+            session.summary_path,
+            session.template_id,
+            session.archive_path,
+            session.archive_format,
+            session.category,
+            session.sample_rate,
+            session.notes,
+            session.starred,
+            session.system_audio_partial,
+            session.status_note,
+            session.transcript_removed,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Records that a session's system audio capture failed part way through.
+pub(crate) fn set_system_audio_partial(db_path: &PathBuf, session_id: &str) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    conn.execute(
+        "UPDATE meeting_sessions SET system_audio_partial = 1 WHERE id = ?1",
+        params![session_id],
+    )?;
+    Ok(())
+}
+
+/// Sets or clears the informational note of a meeting session.
+pub(crate) fn set_session_status_note(
+    db_path: &PathBuf,
+    session_id: &str,
+    note: Option<&str>,
+) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET status_note = ?1 WHERE id = ?2",
+        params![note, session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
 /// Retrieves a meeting session by its ID.
 pub(crate) fn get_session(db_path: &PathBuf, session_id: &str) -> Result<Option<MeetingSession>> {
     let conn = get_connection(db_path)?;
     let mut stmt = conn.prepare(
-        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id
+        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
          FROM meeting_sessions WHERE id = ?1",
     )?;
     let session = stmt
@@ -184,6 +314,58 @@ pub(crate) fn get_session(db_path: &PathBuf, session_id: &str) -> Result<Option<
     Ok(session)
 }
 
+/// Retrieves only a session's id, title, status, and error message.
+pub(crate) fn get_session_lite(
+    db_path: &PathBuf,
+    session_id: &str,
+) -> Result<Option<SessionLite>> {
+    let conn = get_connection(db_path)?;
+    let session = conn
+        .query_row(
+            "SELECT id, title, status, error_message FROM meeting_sessions WHERE id = ?1",
+            params![session_id],
+            |row| {
+                let status: String = row.get(2)?;
+                Ok(SessionLite {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    status: string_to_status(&status),
+                    error_message: row.get(3)?,
+                })
+            },
+        )
+        .optional()?;
+    Ok(session)
+}
+
+/// Normalizes a stored relative path for comparison: forward slashes, no leading `./` or `/`.
+pub(crate) fn normalize_relative_path(path: &str) -> String {
+    let mut normalized = path.trim().replace('\\', "/");
+    while let Some(stripped) = normalized.strip_prefix("./") {
+        normalized = stripped.to_string();
+    }
+    normalized.trim_start_matches('/').to_string()
+}
+
+/// Retrieves the meeting session whose audio file is at the given relative path.
+///
+/// Separators are normalized on both sides, so `{id}\audio.wav` matches `{id}/audio.wav`.
+pub(crate) fn get_session_by_audio_path(
+    db_path: &PathBuf,
+    rel_path: &str,
+) -> Result<Option<MeetingSession>> {
+    let conn = get_connection(db_path)?;
+    let mut stmt = conn.prepare(
+        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
+         FROM meeting_sessions WHERE replace(audio_path, '\\', '/') = ?1
+         ORDER BY created_at DESC LIMIT 1",
+    )?;
+    let session = stmt
+        .query_row(params![normalize_relative_path(rel_path)], |row| row_to_session(row))
+        .optional()?;
+    Ok(session)
+}
+
 /// Updates the status of a meeting session.
 pub(crate) fn update_session_status(
     db_path: &PathBuf,
@@ -225,11 +407,24 @@ pub(crate) fn update_session_status_with_error(
     Ok(())
 }
 
+/// Clears the error message of a meeting session, leaving its status unchanged.
+pub(crate) fn clear_session_error(db_path: &PathBuf, session_id: &str) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET error_message = NULL WHERE id = ?1",
+        params![session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
 /// Lists all meeting sessions, ordered by creation time (newest first).
 pub(crate) fn list_sessions(db_path: &PathBuf) -> Result<Vec<MeetingSession>> {
     let conn = get_connection(db_path)?;
     let mut stmt = conn.prepare(
-        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id
+        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
          FROM meeting_sessions ORDER BY created_at DESC",
     )?;
     let sessions = stmt
@@ -239,6 +434,152 @@ pub(crate) fn list_sessions(db_path: &PathBuf) -> Result<Vec<MeetingSession>> {
     Ok(sessions)
 }
 
+/// Counts sessions per status with a single grouped query.
+///
+/// Every status is present in the result, with zero for statuses no session has.
+pub(crate) fn get_status_counts(db_path: &PathBuf) -> Result<HashMap<MeetingStatus, u64>> {
+    let mut counts: HashMap<MeetingStatus, u64> = MeetingStatus::ALL
+        .iter()
+        .map(|status| (status.clone(), 0))
+        .collect();
+
+    let conn = get_connection(db_path)?;
+    let mut stmt = conn.prepare("SELECT status, COUNT(*) FROM meeting_sessions GROUP BY status")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+    })?;
+    for row in rows {
+        let (status, count) = row?;
+        // Unknown strings map to Idle, so add rather than overwrite
+        *counts.entry(string_to_status(&status)).or_insert(0) += count.max(0) as u64;
+    }
+    Ok(counts)
+}
+
+/// Lists sessions with the given category, ordered by creation time (newest first).
+pub(crate) fn list_sessions_by_category(
+    db_path: &PathBuf,
+    category: &str,
+) -> Result<Vec<MeetingSession>> {
+    let conn = get_connection(db_path)?;
+    let mut stmt = conn.prepare(
+        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
+         FROM meeting_sessions WHERE category = ?1 ORDER BY created_at DESC",
+    )?;
+    let sessions = stmt
+        .query_map(params![category], |row| row_to_session(row))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(sessions)
+}
+
+/// Lists sessions in the given status, ordered by creation time (newest first).
+pub(crate) fn list_sessions_with_status(
+    db_path: &PathBuf,
+    status: &MeetingStatus,
+) -> Result<Vec<MeetingSession>> {
+    let conn = get_connection(db_path)?;
+    let mut stmt = conn.prepare(
+        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
+         FROM meeting_sessions WHERE status = ?1 ORDER BY created_at DESC",
+    )?;
+    let sessions = stmt
+        .query_map(params![status_to_string(status)], |row| row_to_session(row))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(sessions)
+}
+
+/// Sets or clears (with `None`) the category of a meeting session.
+pub(crate) fn update_session_category(
+    db_path: &PathBuf,
+    session_id: &str,
+    category: Option<&str>,
+) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET category = ?1 WHERE id = ?2",
+        params![category, session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
+/// Stores a session's duration and the sample rate it was derived from.
+pub(crate) fn update_session_duration(
+    db_path: &PathBuf,
+    session_id: &str,
+    duration: i64,
+    sample_rate: u32,
+) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET duration = ?1, sample_rate = ?2 WHERE id = ?3",
+        params![duration, sample_rate, session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
+/// Applies every field present in `patch` to a session in a single transaction.
+///
+/// Either all fields are written or, if the session doesn't exist or any
+/// update fails, none are. `patch` is expected to be validated already.
+pub(crate) fn update_session_fields(
+    db_path: &PathBuf,
+    session_id: &str,
+    patch: &SessionPatch,
+) -> Result<()> {
+    let mut conn = get_connection(db_path)?;
+    let tx = conn.transaction()?;
+
+    let exists = tx
+        .query_row(
+            "SELECT 1 FROM meeting_sessions WHERE id = ?1",
+            params![session_id],
+            |_| Ok(()),
+        )
+        .optional()?
+        .is_some();
+    if !exists {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+
+    if let Some(title) = patch.title.as_deref() {
+        tx.execute(
+            "UPDATE meeting_sessions SET title = ?1 WHERE id = ?2",
+            params![title.trim(), session_id],
+        )?;
+    }
+    if let Some(notes) = patch.notes.as_deref() {
+        let notes = Some(notes).filter(|n| !n.trim().is_empty());
+        tx.execute(
+            "UPDATE meeting_sessions SET notes = ?1 WHERE id = ?2",
+            params![notes, session_id],
+        )?;
+    }
+    if let Some(category) = patch.category.as_deref() {
+        let category = Some(category).filter(|c| !c.is_empty());
+        tx.execute(
+            "UPDATE meeting_sessions SET category = ?1 WHERE id = ?2",
+            params![category, session_id],
+        )?;
+    }
+    if let Some(starred) = patch.starred {
+        tx.execute(
+            "UPDATE meeting_sessions SET starred = ?1 WHERE id = ?2",
+            params![starred, session_id],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
 /// Deletes a meeting session record from the database.
 pub(crate) fn delete_session_record(db_path: &PathBuf, session_id: &str) -> Result<()> {
     let conn = get_connection(db_path)?;
@@ -246,6 +587,10 @@ pub(crate) fn delete_session_record(db_path: &PathBuf, session_id: &str) -> Resu
         "DELETE FROM meeting_sessions WHERE id = ?1",
         params![session_id],
     )?;
+    conn.execute(
+        "DELETE FROM meeting_transcripts_fts WHERE session_id = ?1",
+        params![session_id],
+    )?;
     Ok(())
 }
 
@@ -283,6 +628,22 @@ pub(crate) fn update_session_template_id(
     Ok(())
 }
 
+/// Takes the next number for a session started from a template.
+///
+/// The stored counter only increases, so deleting sessions never hands a
+/// number out twice.
+pub(crate) fn next_template_number(db_path: &PathBuf, template_id: &str) -> Result<usize> {
+    let conn = get_connection(db_path)?;
+    let number: i64 = conn.query_row(
+        "INSERT INTO template_counters (template_id, last_number) VALUES (?1, 1)
+         ON CONFLICT(template_id) DO UPDATE SET last_number = last_number + 1
+         RETURNING last_number",
+        params![template_id],
+        |row| row.get(0),
+    )?;
+    Ok(number as usize)
+}
+
 /// Updates the summary path of a meeting session.
 pub(crate) fn update_session_summary_path(
     db_path: &PathBuf,
@@ -310,7 +671,7 @@ pub(crate) fn update_session_audio(
 ) -> Result<()> {
     let conn = get_connection(db_path)?;
     conn.execute(
-        "UPDATE meeting_sessions SET audio_path = ?1, duration = ?2, status = ?3 WHERE id = ?4",
+        "UPDATE meeting_sessions SET audio_path = ?1, duration = ?2, status = ?3, audio_hash = NULL WHERE id = ?4",
         params![audio_path, duration, status_to_string(status), session_id],
     )?;
     Ok(())
@@ -331,11 +692,129 @@ pub(crate) fn update_session_transcript(
     Ok(())
 }
 
+/// Records the archived copy of a session's audio.
+///
+/// When `replace_audio_path` is true, `audio_path` is pointed at the archive as well,
+/// because the original WAV has been removed.
+pub(crate) fn update_session_archive(
+    db_path: &PathBuf,
+    session_id: &str,
+    archive_path: &str,
+    archive_format: &str,
+    replace_audio_path: bool,
+) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = if replace_audio_path {
+        conn.execute(
+            "UPDATE meeting_sessions SET archive_path = ?1, archive_format = ?2, audio_path = ?1, audio_hash = NULL WHERE id = ?3",
+            params![archive_path, archive_format, session_id],
+        )?
+    } else {
+        conn.execute(
+            "UPDATE meeting_sessions SET archive_path = ?1, archive_format = ?2 WHERE id = ?3",
+            params![archive_path, archive_format, session_id],
+        )?
+    };
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
+/// Reads the cached hash of a session's audio, if one has been computed.
+pub(crate) fn get_audio_hash(db_path: &PathBuf, session_id: &str) -> Result<Option<String>> {
+    let conn = get_connection(db_path)?;
+    let hash = conn
+        .query_row(
+            "SELECT audio_hash FROM meeting_sessions WHERE id = ?1",
+            params![session_id],
+            |row| row.get::<_, Option<String>>(0),
+        )
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+    Ok(hash)
+}
+
+/// Caches the hash of a session's audio. Changing the audio clears it again.
+pub(crate) fn set_audio_hash(db_path: &PathBuf, session_id: &str, hash: &str) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET audio_hash = ?1 WHERE id = ?2",
+        params![hash, session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
+/// Reads where playback of a session's audio was last left, in milliseconds.
+pub(crate) fn get_play_position(db_path: &PathBuf, session_id: &str) -> Result<u64> {
+    let conn = get_connection(db_path)?;
+    let position = conn
+        .query_row(
+            "SELECT last_play_position_ms FROM meeting_sessions WHERE id = ?1",
+            params![session_id],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+    Ok(position.max(0) as u64)
+}
+
+/// Stores where playback of a session's audio was left, in milliseconds.
+pub(crate) fn set_play_position(
+    db_path: &PathBuf,
+    session_id: &str,
+    position_ms: u64,
+) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET last_play_position_ms = ?1 WHERE id = ?2",
+        params![position_ms.min(i64::MAX as u64) as i64, session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
+/// Clears audio_path and the archive columns after the audio has been deleted.
+pub(crate) fn clear_session_audio(db_path: &PathBuf, session_id: &str) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET audio_path = NULL, archive_path = NULL, archive_format = NULL, audio_hash = NULL WHERE id = ?1",
+        params![session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    Ok(())
+}
+
+/// Clears transcript_path after the transcript file has been deleted, marking
+/// the session as having its transcript removed on purpose.
+pub(crate) fn clear_session_transcript(db_path: &PathBuf, session_id: &str) -> Result<()> {
+    let conn = get_connection(db_path)?;
+    let rows = conn.execute(
+        "UPDATE meeting_sessions SET transcript_path = NULL, transcript_removed = 1 WHERE id = ?1",
+        params![session_id],
+    )?;
+    if rows == 0 {
+        return Err(anyhow::anyhow!("Session not found: {}", session_id));
+    }
+    conn.execute(
+        "DELETE FROM meeting_transcripts_fts WHERE session_id = ?1",
+        params![session_id],
+    )?;
+    Ok(())
+}
+
 /// Finds sessions in Recording or Interrupted status (for recovery on restart).
 pub(crate) fn find_interrupted_sessions(db_path: &PathBuf) -> Result<Vec<MeetingSession>> {
     let conn = get_connection(db_path)?;
     let mut stmt = conn.prepare(
-        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id
+        "SELECT id, title, created_at, duration, status, audio_path, transcript_path, audio_source, error_message, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
          FROM meeting_sessions WHERE status IN ('recording', 'interrupted') ORDER BY created_at DESC",
     )?;
     let sessions = stmt
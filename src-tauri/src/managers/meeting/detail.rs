@@ -0,0 +1,106 @@
+//! One-shot bundle of everything the meeting detail view shows.
+
+use serde::Serialize;
+use specta::Type;
+use std::fs;
+use std::path::Path;
+
+use super::direction::{transcript_direction, TextDirection};
+use super::export::session_storage_bytes;
+use super::models::MeetingSession;
+use super::transcript::{read_transcript_details, Segment};
+
+/// Simple statistics about a session's outputs.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct SessionStats {
+    /// Number of whitespace-separated words in the transcript (0 without one)
+    pub word_count: usize,
+    /// Number of characters in the transcript (0 without one)
+    pub character_count: usize,
+    /// Size of the audio file in bytes, if it exists
+    pub audio_bytes: Option<u64>,
+    /// Total size of the session folder in bytes
+    pub storage_bytes: u64,
+}
+
+/// A session together with its files' contents, for the detail view.
+///
+/// Parts that don't exist (yet) are `None` rather than errors.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct SessionDetail {
+    pub session: MeetingSession,
+    /// Absolute path to the audio file, if it exists on disk
+    pub audio_path: Option<String>,
+    pub transcript: Option<String>,
+    /// Timed segments of the transcript, empty if none were stored
+    pub segments: Vec<Segment>,
+    pub summary: Option<String>,
+    /// Direction of the transcript text, if it can be determined
+    pub text_direction: Option<TextDirection>,
+    /// Convenience flag for `text_direction == rtl`
+    pub is_rtl: bool,
+    pub stats: SessionStats,
+}
+
+/// Reads a file referenced by a session, relative to the meetings directory.
+///
+/// Missing files and paths that try to leave the meetings directory yield `None`.
+fn read_session_file(meetings_dir: &Path, rel_path: Option<&String>) -> Option<String> {
+    let rel_path = Path::new(rel_path?);
+    if rel_path.is_absolute()
+        || rel_path
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return None;
+    }
+    fs::read_to_string(meetings_dir.join(rel_path)).ok()
+}
+
+/// Assembles the detail bundle for a session from the files on disk.
+///
+/// `language` is the language of the transcript, used to derive the text direction.
+pub(crate) fn build_session_detail(
+    meetings_dir: &Path,
+    session: MeetingSession,
+    language: &str,
+) -> SessionDetail {
+    let transcript = read_session_file(meetings_dir, session.transcript_path.as_ref());
+    let summary = read_session_file(meetings_dir, session.summary_path.as_ref());
+    let segments = read_transcript_details(meetings_dir, &session.id)
+        .ok()
+        .flatten()
+        .map_or_else(Vec::new, |details| details.segments);
+
+    let audio_full_path = session
+        .audio_path
+        .as_ref()
+        .map(|path| meetings_dir.join(path))
+        .filter(|path| path.is_file());
+    let audio_bytes = audio_full_path
+        .as_ref()
+        .and_then(|path| fs::metadata(path).ok())
+        .map(|meta| meta.len());
+
+    let stats = SessionStats {
+        word_count: transcript
+            .as_deref()
+            .map_or(0, |text| text.split_whitespace().count()),
+        character_count: transcript.as_deref().map_or(0, |text| text.chars().count()),
+        audio_bytes,
+        storage_bytes: session_storage_bytes(meetings_dir, &session.id),
+    };
+
+    let text_direction = transcript_direction(language, transcript.as_deref());
+
+    SessionDetail {
+        audio_path: audio_full_path.map(|path| path.to_string_lossy().into_owned()),
+        transcript,
+        segments,
+        summary,
+        is_rtl: text_direction.map_or(false, TextDirection::is_rtl),
+        text_direction,
+        stats,
+        session,
+    }
+}
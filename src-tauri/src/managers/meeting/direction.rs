@@ -0,0 +1,89 @@
+//! Text direction of meeting transcripts, for right-to-left rendering.
+//!
+//! Direction is derived on demand from the transcription language, falling back
+//! to the script of the transcript itself when the language is auto-detected.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Writing direction of a transcript.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+impl TextDirection {
+    pub fn is_rtl(self) -> bool {
+        self == TextDirection::Rtl
+    }
+}
+
+/// Language codes (ISO 639-1, as used by Whisper) written right-to-left.
+const RTL_LANGUAGES: &[&str] = &[
+    "ar", // Arabic
+    "dv", // Divehi
+    "fa", // Persian
+    "he", // Hebrew
+    "iw", // Hebrew (legacy code)
+    "ps", // Pashto
+    "sd", // Sindhi
+    "ug", // Uyghur
+    "ur", // Urdu
+    "yi", // Yiddish
+];
+
+/// Returns the direction for a language code such as "he" or "ar-EG".
+///
+/// Returns `None` for "auto" or an empty code, since the language is unknown.
+pub(crate) fn direction_for_language(language: &str) -> Option<TextDirection> {
+    let primary = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+
+    if primary.is_empty() || primary == "auto" {
+        return None;
+    }
+
+    if RTL_LANGUAGES.contains(&primary.as_str()) {
+        Some(TextDirection::Rtl)
+    } else {
+        Some(TextDirection::Ltr)
+    }
+}
+
+fn is_rtl_char(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}' // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic
+        | '\u{FB1D}'..='\u{FDFF}' // Hebrew and Arabic presentation forms A
+        | '\u{FE70}'..='\u{FEFF}' // Arabic presentation forms B
+    )
+}
+
+/// Returns the direction of the first strongly directional character in `text`.
+pub(crate) fn direction_for_text(text: &str) -> Option<TextDirection> {
+    text.chars().find_map(|c| {
+        if is_rtl_char(c) {
+            Some(TextDirection::Rtl)
+        } else if c.is_alphabetic() {
+            Some(TextDirection::Ltr)
+        } else {
+            None
+        }
+    })
+}
+
+/// Resolves a transcript's direction from the transcription language, or from
+/// the transcript text when the language is auto-detected.
+///
+/// Returns `None` if neither gives an answer (auto language and no transcript text).
+pub(crate) fn transcript_direction(
+    language: &str,
+    transcript: Option<&str>,
+) -> Option<TextDirection> {
+    direction_for_language(language).or_else(|| transcript.and_then(direction_for_text))
+}
@@ -0,0 +1,80 @@
+//! Free disk space, and how much more audio fits in it.
+
+use anyhow::Result;
+use hound::WavSpec;
+use std::path::Path;
+
+/// Format of the WAV files meetings are recorded to.
+pub(crate) const MEETING_WAV_SPEC: WavSpec = WavSpec {
+    channels: 1,
+    sample_rate: 16000,
+    bits_per_sample: 16,
+    sample_format: hound::SampleFormat::Int,
+};
+
+/// Bytes of audio data written per second of recording in `spec`.
+pub(crate) fn bytes_per_second(spec: &WavSpec) -> u64 {
+    spec.sample_rate as u64 * spec.channels as u64 * (spec.bits_per_sample as u64).div_ceil(8)
+}
+
+/// Whole minutes of audio in `spec` that fit into `free_bytes`.
+pub(crate) fn remaining_recording_minutes(free_bytes: u64, spec: &WavSpec) -> u64 {
+    match bytes_per_second(spec) {
+        0 => 0,
+        rate => free_bytes / rate / 60,
+    }
+}
+
+/// Parses the available space (in bytes) from `df -Pk` output.
+pub(crate) fn parse_df_available(output: &str) -> Option<u64> {
+    // POSIX format: header line, then "<fs> <blocks> <used> <available> <capacity> <mount>"
+    let line = output.lines().nth(1)?;
+    let available_kb: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+    Some(available_kb * 1024)
+}
+
+/// Returns the free space in bytes available to the current user on the volume holding `dir`.
+#[cfg(unix)]
+pub(crate) fn available_space(dir: &Path) -> Result<u64> {
+    let output = std::process::Command::new("df")
+        .arg("-Pk")
+        .arg(dir)
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run df: {}", e))?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "df failed for {:?}: {}",
+            dir,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    parse_df_available(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| anyhow::anyhow!("Could not parse df output for {:?}", dir))
+}
+
+#[cfg(windows)]
+pub(crate) fn available_space(dir: &Path) -> Result<u64> {
+    use windows::core::HSTRING;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let mut free_bytes: u64 = 0;
+    unsafe {
+        GetDiskFreeSpaceExW(
+            &HSTRING::from(dir.as_os_str()),
+            Some(&mut free_bytes),
+            None,
+            None,
+        )
+    }
+    .map_err(|e| anyhow::anyhow!("Failed to query free space for {:?}: {}", dir, e))?;
+    Ok(free_bytes)
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn available_space(dir: &Path) -> Result<u64> {
+    Err(anyhow::anyhow!(
+        "Free space lookup is not supported on this platform ({:?})",
+        dir
+    ))
+}
@@ -0,0 +1,306 @@
+//! Export of meeting data: CSV metadata for analysis in a spreadsheet, and SRT
+//! subtitles of a session's timed transcript segments.
+
+use anyhow::Result;
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use super::db::status_to_string;
+use super::files::{commit_temp_file, temp_path_for, write_atomic};
+use super::models::MeetingSession;
+use super::transcript::{read_transcript_details, Segment};
+
+/// Number of SRT cues written between flushes of the output file.
+const SRT_FLUSH_INTERVAL_CUES: usize = 200;
+
+/// Formats meetings can be exported in.
+///
+/// Adding a variant forces it to be handled by both the format list shown to the
+/// UI and `write_sessions_export`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// One row of metadata per session
+    Csv,
+    /// Subtitles of a single session's timed transcript
+    Srt,
+}
+
+impl ExportFormat {
+    /// Every supported format, in the order the UI should list them.
+    pub const ALL: &'static [ExportFormat] = &[ExportFormat::Csv, ExportFormat::Srt];
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV (session list)",
+            ExportFormat::Srt => "SRT (subtitles)",
+        }
+    }
+
+    /// File extension without the leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Srt => "srt",
+        }
+    }
+
+    /// Whether the format needs timestamped transcript segments, which only
+    /// some sessions have stored.
+    pub fn requires_segments(self) -> bool {
+        match self {
+            ExportFormat::Csv => false,
+            ExportFormat::Srt => true,
+        }
+    }
+}
+
+/// Description of an export format for the UI's format picker.
+#[derive(Clone, Debug, Serialize, Type, PartialEq, Eq)]
+pub struct ExportFormatInfo {
+    pub format: ExportFormat,
+    pub display_name: String,
+    pub extension: String,
+    pub requires_segments: bool,
+}
+
+impl From<ExportFormat> for ExportFormatInfo {
+    fn from(format: ExportFormat) -> Self {
+        Self {
+            format,
+            display_name: format.display_name().to_string(),
+            extension: format.extension().to_string(),
+            requires_segments: format.requires_segments(),
+        }
+    }
+}
+
+/// Returns every supported export format.
+pub fn list_export_formats() -> Vec<ExportFormatInfo> {
+    ExportFormat::ALL.iter().copied().map(Into::into).collect()
+}
+
+/// Column headers of the exported CSV, in order.
+pub(crate) const CSV_HEADER: &[&str] = &[
+    "id",
+    "title",
+    "created_at",
+    "duration_seconds",
+    "sample_rate",
+    "status",
+    "has_audio",
+    "has_transcript",
+    "storage_bytes",
+];
+
+/// Escapes a single CSV field per RFC 4180.
+///
+/// Fields containing a comma, double quote, CR, or LF are wrapped in double
+/// quotes, with embedded quotes doubled.
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Total size in bytes of all files in a session's folder (0 if it is missing).
+pub(crate) fn session_storage_bytes(meetings_dir: &Path, session_id: &str) -> u64 {
+    fn dir_size(dir: &Path) -> u64 {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return 0,
+        };
+        entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| match entry.metadata() {
+                Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+                Ok(meta) => meta.len(),
+                Err(_) => 0,
+            })
+            .sum()
+    }
+
+    dir_size(&meetings_dir.join(session_id))
+}
+
+fn iso8601(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Renders sessions as CSV text (header plus one row per session, CRLF line endings).
+pub(crate) fn sessions_to_csv(meetings_dir: &Path, sessions: &[MeetingSession]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+
+    for session in sessions {
+        let row = [
+            csv_field(&session.id),
+            csv_field(&session.title),
+            iso8601(session.created_at),
+            session.duration.map(|d| d.to_string()).unwrap_or_default(),
+            session
+                .sample_rate
+                .map(|r| r.to_string())
+                .unwrap_or_default(),
+            status_to_string(&session.status),
+            session.audio_path.is_some().to_string(),
+            session.transcript_path.is_some().to_string(),
+            session_storage_bytes(meetings_dir, &session.id).to_string(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+/// Writes the sessions as CSV to `dest`, replacing any existing file.
+///
+/// # Returns
+/// The number of sessions written
+pub(crate) fn write_sessions_csv(
+    meetings_dir: &Path,
+    sessions: &[MeetingSession],
+    dest: &Path,
+) -> Result<usize> {
+    let csv = sessions_to_csv(meetings_dir, sessions);
+    write_atomic(dest, csv.as_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to write CSV export {:?}: {}", dest, e))?;
+    Ok(sessions.len())
+}
+
+/// Writes the sessions to `dest` in the given format, replacing any existing file.
+///
+/// # Returns
+/// The number of sessions written
+pub(crate) fn write_sessions_export(
+    meetings_dir: &Path,
+    sessions: &[MeetingSession],
+    format: ExportFormat,
+    dest: &Path,
+) -> Result<usize> {
+    match format {
+        ExportFormat::Csv => write_sessions_csv(meetings_dir, sessions, dest),
+        ExportFormat::Srt => write_session_srt(meetings_dir, sessions, dest),
+    }
+}
+
+/// Writes the timed transcript of the only session in `sessions` as SRT.
+fn write_session_srt(
+    meetings_dir: &Path,
+    sessions: &[MeetingSession],
+    dest: &Path,
+) -> Result<usize> {
+    let [session] = sessions else {
+        return Err(anyhow::anyhow!(
+            "SRT export covers a single session, got {}",
+            sessions.len()
+        ));
+    };
+    let segments = read_transcript_details(meetings_dir, &session.id)?
+        .map(|details| details.segments)
+        .unwrap_or_default();
+    if segments.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Session {} has no timed transcript segments",
+            session.id
+        ));
+    }
+    write_srt_to_path(&segments, dest)?;
+    Ok(1)
+}
+
+/// Formats seconds as an SRT timestamp (`HH:MM:SS,mmm`).
+pub(crate) fn srt_timestamp(secs: f32) -> String {
+    let total_ms = (secs.max(0.0) as f64 * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02},{:03}",
+        total_ms / 3_600_000,
+        total_ms / 60_000 % 60,
+        total_ms / 1000 % 60,
+        total_ms % 1000
+    )
+}
+
+/// Writes segments to `writer` as SRT cues, one at a time, numbered from 1.
+///
+/// Segments without text are skipped. The writer is flushed every
+/// `SRT_FLUSH_INTERVAL_CUES` cues and once at the end.
+///
+/// # Returns
+/// The number of cues written
+pub(crate) fn write_srt<'a, W, I>(writer: &mut W, segments: I) -> std::io::Result<usize>
+where
+    W: Write,
+    I: IntoIterator<Item = &'a Segment>,
+{
+    let mut cues = 0;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        cues += 1;
+        write!(
+            writer,
+            "{}\n{} --> {}\n{}\n\n",
+            cues,
+            srt_timestamp(segment.start),
+            srt_timestamp(segment.end.max(segment.start)),
+            text
+        )?;
+        if cues % SRT_FLUSH_INTERVAL_CUES == 0 {
+            writer.flush()?;
+        }
+    }
+    writer.flush()?;
+    Ok(cues)
+}
+
+/// Renders segments as SRT text in memory, for short transcripts.
+pub(crate) fn segments_to_srt(segments: &[Segment]) -> String {
+    let mut srt = Vec::new();
+    // Writing to a Vec can't fail
+    let _ = write_srt(&mut srt, segments);
+    String::from_utf8_lossy(&srt).into_owned()
+}
+
+/// Streams segments to `dest` as SRT, replacing any existing file.
+///
+/// Cues go to disk as they are written rather than being collected into one
+/// string first, so memory use doesn't grow with the transcript's length.
+/// Like `write_atomic`, they are written to a temp file that replaces `dest`
+/// only once complete, so a failed export never leaves a truncated file.
+///
+/// # Returns
+/// The number of cues written
+pub(crate) fn write_srt_to_path(segments: &[Segment], dest: &Path) -> Result<usize> {
+    let tmp_path = temp_path_for(dest)?;
+    let written = File::create(&tmp_path).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        let cues = write_srt(&mut writer, segments)?;
+        writer.into_inner()?.sync_all()?;
+        Ok(cues)
+    });
+    let cues = match written {
+        Ok(cues) => cues,
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(anyhow::anyhow!(
+                "Failed to write SRT export {:?}: {}",
+                dest,
+                e
+            ));
+        }
+    };
+    commit_temp_file(&tmp_path, dest)?;
+    Ok(cues)
+}
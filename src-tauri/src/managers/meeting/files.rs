@@ -0,0 +1,184 @@
+//! File helpers for meeting outputs.
+
+use anyhow::Result;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// Stable file at the meetings root mirroring the most recent completed transcript.
+pub(crate) const LATEST_TRANSCRIPT_FILENAME: &str = "latest_transcript.txt";
+
+/// Returns the temp file used while atomically writing `path` (e.g. `transcript.txt.tmp`).
+pub(crate) fn temp_path_for(path: &Path) -> Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Invalid file path: {:?}", path))?
+        .to_string_lossy();
+    Ok(path.with_file_name(format!("{}.tmp", file_name)))
+}
+
+/// Writes `contents` to the temp file for `path` and flushes it to disk.
+///
+/// The target itself is untouched until [`commit_temp_file`] renames the temp file over it.
+pub(crate) fn write_temp_file(path: &Path, contents: &[u8]) -> Result<PathBuf> {
+    let tmp_path = temp_path_for(path)?;
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    Ok(tmp_path)
+}
+
+/// Renames a temp file written by [`write_temp_file`] over `path` and syncs the
+/// parent directory so the rename itself survives a crash.
+pub(crate) fn commit_temp_file(tmp_path: &Path, path: &Path) -> Result<()> {
+    if let Err(e) = fs::rename(tmp_path, path) {
+        let _ = fs::remove_file(tmp_path);
+        return Err(e.into());
+    }
+    sync_parent_dir(path);
+    Ok(())
+}
+
+/// Fsyncs the directory containing `path`. Directories can't be opened for
+/// syncing on Windows, so this is a no-op there.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        if let Err(e) = File::open(parent).and_then(|dir| dir.sync_all()) {
+            log::warn!("Failed to sync directory {:?}: {}", parent, e);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) {}
+
+/// Attempts made to open a file another process has locked before giving up.
+const SHARED_OPEN_ATTEMPTS: u32 = 5;
+
+/// Wait between attempts to open a locked file.
+const SHARED_OPEN_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Opens `path` read-only, letting other handles (playback, export) read,
+/// write, or delete it at the same time.
+///
+/// On Windows a file another process opened without sharing fails with a
+/// sharing or lock violation; those are retried briefly before the error is
+/// returned.
+pub(crate) fn open_shared_read(path: &Path) -> io::Result<File> {
+    let mut attempt = 1;
+    loop {
+        match shared_read_options().open(path) {
+            Ok(file) => return Ok(file),
+            Err(e) if attempt < SHARED_OPEN_ATTEMPTS && is_transient_lock_error(&e) => {
+                log::debug!(
+                    "{:?} is locked (attempt {}/{}), retrying: {}",
+                    path,
+                    attempt,
+                    SHARED_OPEN_ATTEMPTS,
+                    e
+                );
+                thread::sleep(SHARED_OPEN_RETRY_DELAY);
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(windows)]
+fn shared_read_options() -> fs::OpenOptions {
+    use std::os::windows::fs::OpenOptionsExt;
+    use windows::Win32::Storage::FileSystem::{
+        FILE_SHARE_DELETE, FILE_SHARE_READ, FILE_SHARE_WRITE,
+    };
+
+    let mut options = fs::OpenOptions::new();
+    options
+        .read(true)
+        .share_mode((FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE).0);
+    options
+}
+
+#[cfg(not(windows))]
+fn shared_read_options() -> fs::OpenOptions {
+    let mut options = fs::OpenOptions::new();
+    options.read(true);
+    options
+}
+
+#[cfg(windows)]
+fn is_transient_lock_error(e: &io::Error) -> bool {
+    use windows::Win32::Foundation::{ERROR_LOCK_VIOLATION, ERROR_SHARING_VIOLATION};
+
+    let code = e.raw_os_error();
+    code == Some(ERROR_SHARING_VIOLATION.0 as i32) || code == Some(ERROR_LOCK_VIOLATION.0 as i32)
+}
+
+/// Other platforms don't use mandatory locks, so no open error goes away by waiting.
+#[cfg(not(windows))]
+fn is_transient_lock_error(_e: &io::Error) -> bool {
+    false
+}
+
+/// Largest range `read_file_range` returns in one call, so a single IPC
+/// response stays a reasonable size.
+pub(crate) const MAX_FILE_RANGE_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Reads `len` bytes of `path` starting at `offset`.
+///
+/// The range must lie within the file and be at most `MAX_FILE_RANGE_BYTES`
+/// long; a range running past the end of the file is rejected rather than
+/// shortened, so callers learn the real size through `Err`.
+pub(crate) fn read_file_range(path: &Path, offset: u64, len: u64) -> Result<Vec<u8>> {
+    if len > MAX_FILE_RANGE_BYTES {
+        return Err(anyhow::anyhow!(
+            "Requested {} bytes, at most {} can be read at once",
+            len,
+            MAX_FILE_RANGE_BYTES
+        ));
+    }
+
+    let mut file = open_shared_read(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open {:?}: {}", path, e))?;
+    let size = file.metadata()?.len();
+    let end = offset
+        .checked_add(len)
+        .filter(|end| *end <= size)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Range {}+{} is outside {:?} ({} bytes)",
+                offset,
+                len,
+                path,
+                size
+            )
+        })?;
+
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buffer = vec![0u8; (end - offset) as usize];
+    file.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Writes `contents` to `path` atomically.
+///
+/// The data is written to a sibling temp file which is then renamed over the
+/// target, so readers never observe a partially written file and a crash leaves
+/// either the old or the new contents in place.
+pub(crate) fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = write_temp_file(path, contents)?;
+    commit_temp_file(&tmp_path, path)
+}
+
+/// Replaces `latest_transcript.txt` in the meetings directory with the given transcript.
+///
+/// # Returns
+/// The path of the mirrored transcript
+pub(crate) fn update_latest_transcript(meetings_dir: &Path, transcript: &str) -> Result<PathBuf> {
+    let path = meetings_dir.join(LATEST_TRANSCRIPT_FILENAME);
+    write_atomic(&path, transcript.as_bytes())?;
+    Ok(path)
+}
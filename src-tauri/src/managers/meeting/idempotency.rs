@@ -0,0 +1,78 @@
+//! Returning the same session when a start request is retried.
+//!
+//! A frontend that retries `start_meeting_session` (e.g. after a timeout) sends
+//! the same idempotency key again. Keys are kept in memory for a short window
+//! and map to the session the first request created.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::models::MeetingSession;
+
+/// How long a key keeps returning the session it created.
+pub(crate) const IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(60);
+
+/// Recently used idempotency keys and the sessions they created.
+pub(crate) struct IdempotencyKeys {
+    window: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl IdempotencyKeys {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the session id stored for `key` if it is still within the window.
+    /// Expired keys are dropped.
+    pub fn lookup(&mut self, key: &str, now: Instant) -> Option<String> {
+        let window = self.window;
+        self.entries
+            .retain(|_, (_, created)| now.saturating_duration_since(*created) < window);
+        self.entries
+            .get(key)
+            .map(|(session_id, _)| session_id.clone())
+    }
+
+    pub fn remember(&mut self, key: &str, session_id: &str, now: Instant) {
+        self.entries
+            .insert(key.to_string(), (session_id.to_string(), now));
+    }
+}
+
+/// Runs `start` unless `key` already created a session that still exists.
+///
+/// The keys stay locked while `start` runs, so a retry arriving during the
+/// first start waits for it and then gets its session instead of starting again.
+///
+/// # Arguments
+/// * `key` - The request's idempotency key; `None` always starts
+/// * `existing` - Looks up a session by id, as it is now
+/// * `start` - Starts a new session
+pub(crate) fn start_once<E>(
+    keys: &Mutex<IdempotencyKeys>,
+    key: Option<&str>,
+    existing: impl Fn(&str) -> Option<MeetingSession>,
+    start: impl FnOnce() -> Result<MeetingSession, E>,
+) -> Result<MeetingSession, E> {
+    let key = match key.filter(|k| !k.trim().is_empty()) {
+        Some(key) => key,
+        None => return start(),
+    };
+
+    let mut keys = keys.lock().unwrap_or_else(|p| p.into_inner());
+    if let Some(session) = keys
+        .lookup(key, Instant::now())
+        .and_then(|session_id| existing(&session_id))
+    {
+        return Ok(session);
+    }
+
+    let session = start()?;
+    keys.remember(key, &session.id, Instant::now());
+    Ok(session)
+}
@@ -0,0 +1,56 @@
+//! Checking up front that a recording has a microphone to record from, and
+//! picking the one the user asked for.
+
+use log::warn;
+use std::fmt::Display;
+
+use super::models::AudioSourceType;
+use crate::audio_toolkit::NoInputDevice;
+
+/// Fails with `NoInputDevice` if `audio_source` records the microphone and
+/// `list_devices` finds no input devices.
+///
+/// System-only recordings don't need a microphone and skip the lookup. If the
+/// devices can't be listed, the start goes ahead and opening the device reports
+/// the problem instead.
+pub(crate) fn check_input_device<D, E: Display>(
+    audio_source: &AudioSourceType,
+    list_devices: impl FnOnce() -> Result<Vec<D>, E>,
+) -> Result<(), NoInputDevice> {
+    if *audio_source == AudioSourceType::SystemOnly {
+        return Ok(());
+    }
+
+    match list_devices() {
+        Ok(devices) if devices.is_empty() => Err(NoInputDevice),
+        Ok(_) => Ok(()),
+        Err(e) => {
+            warn!("Could not list input devices before recording: {}", e);
+            Ok(())
+        }
+    }
+}
+
+/// Picks the device named `requested` from `devices`, using `name_of` to get
+/// each device's name.
+///
+/// # Returns
+/// `None` to record from the default device: when no device was requested, or
+/// the requested one isn't connected (logged as a warning)
+pub(crate) fn select_input_device<D>(
+    devices: Vec<D>,
+    requested: Option<&str>,
+    name_of: impl Fn(&D) -> &str,
+) -> Option<D> {
+    let requested = requested?;
+    let found = devices
+        .into_iter()
+        .find(|device| name_of(device) == requested);
+    if found.is_none() {
+        warn!(
+            "Input device '{}' not found; recording from the default device",
+            requested
+        );
+    }
+    found
+}
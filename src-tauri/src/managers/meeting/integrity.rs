@@ -0,0 +1,207 @@
+//! One-pass diagnostic of the meetings database and the files it points to.
+
+use anyhow::Result;
+use hound::WavReader;
+use serde::Serialize;
+use specta::Type;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::daily_note::DAILY_NOTES_DIR;
+use super::db::{get_connection, list_sessions, list_sessions_with_status};
+use super::models::{MeetingSession, MeetingStatus};
+
+/// A single problem found by the integrity check.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IntegrityIssue {
+    /// The row references an audio file that doesn't exist
+    MissingAudio { session_id: String, path: String },
+    /// The row references a transcript file that doesn't exist
+    MissingTranscript { session_id: String, path: String },
+    /// The row references a summary file that doesn't exist
+    MissingSummary { session_id: String, path: String },
+    /// The audio file exists but can't be read as WAV
+    UnreadableAudio {
+        session_id: String,
+        path: String,
+        error: String,
+    },
+    /// A folder in the meetings directory with no matching session row
+    OrphanFolder { folder: String },
+    /// The row's status contradicts its data or what is currently running
+    ImpossibleState { session_id: String, reason: String },
+}
+
+/// Result of `run_integrity_check`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Type)]
+pub struct IntegrityReport {
+    /// Whether SQLite's `PRAGMA integrity_check` returned "ok"
+    pub database_ok: bool,
+    /// Messages from `PRAGMA integrity_check` when it found problems
+    pub database_messages: Vec<String>,
+    /// Number of session rows examined
+    pub sessions_checked: usize,
+    /// Problems found in session rows, followed by orphan folders
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    /// Returns true if the database and every session are consistent.
+    pub fn is_healthy(&self) -> bool {
+        self.database_ok && self.issues.is_empty()
+    }
+}
+
+fn is_wav(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .map_or(false, |ext| ext.eq_ignore_ascii_case("wav"))
+}
+
+fn check_session(
+    meetings_dir: &Path,
+    session: &MeetingSession,
+    is_active: bool,
+    issues: &mut Vec<IntegrityIssue>,
+) {
+    let id = || session.id.clone();
+
+    if let Some(path) = session.audio_path.as_ref() {
+        let full_path = meetings_dir.join(path);
+        if !full_path.is_file() {
+            issues.push(IntegrityIssue::MissingAudio {
+                session_id: id(),
+                path: path.clone(),
+            });
+        } else if is_wav(path) && !is_active {
+            // A recording in progress has no finalized header yet
+            if let Err(e) = WavReader::open(&full_path) {
+                issues.push(IntegrityIssue::UnreadableAudio {
+                    session_id: id(),
+                    path: path.clone(),
+                    error: e.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(path) = session.transcript_path.as_ref() {
+        if !meetings_dir.join(path).is_file() {
+            issues.push(IntegrityIssue::MissingTranscript {
+                session_id: id(),
+                path: path.clone(),
+            });
+        }
+    }
+
+    if let Some(path) = session.summary_path.as_ref() {
+        if !meetings_dir.join(path).is_file() {
+            issues.push(IntegrityIssue::MissingSummary {
+                session_id: id(),
+                path: path.clone(),
+            });
+        }
+    }
+
+    let reason = match session.status {
+        MeetingStatus::Completed
+            if session.transcript_path.is_none() && !session.transcript_removed =>
+        {
+            Some("Completed without a transcript".to_string())
+        }
+        MeetingStatus::Recording | MeetingStatus::Paused if !is_active => Some(format!(
+            "Marked {:?} but no recording is running",
+            session.status
+        )),
+        MeetingStatus::Processing if !is_active => {
+            Some("Marked Processing but not queued for transcription".to_string())
+        }
+        MeetingStatus::Processing | MeetingStatus::Interrupted | MeetingStatus::Recorded
+            if session.audio_path.is_none() && session.archive_path.is_none() =>
+        {
+            Some(format!("{:?} without any audio", session.status))
+        }
+        _ if session.duration.map_or(false, |d| d < 0) => Some(format!(
+            "Negative duration ({}s)",
+            session.duration.unwrap_or(0)
+        )),
+        _ => None,
+    };
+    if let Some(reason) = reason {
+        issues.push(IntegrityIssue::ImpossibleState {
+            session_id: id(),
+            reason,
+        });
+    }
+}
+
+/// Checks the database and every session's files.
+///
+/// # Arguments
+/// * `active_ids` - Sessions currently recording, queued, or held for resuming;
+///   their Recording/Processing status is expected rather than stuck
+pub(crate) fn check_integrity(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    active_ids: &[String],
+) -> Result<IntegrityReport> {
+    let mut report = IntegrityReport::default();
+
+    let conn = get_connection(db_path)?;
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let messages = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    report.database_ok = messages.len() == 1 && messages[0] == "ok";
+    if !report.database_ok {
+        report.database_messages = messages;
+    }
+
+    let sessions = list_sessions(db_path)?;
+    report.sessions_checked = sessions.len();
+    for session in &sessions {
+        let is_active = active_ids.contains(&session.id);
+        check_session(meetings_dir, session, is_active, &mut report.issues);
+    }
+
+    let known: HashSet<&str> = sessions.iter().map(|s| s.id.as_str()).collect();
+    if let Ok(entries) = fs::read_dir(meetings_dir) {
+        let mut orphans: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().map_or(false, |t| t.is_dir()))
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| !known.contains(name.as_str()) && name != DAILY_NOTES_DIR)
+            .collect();
+        orphans.sort();
+        report.issues.extend(
+            orphans
+                .into_iter()
+                .map(|folder| IntegrityIssue::OrphanFolder { folder }),
+        );
+    }
+
+    Ok(report)
+}
+
+/// Lists Completed sessions whose transcript is gone, either because no
+/// transcript path was saved or because the file it names no longer exists.
+///
+/// Unlike `check_integrity`, this only looks for sessions that can be fixed
+/// by transcribing them again. Sessions whose transcript was removed by
+/// summary-only retention are left out.
+pub(crate) fn sessions_missing_transcript(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+) -> Result<Vec<MeetingSession>> {
+    let sessions = list_sessions_with_status(db_path, &MeetingStatus::Completed)?;
+    Ok(sessions
+        .into_iter()
+        .filter(|session| !session.transcript_removed)
+        .filter(|session| match session.transcript_path.as_ref() {
+            Some(path) => !meetings_dir.join(path).is_file(),
+            None => true,
+        })
+        .collect())
+}
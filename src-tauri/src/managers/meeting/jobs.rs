@@ -0,0 +1,238 @@
+//! Bounded worker pool for long-running file operations.
+//!
+//! Exports and audio hashing can take seconds on large sessions. Instead of
+//! blocking the command that asked for them, they are queued here and run on
+//! at most `max_workers` threads; the command returns a job id right away and
+//! the frontend follows the job through `file_job_updated` events or by polling
+//! `get_job_status`.
+
+use anyhow::Result;
+use log::{debug, warn};
+use serde::Serialize;
+use specta::Type;
+use std::collections::{HashMap, VecDeque};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use uuid::Uuid;
+
+/// Default number of file jobs that run at the same time.
+pub(crate) const DEFAULT_FILE_JOB_CONCURRENCY: u32 = 2;
+
+/// Upper bound for the `meeting_file_job_concurrency` setting.
+pub(crate) const MAX_FILE_JOB_CONCURRENCY: u32 = 8;
+
+/// Number of finished jobs whose status is kept for polling.
+const FINISHED_JOBS_KEPT: usize = 100;
+
+/// Kind of operation a file job performs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum FileJobKind {
+    Export,
+    SrtExport,
+    AudioHash,
+}
+
+/// Where a file job is in its lifecycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum FileJobState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Status of a file job, returned by `get_job_status` and sent with
+/// `file_job_updated` whenever it changes.
+#[derive(Clone, Debug, PartialEq, Serialize, Type)]
+pub struct FileJobStatus {
+    pub id: String,
+    pub kind: FileJobKind,
+    pub state: FileJobState,
+    /// Result of a completed job: the number of sessions or cues exported, or the hash
+    pub result: Option<String>,
+    /// Why a failed job failed
+    pub error: Option<String>,
+}
+
+impl FileJobStatus {
+    fn is_finished(&self) -> bool {
+        matches!(self.state, FileJobState::Completed | FileJobState::Failed)
+    }
+}
+
+type Job = Box<dyn FnOnce() -> Result<String> + Send>;
+type UpdateFn = Arc<dyn Fn(&FileJobStatus) + Send + Sync>;
+
+#[derive(Default)]
+struct PoolState {
+    max_workers: usize,
+    workers: usize,
+    pending: VecDeque<(String, Job)>,
+    statuses: HashMap<String, FileJobStatus>,
+    /// Finished job ids, oldest first, so old statuses can be dropped
+    finished: VecDeque<String>,
+}
+
+impl PoolState {
+    fn set_status(&mut self, status: FileJobStatus) {
+        if status.is_finished() {
+            self.finished.push_back(status.id.clone());
+            while self.finished.len() > FINISHED_JOBS_KEPT {
+                if let Some(old) = self.finished.pop_front() {
+                    self.statuses.remove(&old);
+                }
+            }
+        }
+        self.statuses.insert(status.id.clone(), status);
+    }
+}
+
+/// Runs file jobs on at most `max_workers` threads, in submission order.
+///
+/// Worker threads are started as jobs are submitted and exit once the queue is
+/// empty, so an idle pool holds no threads.
+pub(crate) struct FileJobPool {
+    state: Arc<Mutex<PoolState>>,
+    on_update: UpdateFn,
+}
+
+impl FileJobPool {
+    /// # Arguments
+    /// * `max_workers` - Jobs that may run at once; 0 is treated as 1
+    /// * `on_update` - Called with a job's status each time it changes
+    pub fn new(
+        max_workers: u32,
+        on_update: impl Fn(&FileJobStatus) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(PoolState {
+                max_workers: max_workers.max(1) as usize,
+                ..Default::default()
+            })),
+            on_update: Arc::new(on_update),
+        }
+    }
+
+    /// Changes how many jobs may run at once. Running jobs are not interrupted;
+    /// a lower limit applies as they finish.
+    pub fn set_max_workers(&self, max_workers: u32) {
+        let spawn = {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.max_workers = max_workers.max(1) as usize;
+            let idle = state.max_workers.saturating_sub(state.workers);
+            let spawn = idle.min(state.pending.len());
+            state.workers += spawn;
+            spawn
+        };
+        for _ in 0..spawn {
+            self.spawn_worker();
+        }
+    }
+
+    /// Queues `job` and returns its id.
+    ///
+    /// The job's `Ok` value becomes the status's `result`.
+    pub fn submit(
+        &self,
+        kind: FileJobKind,
+        job: impl FnOnce() -> Result<String> + Send + 'static,
+    ) -> String {
+        let id = Uuid::new_v4().to_string();
+        let status = FileJobStatus {
+            id: id.clone(),
+            kind,
+            state: FileJobState::Queued,
+            result: None,
+            error: None,
+        };
+
+        let spawn = {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.set_status(status.clone());
+            state.pending.push_back((id.clone(), Box::new(job)));
+            let spawn = state.workers < state.max_workers;
+            if spawn {
+                state.workers += 1;
+            }
+            spawn
+        };
+        debug!("Queued {:?} file job {}", kind, id);
+        (self.on_update)(&status);
+
+        if spawn {
+            self.spawn_worker();
+        }
+        id
+    }
+
+    /// Returns the status of a job, or `None` if the id is unknown or the job
+    /// finished long enough ago to have been forgotten.
+    pub fn status(&self, job_id: &str) -> Option<FileJobStatus> {
+        let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        state.statuses.get(job_id).cloned()
+    }
+
+    fn spawn_worker(&self) {
+        let state = self.state.clone();
+        let on_update = self.on_update.clone();
+        thread::spawn(move || run_worker(&state, on_update.as_ref()));
+    }
+}
+
+/// Takes jobs off the queue until it is empty or the pool has more workers
+/// than it is now allowed.
+fn run_worker(state: &Mutex<PoolState>, on_update: &(dyn Fn(&FileJobStatus) + Send + Sync)) {
+    loop {
+        let (id, job, running) = {
+            let mut state = state.lock().unwrap_or_else(|p| p.into_inner());
+            let next = if state.workers > state.max_workers {
+                None
+            } else {
+                state.pending.pop_front()
+            };
+            let (id, job) = match next {
+                Some(next) => next,
+                None => {
+                    state.workers -= 1;
+                    return;
+                }
+            };
+            let running = state.statuses.get_mut(&id).map(|status| {
+                status.state = FileJobState::Running;
+                status.clone()
+            });
+            (id, job, running)
+        };
+        if let Some(running) = running {
+            on_update(&running);
+        }
+
+        let outcome = catch_unwind(AssertUnwindSafe(job))
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("File job panicked")));
+
+        let finished = {
+            let mut state = state.lock().unwrap_or_else(|p| p.into_inner());
+            let mut status = match state.statuses.get(&id) {
+                Some(status) => status.clone(),
+                None => continue,
+            };
+            match outcome {
+                Ok(result) => {
+                    status.state = FileJobState::Completed;
+                    status.result = Some(result);
+                }
+                Err(e) => {
+                    warn!("File job {} failed: {}", id, e);
+                    status.state = FileJobState::Failed;
+                    status.error = Some(e.to_string());
+                }
+            }
+            state.set_status(status.clone());
+            status
+        };
+        on_update(&finished);
+    }
+}
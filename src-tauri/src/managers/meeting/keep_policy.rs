@@ -0,0 +1,115 @@
+//! Choosing between a stored transcript and the one a retry produced.
+//!
+//! Retrying with a different model doesn't always give a better transcript.
+//! Depending on the retry's `RetryKeepPolicy`, the new result either replaces
+//! the stored one or only does so when it is longer. Both texts are kept in
+//! memory afterwards so the frontend can show a diff.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fs;
+use std::path::Path;
+
+use super::transcript::{read_transcript_details, TranscriptionResult};
+
+/// Which transcript a retry keeps when the session already has one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryKeepPolicy {
+    /// Always keep the new transcript
+    #[default]
+    Overwrite,
+    /// Keep whichever transcript has more characters
+    KeepLonger,
+}
+
+/// Which of the two transcripts a retry kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum KeptTranscript {
+    Previous,
+    New,
+}
+
+/// Both transcripts of the last retry of a session, for the diff view.
+///
+/// Only kept in memory; it is gone after a restart or the next retry.
+#[derive(Clone, Debug, PartialEq, Serialize, Type)]
+pub struct RetryComparison {
+    pub session_id: String,
+    pub policy: RetryKeepPolicy,
+    pub previous_text: String,
+    pub new_text: String,
+    pub kept: KeptTranscript,
+}
+
+fn text_len(result: &TranscriptionResult) -> usize {
+    result.text.trim().chars().count()
+}
+
+/// Decides which transcript to keep. Ties keep the new one.
+pub(crate) fn choose_transcript(
+    policy: RetryKeepPolicy,
+    previous: &TranscriptionResult,
+    new: &TranscriptionResult,
+) -> KeptTranscript {
+    let keep_new = match policy {
+        RetryKeepPolicy::Overwrite => true,
+        RetryKeepPolicy::KeepLonger => text_len(new) >= text_len(previous),
+    };
+    if keep_new {
+        KeptTranscript::New
+    } else {
+        KeptTranscript::Previous
+    }
+}
+
+/// Loads the transcript a session already has, with its details if stored.
+///
+/// # Returns
+/// * `Ok(None)` - If the session has no transcript yet
+pub(crate) fn load_stored_transcript(
+    meetings_dir: &Path,
+    session_id: &str,
+    transcript_path: Option<&str>,
+) -> Result<Option<TranscriptionResult>> {
+    let rel_path = match transcript_path {
+        Some(rel_path) => rel_path,
+        None => return Ok(None),
+    };
+    let path = meetings_dir.join(rel_path);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let text = fs::read_to_string(&path)?;
+    let mut result = read_transcript_details(meetings_dir, session_id)?.unwrap_or_default();
+    result.text = text;
+    Ok(Some(result))
+}
+
+/// Applies `policy` to a retry's result.
+///
+/// # Returns
+/// The transcript to save, and the comparison of both for the diff view
+pub(crate) fn apply_keep_policy(
+    session_id: &str,
+    policy: RetryKeepPolicy,
+    previous: TranscriptionResult,
+    new: TranscriptionResult,
+) -> (TranscriptionResult, RetryComparison) {
+    let kept = choose_transcript(policy, &previous, &new);
+    let comparison = RetryComparison {
+        session_id: session_id.to_string(),
+        policy,
+        previous_text: previous.text.clone(),
+        new_text: new.text.clone(),
+        kept,
+    };
+    let chosen = match kept {
+        KeptTranscript::Previous => previous,
+        KeptTranscript::New => new,
+    };
+    (chosen, comparison)
+}
@@ -0,0 +1,62 @@
+//! Input level of a recording in progress, for a VU meter.
+//!
+//! The sample callback measures every batch it receives, and at most once per
+//! `LEVEL_EMIT_INTERVAL` reports the loudest batch since the last report as a
+//! `meeting_audio_level` event. Measuring is a single pass over the batch, so
+//! it adds nothing noticeable to the callback.
+
+use serde::Serialize;
+use specta::Type;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two `meeting_audio_level` events.
+pub(crate) const LEVEL_EMIT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Level of a recording, sent to the frontend as a `meeting_audio_level` event.
+#[derive(Clone, Debug, PartialEq, Serialize, Type)]
+pub struct AudioLevel {
+    pub session_id: String,
+    /// RMS of the loudest recent batch, from 0.0 (silence) to 1.0 (full scale)
+    pub level: f32,
+}
+
+/// RMS of `samples`, clamped to 0.0-1.0; 0.0 for an empty batch.
+pub(crate) fn rms_level(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let mean_square = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+    mean_square.sqrt().min(1.0)
+}
+
+/// Throttles per-batch levels down to one report per interval.
+pub(crate) struct LevelMeter {
+    interval: Duration,
+    last_report: Option<Instant>,
+    loudest: f32,
+}
+
+impl LevelMeter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_report: None,
+            loudest: 0.0,
+        }
+    }
+
+    /// Measures a batch received at `now`.
+    ///
+    /// # Returns
+    /// The loudest level since the last report, once `interval` has passed
+    pub fn observe(&mut self, samples: &[f32], now: Instant) -> Option<f32> {
+        self.loudest = self.loudest.max(rms_level(samples));
+        if let Some(last) = self.last_report {
+            if now.duration_since(last) < self.interval {
+                return None;
+            }
+        }
+        self.last_report = Some(now);
+        Some(std::mem::take(&mut self.loudest))
+    }
+}
@@ -0,0 +1,179 @@
+//! Transcribing a recording in chunks while it is still being captured.
+//!
+//! The sample callback hands audio to a `LiveChunker`, which cuts it into
+//! chunks of `meeting_live_chunk_secs` and queues them for a worker thread.
+//! The worker transcribes each chunk and reports it as a `PartialTranscript`
+//! with its offset into the recording. Queuing never blocks the callback: if
+//! the worker falls behind, chunks are left out of the captions, never out of
+//! the audio. The saved transcript is still made from the full recording.
+
+use log::{debug, warn};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread;
+
+use super::corrections::CustomWords;
+use super::transcript::{PartialTranscript, Transcriber};
+use crate::audio_toolkit::AudioBuffer;
+
+/// Default for the `meeting_live_chunk_secs` setting.
+pub(crate) const DEFAULT_LIVE_CHUNK_SECS: u32 = 10;
+/// Lower bound for `meeting_live_chunk_secs`; shorter chunks give the engine
+/// too little context to be useful.
+pub(crate) const MIN_LIVE_CHUNK_SECS: u32 = 2;
+/// Upper bound for `meeting_live_chunk_secs`.
+pub(crate) const MAX_LIVE_CHUNK_SECS: u32 = 60;
+
+/// Chunks that may wait for the worker before new ones are skipped.
+const LIVE_QUEUE_DEPTH: usize = 2;
+
+/// A chunk of recorded audio waiting to be transcribed.
+pub(crate) struct LiveChunk {
+    pub index: u32,
+    /// Seconds from the start of the recording to the start of the chunk
+    pub offset_secs: f32,
+    pub audio: AudioBuffer,
+}
+
+/// Collects captured samples into fixed-length chunks for the live worker.
+pub(crate) struct LiveChunker {
+    sample_rate: u32,
+    channels: u16,
+    chunk_samples: usize,
+    buffer: Vec<f32>,
+    next_index: u32,
+    frames_sent: u64,
+    sender: SyncSender<LiveChunk>,
+}
+
+impl LiveChunker {
+    /// # Arguments
+    /// * `chunk_secs` - Length of each chunk; clamped to the allowed range
+    /// * `sample_rate`, `channels` - Format of the samples passed to `push`
+    ///
+    /// # Returns
+    /// The chunker, and the receiving end of its queue
+    pub fn new(chunk_secs: u32, sample_rate: u32, channels: u16) -> (Self, Receiver<LiveChunk>) {
+        let chunk_secs = chunk_secs.clamp(MIN_LIVE_CHUNK_SECS, MAX_LIVE_CHUNK_SECS);
+        let channels = channels.max(1);
+        let (sender, receiver) = mpsc::sync_channel(LIVE_QUEUE_DEPTH);
+        let chunk_samples = (chunk_secs * sample_rate) as usize * channels as usize;
+        let chunker = Self {
+            sample_rate,
+            channels,
+            chunk_samples,
+            buffer: Vec::with_capacity(chunk_samples),
+            next_index: 0,
+            frames_sent: 0,
+            sender,
+        };
+        (chunker, receiver)
+    }
+
+    /// Adds interleaved samples, queueing every chunk that fills up.
+    pub fn push(&mut self, samples: &[f32]) {
+        let mut rest = samples;
+        while !rest.is_empty() {
+            let take = (self.chunk_samples - self.buffer.len()).min(rest.len());
+            self.buffer.extend_from_slice(&rest[..take]);
+            rest = &rest[take..];
+            if self.buffer.len() == self.chunk_samples {
+                self.send_chunk();
+            }
+        }
+    }
+
+    fn send_chunk(&mut self) {
+        let samples = std::mem::replace(&mut self.buffer, Vec::with_capacity(self.chunk_samples));
+        let frames = (samples.len() / self.channels as usize) as u64;
+        let chunk = LiveChunk {
+            index: self.next_index,
+            offset_secs: self.frames_sent as f32 / self.sample_rate as f32,
+            audio: AudioBuffer::new(samples, self.sample_rate, self.channels),
+        };
+        self.next_index += 1;
+        self.frames_sent += frames;
+
+        match self.sender.try_send(chunk) {
+            Ok(()) => {}
+            Err(TrySendError::Full(chunk)) => {
+                debug!(
+                    "Live transcription is behind; skipping chunk {}",
+                    chunk.index
+                );
+            }
+            // The worker is gone; the recording carries on without captions
+            Err(TrySendError::Disconnected(_)) => {}
+        }
+    }
+}
+
+/// Transcribes queued chunks until the chunker is dropped, passing each
+/// non-empty result to `emit`.
+///
+/// Captions get the same custom words as the saved transcript.
+pub(crate) fn run_live_worker<T>(
+    transcriber: &T,
+    session_id: &str,
+    custom_words: Option<&CustomWords>,
+    chunks: Receiver<LiveChunk>,
+    mut emit: impl FnMut(PartialTranscript),
+) where
+    T: Transcriber + ?Sized,
+{
+    for chunk in chunks {
+        let audio = chunk.audio.to_mono_16k();
+        match transcriber.transcribe_detailed(audio.into_samples()) {
+            Ok(result) => {
+                let text = result.text.trim();
+                if !text.is_empty() {
+                    let text = match custom_words {
+                        Some(custom_words) => custom_words.correct_text(text),
+                        None => text.to_string(),
+                    };
+                    emit(PartialTranscript {
+                        session_id: session_id.to_string(),
+                        chunk: chunk.index,
+                        text,
+                        offset_secs: Some(chunk.offset_secs),
+                    });
+                }
+            }
+            Err(e) => warn!(
+                "Live transcription of chunk {} for session {} failed: {}",
+                chunk.index, session_id, e
+            ),
+        }
+    }
+    debug!("Live transcription for session {} finished", session_id);
+}
+
+/// Starts the live worker on its own thread.
+///
+/// # Returns
+/// The chunker to feed from the sample callback; dropping it stops the worker
+/// once the queued chunks are done
+pub(crate) fn spawn_live_transcription<T>(
+    transcriber: Arc<T>,
+    session_id: String,
+    custom_words: Option<CustomWords>,
+    chunk_secs: u32,
+    sample_rate: u32,
+    channels: u16,
+    emit: impl FnMut(PartialTranscript) + Send + 'static,
+) -> LiveChunker
+where
+    T: Transcriber + Send + Sync + ?Sized + 'static,
+{
+    let (chunker, chunks) = LiveChunker::new(chunk_secs, sample_rate, channels);
+    thread::spawn(move || {
+        run_live_worker(
+            transcriber.as_ref(),
+            &session_id,
+            custom_words.as_ref(),
+            chunks,
+            emit,
+        )
+    });
+    chunker
+}
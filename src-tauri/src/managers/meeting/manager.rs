@@ -5,27 +5,92 @@
 
 use anyhow::Result;
 use chrono::{DateTime, Local};
-use hound::{WavReader, WavSpec, WavWriter};
-use log::{debug, error, info};
+use hound::WavWriter;
+use log::{debug, error, info, warn};
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
-use std::path::PathBuf;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
 use uuid::Uuid;
 
-use crate::audio_toolkit::{AudioSourceConfig, MixedAudioRecorder};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{
+    apply_preprocessing, default_input_device, list_input_devices, microphone_permission,
+    request_microphone_permission, AudioBuffer, AudioSourceConfig, MicrophonePermissionError,
+    MixedAudioRecorder, PreprocessOp, SileroVad, TrimMap, VoiceActivityDetector,
+};
+use crate::commands::audio::AudioDevice;
 use crate::managers::meeting_logger::{
-    log_meeting_event, log_performance_metric, MeetingLogContext, MeetingTimer,
+    log_meeting_event, log_performance_metric, ErrorEntry, MeetingErrorLog, MeetingLogContext,
+    MeetingTimer, MEETING_ERROR_LOG_CAPACITY,
 };
-
+use crate::settings::{MeetingAudioArchival, MeetingTemplate, PostAction};
+
+use super::archive::archive_session_audio;
+use super::audio_cache::load_16k_samples;
+use super::audio_hash::hash_file;
+use super::bundle::{export_sessions_bundle, import_sessions_bundle};
+use super::corrections::{reapply_custom_words, CustomWords};
+use super::daily_note::append_to_daily_note;
 use super::db::init_meeting_database;
-use super::models::{AudioSourceType, MeetingManagerState, MeetingSession, MeetingStatus};
-use super::wav_writer::WavWriterHandle;
+use super::detail::{build_session_detail, SessionDetail};
+use super::direction::{transcript_direction, TextDirection};
+use super::disk::{available_space, remaining_recording_minutes, MEETING_WAV_SPEC};
+use super::export::{segments_to_srt, write_sessions_export, write_srt_to_path, ExportFormat};
+use super::files::{open_shared_read, read_file_range, write_atomic};
+use super::idempotency::{start_once, IdempotencyKeys, IDEMPOTENCY_WINDOW};
+use super::input_check::{check_input_device, select_input_device};
+use super::integrity::{check_integrity, sessions_missing_transcript, IntegrityReport};
+use super::jobs::{FileJobKind, FileJobPool, FileJobStatus};
+use super::keep_policy::{
+    apply_keep_policy, load_stored_transcript, RetryComparison, RetryKeepPolicy,
+};
+use super::level::{AudioLevel, LevelMeter, LEVEL_EMIT_INTERVAL};
+use super::live::spawn_live_transcription;
+use super::models::{
+    AudioSourceType, MeetingManagerState, MeetingSession, MeetingStatus, RecordingOptions,
+    SessionLite, SessionPatch, SuspendSummary, MEETING_CATEGORIES,
+};
+use super::paragraphs::{format_paragraphs, RAW_TRANSCRIPT_FILENAME};
+use super::post_actions::{run_post_actions, POST_ACTION_SRT_FILENAME};
+use super::pre_roll::{pre_roll_capacity, DevicePreRoll, PreRollHandoff};
+use super::preview::resolve_meeting_config;
+use super::queue::{load_queue, save_queue, CancelOutcome, DrainGuard, TranscriptionQueue};
+use super::recovery::{sessions_to_auto_retry, sessions_to_resume};
+use super::repair::{recompute_session_durations, repair_session_status};
+use super::reset::{reset_meetings_data, ResetSummary};
+use super::rollback::StartRollback;
+use super::resume::{open_wav_for_append, resume_grace, RecentStop};
+use super::retention::apply_summary_retention;
+use super::search::{
+    needs_reindex, reindex_transcripts, search_transcripts, MeetingSearchResult, SEARCH_RESULT_LIMIT,
+};
+use super::silence::{classify_recording, classify_speech, RecordingContent, NO_SPEECH_NOTE};
+use super::speech::{
+    speech_ratio, trim_to_speech, SpeechRatioCache, TrimmedAudio, SPEECH_VAD_THRESHOLD,
+    TRIM_PADDING_MS,
+};
+use super::split::split_session_files;
+use super::start_guard::StartGuard;
+use super::stall::{StallDetector, DEVICE_STALL_POLL_INTERVAL, DEVICE_STALL_TIMEOUT};
+use super::stream::{should_stream, WavWindowReader, STREAM_WINDOW_SECS};
+use super::summary::{read_transcript_for_summary, summarize_with_provider};
+use super::timeline::{group_sessions_by_day, DayGroup};
+use super::title::auto_title_for;
+use super::toggle::{RecordingToggle, ToggleAction};
+use super::transcript::{
+    forward_partials, read_transcript_details, run_transcription, run_windowed_transcription,
+    save_transcript, PartialCallback, Segment, TranscriptionResult,
+};
+use super::wav_writer::{recorded_duration, WavWriterHandle};
 
 
 /// Manager for meeting sessions.
@@ -54,6 +119,26 @@ pub struct MeetingSessionManager {
     db_path: PathBuf,
     /// Transcription manager for STT processing
     transcription_manager: Arc<crate::managers::transcription::TranscriptionManager>,
+    /// Sessions waiting for (or undergoing) background transcription
+    transcription_queue: Arc<Mutex<TranscriptionQueue>>,
+    /// Speech ratios computed by `get_speech_ratio`
+    speech_ratios: Arc<Mutex<SpeechRatioCache>>,
+    /// Held for the duration of a recording start
+    start_guard: StartGuard,
+    /// Serializes `toggle_recording` calls
+    recording_toggle: RecordingToggle,
+    /// Most recent errors and warnings from meeting operations
+    error_log: Arc<MeetingErrorLog>,
+    /// Idempotency keys of recent start requests
+    idempotency_keys: Arc<Mutex<IdempotencyKeys>>,
+    /// Background pool for exports and hashing
+    file_jobs: Arc<FileJobPool>,
+    /// Keep policies of retries waiting in the transcription queue
+    retry_keep_policies: Arc<Mutex<HashMap<String, RetryKeepPolicy>>>,
+    /// Both transcripts of each session's last policy retry, for the diff view
+    retry_comparisons: Arc<Mutex<HashMap<String, RetryComparison>>>,
+    /// Reprocessed sessions whose summary is regenerated once they are transcribed
+    resummarize_sessions: Arc<Mutex<HashSet<String>>>,
 }
 
 impl MeetingSessionManager {
@@ -96,12 +181,45 @@ impl MeetingSessionManager {
         // Initialize the database and run migrations
         init_meeting_database(&db_path)?;
 
+        // Transcripts saved before search existed aren't indexed yet
+        match needs_reindex(&db_path) {
+            Ok(true) => {
+                let (db_path, meetings_dir) = (db_path.clone(), meetings_dir.clone());
+                thread::spawn(move || match reindex_transcripts(&db_path, &meetings_dir) {
+                    Ok(count) => info!("Indexed {} existing transcript(s) for search", count),
+                    Err(e) => error!("Failed to index existing transcripts: {}", e),
+                });
+            }
+            Ok(false) => {}
+            Err(e) => warn!("Failed to check the transcript search index: {}", e),
+        }
+
+        let job_events = app_handle.clone();
+        let file_jobs = Arc::new(FileJobPool::new(
+            crate::settings::get_settings(app_handle).meeting_file_job_concurrency,
+            move |status| {
+                if let Err(e) = job_events.emit("file_job_updated", status) {
+                    warn!("Failed to emit file_job_updated event: {}", e);
+                }
+            },
+        ));
+
         let manager = Self {
             state: Arc::new(Mutex::new(MeetingManagerState::default())),
             app_handle: app_handle.clone(),
             meetings_dir,
             db_path,
             transcription_manager,
+            transcription_queue: Arc::new(Mutex::new(TranscriptionQueue::new())),
+            speech_ratios: Arc::new(Mutex::new(SpeechRatioCache::default())),
+            start_guard: StartGuard::default(),
+            recording_toggle: RecordingToggle::default(),
+            error_log: Arc::new(MeetingErrorLog::new(MEETING_ERROR_LOG_CAPACITY)),
+            idempotency_keys: Arc::new(Mutex::new(IdempotencyKeys::new(IDEMPOTENCY_WINDOW))),
+            file_jobs,
+            retry_keep_policies: Arc::new(Mutex::new(HashMap::new())),
+            retry_comparisons: Arc::new(Mutex::new(HashMap::new())),
+            resummarize_sessions: Arc::new(Mutex::new(HashSet::new())),
         };
 
         info!("MeetingSessionManager initialized successfully");
@@ -113,6 +231,62 @@ impl MeetingSessionManager {
         Ok(manager)
     }
 
+    /// Creates a log context whose errors and warnings are kept in the error log.
+    fn log_context(&self, session_id: &str, operation: &str) -> MeetingLogContext {
+        MeetingLogContext::new(session_id, operation).with_error_log(self.error_log.clone())
+    }
+
+    /// Returns up to `n` of the most recent meeting errors and warnings, newest first.
+    pub fn recent_errors(&self, n: usize) -> Vec<ErrorEntry> {
+        self.error_log.recent(n)
+    }
+
+    /// Returns the input device the current recording is capturing, or `None`
+    /// when nothing is recording or only system audio is captured.
+    pub fn active_recording_device(&self) -> Option<AudioDevice> {
+        let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        state.recording_device()
+    }
+
+    /// Runs `start` unless a session was already started with the same
+    /// idempotency key within the last minute, in which case that session is
+    /// returned as it is now.
+    ///
+    /// # Arguments
+    /// * `key` - Idempotency key sent by the frontend; `None` always starts
+    /// * `start` - Starts and configures a new session
+    pub fn start_with_idempotency_key<E>(
+        &self,
+        key: Option<&str>,
+        start: impl FnOnce() -> std::result::Result<MeetingSession, E>,
+    ) -> std::result::Result<MeetingSession, E> {
+        start_once(
+            &self.idempotency_keys,
+            key,
+            |session_id| self.get_session(session_id).ok().flatten(),
+            start,
+        )
+    }
+
+    /// Starts a recording if none is active, otherwise stops the current one.
+    ///
+    /// Meant for a single start/stop hotkey; concurrent toggles are handled one
+    /// after the other. `start` performs the start, e.g. with a template.
+    ///
+    /// # Returns
+    /// * `Ok(ToggleAction)` - Whether a recording was started or stopped
+    /// * `Err` - If starting or stopping fails
+    pub fn toggle_recording(&self, start: impl FnOnce() -> Result<()>) -> Result<ToggleAction> {
+        self.recording_toggle.toggle(
+            || {
+                self.get_current_session()
+                    .map_or(false, |session| session.status.is_capturing())
+            },
+            start,
+            || self.stop_recording().map(|_| ()),
+        )
+    }
+
     /// Returns the path to the meetings directory.
     pub fn get_meetings_dir(&self) -> &PathBuf {
         &self.meetings_dir
@@ -124,6 +298,11 @@ impl MeetingSessionManager {
         &self.db_path
     }
 
+    /// Returns the current schema version of the meetings database.
+    pub fn get_db_version(&self) -> Result<i32> {
+        super::db::get_db_version(&self.db_path)
+    }
+
     /// Gets the current session status atomically.
     ///
     /// # Returns
@@ -181,6 +360,86 @@ impl MeetingSessionManager {
         Ok(())
     }
 
+    /// Sets or clears the color category of a meeting session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to update
+    /// * `category` - One of `MEETING_CATEGORIES`, or `None` to clear it
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the category was updated successfully
+    /// * `Err` - If the category is not allowed, the session is not found, or the update fails
+    pub fn set_session_category(&self, session_id: &str, category: Option<&str>) -> Result<()> {
+        if let Some(category) = category {
+            if !MEETING_CATEGORIES.contains(&category) {
+                return Err(anyhow::anyhow!(
+                    "Invalid category '{}' (expected one of: {})",
+                    category,
+                    MEETING_CATEGORIES.join(", ")
+                ));
+            }
+        }
+
+        super::db::update_session_category(&self.db_path, session_id, category)?;
+
+        // Update in-memory state if this is the current session
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(session) = state.current_session.as_mut() {
+                if session.id == session_id {
+                    session.category = category.map(str::to_string);
+                }
+            }
+        }
+
+        info!(
+            "Updated meeting category for session {}: {:?}",
+            session_id, category
+        );
+        Ok(())
+    }
+
+    /// Updates several fields of a meeting session at once.
+    ///
+    /// All fields present in `patch` are validated first and then written in a
+    /// single transaction, so either every change is saved or none is.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to update
+    /// * `patch` - The fields to change; absent fields are left untouched
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The session with the changes applied
+    /// * `Err` - If a field is invalid, the session is not found, or the update fails
+    pub fn update_session_fields(
+        &self,
+        session_id: &str,
+        patch: &SessionPatch,
+    ) -> Result<MeetingSession> {
+        patch.validate().map_err(|e| anyhow::anyhow!(e))?;
+        super::db::update_session_fields(&self.db_path, session_id, patch)?;
+
+        let updated = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+
+        // Update in-memory state if this is the current session
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(session) = state.current_session.as_mut() {
+                if session.id == session_id {
+                    session.title = updated.title.clone();
+                    session.notes = updated.notes.clone();
+                    session.category = updated.category.clone();
+                    session.starred = updated.starred;
+                }
+            }
+        }
+
+        info!("Updated fields of session {}: {:?}", session_id, patch);
+        Ok(updated)
+    }
+
     /// Updates the template_id for a meeting session.
     ///
     /// # Arguments
@@ -218,6 +477,11 @@ impl MeetingSessionManager {
         Ok(())
     }
 
+    /// Takes the next number for a template's `{count}` title placeholder.
+    pub fn next_template_number(&self, template_id: &str) -> Result<usize> {
+        super::db::next_template_number(&self.db_path, template_id)
+    }
+
     /// Updates the summary path for a meeting session.
     ///
     /// # Arguments
@@ -255,6 +519,142 @@ impl MeetingSessionManager {
         Ok(())
     }
 
+    /// Deletes the session's audio and/or transcript after summarization when its
+    /// template opts out of keeping them, clearing the corresponding paths.
+    ///
+    /// Must only be called once the summary has been saved; nothing is removed otherwise.
+    pub fn apply_summary_retention(&self, session_id: &str) -> Result<()> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+
+        let Some(template) = session.template_id.as_ref().and_then(|id| {
+            crate::settings::get_settings(&self.app_handle)
+                .meeting_templates
+                .into_iter()
+                .find(|t| &t.id == id)
+        }) else {
+            return Ok(());
+        };
+
+        let outcome = apply_summary_retention(
+            &self.db_path,
+            &self.meetings_dir,
+            &session,
+            template.retain_audio,
+            template.retain_transcript,
+        )?;
+
+        // Update in-memory state if this is the current session
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(current) = state.current_session.as_mut() {
+            if current.id == session_id {
+                if outcome.removed_audio {
+                    current.audio_path = None;
+                    current.archive_path = None;
+                    current.archive_format = None;
+                }
+                if outcome.removed_transcript {
+                    current.transcript_path = None;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates an AI summary of a session's transcript.
+    ///
+    /// Sends the transcript to the configured LLM provider, saves the reply as
+    /// `summary.md` in the session folder, applies the template's retention
+    /// policy, and emits `meeting_summary_ready` with the summary's path and
+    /// `meeting_summary_generated` with the updated session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to summarize
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The generated summary text
+    /// * `Err` - If session not found, no transcript, or LLM call fails
+    pub async fn generate_summary(&self, session_id: &str) -> Result<String> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        let transcript_path = session
+            .transcript_path
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No transcript available for this session"))?;
+
+        let full_transcript_path = self.meetings_dir.join(transcript_path);
+        let transcript = tauri::async_runtime::spawn_blocking(move || {
+            read_transcript_for_summary(&full_transcript_path)
+        })
+        .await?
+        .map_err(anyhow::Error::msg)?;
+
+        let template = self.template_for_session(&session);
+        if let (Some(template_id), None) = (session.template_id.as_ref(), template.as_ref()) {
+            // Template ID exists but template not found (may have been deleted)
+            warn!(
+                "Template '{}' not found, using default summary prompt",
+                template_id
+            );
+        }
+
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let summary =
+            summarize_with_provider(&self.app_handle, &settings, &transcript, template.as_ref())
+                .await
+                .map_err(anyhow::Error::msg)?;
+
+        let summary_filename = format!("{}/summary.md", session_id);
+        let summary_path = self.meetings_dir.join(&summary_filename);
+        let contents = summary.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            write_atomic(&summary_path, contents.as_bytes())
+        })
+        .await?
+        .map_err(|e| anyhow::anyhow!("Failed to save summary: {}", e))?;
+
+        self.update_session_summary_path(session_id, &summary_filename)?;
+        info!(
+            "Summary generated and saved for session {}: {} bytes",
+            session_id,
+            summary.len()
+        );
+
+        #[derive(Clone, Serialize)]
+        struct SummaryReadyEvent {
+            session_id: String,
+            summary_path: String,
+        }
+
+        if let Err(e) = self.app_handle.emit(
+            "meeting_summary_ready",
+            SummaryReadyEvent {
+                session_id: session_id.to_string(),
+                summary_path: summary_filename,
+            },
+        ) {
+            error!("Failed to emit meeting_summary_ready event: {}", e);
+        }
+
+        // Summary-only templates drop the audio/transcript now that the summary is saved
+        if let Err(e) = self.apply_summary_retention(session_id) {
+            warn!(
+                "Failed to apply retention policy for session {}: {}",
+                session_id, e
+            );
+        }
+
+        if let Ok(Some(updated_session)) = self.get_session(session_id) {
+            let _ = self
+                .app_handle
+                .emit("meeting_summary_generated", &updated_session);
+        }
+
+        Ok(summary)
+    }
+
     /// Retries transcription for a failed or interrupted session.
     ///
     /// This method:
@@ -313,7 +713,10 @@ impl MeetingSessionManager {
     /// * `Ok(())` - If the transcript was saved and status updated successfully
     /// * `Err` - If file writing or database update fails
     pub fn save_transcript(&self, session_id: &str, transcript_text: &str) -> Result<()> {
-        self.save_transcript_and_update_status(session_id, transcript_text)
+        self.save_transcript_and_update_status(
+            session_id,
+            &TranscriptionResult::text_only(transcript_text),
+        )
     }
 
     /// Updates the in-memory state with error message for a failed session.
@@ -339,154 +742,1588 @@ impl MeetingSessionManager {
     /// * `session_id` - The unique ID of the session that failed
     /// * `error_msg` - The error message describing the failure
     fn handle_transcription_failure(&self, session_id: &str, error_msg: &str) {
+        // Logged through the context so the failure shows up in the recent error log
+        let log_ctx = self.log_context(session_id, "transcription");
+        log_ctx.log_error(error_msg);
+
         // Update status to Failed in database
         if let Err(update_err) = self.update_session_status_with_error(
             session_id,
             MeetingStatus::Failed,
             error_msg,
         ) {
-            error!(
-                "Failed to update session {} status to Failed: {}",
-                session_id, update_err
-            );
+            log_ctx.log_error(&format!(
+                "Failed to update status to Failed: {}",
+                update_err
+            ));
             return;
         }
 
         // Emit meeting_failed event
         if let Ok(Some(session_data)) = self.get_session(session_id) {
             if let Err(emit_err) = self.app_handle.emit("meeting_failed", session_data.clone()) {
-                error!("Failed to emit meeting_failed event: {}", emit_err);
+                log_ctx.log_error(&format!(
+                    "Failed to emit meeting_failed event: {}",
+                    emit_err
+                ));
             } else {
-                info!("Emitted meeting_failed event for session {}", session_id);
+                log_ctx.log_debug("Emitted meeting_failed event");
             }
         }
 
         // Update in-memory state with error message
-        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
-        if let Some(mut session) = state.current_session.take() {
-            if session.id == session_id {
-                session.status = MeetingStatus::Failed;
-                session.error_message = Some(error_msg.to_string());
-                state.current_session = Some(session);
-            }
+        self.set_session_error(session_id, error_msg);
+    }
+
+    /// Adds a session to the background transcription queue.
+    ///
+    /// Sessions are transcribed one at a time in the order they were queued. If no
+    /// worker is currently draining the queue, one is spawned.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to transcribe
+    pub fn enqueue_transcription(&self, session_id: &str) {
+        let should_spawn = {
+            let mut queue = self
+                .transcription_queue
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            queue.enqueue(session_id)
+        };
+
+        info!("Queued transcription for session {}", session_id);
+        self.emit_queue_updated();
+
+        if should_spawn {
+            let manager = self.clone();
+            thread::spawn(move || manager.drain_transcription_queue());
         }
     }
 
-    /// Gets a connection to the meetings database.
-    fn get_connection(&self) -> Result<Connection> {
-        Ok(Connection::open(&self.db_path)?)
+    /// Queues a finished session to go through the full pipeline again with the
+    /// current settings, overwriting its transcript. A session that had a summary
+    /// gets a new one once the transcript is saved.
+    ///
+    /// The session is checked and queued under the queue lock, so concurrent
+    /// calls can't queue it twice or reprocess it while it is transcribing.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to reprocess
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The session, now Processing
+    /// * `Err` - If the session is not Completed/Failed, has no audio, or is already queued
+    pub fn reprocess_session(&self, session_id: &str) -> Result<MeetingSession> {
+        let should_spawn = {
+            let mut queue = self
+                .transcription_queue
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            if queue.contains(session_id) {
+                return Err(anyhow::anyhow!(
+                    "Session is already queued for transcription"
+                ));
+            }
+
+            let session = self
+                .get_session(session_id)?
+                .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+            if !matches!(
+                session.status,
+                MeetingStatus::Completed | MeetingStatus::Failed
+            ) {
+                return Err(anyhow::anyhow!(
+                    "Cannot reprocess session: session is in {:?} status, expected Completed or Failed",
+                    session.status
+                ));
+            }
+
+            self.retry_transcription_for_session(session_id)?;
+            if session.summary_path.is_some() {
+                let mut resummarize = self
+                    .resummarize_sessions
+                    .lock()
+                    .unwrap_or_else(|p| p.into_inner());
+                resummarize.insert(session_id.to_string());
+            }
+            queue.enqueue(session_id)
+        };
+
+        info!("Queued session {} for reprocessing", session_id);
+        self.emit_queue_updated();
+
+        if should_spawn {
+            let manager = self.clone();
+            thread::spawn(move || manager.drain_transcription_queue());
+        }
+
+        self.get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))
     }
 
-    /// Formats a Unix timestamp into a human-readable meeting title.
+    /// Removes a session from the transcription queue before it starts.
+    ///
+    /// A removed session is marked Failed with a "cancelled" message so that it
+    /// can be retried later.
     ///
     /// # Arguments
-    /// * `timestamp` - Unix timestamp in seconds
+    /// * `session_id` - The unique ID of the queued session
     ///
     /// # Returns
-    /// A formatted string like "Meeting - January 15, 2025 3:30 PM"
-    fn format_meeting_title(&self, timestamp: i64) -> String {
-        if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
-            let local_datetime = utc_datetime.with_timezone(&Local);
-            format!(
-                "Meeting - {}",
-                local_datetime
-                    .format("%B %e, %Y %l:%M %p")
-                    .to_string()
-                    .trim()
-            )
+    /// * `Ok(CancelOutcome)` - Whether the session was removed, already running, or not queued
+    /// * `Err` - If the session status could not be updated
+    pub(crate) fn cancel_queued_transcription(&self, session_id: &str) -> Result<CancelOutcome> {
+        let outcome = {
+            let mut queue = self
+                .transcription_queue
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            queue.cancel(session_id)
+        };
+
+        if outcome == CancelOutcome::Removed {
+            info!("Cancelled queued transcription for session {}", session_id);
+            self.handle_transcription_failure(session_id, "Transcription cancelled");
+            self.emit_queue_updated();
+        }
+
+        Ok(outcome)
+    }
+
+    /// Stops all in-flight meeting work, e.g. when the user logs out or locks the screen.
+    ///
+    /// This method:
+    /// 1. Stops an active recording, finalizing its audio and marking it Interrupted
+    ///    so it is offered for recovery later
+    /// 2. Cancels a stop held for resuming, and every transcription still waiting in
+    ///    the queue, marking those sessions Failed so they can be retried
+    /// 3. Clears the current session from memory
+    ///
+    /// A transcription that is already running can't be aborted and is left to finish.
+    /// Safe to call repeatedly and when nothing is active.
+    ///
+    /// # Returns
+    /// A summary of what was stopped or cancelled
+    pub fn suspend(&self) -> SuspendSummary {
+        let mut summary = SuspendSummary::default();
+
+        let recording_id = {
+            let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state
+                .current_session
+                .as_ref()
+                .filter(|s| s.status.is_capturing())
+                .map(|s| s.id.clone())
+        };
+        if let Some(session_id) = recording_id {
+            let interrupted =
+                self.interrupt_active_recording("suspend", "suspend", "suspend_interrupted");
+            if interrupted {
+                summary.interrupted_session = Some(session_id);
+            }
+        }
+
+        // A stop held for resuming would otherwise be queued once the grace window ends
+        let held_stop = {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.recent_stop.take()
+        };
+
+        let (pending, running) = {
+            let mut queue = self
+                .transcription_queue
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            (queue.cancel_all_pending(), queue.running().map(str::to_string))
+        };
+
+        let cancelled = held_stop.map(|stop| stop.session_id).into_iter().chain(pending);
+        for session_id in cancelled {
+            self.handle_transcription_failure(&session_id, "Transcription cancelled");
+            summary.cancelled_sessions.push(session_id);
+        }
+        summary.running_session = running;
+
+        // Drop the finished session from memory; the running one is tracked by the queue
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if state
+                .current_session
+                .as_ref()
+                .map_or(false, |s| !s.status.is_capturing())
+            {
+                state.current_session = None;
+            }
+        }
+
+        if !summary.cancelled_sessions.is_empty() {
+            self.emit_queue_updated();
+        }
+
+        info!("Suspended meeting work: {:?}", summary);
+        summary
+    }
+
+    /// Checks the meetings database and every session's files for problems.
+    ///
+    /// Runs SQLite's integrity check, then reports missing or unreadable files,
+    /// session folders without a row, and statuses that contradict the session's
+    /// data or what is currently recording or queued. Nothing is changed; use
+    /// `refresh_session_status_from_files` to fix a reported session.
+    ///
+    /// # Returns
+    /// * `Ok(IntegrityReport)` - The problems found (empty if healthy)
+    /// * `Err` - If the database can't be queried
+    pub fn run_integrity_check(&self) -> Result<IntegrityReport> {
+        let mut active_ids: Vec<String> = {
+            let queue = self
+                .transcription_queue
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            let snapshot = queue.snapshot();
+            snapshot.running.into_iter().chain(snapshot.pending).collect()
+        };
+        {
+            let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(current) = state.current_session.as_ref() {
+                if current.status.is_capturing() {
+                    active_ids.push(current.id.clone());
+                }
+            }
+            if let Some(stop) = state.recent_stop.as_ref() {
+                active_ids.push(stop.session_id.clone());
+            }
+        }
+
+        let report = check_integrity(&self.db_path, &self.meetings_dir, &active_ids)?;
+        if report.is_healthy() {
+            info!("Meetings integrity check passed ({} sessions)", report.sessions_checked);
         } else {
-            format!("Meeting {}", timestamp)
+            warn!(
+                "Meetings integrity check found {} issue(s) (database ok: {})",
+                report.issues.len(),
+                report.database_ok
+            );
+        }
+        Ok(report)
+    }
+
+    /// Lists Completed sessions whose transcript file is missing, so they can
+    /// be offered for transcribing again.
+    pub fn list_sessions_missing_transcript(&self) -> Result<Vec<MeetingSession>> {
+        let sessions = sessions_missing_transcript(&self.db_path, &self.meetings_dir)?;
+        if !sessions.is_empty() {
+            warn!(
+                "{} completed session(s) are missing their transcript",
+                sessions.len()
+            );
+        }
+        Ok(sessions)
+    }
+
+    /// Re-applies the global custom words to every Completed transcript.
+    ///
+    /// Emits `meeting_transcript_updated` with each session whose transcript changed.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - How many transcripts were rewritten
+    /// * `Err` - If the sessions can't be listed
+    pub fn reapply_custom_words_all(&self) -> Result<u32> {
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let changed = reapply_custom_words(
+            &self.db_path,
+            &self.meetings_dir,
+            &settings.custom_words,
+            settings.word_correction_threshold,
+        )?;
+
+        for session in &changed {
+            if let Err(e) = self.app_handle.emit("meeting_transcript_updated", session) {
+                error!("Failed to emit meeting_transcript_updated event: {}", e);
+            }
+        }
+        info!("Custom words changed {} transcript(s)", changed.len());
+        Ok(changed.len() as u32)
+    }
+
+    /// Finds the sessions whose transcript contains every word of `query`.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<MeetingSearchResult>)` - Matching sessions with a snippet, best
+    ///   match first; none for an empty query
+    /// * `Err` - If the search index can't be queried
+    pub fn search_meetings(&self, query: &str) -> Result<Vec<MeetingSearchResult>> {
+        search_transcripts(&self.db_path, query, SEARCH_RESULT_LIMIT)
+    }
+
+    /// Rebuilds the transcript search index from the transcript files on disk.
+    ///
+    /// Runs on its own at startup when transcripts exist but none are indexed;
+    /// this is for bringing the index back in line by hand.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - How many transcripts were indexed
+    /// * `Err` - If the index can't be rebuilt
+    pub fn reindex_transcripts(&self) -> Result<u32> {
+        let count = reindex_transcripts(&self.db_path, &self.meetings_dir)?;
+        info!("Indexed {} transcript(s) for search", count);
+        Ok(count)
+    }
+
+    /// Deletes every meeting session, its files, and its database row.
+    ///
+    /// Queued transcriptions and a stop held for resuming are cancelled first.
+    /// Refuses while recording, while a recording is starting, or while a
+    /// transcription is running, since those would write into the wiped data.
+    ///
+    /// # Returns
+    /// * `Ok(ResetSummary)` - How many sessions, folders, and bytes were removed
+    /// * `Err` - If something is active or deleting fails
+    pub fn reset_all(&self) -> Result<ResetSummary> {
+        if self
+            .get_current_status()
+            .map_or(false, |status| status.is_capturing())
+        {
+            return Err(anyhow::anyhow!(
+                "Cannot reset meetings while recording; stop the recording first"
+            ));
+        }
+        let _start_permit = self.start_guard.try_begin().ok_or_else(|| {
+            anyhow::anyhow!("Cannot reset meetings while a recording is starting")
+        })?;
+        // Checked before anything is cancelled, so a refused reset changes nothing
+        let running = {
+            let queue = self
+                .transcription_queue
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            queue.running().map(str::to_string)
+        };
+        if let Some(running) = running {
+            return Err(anyhow::anyhow!(
+                "Cannot reset meetings while session {} is being transcribed",
+                running
+            ));
+        }
+
+        let suspended = self.suspend();
+        // A queued transcription may have started since the check
+        if let Some(running) = suspended.running_session {
+            return Err(anyhow::anyhow!(
+                "Cannot reset meetings while session {} is being transcribed",
+                running
+            ));
+        }
+
+        let summary = reset_meetings_data(&self.db_path, &self.meetings_dir)?;
+
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.current_session = None;
+        }
+        *self.speech_ratios.lock().unwrap_or_else(|p| p.into_inner()) = SpeechRatioCache::default();
+
+        warn!(
+            "Reset all meetings: removed {} session(s) and {} bytes",
+            summary.sessions_removed, summary.bytes_freed
+        );
+        Ok(summary)
+    }
+
+    /// Returns true if the session is waiting in, or running from, the transcription queue.
+    pub fn is_transcription_queued(&self, session_id: &str) -> bool {
+        let queue = self
+            .transcription_queue
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        queue.contains(session_id)
+    }
+
+    /// Returns a snapshot of the transcription queue.
+    pub fn get_transcription_queue(&self) -> super::queue::TranscriptionQueueSnapshot {
+        let queue = self
+            .transcription_queue
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        queue.snapshot()
+    }
+
+    /// Saves the queue to disk and emits `meeting_queue_updated` with its contents.
+    ///
+    /// Called after every change to the queue, so the saved copy never lists a
+    /// session that has already finished.
+    fn emit_queue_updated(&self) {
+        let snapshot = self.get_transcription_queue();
+        if let Err(e) = save_queue(&self.meetings_dir, &snapshot) {
+            warn!("Failed to save transcription queue: {}", e);
+        }
+        if let Err(e) = self.app_handle.emit("meeting_queue_updated", snapshot) {
+            error!("Failed to emit meeting_queue_updated event: {}", e);
+        }
+    }
+
+    /// Transcribes queued sessions one by one until the queue is empty.
+    fn drain_transcription_queue(&self) {
+        let _guard = DrainGuard::new(&self.transcription_queue);
+        loop {
+            let next = {
+                let mut queue = self
+                    .transcription_queue
+                    .lock()
+                    .unwrap_or_else(|p| p.into_inner());
+                queue.start_next()
+            };
+            self.emit_queue_updated();
+
+            match next {
+                Some(session_id) => self.run_transcription_job(&session_id),
+                None => {
+                    debug!("Transcription queue drained");
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Transcribes a single session's audio, saves the transcript, and emits the
+    /// resulting `meeting_completed` or `meeting_failed` event.
+    fn run_transcription_job(&self, session_id: &str) {
+        debug!(
+            "Background transcription task started for session {}",
+            session_id
+        );
+        // Taken up front so a failed run doesn't leave it for the next one
+        let keep_policy = {
+            let mut policies = self
+                .retry_keep_policies
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            policies.remove(session_id)
+        };
+        let resummarize = {
+            let mut resummarize = self
+                .resummarize_sessions
+                .lock()
+                .unwrap_or_else(|p| p.into_inner());
+            resummarize.remove(session_id)
+        };
+
+        let session = match self.get_session(session_id) {
+            Ok(Some(session)) => session,
+            Ok(None) => {
+                error!("Queued session {} no longer exists", session_id);
+                return;
+            }
+            Err(e) => {
+                self.handle_transcription_failure(
+                    session_id,
+                    &format!("Failed to load session: {}", e),
+                );
+                return;
+            }
+        };
+
+        let audio_path = match session.audio_path.as_ref() {
+            Some(path) => path,
+            None => {
+                self.handle_transcription_failure(
+                    session_id,
+                    "Session has no audio file to transcribe",
+                );
+                return;
+            }
+        };
+
+        let preprocessing = self.preprocessing_for_session(&session);
+        let live_captions = self
+            .template_for_session(&session)
+            .map_or(false, |template| template.live_captions);
+        let mut emit_partial = forward_partials(session_id, |partial| {
+            if let Err(e) = self.app_handle.emit("meeting_partial_transcript", partial) {
+                warn!("Failed to emit meeting_partial_transcript event: {}", e);
+            }
+        });
+        let on_partial: Option<PartialCallback> = if live_captions {
+            Some(&mut emit_partial)
+        } else {
+            None
+        };
+
+        match self.process_transcription(audio_path, &preprocessing, on_partial) {
+            Ok(mut transcription) => {
+                debug!(
+                    "Background transcription succeeded for session {}: {} bytes, {} segment(s)",
+                    session_id,
+                    transcription.text.len(),
+                    transcription.segments.len()
+                );
+
+                self.apply_paragraph_formatting(&session, &mut transcription);
+                let transcription = match keep_policy {
+                    Some(policy) => self.apply_retry_keep_policy(&session, policy, transcription),
+                    None => transcription,
+                };
+
+                // Save transcript and update status to Completed
+                if let Err(e) = self.save_transcript_and_update_status(session_id, &transcription) {
+                    let error_msg = format!("Failed to save transcript: {}", e);
+                    error!(
+                        "Failed to save transcript for session {}: {}",
+                        session_id, error_msg
+                    );
+                    self.handle_transcription_failure(session_id, &error_msg);
+                } else {
+                    info!("Session {} transcription completed successfully", session_id);
+
+                    self.apply_auto_title(&session, &transcription.text);
+                    self.apply_audio_archival(&session);
+
+                    // Emit meeting_completed event
+                    if let Ok(Some(session_data)) = self.get_session(session_id) {
+                        if let Err(emit_err) =
+                            self.app_handle.emit("meeting_completed", session_data.clone())
+                        {
+                            error!("Failed to emit meeting_completed event: {}", emit_err);
+                        } else {
+                            info!("Emitted meeting_completed event for session {}", session_id);
+                        }
+                    }
+
+                    // Summarizing and post-actions may call out to an LLM, so they run
+                    // apart from the queue to let the next session start transcribing
+                    let manager = self.clone();
+                    let session_id = session_id.to_string();
+                    thread::spawn(move || {
+                        manager.run_after_completion(&session_id, &session, resummarize)
+                    });
+                }
+            }
+            Err(e) => {
+                let error_msg = format!("Transcription failed: {}", e);
+                error!(
+                    "Background transcription failed for session {}: {}",
+                    session_id, error_msg
+                );
+                self.handle_transcription_failure(session_id, &error_msg);
+            }
+        }
+    }
+
+    /// Sets which transcript the next retry of a session keeps if the session
+    /// already has one. Taken by the retry when it runs.
+    pub fn set_retry_keep_policy(&self, session_id: &str, policy: RetryKeepPolicy) {
+        let mut policies = self
+            .retry_keep_policies
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        policies.insert(session_id.to_string(), policy);
+    }
+
+    /// Returns both transcripts of the session's last retry that had a keep
+    /// policy, or `None` if there was none since launch.
+    pub fn get_retry_comparison(&self, session_id: &str) -> Option<RetryComparison> {
+        let comparisons = self
+            .retry_comparisons
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        comparisons.get(session_id).cloned()
+    }
+
+    /// Picks between the session's stored transcript and a retry's result
+    /// according to `policy`.
+    ///
+    /// When nothing is stored yet, the new transcript is kept.
+    fn apply_retry_keep_policy(
+        &self,
+        session: &MeetingSession,
+        policy: RetryKeepPolicy,
+        transcription: TranscriptionResult,
+    ) -> TranscriptionResult {
+        let previous = match load_stored_transcript(
+            &self.meetings_dir,
+            &session.id,
+            session.transcript_path.as_deref(),
+        ) {
+            Ok(Some(previous)) => previous,
+            Ok(None) => return transcription,
+            Err(e) => {
+                warn!(
+                    "Failed to read stored transcript of session {}, keeping the new one: {}",
+                    session.id, e
+                );
+                return transcription;
+            }
+        };
+
+        let (chosen, comparison) = apply_keep_policy(&session.id, policy, previous, transcription);
+        info!(
+            "Retry of session {} with {:?} kept the {:?} transcript",
+            session.id, policy, comparison.kept
+        );
+        let mut comparisons = self
+            .retry_comparisons
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        comparisons.insert(session.id.clone(), comparison);
+        chosen
+    }
+
+    /// Finishes a session whose recording contained no speech, without transcribing it.
+    ///
+    /// By default the session is Completed with an empty transcript and a
+    /// "No speech detected" note; templates with `fail_on_no_speech` mark it Failed instead.
+    fn finish_without_speech(&self, session_id: &str) {
+        info!("No speech detected in session {}, skipping transcription", session_id);
+
+        let template_id = self
+            .get_session(session_id)
+            .ok()
+            .flatten()
+            .and_then(|session| session.template_id);
+        let fail_on_no_speech = template_id.map_or(false, |id| {
+            crate::settings::get_settings(&self.app_handle)
+                .meeting_templates
+                .iter()
+                .any(|t| t.id == id && t.fail_on_no_speech)
+        });
+
+        if fail_on_no_speech {
+            self.handle_transcription_failure(session_id, NO_SPEECH_NOTE);
+            return;
+        }
+
+        if let Err(e) =
+            self.save_transcript_and_update_status(session_id, &TranscriptionResult::text_only(""))
+        {
+            self.handle_transcription_failure(
+                session_id,
+                &format!("Failed to save transcript: {}", e),
+            );
+            return;
+        }
+
+        // The note explains the empty transcript without marking the session as errored
+        if let Err(e) =
+            super::db::set_session_status_note(&self.db_path, session_id, Some(NO_SPEECH_NOTE))
+        {
+            error!("Failed to record no-speech note for session {}: {}", session_id, e);
+        }
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(session) = state.current_session.as_mut() {
+                if session.id == session_id {
+                    session.status_note = Some(NO_SPEECH_NOTE.to_string());
+                }
+            }
+        }
+
+        if let Ok(Some(session_data)) = self.get_session(session_id) {
+            if let Err(e) = self.app_handle.emit("meeting_completed", session_data) {
+                error!("Failed to emit meeting_completed event: {}", e);
+            } else {
+                info!("Emitted meeting_completed event for session {}", session_id);
+            }
+        }
+    }
+
+    /// Looks up the template a session was started from, if it still exists.
+    fn template_for_session(&self, session: &MeetingSession) -> Option<MeetingTemplate> {
+        let template_id = session.template_id.as_ref()?;
+        crate::settings::get_settings(&self.app_handle)
+            .meeting_templates
+            .into_iter()
+            .find(|t| &t.id == template_id)
+    }
+
+    /// Runs the optional steps that follow a session's transcription: the
+    /// summary when `meeting_auto_summarize` is on or `resummarize` is set by a
+    /// reprocess, then the template's post-actions.
+    ///
+    /// Templates whose post-actions already generate a summary aren't summarized twice.
+    fn run_after_completion(&self, session_id: &str, session: &MeetingSession, resummarize: bool) {
+        let template = self.template_for_session(session);
+        let summarized_by_template = template.as_ref().map_or(false, |t| {
+            t.post_actions.contains(&PostAction::GenerateSummary)
+        });
+        let summarize =
+            resummarize || crate::settings::get_settings(&self.app_handle).meeting_auto_summarize;
+        if summarize && !summarized_by_template {
+            if let Err(e) = tauri::async_runtime::block_on(self.generate_summary(session_id)) {
+                self.log_context(session_id, "summary")
+                    .log_warning(format!("Summary after transcription failed: {}", e));
+            }
+        }
+
+        if let Some(template) = template {
+            self.apply_post_actions(session_id, &template);
+        }
+    }
+
+    /// Runs the template's post-completion actions for a Completed session, in
+    /// order. Failures are logged and don't stop the remaining actions or change
+    /// the session's status.
+    pub fn apply_post_actions(&self, session_id: &str, template: &MeetingTemplate) {
+        if template.post_actions.is_empty() {
+            return;
+        }
+        let failures =
+            run_post_actions(session_id, &template.post_actions, |action| match action {
+                PostAction::ExportSrt => self.post_action_export_srt(session_id),
+                PostAction::GenerateSummary => self.post_action_generate_summary(session_id),
+                PostAction::AddToHistory => self.post_action_add_to_history(session_id),
+                PostAction::CopyTranscriptPath => self.post_action_copy_transcript_path(session_id),
+                PostAction::AppendToDailyNote => self.post_action_append_to_daily_note(session_id),
+            });
+        // Recorded in the error log so the support panel shows them
+        for (action, error) in failures {
+            self.log_context(session_id, "post_actions")
+                .log_warning(format!("{:?} failed: {}", action, error));
+        }
+    }
+
+    fn post_action_export_srt(&self, session_id: &str) -> Result<()> {
+        let dest = self
+            .meetings_dir
+            .join(session_id)
+            .join(POST_ACTION_SRT_FILENAME);
+        self.export_srt_to_path(session_id, &dest)?;
+        Ok(())
+    }
+
+    fn post_action_generate_summary(&self, session_id: &str) -> Result<()> {
+        // Runs on the after-completion thread, so blocking on the request is fine
+        tauri::async_runtime::block_on(self.generate_summary(session_id))?;
+        Ok(())
+    }
+
+    fn post_action_add_to_history(&self, session_id: &str) -> Result<()> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        let transcript_path = session
+            .transcript_path
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Session has no transcript"))?;
+        let transcript = fs::read_to_string(self.meetings_dir.join(transcript_path))?;
+        let samples = match session.audio_path.as_ref() {
+            Some(audio_path) => load_16k_samples(&self.meetings_dir.join(audio_path))?
+                .audio
+                .into_samples(),
+            None => Vec::new(),
+        };
+
+        let history = self
+            .app_handle
+            .state::<Arc<crate::managers::history::HistoryManager>>();
+        tauri::async_runtime::block_on(history.save_transcription(samples, transcript, None, None))
+    }
+
+    fn post_action_copy_transcript_path(&self, session_id: &str) -> Result<()> {
+        let transcript_path = self
+            .get_session(session_id)?
+            .and_then(|session| session.transcript_path)
+            .ok_or_else(|| anyhow::anyhow!("Session has no transcript"))?;
+        let full_path = self.meetings_dir.join(transcript_path);
+        self.app_handle
+            .clipboard()
+            .write_text(full_path.to_string_lossy().into_owned())
+            .map_err(|e| anyhow::anyhow!("Failed to write to clipboard: {}", e))
+    }
+
+    fn post_action_append_to_daily_note(&self, session_id: &str) -> Result<()> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        // A summary generated by an earlier action is preferred over the full transcript
+        let body_path = session
+            .summary_path
+            .as_ref()
+            .or(session.transcript_path.as_ref())
+            .ok_or_else(|| anyhow::anyhow!("Session has no transcript"))?;
+        let body = fs::read_to_string(self.meetings_dir.join(body_path))?;
+        let note = append_to_daily_note(&self.meetings_dir, &session, &body, &Local)?;
+        debug!("Appended session {} to {:?}", session_id, note);
+        Ok(())
+    }
+
+    /// Retitles a session from its transcript if its template opts in and the
+    /// title is still the generated one. User-edited titles are never touched.
+    fn apply_auto_title(&self, session: &MeetingSession, transcript: &str) {
+        let template_id = match session.template_id.as_ref() {
+            Some(id) => id,
+            None => return,
+        };
+
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let template = match settings.meeting_templates.iter().find(|t| &t.id == template_id) {
+            Some(template) if template.auto_title_from_transcript => template,
+            _ => return,
+        };
+
+        // Re-read the title in case it was edited while transcribing
+        let current_title = match self.get_session(&session.id) {
+            Ok(Some(latest)) => latest.title,
+            _ => return,
+        };
+
+        let new_title =
+            match auto_title_for(&current_title, Some(&template.title_template), transcript) {
+                Some(title) => title,
+                None => return,
+            };
+
+        if let Err(e) = self.update_session_title(&session.id, &new_title) {
+            error!("Failed to auto-update title for session {}: {}", session.id, e);
+            return;
+        }
+
+        #[derive(Clone, Serialize)]
+        struct TitleUpdatedEvent {
+            session_id: String,
+            title: String,
+        }
+
+        if let Err(e) = self.app_handle.emit(
+            "meeting_title_updated",
+            TitleUpdatedEvent {
+                session_id: session.id.clone(),
+                title: new_title,
+            },
+        ) {
+            error!("Failed to emit meeting_title_updated event: {}", e);
+        }
+    }
+
+    /// Writes a compact archive copy of the session's audio if archival is enabled.
+    ///
+    /// Failures are logged but never fail the session; the original audio is only
+    /// deleted after the archive has been written successfully.
+    fn apply_audio_archival(&self, session: &MeetingSession) {
+        let replace_original = match crate::settings::get_settings(&self.app_handle)
+            .meeting_audio_archival
+        {
+            MeetingAudioArchival::Off => return,
+            MeetingAudioArchival::KeepOriginal => false,
+            MeetingAudioArchival::ReplaceOriginal => true,
+        };
+
+        let audio_path = match session.audio_path.as_ref() {
+            Some(path) => path,
+            None => return,
+        };
+
+        // Already transcribed from the archive (e.g., on reprocess)
+        if session.archive_path.as_ref() == Some(audio_path) {
+            return;
+        }
+
+        if let Err(e) = archive_session_audio(
+            &self.db_path,
+            &self.meetings_dir,
+            &session.id,
+            audio_path,
+            replace_original,
+        ) {
+            error!("Failed to archive audio for session {}: {}", session.id, e);
+        }
+    }
+
+    /// Resolves the preprocessing chain from the session's template, if any.
+    ///
+    /// Steps are validated when templates are saved; any that fail to parse
+    /// (e.g., from a hand-edited settings file) are skipped.
+    fn preprocessing_for_session(&self, session: &MeetingSession) -> Vec<PreprocessOp> {
+        let template_id = match session.template_id.as_ref() {
+            Some(id) => id,
+            None => return Vec::new(),
+        };
+
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let template = match settings.meeting_templates.iter().find(|t| &t.id == template_id) {
+            Some(template) => template,
+            None => return Vec::new(),
+        };
+
+        template
+            .preprocessing
+            .iter()
+            .filter_map(|step| match PreprocessOp::parse(&step.name, &step.params) {
+                Ok(op) => Some(op),
+                Err(e) => {
+                    warn!("Skipping invalid preprocessing step in template '{}': {}", template.id, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Breaks the transcript into paragraphs if the session's template asks for it.
+    ///
+    /// The unformatted text is saved to `transcript.raw.txt` first; if that
+    /// fails the transcript is left as-is rather than losing the original.
+    fn apply_paragraph_formatting(
+        &self,
+        session: &MeetingSession,
+        transcription: &mut TranscriptionResult,
+    ) {
+        let template_id = match session.template_id.as_ref() {
+            Some(id) => id,
+            None => return,
+        };
+
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let template = settings
+            .meeting_templates
+            .iter()
+            .find(|t| &t.id == template_id);
+        let target_chars = match template {
+            Some(template) if template.format_paragraphs => template.paragraph_target_chars,
+            _ => return,
+        };
+
+        let session_dir = self.meetings_dir.join(&session.id);
+        let raw_path = session_dir.join(RAW_TRANSCRIPT_FILENAME);
+        if let Err(e) = write_atomic(&raw_path, transcription.text.as_bytes()) {
+            warn!(
+                "Failed to save raw transcript for session {}, skipping paragraph formatting: {}",
+                session.id, e
+            );
+            return;
+        }
+
+        transcription.text = format_paragraphs(&transcription.text, target_chars as usize);
+    }
+
+    /// Gets a connection to the meetings database.
+    fn get_connection(&self) -> Result<Connection> {
+        Ok(Connection::open(&self.db_path)?)
+    }
+
+    /// Formats a Unix timestamp into a human-readable meeting title.
+    ///
+    /// # Arguments
+    /// * `timestamp` - Unix timestamp in seconds
+    ///
+    /// # Returns
+    /// A formatted string like "Meeting - January 15, 2025 3:30 PM"
+    fn format_meeting_title(&self, timestamp: i64) -> String {
+        if let Some(utc_datetime) = DateTime::from_timestamp(timestamp, 0) {
+            let local_datetime = utc_datetime.with_timezone(&Local);
+            format!(
+                "Meeting - {}",
+                local_datetime
+                    .format("%B %e, %Y %l:%M %p")
+                    .to_string()
+                    .trim()
+            )
+        } else {
+            format!("Meeting {}", timestamp)
+        }
+    }
+
+    /// Creates a new meeting session with a unique UUID and dedicated folder.
+    ///
+    /// This method:
+    /// 1. Generates a unique UUID for the session
+    /// 2. Creates a dedicated folder under `meetings/{session-id}/`
+    /// 3. Inserts the session into the database
+    /// 4. Returns the created session
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The newly created session
+    /// * `Err` - If folder creation or database insertion fails
+    #[allow(dead_code)]
+    pub fn create_session(&self) -> Result<MeetingSession> {
+        self.create_session_with_audio_source(AudioSourceType::default())
+    }
+
+    /// Creates a new meeting session with a specified audio source.
+    ///
+    /// # Arguments
+    /// * `audio_source` - The audio source configuration for this meeting
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The newly created session
+    /// * `Err` - If folder creation or database insertion fails
+    pub fn create_session_with_audio_source(
+        &self,
+        audio_source: AudioSourceType,
+    ) -> Result<MeetingSession> {
+        let id = Uuid::new_v4().to_string();
+        let created_at = chrono::Utc::now().timestamp();
+        let title = self.format_meeting_title(created_at);
+
+        // Create the session folder
+        let session_dir = self.meetings_dir.join(&id);
+        fs::create_dir_all(&session_dir)?;
+        debug!("Created session folder: {:?}", session_dir);
+
+        // Create the session object
+        let session = MeetingSession::new_with_audio_source(
+            id.clone(),
+            title.clone(),
+            created_at,
+            audio_source.clone(),
+        );
+
+        // Insert into database
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO meeting_sessions (id, title, created_at, status, audio_source, template_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                session.id,
+                session.title,
+                session.created_at,
+                self.status_to_string(&session.status),
+                self.audio_source_to_string(&audio_source),
+                session.template_id
+            ],
+        )?;
+
+        info!(
+            "Created new meeting session: {} - {} (audio: {:?})",
+            session.id, session.title, audio_source
+        );
+
+        Ok(session)
+    }
+
+    /// Retrieves just a session's id, title, status, and error message.
+    ///
+    /// Cheaper than `get_session` for UIs polling while a transcription runs.
+    ///
+    /// # Returns
+    /// * `Ok(Some(SessionLite))` - The session if found
+    /// * `Ok(None)` - If no session with the given ID exists
+    /// * `Err` - If database query fails
+    pub fn get_session_lite(&self, session_id: &str) -> Result<Option<SessionLite>> {
+        super::db::get_session_lite(&self.db_path, session_id)
+    }
+
+    /// Retrieves a meeting session by its ID.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to retrieve
+    ///
+    /// # Returns
+    /// * `Ok(Some(MeetingSession))` - The session if found
+    /// * `Ok(None)` - If no session with the given ID exists
+    /// * `Err` - If database query fails
+    pub fn get_session(&self, session_id: &str) -> Result<Option<MeetingSession>> {
+        let conn = self.get_connection()?;
+        let session = conn
+            .query_row(
+                "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
+                 FROM meeting_sessions WHERE id = ?1",
+                params![session_id],
+                |row| self.row_to_session(row),
+            )
+            .optional()?;
+
+        Ok(session)
+    }
+
+    /// Retrieves the meeting session that owns the given audio file.
+    ///
+    /// # Arguments
+    /// * `rel_path` - Audio path relative to the meetings directory (e.g., "{session-id}/audio.wav").
+    ///   Backslash separators are accepted.
+    ///
+    /// # Returns
+    /// * `Ok(Some(MeetingSession))` - The session if found
+    /// * `Ok(None)` - If no session references this audio path
+    /// * `Err` - If database query fails
+    pub fn get_session_by_audio_path(&self, rel_path: &str) -> Result<Option<MeetingSession>> {
+        super::db::get_session_by_audio_path(&self.db_path, rel_path)
+    }
+
+    /// Loads a session together with its audio path, transcript, summary, and stats.
+    ///
+    /// Missing parts (no transcript yet, no summary, deleted audio) are returned as
+    /// `None` instead of failing the whole lookup.
+    ///
+    /// # Returns
+    /// * `Ok(Some(SessionDetail))` - The session and whatever outputs exist
+    /// * `Ok(None)` - If the session doesn't exist
+    /// * `Err` - If the database query fails
+    pub fn get_session_detail(&self, session_id: &str) -> Result<Option<SessionDetail>> {
+        let session = match self.get_session(session_id)? {
+            Some(session) => session,
+            None => return Ok(None),
+        };
+
+        let language = self.session_language(&session);
+        Ok(Some(build_session_detail(
+            &self.meetings_dir,
+            session,
+            &language,
+        )))
+    }
+
+    /// Determines whether a session's transcript reads left-to-right or right-to-left.
+    ///
+    /// Uses the session's transcription language, or the script of the transcript
+    /// itself when the language is auto-detected.
+    ///
+    /// # Returns
+    /// * `Ok(Some(TextDirection))` - The transcript's direction
+    /// * `Ok(None)` - If the direction can't be determined (e.g., no transcript yet)
+    /// * `Err` - If the session is not found or the database query fails
+    pub fn transcript_direction(&self, session_id: &str) -> Result<Option<TextDirection>> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+
+        let transcript = session
+            .transcript_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(self.meetings_dir.join(path)).ok());
+
+        let language = self.session_language(&session);
+        Ok(transcript_direction(&language, transcript.as_deref()))
+    }
+
+    /// Language of a session's transcript: the one stored with the transcript
+    /// when the engine reported it, otherwise the transcription language
+    /// resolved from its template like at recording start.
+    fn session_language(&self, session: &MeetingSession) -> String {
+        let stored = read_transcript_details(&self.meetings_dir, &session.id)
+            .ok()
+            .flatten()
+            .and_then(|details| details.language);
+        if let Some(language) = stored {
+            return language;
+        }
+
+        let settings = crate::settings::get_settings(&self.app_handle);
+        resolve_meeting_config(
+            &settings,
+            Some(session.audio_source.clone()),
+            session.template_id.as_deref(),
+            None,
+        )
+        .language
+    }
+
+    /// Returns the fraction of a session's recording that is speech (0.0–1.0).
+    ///
+    /// Runs the Silero VAD over the audio; the result is cached per session until
+    /// the audio file changes.
+    ///
+    /// # Returns
+    /// * `Ok(f32)` - Share of 30 ms frames classified as speech
+    /// * `Err` - If the session has no audio, or loading the audio or VAD model fails
+    pub fn get_speech_ratio(&self, session_id: &str) -> Result<f32> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        let audio_path = session
+            .audio_path
+            .as_ref()
+            .map(|path| self.meetings_dir.join(path))
+            .ok_or_else(|| anyhow::anyhow!("Session {} has no audio", session_id))?;
+        let audio_len = fs::metadata(&audio_path)?.len();
+
+        if let Some(ratio) = self
+            .speech_ratios
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .get(session_id, audio_len)
+        {
+            return Ok(ratio);
+        }
+
+        let mut vad = self.load_vad(SPEECH_VAD_THRESHOLD)?;
+
+        let audio = load_16k_samples(&audio_path)?.audio;
+        let ratio = speech_ratio(&mut vad, &audio.samples)?;
+
+        self.speech_ratios
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .insert(session_id, audio_len, ratio);
+        debug!("Speech ratio for session {}: {:.2}", session_id, ratio);
+        Ok(ratio)
+    }
+
+    /// Loads the Silero VAD model bundled with the app.
+    ///
+    /// # Arguments
+    /// * `threshold` - Speech probability above which a frame counts as speech
+    fn load_vad(&self, threshold: f32) -> Result<SileroVad> {
+        let vad_path = self
+            .app_handle
+            .path()
+            .resolve(
+                "resources/models/silero_vad_v4.onnx",
+                tauri::path::BaseDirectory::Resource,
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}", e))?;
+        SileroVad::new(vad_path, threshold)
+    }
+
+    /// Loads the VAD that drops non-speech before transcription, if
+    /// `meeting_vad_trim` is on.
+    ///
+    /// # Returns
+    /// `None` when trimming is off or the VAD can't be loaded
+    fn load_trim_vad(&self) -> Option<SileroVad> {
+        let settings = crate::settings::get_settings(&self.app_handle);
+        if !settings.meeting_vad_trim {
+            return None;
+        }
+        match self.load_vad(settings.meeting_vad_trim_threshold) {
+            Ok(vad) => Some(vad),
+            Err(e) => {
+                warn!("Skipping silence trimming, VAD unavailable: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Drops the non-speech from 16 kHz mono `audio` if `meeting_vad_trim` is on.
+    ///
+    /// # Returns
+    /// `None` when trimming is off, the VAD can't be loaded, or it found no
+    /// speech; the audio is then transcribed as it is
+    fn trim_to_speech(&self, audio: &AudioBuffer) -> Option<TrimmedAudio> {
+        let mut vad = self.load_trim_vad()?;
+        match trim_to_speech(&mut vad, &audio.samples, TRIM_PADDING_MS) {
+            Ok(trimmed) if trimmed.samples.is_empty() => {
+                debug!("VAD found no speech; transcribing the audio untrimmed");
+                None
+            }
+            Ok(trimmed) => {
+                debug!(
+                    "Trimmed non-speech before transcription: {:.1}s -> {:.1}s",
+                    audio.duration().as_secs_f32(),
+                    trimmed.samples.len() as f32 / WHISPER_SAMPLE_RATE as f32
+                );
+                Some(trimmed)
+            }
+            Err(e) => {
+                warn!("Skipping silence trimming, VAD failed: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Counts sessions per status, for rendering filter badges.
+    ///
+    /// # Returns
+    /// * `Ok(HashMap)` - Count for every status, zero where no session has it
+    /// * `Err` - If database query fails
+    pub fn get_status_counts(&self) -> Result<HashMap<MeetingStatus, u64>> {
+        super::db::get_status_counts(&self.db_path)
+    }
+
+    /// Lists sessions with the given category, newest first.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<MeetingSession>)` - Matching sessions (empty for an unknown category)
+    /// * `Err` - If database query fails
+    pub fn list_sessions_by_category(&self, category: &str) -> Result<Vec<MeetingSession>> {
+        let sessions = super::db::list_sessions_by_category(&self.db_path, category)?;
+        debug!(
+            "Listed {} meeting sessions in category {}",
+            sessions.len(),
+            category
+        );
+        Ok(sessions)
+    }
+
+    /// Estimates how many more minutes of meeting audio fit on the disk holding
+    /// the meetings directory, based on the recording WAV format.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Whole minutes of recording the free space allows
+    /// * `Err` - If the free space can't be determined
+    pub fn remaining_recording_minutes(&self) -> Result<u64> {
+        let free_bytes = available_space(&self.meetings_dir)?;
+        let minutes = remaining_recording_minutes(free_bytes, &MEETING_WAV_SPEC);
+        debug!(
+            "{} bytes free in {:?}: about {} minutes of recording",
+            free_bytes, self.meetings_dir, minutes
+        );
+        Ok(minutes)
+    }
+
+    /// Exports metadata for all sessions as CSV.
+    ///
+    /// Writes one row per session with id, title, created_at (ISO-8601), duration,
+    /// status, audio/transcript presence, and the session folder's size on disk.
+    ///
+    /// # Arguments
+    /// * `dest` - Path of the CSV file to write (replaced if it exists)
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of sessions exported
+    /// * `Err` - If listing sessions or writing the file fails
+    pub fn export_sessions_csv(&self, dest: PathBuf) -> Result<usize> {
+        self.export_sessions(ExportFormat::Csv, None, dest)
+    }
+
+    /// Exports sessions in the given format.
+    ///
+    /// # Arguments
+    /// * `format` - One of the formats returned by `list_export_formats`
+    /// * `ids` - Sessions to include; `None` includes every session. SRT
+    ///   covers exactly one session.
+    /// * `dest` - Path of the file to write (replaced if it exists)
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of sessions exported
+    /// * `Err` - If one of `ids` doesn't exist, or listing sessions or writing the file fails
+    pub fn export_sessions(
+        &self,
+        format: ExportFormat,
+        ids: Option<&[String]>,
+        dest: PathBuf,
+    ) -> Result<usize> {
+        let sessions = match ids {
+            Some(ids) => ids
+                .iter()
+                .map(|id| {
+                    self.get_session(id)?
+                        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", id))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => self.list_sessions()?,
+        };
+        let count = write_sessions_export(&self.meetings_dir, &sessions, format, &dest)?;
+        info!("Exported {} meeting sessions as {:?} to {:?}", count, format, dest);
+        Ok(count)
+    }
+
+    /// Serializes sessions with their transcript and summary text into one
+    /// JSON document that `import_sessions_json` on another instance can read.
+    ///
+    /// # Arguments
+    /// * `ids` - Sessions to include; `None` includes every session
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The JSON bundle
+    /// * `Err` - If one of `ids` doesn't exist or the database can't be read
+    pub fn export_sessions_json(&self, ids: Option<Vec<String>>) -> Result<String> {
+        let json = export_sessions_bundle(&self.db_path, &self.meetings_dir, ids.as_deref())?;
+        info!("Exported session bundle ({} bytes)", json.len());
+        Ok(json)
+    }
+
+    /// Recreates the sessions of a bundle from `export_sessions_json`, each
+    /// under a new id.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<MeetingSession>)` - The imported sessions
+    /// * `Err` - If the bundle is invalid, from a newer version, or can't be written
+    pub fn import_sessions_json(&self, json: &str) -> Result<Vec<MeetingSession>> {
+        let sessions = import_sessions_bundle(&self.db_path, &self.meetings_dir, json)?;
+        info!("Imported {} meeting sessions from bundle", sessions.len());
+        Ok(sessions)
+    }
+
+    /// Splits a session's recording at the given times into new sessions.
+    ///
+    /// Each part gets its own folder and row, titled "<title> (part N)". The
+    /// original is left as it is unless `delete_original` is set.
+    ///
+    /// # Arguments
+    /// * `split_points_ms` - Where to split, ascending and inside the recording
+    /// * `distribute_transcript` - Give each part the transcript segments that
+    ///   start in it; otherwise parts are left Recorded to be transcribed
+    /// * `delete_original` - Delete the original session once the parts exist
+    ///
+    /// # Returns
+    /// * `Ok(Vec<MeetingSession>)` - The new sessions, in recording order
+    /// * `Err` - If the session is busy, has no audio, or a split point is invalid
+    pub fn split_session(
+        &self,
+        session_id: &str,
+        split_points_ms: Vec<u64>,
+        distribute_transcript: bool,
+        delete_original: bool,
+    ) -> Result<Vec<MeetingSession>> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        if matches!(
+            session.status,
+            MeetingStatus::Recording | MeetingStatus::Paused | MeetingStatus::Processing
+        ) {
+            return Err(anyhow::anyhow!(
+                "Cannot split session {} while it is {:?}",
+                session_id,
+                session.status
+            ));
+        }
+
+        let parts = split_session_files(
+            &self.db_path,
+            &self.meetings_dir,
+            &session,
+            &split_points_ms,
+            distribute_transcript,
+        )?;
+        info!(
+            "Split session {} into {} sessions at {:?}ms",
+            session_id,
+            parts.len(),
+            split_points_ms
+        );
+
+        if delete_original {
+            self.delete_session(session_id)?;
+        }
+        Ok(parts)
+    }
+
+    /// Gets the timed segments of a session's transcript.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Segment>)` - The segments; empty if the session was never
+    ///   transcribed or its engine gave no timings
+    /// * `Err` - If the session doesn't exist or `transcript.json` can't be read
+    pub fn get_transcript_segments(&self, session_id: &str) -> Result<Vec<Segment>> {
+        if self.get_session(session_id)?.is_none() {
+            return Err(anyhow::anyhow!("Session not found: {}", session_id));
+        }
+        Ok(read_transcript_details(&self.meetings_dir, session_id)?
+            .map(|details| details.segments)
+            .unwrap_or_default())
+    }
+
+    /// Loads a session's timed transcript segments.
+    ///
+    /// Fails if the session has no stored segments, as SRT needs timings.
+    fn transcript_segments(&self, session_id: &str) -> Result<Vec<Segment>> {
+        let details = read_transcript_details(&self.meetings_dir, session_id)?
+            .ok_or_else(|| anyhow::anyhow!("No timed transcript for session {}", session_id))?;
+        if details.segments.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Transcript of session {} has no timed segments",
+                session_id
+            ));
+        }
+        Ok(details.segments)
+    }
+
+    /// Queues a long-running file operation on the background pool.
+    ///
+    /// # Returns
+    /// The job id; progress is reported with `file_job_updated` events
+    pub fn submit_file_job(
+        &self,
+        kind: FileJobKind,
+        job: impl FnOnce() -> Result<String> + Send + 'static,
+    ) -> String {
+        self.file_jobs.submit(kind, job)
+    }
+
+    /// Returns the status of a file job, or `None` if the id is unknown.
+    pub fn get_job_status(&self, job_id: &str) -> Option<FileJobStatus> {
+        self.file_jobs.status(job_id)
+    }
+
+    /// Changes how many file jobs may run at once.
+    pub fn set_file_job_concurrency(&self, max_workers: u32) {
+        self.file_jobs.set_max_workers(max_workers);
+    }
+
+    /// Returns the SHA-256 of a session's audio file as lowercase hex.
+    ///
+    /// Computed on first request and cached in the database; the cache is
+    /// cleared whenever the session's audio changes.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The hash of the audio file's bytes
+    /// * `Err` - If the session has no audio, is still recording, or the file can't be read
+    pub fn get_audio_hash(&self, session_id: &str) -> Result<String> {
+        if let Some(hash) = super::db::get_audio_hash(&self.db_path, session_id)? {
+            return Ok(hash);
+        }
+
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        if session.status.is_capturing() {
+            return Err(anyhow::anyhow!(
+                "Session {} is still recording",
+                session_id
+            ));
         }
+        let audio_path = session
+            .audio_path
+            .ok_or_else(|| anyhow::anyhow!("Session {} has no audio", session_id))?;
+
+        let hash = hash_file(&self.meetings_dir.join(&audio_path))?;
+        super::db::set_audio_hash(&self.db_path, session_id, &hash)?;
+        debug!("Hashed audio of session {}: {}", session_id, hash);
+        Ok(hash)
     }
 
-    /// Creates a new meeting session with a unique UUID and dedicated folder.
+    /// Returns where the user last left playback of a session's audio.
     ///
-    /// This method:
-    /// 1. Generates a unique UUID for the session
-    /// 2. Creates a dedicated folder under `meetings/{session-id}/`
-    /// 3. Inserts the session into the database
-    /// 4. Returns the created session
+    /// Purely advisory for the player; 0 if playback was never saved.
     ///
     /// # Returns
-    /// * `Ok(MeetingSession)` - The newly created session
-    /// * `Err` - If folder creation or database insertion fails
-    #[allow(dead_code)]
-    pub fn create_session(&self) -> Result<MeetingSession> {
-        self.create_session_with_audio_source(AudioSourceType::default())
+    /// * `Ok(u64)` - The position in milliseconds
+    /// * `Err` - If the session doesn't exist
+    pub fn get_play_position(&self, session_id: &str) -> Result<u64> {
+        super::db::get_play_position(&self.db_path, session_id)
     }
 
-    /// Creates a new meeting session with a specified audio source.
+    /// Remembers where the user left playback of a session's audio.
+    ///
+    /// Called as the user scrubs; has no effect on processing.
     ///
     /// # Arguments
-    /// * `audio_source` - The audio source configuration for this meeting
+    /// * `position_ms` - The playback position in milliseconds
+    pub fn set_play_position(&self, session_id: &str, position_ms: u64) -> Result<()> {
+        super::db::set_play_position(&self.db_path, session_id, position_ms)
+    }
+
+    /// Reads part of a session's audio file, for frontends that fetch the
+    /// audio over IPC instead of from disk.
+    ///
+    /// # Arguments
+    /// * `offset` - Byte offset into the file
+    /// * `len` - Number of bytes to read, at most `MAX_FILE_RANGE_BYTES`
     ///
     /// # Returns
-    /// * `Ok(MeetingSession)` - The newly created session
-    /// * `Err` - If folder creation or database insertion fails
-    pub fn create_session_with_audio_source(
-        &self,
-        audio_source: AudioSourceType,
-    ) -> Result<MeetingSession> {
-        let id = Uuid::new_v4().to_string();
-        let created_at = chrono::Utc::now().timestamp();
-        let title = self.format_meeting_title(created_at);
-
-        // Create the session folder
-        let session_dir = self.meetings_dir.join(&id);
-        fs::create_dir_all(&session_dir)?;
-        debug!("Created session folder: {:?}", session_dir);
-
-        // Create the session object
-        let session = MeetingSession::new_with_audio_source(
-            id.clone(),
-            title.clone(),
-            created_at,
-            audio_source.clone(),
-        );
-
-        // Insert into database
-        let conn = self.get_connection()?;
-        conn.execute(
-            "INSERT INTO meeting_sessions (id, title, created_at, status, audio_source, template_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![
-                session.id,
-                session.title,
-                session.created_at,
-                self.status_to_string(&session.status),
-                self.audio_source_to_string(&audio_source),
-                session.template_id
-            ],
-        )?;
-
-        info!(
-            "Created new meeting session: {} - {} (audio: {:?})",
-            session.id, session.title, audio_source
-        );
+    /// * `Ok(Vec<u8>)` - Exactly `len` bytes of the file
+    /// * `Err` - If the session has no audio or the range is outside the file
+    pub fn read_audio_bytes(&self, session_id: &str, offset: u64, len: u64) -> Result<Vec<u8>> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        let audio_path = session
+            .audio_path
+            .ok_or_else(|| anyhow::anyhow!("Session {} has no audio", session_id))?;
 
-        Ok(session)
+        read_file_range(&self.meetings_dir.join(&audio_path), offset, len)
     }
 
-    /// Retrieves a meeting session by its ID.
+    /// Renders a session's transcript as SRT subtitles in memory.
     ///
-    /// # Arguments
-    /// * `session_id` - The unique ID of the session to retrieve
+    /// Suited to short transcripts; use `export_srt_to_path` for long ones.
+    pub fn export_transcript_srt(&self, session_id: &str) -> Result<String> {
+        Ok(segments_to_srt(&self.transcript_segments(session_id)?))
+    }
+
+    /// Writes a session's transcript as SRT subtitles to `dest`, streaming cues
+    /// to the file instead of building the whole document in memory.
     ///
     /// # Returns
-    /// * `Ok(Some(MeetingSession))` - The session if found
-    /// * `Ok(None)` - If no session with the given ID exists
-    /// * `Err` - If database query fails
-    pub fn get_session(&self, session_id: &str) -> Result<Option<MeetingSession>> {
-        let conn = self.get_connection()?;
-        let session = conn
-            .query_row(
-                "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id
-                 FROM meeting_sessions WHERE id = ?1",
-                params![session_id],
-                |row| self.row_to_session(row),
-            )
-            .optional()?;
-
-        Ok(session)
+    /// * `Ok(usize)` - The number of cues written
+    /// * `Err` - If the session has no timed segments or writing fails
+    pub fn export_srt_to_path(&self, session_id: &str, dest: &Path) -> Result<usize> {
+        let segments = self.transcript_segments(session_id)?;
+        let cues = write_srt_to_path(&segments, dest)?;
+        info!(
+            "Exported {} SRT cues for session {} to {:?}",
+            cues, session_id, dest
+        );
+        Ok(cues)
     }
 
     /// Updates the status of a meeting session.
@@ -552,6 +2389,104 @@ impl MeetingSessionManager {
         Ok(())
     }
 
+    /// Dismisses a session's error message without changing its status.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the error message was cleared
+    /// * `Err` - If the session doesn't exist or the database update fails
+    pub fn clear_session_error(&self, session_id: &str) -> Result<()> {
+        super::db::clear_session_error(&self.db_path, session_id)?;
+
+        // Update in-memory state if this is the current session
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(session) = state.current_session.as_mut() {
+                if session.id == session_id {
+                    session.error_message = None;
+                }
+            }
+        }
+
+        info!("Cleared error message for session {}", session_id);
+        Ok(())
+    }
+
+    /// Re-derives one session's status from the files that exist on disk.
+    ///
+    /// Used to repair a row whose status no longer matches its audio/transcript
+    /// files. The session being recorded right now is left alone.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of the session to repair
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The session with its repaired status
+    /// * `Err` - If the session doesn't exist, is recording, or the update fails
+    pub fn refresh_session_status_from_files(&self, session_id: &str) -> Result<MeetingSession> {
+        {
+            let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(current) = state.current_session.as_ref() {
+                if current.id == session_id && current.status.is_capturing() {
+                    return Err(anyhow::anyhow!(
+                        "Cannot repair session {} while it is recording",
+                        session_id
+                    ));
+                }
+            }
+        }
+
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        let repaired = repair_session_status(
+            &self.db_path,
+            &self.meetings_dir,
+            &session,
+            self.is_transcription_queued(session_id),
+        )?;
+
+        // Update in-memory state if this is the current session
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(current) = state.current_session.as_mut() {
+                if current.id == session_id {
+                    current.status = repaired.status.clone();
+                    current.error_message = repaired.error_message.clone();
+                }
+            }
+        }
+
+        Ok(repaired)
+    }
+
+    /// Recalculates stored durations from each session's audio file, fixing
+    /// sessions with a missing or wrong duration.
+    ///
+    /// The session being recorded is skipped, as its file is still growing.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - How many sessions had their duration corrected
+    /// * `Err` - If the database can't be read or updated
+    pub fn recompute_durations(&self) -> Result<u32> {
+        let skip_ids: Vec<String> = {
+            let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state
+                .current_session
+                .as_ref()
+                .filter(|session| session.status.is_capturing())
+                .map(|session| session.id.clone())
+                .into_iter()
+                .collect()
+        };
+
+        let fixed = recompute_session_durations(&self.db_path, &self.meetings_dir, &skip_ids)?;
+        info!("Recomputed durations: {} session(s) corrected", fixed);
+        Ok(fixed)
+    }
+
     /// Lists all meeting sessions, ordered by creation time (newest first).
     ///
     /// # Returns
@@ -560,7 +2495,7 @@ impl MeetingSessionManager {
     pub fn list_sessions(&self) -> Result<Vec<MeetingSession>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id
+            "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
              FROM meeting_sessions ORDER BY created_at DESC",
         )?;
 
@@ -575,27 +2510,52 @@ impl MeetingSessionManager {
         Ok(sessions)
     }
 
+    /// Lists all sessions grouped by the local calendar day they started on.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DayGroup>)` - Day groups, newest day first, each with its sessions newest first
+    /// * `Err` - If the database query fails
+    pub fn list_sessions_grouped_by_day(&self) -> Result<Vec<DayGroup>> {
+        let groups = group_sessions_by_day(self.list_sessions()?, &Local);
+        debug!("Grouped meeting sessions into {} days", groups.len());
+        Ok(groups)
+    }
+
     /// Deletes a meeting session and its associated files.
     ///
     /// This method:
     /// 1. Retrieves the session from the database
-    /// 2. Deletes the session folder (containing audio and transcript files)
-    /// 3. Removes the session record from the database
+    /// 2. Refuses sessions that are recording or being transcribed
+    /// 3. Deletes the session folder (containing audio and transcript files)
+    /// 4. Removes the session record from the database
+    /// 5. Emits `meeting_deleted` with the session id
     ///
     /// # Arguments
     /// * `session_id` - The unique ID of the session to delete
     ///
     /// # Returns
     /// * `Ok(())` if the session was deleted successfully
-    /// * `Err` if session not found or deletion fails
+    /// * `Err` if session not found, busy, or deletion fails
     pub fn delete_session(&self, session_id: &str) -> Result<()> {
         info!("Deleting meeting session: {}", session_id);
 
         // Verify session exists before deleting
-        let _session = self
+        let session = self
             .get_session(session_id)?
             .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
 
+        // Its recorder or transcription would keep writing into the deleted folder
+        if matches!(
+            session.status,
+            MeetingStatus::Recording | MeetingStatus::Paused | MeetingStatus::Processing
+        ) {
+            return Err(anyhow::anyhow!(
+                "Cannot delete session {} while it is {:?}",
+                session_id,
+                session.status
+            ));
+        }
+
         // Delete session folder if it exists
         let session_folder = self.meetings_dir.join(session_id);
         if session_folder.exists() {
@@ -617,7 +2577,16 @@ impl MeetingSessionManager {
             ));
         }
 
+        conn.execute(
+            "DELETE FROM meeting_transcripts_fts WHERE session_id = ?1",
+            params![session_id],
+        )?;
+
         info!("Deleted meeting session from database: {}", session_id);
+
+        if let Err(e) = self.app_handle.emit("meeting_deleted", session_id) {
+            warn!("Failed to emit meeting_deleted event: {}", e);
+        }
         Ok(())
     }
 
@@ -626,6 +2595,8 @@ impl MeetingSessionManager {
         match status {
             MeetingStatus::Idle => "idle".to_string(),
             MeetingStatus::Recording => "recording".to_string(),
+            MeetingStatus::Paused => "paused".to_string(),
+            MeetingStatus::Recorded => "recorded".to_string(),
             MeetingStatus::Processing => "processing".to_string(),
             MeetingStatus::Completed => "completed".to_string(),
             MeetingStatus::Failed => "failed".to_string(),
@@ -638,6 +2609,8 @@ impl MeetingSessionManager {
         match s {
             "idle" => MeetingStatus::Idle,
             "recording" => MeetingStatus::Recording,
+            "paused" => MeetingStatus::Paused,
+            "recorded" => MeetingStatus::Recorded,
             "processing" => MeetingStatus::Processing,
             "completed" => MeetingStatus::Completed,
             "failed" => MeetingStatus::Failed,
@@ -651,12 +2624,17 @@ impl MeetingSessionManager {
     /// Allowed transitions:
     /// - Idle -> Recording (start recording)
     /// - Recording -> Processing (stop recording)
+    /// - Recording -> Recorded (stop recording, transcribe later)
     /// - Recording -> Failed (mic disconnect or critical error)
     /// - Recording -> Interrupted (app closed during recording)
+    /// - Recording -> Paused (pause recording)
+    /// - Paused -> Recording (resume recording)
+    /// - Paused -> Processing / Recorded / Failed / Interrupted (as from Recording)
     /// - Processing -> Completed (transcription success)
     /// - Processing -> Failed (transcription failure)
     /// - Failed -> Processing (retry transcription)
     /// - Interrupted -> Processing (resume transcription on next launch)
+    /// - Recorded -> Processing (manual transcription)
     ///
     /// # Arguments
     /// * `from` - The current state
@@ -670,8 +2648,16 @@ impl MeetingSessionManager {
             // Allowed transitions
             (MeetingStatus::Idle, MeetingStatus::Recording) => Ok(()),
             (MeetingStatus::Recording, MeetingStatus::Processing) => Ok(()),
+            (MeetingStatus::Recording, MeetingStatus::Recorded) => Ok(()), // Transcribe later
+            (MeetingStatus::Recorded, MeetingStatus::Processing) => Ok(()),
             (MeetingStatus::Recording, MeetingStatus::Failed) => Ok(()), // Mic disconnect
             (MeetingStatus::Recording, MeetingStatus::Interrupted) => Ok(()), // App shutdown
+            (MeetingStatus::Recording, MeetingStatus::Paused) => Ok(()),
+            (MeetingStatus::Paused, MeetingStatus::Recording) => Ok(()), // Resume capture
+            (MeetingStatus::Paused, MeetingStatus::Processing) => Ok(()),
+            (MeetingStatus::Paused, MeetingStatus::Recorded) => Ok(()),
+            (MeetingStatus::Paused, MeetingStatus::Failed) => Ok(()),
+            (MeetingStatus::Paused, MeetingStatus::Interrupted) => Ok(()),
             (MeetingStatus::Processing, MeetingStatus::Completed) => Ok(()),
             (MeetingStatus::Processing, MeetingStatus::Failed) => Ok(()),
             (MeetingStatus::Failed, MeetingStatus::Processing) => Ok(()),
@@ -706,6 +2692,15 @@ impl MeetingSessionManager {
             audio_source: self.string_to_audio_source(&audio_source_str),
             summary_path,
             template_id,
+            archive_path: row.get("archive_path").unwrap_or(None),
+            archive_format: row.get("archive_format").unwrap_or(None),
+            category: row.get("category").unwrap_or(None),
+            sample_rate: row.get("sample_rate").unwrap_or(None),
+            notes: row.get("notes").unwrap_or(None),
+            starred: row.get("starred").unwrap_or(false),
+            system_audio_partial: row.get("system_audio_partial").unwrap_or(false),
+            status_note: row.get("status_note").unwrap_or(None),
+            transcript_removed: row.get("transcript_removed").unwrap_or(false),
         })
     }
 
@@ -744,12 +2739,58 @@ impl MeetingSessionManager {
     /// # Returns
     /// * `Ok(MeetingSession)` - The newly created and active session
     /// * `Err` - If state guard fails, session creation, recorder initialization, or audio capture fails
+    #[allow(dead_code)]
     pub fn start_recording(&self, audio_source: AudioSourceType) -> Result<MeetingSession> {
+        self.start_recording_with_options(audio_source, RecordingOptions::default())
+    }
+
+    /// Starts recording for a new meeting session with explicit recording options.
+    ///
+    /// Same as `start_recording`, but allows tuning capture behavior (e.g., loudness
+    /// normalized mixing) per session.
+    ///
+    /// # Arguments
+    /// * `audio_source` - The audio source configuration (MicrophoneOnly, SystemOnly, or Mixed)
+    /// * `options` - Recording options, usually derived from the meeting template
+    pub fn start_recording_with_options(
+        &self,
+        audio_source: AudioSourceType,
+        options: RecordingOptions,
+    ) -> Result<MeetingSession> {
         let timer = MeetingTimer::start();
 
+        // Held until this function returns so a concurrent start can't pass the
+        // status guard before this one has stored its session
+        let _start_permit = self.start_guard.try_begin().ok_or_else(|| {
+            error!("[MEETING_START] Rejected: another start is in progress");
+            anyhow::anyhow!("Cannot start recording: a recording is already starting")
+        })?;
+
+        // Pre-flight: without any input device, opening the mic fails with a low-level error
+        check_input_device(&audio_source, list_input_devices).map_err(|e| {
+            error!("[MEETING_START] Rejected: no input device present");
+            e
+        })?;
+
+        // Pre-flight: on macOS the microphone records silence until access is granted
+        if audio_source != AudioSourceType::SystemOnly && !request_microphone_permission() {
+            error!("[MEETING_START] Rejected: microphone permission not granted");
+            return Err(MicrophonePermissionError {
+                status: microphone_permission(),
+            }
+            .into());
+        }
+
+        // A start right after a stop resumes that session instead of fragmenting it;
+        // nothing between taking it and arming the rollback below can fail
+        let resumed = self.take_resumable_session(&audio_source);
+        let (resumed_session, resumed_stop) = resumed.unzip();
+
         // State machine guard: validate transition from Idle -> Recording
         // Cannot start recording if already recording or processing
-        let current_status = {
+        let current_status = if resumed_session.is_some() {
+            None
+        } else {
             let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
             state.current_session.as_ref().map(|s| s.status.clone())
         };
@@ -762,6 +2803,12 @@ impl MeetingSessionManager {
                         "Cannot start recording: already recording an active session"
                     ));
                 }
+                MeetingStatus::Paused => {
+                    error!("[MEETING_START] Rejected: a recording is paused");
+                    return Err(anyhow::anyhow!(
+                        "Cannot start recording: an active session is paused; resume or stop it first"
+                    ));
+                }
                 MeetingStatus::Processing => {
                     error!("[MEETING_START] Rejected: session being processed");
                     return Err(anyhow::anyhow!(
@@ -790,50 +2837,166 @@ impl MeetingSessionManager {
             audio_source
         );
 
-        // Create a new session with the specified audio source
-        let session = self.create_session_with_audio_source(audio_source.clone())?;
+        let is_resume = resumed_session.is_some();
+        let session = match resumed_session {
+            Some(session) => {
+                info!("[MEETING_START] Resuming recently stopped session {}", session.id);
+                session
+            }
+            // Create a new session with the specified audio source
+            None => self.create_session_with_audio_source(audio_source.clone())?,
+        };
 
-        let log_ctx = MeetingLogContext::new(&session.id, "start_recording");
+        let log_ctx = self.log_context(&session.id, "start_recording");
         log_ctx.log_start();
 
+        // Until the session is stored as recording, any failure below removes
+        // the new session's row, folder, and partial audio again
+        let mut rollback =
+            StartRollback::new(&self.db_path, &self.meetings_dir, &session.id, is_resume);
+
         // Create audio file path: {session-id}/audio.wav
         let audio_filename = format!("{}/audio.wav", session.id);
         let audio_path = self.meetings_dir.join(&audio_filename);
 
+        // A resume that fails hands the session back to the stop it was taken from
+        if let Some(stop) = resumed_stop {
+            let manager = self.clone();
+            let audio_filename = audio_filename.clone();
+            rollback.on_abort(move || manager.restore_recent_stop(stop, &audio_filename));
+        }
+
         log_ctx.log_file_op(&audio_path.display().to_string(), None);
 
         // Initialize WAV writer for incremental writing
-        let spec = WavSpec {
-            channels: 1,
-            sample_rate: 16000,
-            bits_per_sample: 16,
-            sample_format: hound::SampleFormat::Int,
-        };
+        let spec = MEETING_WAV_SPEC;
 
         debug!(
             "[MEETING_START] [{}] WAV spec: {}Hz, {} channel(s), {}bit",
             session.id, spec.sample_rate, spec.channels, spec.bits_per_sample
         );
 
-        let audio_file = File::create(&audio_path).map_err(|e| {
-            log_ctx.log_error(&format!("Failed to create audio file: {}", e));
-            anyhow::anyhow!("Failed to create audio file: {}", e)
-        })?;
+        let wav_writer = if is_resume {
+            // Keep the audio captured before the stop and continue after it
+            open_wav_for_append(&audio_path, spec).map_err(|e| {
+                log_ctx.log_error(&format!("Failed to reopen audio file: {}", e));
+                e
+            })?
+        } else {
+            let audio_file = File::create(&audio_path).map_err(|e| {
+                log_ctx.log_error(&format!("Failed to create audio file: {}", e));
+                anyhow::anyhow!("Failed to create audio file: {}", e)
+            })?;
 
-        let wav_writer = WavWriter::new(audio_file, spec).map_err(|e| {
-            log_ctx.log_error(&format!("Failed to create WAV writer: {}", e));
-            anyhow::anyhow!("Failed to create WAV writer: {}", e)
-        })?;
+            WavWriter::new(audio_file, spec).map_err(|e| {
+                log_ctx.log_error(&format!("Failed to create WAV writer: {}", e));
+                anyhow::anyhow!("Failed to create WAV writer: {}", e)
+            })?
+        };
 
         // Wrap in WavWriterHandle for timeout-based finalization
         let wav_handle = WavWriterHandle::new(wav_writer);
+        let settings = crate::settings::get_settings(&self.app_handle);
+
+        // A requested microphone that isn't connected falls back to the default one
+        let input_device = match (&audio_config, options.input_device.as_deref()) {
+            (AudioSourceConfig::SystemOnly, _) | (_, None) => None,
+            (_, Some(name)) => match list_input_devices() {
+                Ok(devices) => select_input_device(devices, Some(name), |d| d.name.as_str()),
+                Err(e) => {
+                    log_ctx.log_warning(&format!(
+                        "Could not list input devices, recording from the default one: {}",
+                        e
+                    ));
+                    None
+                }
+            },
+        };
+
+        // The session's microphone is opened first and heard while the recorder
+        // is set up; its last moments go ahead of the recorder's first samples
+        let use_pre_roll =
+            settings.meeting_pre_roll_ms > 0 && audio_config != AudioSourceConfig::SystemOnly;
+        let wav_sink = if use_pre_roll {
+            let capacity = pre_roll_capacity(
+                settings.meeting_pre_roll_ms,
+                spec.sample_rate,
+                spec.channels,
+            );
+            let input = DevicePreRoll::new(input_device.as_ref().map(|d| d.device.clone()));
+            match PreRollHandoff::with_input(wav_handle.sink(), capacity, Box::new(input)) {
+                Ok(handoff) => handoff,
+                Err(e) => {
+                    log_ctx.log_warning(&format!("Recording without pre-roll: {}", e));
+                    PreRollHandoff::new(wav_handle.sink())
+                }
+            }
+        } else {
+            PreRollHandoff::new(wav_handle.sink())
+        };
+        rollback.hold_wav(wav_handle);
 
-        // Add sample callback for incremental WAV writing
-        let wav_handle_clone = wav_handle.clone();
+        // Tracks sample delivery so an unplugged device can be detected
+        let stall_detector = StallDetector::new(DEVICE_STALL_TIMEOUT);
+
+        // Add sample callback for incremental WAV writing; only the handle kept
+        // in state can finalize the file
+        let stall_detector_clone = stall_detector.clone();
+        let paused = {
+            let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.capture_paused.store(false, Ordering::SeqCst);
+            state.capture_paused.clone()
+        };
+        // With live captions, the recording is also transcribed chunk by chunk on
+        // a worker; the chunker only queues audio, so capture never waits on it
+        let live_chunker = options.live_captions.then(|| {
+            let app_handle = self.app_handle.clone();
+            Mutex::new(spawn_live_transcription(
+                self.transcription_manager.clone(),
+                session.id.clone(),
+                CustomWords::from_settings(&settings),
+                settings.meeting_live_chunk_secs,
+                spec.sample_rate,
+                spec.channels,
+                move |partial| {
+                    if let Err(e) = app_handle.emit("meeting_partial_transcript", partial) {
+                        warn!("Failed to emit meeting_partial_transcript event: {}", e);
+                    }
+                },
+            ))
+        });
+        // Reports the input level for the VU meter, whatever the audio source
+        let level_meter = Mutex::new(LevelMeter::new(LEVEL_EMIT_INTERVAL));
+        let level_app_handle = self.app_handle.clone();
+        let level_session_id = session.id.clone();
         let sample_callback = move |samples: Vec<f32>| {
-            if let Err(e) = wav_handle_clone.write_samples(&samples) {
+            // The device is still delivering while paused, so it isn't stalled
+            stall_detector_clone.mark();
+            if paused.load(Ordering::SeqCst) {
+                return;
+            }
+            if let Err(e) = wav_sink.write_samples(&samples) {
                 error!("Failed to write audio samples: {}", e);
             }
+            let level = level_meter
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .observe(&samples, Instant::now());
+            if let Some(level) = level {
+                let event = AudioLevel {
+                    session_id: level_session_id.clone(),
+                    level,
+                };
+                if let Err(e) = level_app_handle.emit("meeting_audio_level", event) {
+                    debug!("Failed to emit meeting_audio_level event: {}", e);
+                }
+            }
+            if let Some(chunker) = &live_chunker {
+                chunker
+                    .lock()
+                    .unwrap_or_else(|p| p.into_inner())
+                    .push(&samples);
+            }
         };
 
         debug!(
@@ -847,7 +3010,13 @@ impl MeetingSessionManager {
             anyhow::anyhow!("Failed to create mixed audio recorder: {}", e)
         })?;
 
-        mixed_recorder = mixed_recorder.with_sample_callback(sample_callback);
+        mixed_recorder = mixed_recorder
+            .with_sample_callback(sample_callback)
+            .with_sample_chunk_frames(Some(settings.meeting_sample_chunk_frames as usize))
+            .with_loudness_normalization(options.normalize_loudness)
+            .with_sync(options.sync)
+            .with_own_audio_excluded(options.exclude_app_audio)
+            .with_input_device(input_device.as_ref().map(|d| d.device.clone()));
 
         // Add error callback to detect mic disconnect
         let manager_clone = self.clone();
@@ -869,6 +3038,13 @@ impl MeetingSessionManager {
             }
         });
 
+        // Keep recording from the microphone if system capture fails part way
+        let manager_clone = self.clone();
+        let dropped_session_id = session.id.clone();
+        mixed_recorder = mixed_recorder.with_system_dropped_callback(move |error| {
+            manager_clone.handle_system_audio_dropped(&dropped_session_id, &error);
+        });
+
         let recorder_timer = MeetingTimer::start();
 
         // Start audio capture
@@ -879,6 +3055,18 @@ impl MeetingSessionManager {
 
         log_ctx.log_timing("recorder_start", recorder_timer.elapsed_ms());
 
+        // System audio isn't captured through a cpal input device
+        let active_device = if audio_config == AudioSourceConfig::SystemOnly {
+            None
+        } else {
+            input_device
+                .or_else(default_input_device)
+                .map(|device| AudioDevice::from(&device))
+        };
+        if let Some(device) = &active_device {
+            log_ctx.log_debug(&format!("Recording from input device: {}", device.name));
+        }
+
         // Update session with audio path
         let mut session_with_audio = session.clone();
         session_with_audio.audio_path = Some(audio_filename.clone());
@@ -890,15 +3078,21 @@ impl MeetingSessionManager {
             params![audio_filename, session.id],
         )?;
 
+        // From here on the session is recording and must be stopped normally
+        let wav_handle = rollback.commit();
+
         // Update state with mixed_recorder, wav_handle, and session
-        {
+        let generation = {
             let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
             state.mixed_recorder = Some(mixed_recorder);
-            state.wav_writer = Some(wav_handle);
+            state.wav_writer = wav_handle;
+            state.active_device = active_device;
             state.current_session = Some(session_with_audio.clone());
-        }
+            state.recording_generation += 1;
+            state.recording_generation
+        };
 
-        log_ctx.log_state_transition("Idle", "Recording");
+        log_ctx.log_state_transition(if is_resume { "Processing" } else { "Idle" }, "Recording");
 
         // Update session status to Recording in database
         self.update_session_status(&session.id, MeetingStatus::Recording)?;
@@ -922,6 +3116,12 @@ impl MeetingSessionManager {
             state.current_session = Some(recording_session);
         }
 
+        // System audio may legitimately go quiet, so only watch sources backed by cpal
+        if audio_source != AudioSourceType::SystemOnly {
+            stall_detector.mark();
+            self.spawn_stall_watchdog(session.id.clone(), generation, stall_detector);
+        }
+
         let total_time = timer.elapsed_ms();
         log_ctx.log_success_with_duration(
             total_time,
@@ -932,30 +3132,112 @@ impl MeetingSessionManager {
             ),
         );
 
-        log_meeting_event(
-            &session.id,
-            "session_started",
-            &format!("source={:?} path={}", audio_source, audio_filename),
-        );
+        log_meeting_event(
+            &session.id,
+            "session_started",
+            &format!("source={:?} path={}", audio_source, audio_filename),
+        );
+
+        Ok(session_with_audio)
+    }
+
+    /// Pauses the current recording.
+    ///
+    /// The capture and WAV file stay open, but samples are dropped until
+    /// `resume_recording`, so the recorded duration leaves the pause out.
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The session, now Paused
+    /// * `Err` - If no session is recording
+    pub fn pause_recording(&self) -> Result<MeetingSession> {
+        self.set_recording_paused(true)
+    }
+
+    /// Resumes a paused recording, writing audio into the same file again.
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The session, Recording again
+    /// * `Err` - If no session is paused
+    pub fn resume_recording(&self) -> Result<MeetingSession> {
+        self.set_recording_paused(false)
+    }
+
+    fn set_recording_paused(&self, paused: bool) -> Result<MeetingSession> {
+        let (from, to, operation, event) = if paused {
+            let (from, to) = (MeetingStatus::Recording, MeetingStatus::Paused);
+            (from, to, "pause_recording", "meeting_paused")
+        } else {
+            let (from, to) = (MeetingStatus::Paused, MeetingStatus::Recording);
+            (from, to, "resume_recording", "meeting_resumed")
+        };
+
+        // Flip the flag and status under one lock so a concurrent stop sees both or neither
+        let session = {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            let session = state
+                .current_session
+                .as_mut()
+                .ok_or_else(|| anyhow::anyhow!("No active meeting session"))?;
+            if session.status != from {
+                return Err(anyhow::anyhow!(
+                    "Cannot {}: session is {:?}",
+                    operation.replace('_', " "),
+                    session.status
+                ));
+            }
+            self.validate_state_transition(&from, &to)?;
+            self.update_session_status(&session.id, to.clone())?;
+            session.status = to.clone();
+            state.capture_paused.store(paused, Ordering::SeqCst);
+            session.clone()
+        };
 
-        Ok(session_with_audio)
+        let log_ctx = self.log_context(&session.id, operation);
+        log_ctx.log_state_transition(format!("{:?}", from), format!("{:?}", to));
+        if let Err(e) = self.app_handle.emit(event, session.clone()) {
+            log_ctx.log_error(&format!("Failed to emit {} event: {}", event, e));
+        }
+        Ok(session)
     }
 
     /// Stops recording for the current meeting session.
     ///
     /// This method:
-    /// 1. Validates current session is in Recording state
+    /// 1. Validates current session is in Recording or Paused state
     /// 2. Stops audio capture from the AudioRecorder
     /// 3. Finalizes the WAV file (flush and close)
     /// 4. Calculates the recording duration
     /// 5. Updates the session status to Processing atomically
     /// 6. Returns the audio file path
     ///
+    /// Whether transcription starts follows the `meeting_auto_transcribe` setting;
+    /// see `stop_recording_with_options` to override it.
+    ///
     /// # Returns
     /// * `Ok(String)` - The relative path to the audio file (e.g., "{session-id}/audio.wav")
     /// * `Err` - If no recording is active, invalid state, or if stopping/finalization fails
     pub fn stop_recording(&self) -> Result<String> {
+        self.stop_recording_with_options(None)
+    }
+
+    /// Stops recording for the current meeting session, choosing whether to
+    /// transcribe it right away.
+    ///
+    /// With auto-transcribe off, the session is left in Recorded and waits for
+    /// `transcribe_recorded_session`.
+    ///
+    /// # Arguments
+    /// * `auto_transcribe` - Overrides the `meeting_auto_transcribe` setting for this stop
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The relative path to the audio file (e.g., "{session-id}/audio.wav")
+    /// * `Err` - If no recording is active, invalid state, or if stopping/finalization fails
+    pub fn stop_recording_with_options(&self, auto_transcribe: Option<bool>) -> Result<String> {
         let timer = MeetingTimer::start();
+        let auto_transcribe = auto_transcribe.unwrap_or_else(|| {
+            crate::settings::get_settings(&self.app_handle).meeting_auto_transcribe
+        });
+        let stopped_status = MeetingStatus::after_stop(auto_transcribe);
 
         // State machine guard: validate transition from Recording -> Processing
         // Cannot stop if no active session or not in Recording state
@@ -967,7 +3249,7 @@ impl MeetingSessionManager {
             })?;
 
             match session.status {
-                MeetingStatus::Recording => {
+                MeetingStatus::Recording | MeetingStatus::Paused => {
                     // Valid transition
                     let audio_path = session.audio_path.as_ref().ok_or_else(|| {
                         anyhow::anyhow!(
@@ -983,6 +3265,12 @@ impl MeetingSessionManager {
                         "Cannot stop recording: no recording in progress (session is Idle)"
                     ));
                 }
+                MeetingStatus::Recorded => {
+                    error!("[MEETING_STOP] Rejected: session already stopped");
+                    return Err(anyhow::anyhow!(
+                        "Cannot stop recording: session has already been stopped"
+                    ));
+                }
                 MeetingStatus::Processing => {
                     error!("[MEETING_STOP] Rejected: session already processing");
                     return Err(anyhow::anyhow!(
@@ -1008,13 +3296,14 @@ impl MeetingSessionManager {
             }
         };
 
-        let log_ctx = MeetingLogContext::new(&session_id, "stop_recording");
+        let log_ctx = self.log_context(&session_id, "stop_recording");
         log_ctx.log_start();
 
         // Stop audio capture
         let recorder_timer = MeetingTimer::start();
         let mixed_recorder_opt = {
             let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.active_device = None;
             state.mixed_recorder.take()
         };
 
@@ -1042,6 +3331,8 @@ impl MeetingSessionManager {
             state.wav_writer.take()
         };
 
+        let mut wav_finalized = false;
+        let samples_written = wav_writer_opt.as_ref().map(|h| h.samples_written());
         if let Some(wav_handle) = wav_writer_opt {
             // Try to finalize with 5 second timeout
             if let Err(e) = wav_handle.finalize_with_timeout(Duration::from_secs(5)) {
@@ -1051,6 +3342,7 @@ impl MeetingSessionManager {
             } else {
                 log_ctx.log_timing("wav_finalize", wav_timer.elapsed_ms());
                 log_ctx.log_debug("WAV file finalized successfully");
+                wav_finalized = true;
             }
         }
 
@@ -1059,20 +3351,43 @@ impl MeetingSessionManager {
             anyhow::anyhow!("Session {} not found after stopping recording", session_id)
         })?;
 
-        let duration = chrono::Utc::now().timestamp() - current_session.created_at;
-        if duration < 0 {
-            log_ctx.log_error(&format!(
-                "Invalid duration: created_at {} > now {}",
-                current_session.created_at,
-                chrono::Utc::now().timestamp()
-            ));
-            return Err(anyhow::anyhow!(
-                "Invalid duration calculated for session {}: created_at {} > now {}",
-                session_id,
-                current_session.created_at,
-                chrono::Utc::now().timestamp()
-            ));
-        }
+        // Use the length of the audio actually written, at the rate it was recorded at.
+        // Wall-clock time also counts a delayed start, pauses, and clock changes, so it
+        // is only used when neither the sample count nor the header is available.
+        let recorded = if let Some(written) = &samples_written {
+            Some(written.duration())
+        } else if wav_finalized {
+            match recorded_duration(&self.meetings_dir.join(&audio_path_opt)) {
+                Ok(recorded) => Some(recorded),
+                Err(e) => {
+                    log_ctx.log_warning(&format!("Could not read recorded duration: {}", e));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let duration = match recorded {
+            Some(recorded) => recorded.seconds,
+            None => {
+                let wall_duration = chrono::Utc::now().timestamp() - current_session.created_at;
+                if wall_duration < 0 {
+                    log_ctx.log_error(&format!(
+                        "Invalid duration: created_at {} > now {}",
+                        current_session.created_at,
+                        chrono::Utc::now().timestamp()
+                    ));
+                    return Err(anyhow::anyhow!(
+                        "Invalid duration calculated for session {}: created_at {} > now {}",
+                        session_id,
+                        current_session.created_at,
+                        chrono::Utc::now().timestamp()
+                    ));
+                }
+                wall_duration
+            }
+        };
+        let sample_rate = recorded.map(|r| r.sample_rate);
 
         log_performance_metric(
             &session_id,
@@ -1085,7 +3400,7 @@ impl MeetingSessionManager {
         {
             let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
             if let Some(session) = &state.current_session {
-                self.validate_state_transition(&session.status, &MeetingStatus::Processing)
+                self.validate_state_transition(&session.status, &stopped_status)
                     .map_err(|e| {
                         log_ctx.log_error(&format!("State transition validation failed: {}", e));
                         anyhow::anyhow!("State transition validation failed: {}", e)
@@ -1093,7 +3408,7 @@ impl MeetingSessionManager {
             }
         }
 
-        log_ctx.log_state_transition("Recording", "Processing");
+        log_ctx.log_state_transition("Recording", format!("{:?}", stopped_status));
 
         // Emit meeting_stopped event with session details
         let session_for_event = self.get_session(&session_id)?.ok_or_else(|| {
@@ -1115,10 +3430,11 @@ impl MeetingSessionManager {
         // Update database with duration and status
         let conn = self.get_connection()?;
         conn.execute(
-            "UPDATE meeting_sessions SET duration = ?1, status = ?2 WHERE id = ?3",
+            "UPDATE meeting_sessions SET duration = ?1, status = ?2, sample_rate = ?3, audio_hash = NULL WHERE id = ?4",
             params![
                 duration,
-                self.status_to_string(&MeetingStatus::Processing),
+                self.status_to_string(&stopped_status),
+                sample_rate,
                 session_id
             ],
         )?;
@@ -1127,8 +3443,9 @@ impl MeetingSessionManager {
         let updated_session = {
             let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
             if let Some(mut session) = state.current_session.take() {
-                session.status = MeetingStatus::Processing;
+                session.status = stopped_status.clone();
                 session.duration = Some(duration);
+                session.sample_rate = sample_rate;
                 state.current_session = Some(session.clone());
                 session
             } else {
@@ -1136,14 +3453,16 @@ impl MeetingSessionManager {
             }
         };
 
-        // Emit meeting_processing event after status update
-        if let Err(e) = self
-            .app_handle
-            .emit("meeting_processing", updated_session.clone())
-        {
-            log_ctx.log_error(&format!("Failed to emit meeting_processing event: {}", e));
+        // Emit meeting_processing (or meeting_recorded) event after status update
+        let status_event = if auto_transcribe {
+            "meeting_processing"
+        } else {
+            "meeting_recorded"
+        };
+        if let Err(e) = self.app_handle.emit(status_event, updated_session.clone()) {
+            log_ctx.log_error(&format!("Failed to emit {} event: {}", status_event, e));
         } else {
-            log_ctx.log_debug("Emitted meeting_processing event");
+            log_ctx.log_debug(&format!("Emitted {} event", status_event));
         }
 
         let total_time = timer.elapsed_ms();
@@ -1161,70 +3480,211 @@ impl MeetingSessionManager {
             &format!("duration={}s path={}", duration, audio_path_opt),
         );
 
-        // Spawn background task for transcription to avoid blocking UI
-        let manager_clone = self.clone();
-        let session_id_clone = session_id.clone();
-        let audio_path_clone = audio_path_opt.clone();
-
-        thread::spawn(move || {
-            debug!(
-                "Background transcription task started for session {}",
-                session_id_clone
-            );
-
-            // Process transcription in background
-            match manager_clone.process_transcription(&audio_path_clone) {
-                Ok(transcription_text) => {
-                    debug!(
-                        "Background transcription succeeded for session {}: {} bytes",
-                        session_id_clone,
-                        transcription_text.len()
-                    );
+        if !auto_transcribe {
+            log_ctx.log_debug("Auto-transcribe off, waiting for manual transcription");
+            return Ok(audio_path_opt);
+        }
 
-                    // Save transcript and update status to Completed
-                    if let Err(e) = manager_clone
-                        .save_transcript_and_update_status(&session_id_clone, &transcription_text)
-                    {
-                        let error_msg = format!("Failed to save transcript: {}", e);
-                        error!(
-                            "Failed to save transcript for session {}: {}",
-                            session_id_clone, error_msg
-                        );
-                        manager_clone.handle_transcription_failure(&session_id_clone, &error_msg);
-                    } else {
-                        info!(
-                            "Session {} transcription completed successfully",
-                            session_id_clone
-                        );
-
-                        // Emit meeting_completed event
-                        if let Ok(Some(session_data)) = manager_clone.get_session(&session_id_clone) {
-                            if let Err(emit_err) = manager_clone
-                                .app_handle
-                                .emit("meeting_completed", session_data.clone())
-                            {
-                                error!("Failed to emit meeting_completed event: {}", emit_err);
-                            } else {
-                                info!(
-                                    "Emitted meeting_completed event for session {}",
-                                    session_id_clone
-                                );
-                            }
-                        }
+        let grace = resume_grace(
+            crate::settings::get_settings(&self.app_handle).meeting_resume_grace_secs,
+        );
+        if grace.is_zero() {
+            self.finish_stopped_recording(&session_id, &audio_path_opt);
+        } else {
+            // Hold the session briefly so an immediate restart can resume it
+            {
+                let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+                state.recent_stop = Some(RecentStop::new(&session_id, Instant::now()));
+            }
+            log_ctx.log_debug(&format!("Holding session for {:?} before transcription", grace));
+
+            let manager = self.clone();
+            let audio_path = audio_path_opt.clone();
+            thread::spawn(move || {
+                thread::sleep(grace);
+                let still_held = {
+                    let mut state = manager.state.lock().unwrap_or_else(|p| p.into_inner());
+                    let held = state
+                        .recent_stop
+                        .as_ref()
+                        .map_or(false, |stop| stop.session_id == session_id);
+                    if held {
+                        state.recent_stop = None;
                     }
+                    held
+                };
+                // A resume takes the held stop, in which case the next stop handles it
+                if still_held {
+                    manager.finish_stopped_recording(&session_id, &audio_path);
                 }
-                Err(e) => {
-                    let error_msg = format!("Transcription failed: {}", e);
-                    error!(
-                        "Background transcription failed for session {}: {}",
-                        session_id_clone, error_msg
-                    );
-                    manager_clone.handle_transcription_failure(&session_id_clone, &error_msg);
+            });
+        }
+
+        Ok(audio_path_opt)
+    }
+
+    /// Starts transcribing a session that was stopped with auto-transcribe off.
+    ///
+    /// # Arguments
+    /// * `session_id` - The unique ID of a session in Recorded state
+    ///
+    /// # Returns
+    /// * `Ok(MeetingSession)` - The session, now in Processing
+    /// * `Err` - If the session doesn't exist or isn't waiting for transcription
+    pub fn transcribe_recorded_session(&self, session_id: &str) -> Result<MeetingSession> {
+        let session = self
+            .get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+        if session.status != MeetingStatus::Recorded {
+            return Err(anyhow::anyhow!(
+                "Session {} is not waiting for transcription (status: {:?})",
+                session_id,
+                session.status
+            ));
+        }
+
+        let audio_path = self.retry_transcription_for_session(session_id)?;
+        log_meeting_event(
+            session_id,
+            "manual_transcription",
+            &format!("path={}", audio_path),
+        );
+        self.finish_stopped_recording(session_id, &audio_path);
+
+        self.get_session(session_id)?
+            .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))
+    }
+
+    /// Works out whether a stopped recording contains speech.
+    ///
+    /// Empty and silent recordings are recognised from their level alone; the
+    /// rest are checked with the VAD. If the VAD can't run, the level decides.
+    fn classify_stopped_recording(&self, audio_path: &Path) -> Result<RecordingContent> {
+        let content = classify_recording(audio_path)?;
+        if content != RecordingContent::Signal {
+            return Ok(content);
+        }
+
+        let mut vad = match self.load_vad(SPEECH_VAD_THRESHOLD) {
+            Ok(vad) => vad,
+            Err(e) => {
+                warn!("VAD unavailable, treating recording as speech: {}", e);
+                return Ok(content);
+            }
+        };
+        // Transcription reuses the converted audio this caches
+        let audio = load_16k_samples(audio_path)?.audio;
+        classify_speech(&mut vad, &audio.samples)
+    }
+
+    /// Hands a stopped recording to transcription, or finishes it directly when
+    /// the recording contains no speech.
+    fn finish_stopped_recording(&self, session_id: &str, audio_path: &str) {
+        // Recordings without speech skip transcription entirely
+        match self.classify_stopped_recording(&self.meetings_dir.join(audio_path)) {
+            Ok(content @ (RecordingContent::Empty | RecordingContent::Silent)) => {
+                debug!(
+                    "Skipping transcription for session {}: recording is {:?}",
+                    session_id, content
+                );
+                self.finish_without_speech(session_id);
+            }
+            Ok(RecordingContent::Signal) => {
+                // Queue transcription in the background to avoid blocking UI
+                self.enqueue_transcription(session_id);
+            }
+            Err(e) => {
+                // Let the transcription pipeline report unreadable audio as usual
+                warn!("Could not check session {} recording for speech: {}", session_id, e);
+                self.enqueue_transcription(session_id);
+            }
+        }
+    }
+
+    /// Takes the recently stopped session if a start now should resume it.
+    ///
+    /// The session must still be the current one, be waiting in Processing, and
+    /// use the same audio source. The held stop is consumed only when resuming,
+    /// and is returned so a failed resume can give it back with `restore_recent_stop`.
+    fn take_resumable_session(
+        &self,
+        audio_source: &AudioSourceType,
+    ) -> Option<(MeetingSession, RecentStop)> {
+        let grace = resume_grace(
+            crate::settings::get_settings(&self.app_handle).meeting_resume_grace_secs,
+        );
+
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        let recent_stop = state.recent_stop.as_ref()?;
+        if !recent_stop.allows_resume(grace, Instant::now()) {
+            return None;
+        }
+
+        let session = state
+            .current_session
+            .as_ref()
+            .filter(|s| {
+                s.id == recent_stop.session_id
+                    && s.status == MeetingStatus::Processing
+                    && &s.audio_source == audio_source
+            })?
+            .clone();
+
+        let stop = state.recent_stop.take()?;
+        Some((session, stop))
+    }
+
+    /// Gives a stop taken by a failed resume back.
+    ///
+    /// Within the grace window it is held again, so the waiting stop thread
+    /// still finishes it; after the window that thread has already given up,
+    /// so the recording is finished here.
+    fn restore_recent_stop(&self, stop: RecentStop, audio_path: &str) {
+        let grace =
+            resume_grace(crate::settings::get_settings(&self.app_handle).meeting_resume_grace_secs);
+        let session_id = stop.session_id.clone();
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            // The stop thread sleeps the full window, so it can't have checked yet
+            if Instant::now().saturating_duration_since(stop.stopped_at) < grace {
+                state.recent_stop = Some(stop);
+                return;
+            }
+        }
+        self.finish_stopped_recording(&session_id, audio_path);
+    }
+
+    /// Handles system audio capture failing part way through a Mixed recording.
+    ///
+    /// The recorder carries on with the microphone alone; this marks the session
+    /// as having partial system audio and emits `meeting_system_audio_dropped`.
+    fn handle_system_audio_dropped(&self, session_id: &str, error_message: &str) {
+        let log_ctx = self.log_context(session_id, "system_audio_dropped");
+        log_ctx.log_warning(&format!(
+            "System audio dropped, continuing microphone-only: {}",
+            error_message
+        ));
+
+        if let Err(e) = super::db::set_system_audio_partial(&self.db_path, session_id) {
+            log_ctx.log_error(&format!("Failed to mark system audio as partial: {}", e));
+        }
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            if let Some(current) = state.current_session.as_mut() {
+                if current.id == session_id {
+                    current.system_audio_partial = true;
                 }
             }
-        });
+        }
 
-        Ok(audio_path_opt)
+        if let Ok(Some(session)) = self.get_session(session_id) {
+            if let Err(e) = self
+                .app_handle
+                .emit("meeting_system_audio_dropped", session)
+            {
+                error!("Failed to emit meeting_system_audio_dropped event: {}", e);
+            }
+        }
     }
 
     /// Handles microphone disconnect or audio stream error during recording.
@@ -1263,12 +3723,12 @@ impl MeetingSessionManager {
             }
         };
 
-        let log_ctx = MeetingLogContext::new(&session_id, "handle_mic_disconnect");
+        let log_ctx = self.log_context(&session_id, "handle_mic_disconnect");
         log_ctx.log_start();
         log_ctx.log_error(error_message);
 
         // Only handle if we're currently recording
-        if status != MeetingStatus::Recording {
+        if !status.is_capturing() {
             log_ctx.log_debug(&format!(
                 "Session not recording (status: {:?}) - ignoring",
                 status
@@ -1280,6 +3740,7 @@ impl MeetingSessionManager {
         let recorder_timer = MeetingTimer::start();
         let mixed_recorder_opt = {
             let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.active_device = None;
             state.mixed_recorder.take()
         };
 
@@ -1303,6 +3764,7 @@ impl MeetingSessionManager {
             state.wav_writer.take()
         };
 
+        let samples_written = wav_writer_opt.as_ref().map(|h| h.samples_written());
         if let Some(wav_handle) = wav_writer_opt {
             // Try to finalize with 5 second timeout
             if let Err(e) = wav_handle.finalize_with_timeout(Duration::from_secs(5)) {
@@ -1314,19 +3776,19 @@ impl MeetingSessionManager {
             }
         }
 
-        // Calculate partial duration
-        let duration = {
-            if let Ok(Some(session)) = self.get_session(&session_id) {
-                let now = chrono::Utc::now().timestamp();
-                let partial_duration = now - session.created_at;
-                if partial_duration > 0 {
-                    Some(partial_duration)
-                } else {
-                    None
-                }
+        // Calculate partial duration, from the audio written when known
+        let duration = if let Some(written) = &samples_written {
+            Some(written.duration().seconds)
+        } else if let Ok(Some(session)) = self.get_session(&session_id) {
+            let now = chrono::Utc::now().timestamp();
+            let partial_duration = now - session.created_at;
+            if partial_duration > 0 {
+                Some(partial_duration)
             } else {
                 None
             }
+        } else {
+            None
         };
 
         if let Some(dur) = duration {
@@ -1431,16 +3893,70 @@ impl MeetingSessionManager {
         );
     }
 
+    /// Spawns a watchdog thread that detects when the input device stops delivering samples.
+    ///
+    /// The watchdog exits once the session is no longer the current Recording session.
+    /// If no samples arrive within the stall timeout, it emits a `meeting_device_lost`
+    /// event and stops the recording via `handle_mic_disconnect`, marking the session Failed.
+    fn spawn_stall_watchdog(&self, session_id: String, generation: u64, detector: StallDetector) {
+        let manager = self.clone();
+
+        thread::spawn(move || loop {
+            thread::sleep(DEVICE_STALL_POLL_INTERVAL);
+
+            let still_recording = {
+                let state = manager.state.lock().unwrap_or_else(|p| p.into_inner());
+                state.recording_generation == generation
+                    && state
+                        .current_session
+                        .as_ref()
+                        .map_or(false, |s| s.id == session_id && s.status.is_capturing())
+            };
+            if !still_recording {
+                debug!("[STALL_WATCHDOG] [{}] Recording ended, exiting", session_id);
+                return;
+            }
+
+            if detector.is_stalled() {
+                let stalled_secs = detector.stalled_for().as_secs();
+                error!(
+                    "[STALL_WATCHDOG] [{}] No audio samples for {}s, treating device as lost",
+                    session_id, stalled_secs
+                );
+
+                #[derive(Clone, Serialize)]
+                struct DeviceLostEvent {
+                    session_id: String,
+                    stalled_for_secs: u64,
+                }
+
+                if let Err(e) = manager.app_handle.emit(
+                    "meeting_device_lost",
+                    DeviceLostEvent {
+                        session_id: session_id.clone(),
+                        stalled_for_secs: stalled_secs,
+                    },
+                ) {
+                    error!("Failed to emit meeting_device_lost event: {}", e);
+                }
+
+                manager.handle_mic_disconnect(&format!(
+                    "Input device stopped delivering audio for {} seconds",
+                    stalled_secs
+                ));
+                return;
+            }
+        });
+    }
+
     /// Saves the transcript to a file and updates the session status.
     ///
-    /// This method:
-    /// 1. Creates the transcript file in the session's folder
-    /// 2. Updates the session status (Completed on success, Failed on error)
-    /// 3. Stores the transcript path and optional error message
+    /// See `save_transcript` for what is stored; the in-memory session is
+    /// marked Completed as well.
     ///
     /// # Arguments
     /// * `session_id` - The unique ID of the session
-    /// * `transcript_text` - The transcribed text to save
+    /// * `transcription` - The transcription to save
     ///
     /// # Returns
     /// * `Ok(())` - If the transcript was saved and status updated successfully
@@ -1448,41 +3964,15 @@ impl MeetingSessionManager {
     fn save_transcript_and_update_status(
         &self,
         session_id: &str,
-        transcript_text: &str,
+        transcription: &TranscriptionResult,
     ) -> Result<()> {
-        debug!(
-            "Saving transcript for session {}: {} bytes",
+        let settings = crate::settings::get_settings(&self.app_handle);
+        let transcript_filename = save_transcript(
+            &self.db_path,
+            &self.meetings_dir,
             session_id,
-            transcript_text.len()
-        );
-
-        // Create transcript file path: {session-id}/transcript.txt
-        let transcript_filename = format!("{}/transcript.txt", session_id);
-        let transcript_path = self.meetings_dir.join(&transcript_filename);
-
-        // Write transcript to file
-        fs::write(&transcript_path, transcript_text).map_err(|e| {
-            anyhow::anyhow!(
-                "Failed to write transcript file {:?}: {}",
-                transcript_path,
-                e
-            )
-        })?;
-
-        info!(
-            "Saved transcript to {:?} for session {}",
-            transcript_path, session_id
-        );
-
-        // Update database with transcript path and Completed status
-        let conn = self.get_connection()?;
-        conn.execute(
-            "UPDATE meeting_sessions SET transcript_path = ?1, status = ?2 WHERE id = ?3",
-            params![
-                transcript_filename,
-                self.status_to_string(&MeetingStatus::Completed),
-                session_id
-            ],
+            transcription,
+            &settings,
         )?;
 
         // Update in-memory state
@@ -1492,6 +3982,9 @@ impl MeetingSessionManager {
                 if session.id == session_id {
                     session.transcript_path = Some(transcript_filename.clone());
                     session.status = MeetingStatus::Completed;
+                    session.error_message = None;
+                    session.status_note = None;
+                    session.transcript_removed = false;
                     state.current_session = Some(session);
                 }
             }
@@ -1509,17 +4002,28 @@ impl MeetingSessionManager {
     ///
     /// This method:
     /// 1. Reads the audio file at the given path
-    /// 2. Converts WAV i16 samples to f32 format
-    /// 3. Calls TranscriptionManager to perform STT
-    /// 4. Returns the raw transcription text
+    /// 2. Converts it to 16kHz mono f32 samples (cached across runs)
+    /// 3. Applies the preprocessing chain, in order
+    /// 4. Drops non-speech with the VAD if `meeting_vad_trim` is on (streamed
+    ///    recordings are already cut at pauses and are not trimmed)
+    /// 5. Calls TranscriptionManager to perform STT
+    /// 6. Returns the transcription with its segments, language, and real-time factor
     ///
     /// # Arguments
     /// * `audio_path` - Relative path to the audio file (e.g., "{session-id}/audio.wav")
+    /// * `preprocessing` - Preprocessing steps from the session's template (may be empty)
+    /// * `on_partial` - Receives interim text per chunk; the audio is then
+    ///   transcribed in chunks rather than in one call
     ///
     /// # Returns
-    /// * `Ok(String)` - The transcribed text
+    /// * `Ok(TranscriptionResult)` - The transcription and its timing data
     /// * `Err` - If file not found, reading fails, or transcription fails (including model not loaded)
-    pub fn process_transcription(&self, audio_path: &str) -> Result<String> {
+    pub(crate) fn process_transcription(
+        &self,
+        audio_path: &str,
+        preprocessing: &[PreprocessOp],
+        on_partial: Option<PartialCallback>,
+    ) -> Result<TranscriptionResult> {
         debug!("Processing transcription for audio: {}", audio_path);
 
         // Build full path to audio file
@@ -1533,55 +4037,144 @@ impl MeetingSessionManager {
             ));
         }
 
-        // Read WAV file and convert to f32 samples
-        let reader = WavReader::open(&full_audio_path).map_err(|e| {
-            anyhow::anyhow!("Failed to open audio file {:?}: {}", full_audio_path, e)
-        })?;
-
-        // Verify audio format matches expectations (16-bit, 16000 Hz)
-        let spec = reader.spec();
-        if spec.bits_per_sample != 16 || spec.sample_rate != 16000 {
-            return Err(anyhow::anyhow!(
-                "Audio format mismatch: expected 16-bit/16000Hz, got {}/{}Hz",
-                spec.bits_per_sample,
-                spec.sample_rate
-            ));
+        // Multi-hour recordings are read a window at a time to bound memory use;
+        // preprocessing works on the whole buffer, so it still needs the full load
+        if preprocessing.is_empty() && should_stream(&full_audio_path) {
+            return self.process_transcription_streaming(&full_audio_path, on_partial);
         }
 
-        // Read samples and convert from i16 to f32
-        let samples: Vec<f32> = reader
-            .into_samples::<i16>()
-            .filter_map(Result::ok)
-            .map(|sample| sample as f32 / i16::MAX as f32)
-            .collect();
+        // Load 16kHz mono samples, reusing the cached conversion from earlier runs
+        let converted = load_16k_samples(&full_audio_path)?;
+        let audio = converted.audio;
 
         debug!(
-            "Read {} audio samples from {:?}",
-            samples.len(),
-            full_audio_path
+            "Read {} audio samples ({:.1}s) from {:?} (cached: {})",
+            audio.len(),
+            audio.duration().as_secs_f32(),
+            full_audio_path,
+            converted.from_cache
         );
 
-        if samples.is_empty() {
+        if audio.is_empty() {
             return Err(anyhow::anyhow!(
                 "Audio file contains no samples: {:?}",
                 full_audio_path
             ));
         }
 
-        // Call TranscriptionManager to process audio
-        let transcription_text = self
-            .transcription_manager
-            .transcribe(samples)
-            .map_err(|e| {
-                anyhow::anyhow!("Transcription failed for {:?}: {}", full_audio_path, e)
-            })?;
+        let (audio, preprocess_map) = if preprocessing.is_empty() {
+            (audio, TrimMap::default())
+        } else {
+            let AudioBuffer {
+                samples,
+                sample_rate,
+                channels,
+            } = audio;
+            let (processed, map) = apply_preprocessing(samples, preprocessing);
+            debug!(
+                "Applied {} preprocessing step(s): {} samples remain",
+                preprocessing.len(),
+                processed.len()
+            );
+            (AudioBuffer::new(processed, sample_rate, channels), map)
+        };
+
+        // The STT engine only accepts 16kHz mono; this is a no-op for converted audio
+        let audio = audio.to_mono_16k();
+
+        // Transcribe only the speech if trimming is on; audio.wav itself is untouched
+        let (audio, trim_map) = match self.trim_to_speech(&audio) {
+            Some(trimmed) => (
+                AudioBuffer::mono_16k(trimmed.samples),
+                preprocess_map.then(trimmed.map),
+            ),
+            None => (audio, preprocess_map),
+        };
+
+        // Call TranscriptionManager to process audio; live captions need text
+        // per chunk, so the buffer is then handed over a window at a time
+        let mut transcription = match on_partial {
+            Some(on_partial) => {
+                let window_samples = (STREAM_WINDOW_SECS * WHISPER_SAMPLE_RATE) as usize;
+                let windows = audio
+                    .samples
+                    .chunks(window_samples)
+                    .map(|window| Ok(window.to_vec()));
+                run_windowed_transcription(
+                    self.transcription_manager.as_ref(),
+                    windows,
+                    None,
+                    Some(on_partial),
+                )
+            }
+            None => run_transcription(self.transcription_manager.as_ref(), audio),
+        }
+        .map_err(|e| anyhow::anyhow!("Transcription failed for {:?}: {}", full_audio_path, e))?;
+
+        // Segment times point into the trimmed audio; move them back onto the recording
+        if !trim_map.is_identity() {
+            for segment in &mut transcription.segments {
+                segment.start = trim_map.original_secs(segment.start);
+                segment.end = trim_map.original_secs(segment.end);
+            }
+        }
+
+        debug!(
+            "Transcription completed: {} characters, {} segment(s), rtf {:.2}",
+            transcription.text.len(),
+            transcription.segments.len(),
+            transcription.rtf
+        );
+
+        Ok(transcription)
+    }
+
+    /// Transcribes a long recording in windows read straight from the WAV file.
+    ///
+    /// Windows are cut at pauses found by the VAD; if the VAD model can't be
+    /// loaded they are cut at a fixed length instead.
+    fn process_transcription_streaming(
+        &self,
+        full_audio_path: &Path,
+        on_partial: Option<PartialCallback>,
+    ) -> Result<TranscriptionResult> {
+        let file = open_shared_read(full_audio_path).map_err(|e| {
+            anyhow::anyhow!("Failed to open audio file {:?}: {}", full_audio_path, e)
+        })?;
+        let mut windows = WavWindowReader::new(BufReader::new(file), STREAM_WINDOW_SECS)?;
+        match self.load_vad(SPEECH_VAD_THRESHOLD) {
+            Ok(vad) => windows = windows.with_vad(Box::new(vad)),
+            Err(e) => warn!(
+                "VAD unavailable, cutting transcription windows at fixed length: {}",
+                e
+            ),
+        }
+
+        // Each window is trimmed on its own, as the whole recording never is in memory
+        let mut trim_vad = self.load_trim_vad();
+
+        info!(
+            "Streaming transcription of {:?} in windows of up to {}s",
+            full_audio_path, STREAM_WINDOW_SECS
+        );
+        let transcription = run_windowed_transcription(
+            self.transcription_manager.as_ref(),
+            windows,
+            trim_vad
+                .as_mut()
+                .map(|vad| vad as &mut dyn VoiceActivityDetector),
+            on_partial,
+        )
+        .map_err(|e| anyhow::anyhow!("Transcription failed for {:?}: {}", full_audio_path, e))?;
 
         debug!(
-            "Transcription completed: {} characters",
-            transcription_text.len()
+            "Streamed transcription completed: {} characters, {} segment(s), rtf {:.2}",
+            transcription.text.len(),
+            transcription.segments.len(),
+            transcription.rtf
         );
 
-        Ok(transcription_text)
+        Ok(transcription)
     }
 
     /// Handles app shutdown cleanup for meeting sessions.
@@ -1600,8 +4193,27 @@ impl MeetingSessionManager {
     /// * `true` if there was an active recording that was interrupted
     /// * `false` if no recording was in progress
     pub fn handle_app_shutdown(&self) -> bool {
-        let timer = MeetingTimer::start();
         info!("[APP_SHUTDOWN] Handling app shutdown for meeting sessions");
+        self.interrupt_active_recording(
+            "handle_app_shutdown",
+            "app shutdown",
+            "app_shutdown_interrupted",
+        )
+    }
+
+    /// Stops an active recording, keeping its audio, and marks the session Interrupted
+    /// so that it is offered for recovery.
+    ///
+    /// # Arguments
+    /// * `context` - Operation name used for logging
+    /// * `cause` - Why the recording is interrupted, used in logs and the session's error message
+    /// * `event` - Name of the meeting event logged once the session is interrupted
+    ///
+    /// # Returns
+    /// * `true` if there was an active recording that was interrupted
+    /// * `false` if no recording was in progress
+    fn interrupt_active_recording(&self, context: &str, cause: &str, event: &str) -> bool {
+        let timer = MeetingTimer::start();
 
         // Get current session info
         let session_info = {
@@ -1615,16 +4227,16 @@ impl MeetingSessionManager {
         let (session_id, status) = match session_info {
             Some((id, status)) => (id, status),
             None => {
-                debug!("[APP_SHUTDOWN] No active session");
+                debug!("[{}] No active session", context);
                 return false;
             }
         };
 
-        let log_ctx = MeetingLogContext::new(&session_id, "handle_app_shutdown");
+        let log_ctx = self.log_context(&session_id, context);
         log_ctx.log_start();
 
         // Only handle if we're currently recording
-        if status != MeetingStatus::Recording {
+        if !status.is_capturing() {
             log_ctx.log_debug(&format!(
                 "Session not recording (status: {:?}) - no cleanup needed",
                 status
@@ -1632,12 +4244,13 @@ impl MeetingSessionManager {
             return false;
         }
 
-        log_ctx.log_warning("Interrupting active recording due to app shutdown");
+        log_ctx.log_warning(&format!("Interrupting active recording due to {}", cause));
 
         // Stop the recorder if it exists
         let recorder_timer = MeetingTimer::start();
         let mixed_recorder_opt = {
             let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.active_device = None;
             state.mixed_recorder.take()
         };
 
@@ -1661,6 +4274,7 @@ impl MeetingSessionManager {
             state.wav_writer.take()
         };
 
+        let samples_written = wav_writer_opt.as_ref().map(|h| h.samples_written());
         if let Some(wav_handle) = wav_writer_opt {
             // Try to finalize with 5 second timeout
             if let Err(e) = wav_handle.finalize_with_timeout(Duration::from_secs(5)) {
@@ -1672,19 +4286,19 @@ impl MeetingSessionManager {
             }
         }
 
-        // Calculate partial duration
-        let duration = {
-            if let Ok(Some(session)) = self.get_session(&session_id) {
-                let now = chrono::Utc::now().timestamp();
-                let partial_duration = now - session.created_at;
-                if partial_duration > 0 {
-                    Some(partial_duration)
-                } else {
-                    None
-                }
+        // Calculate partial duration, from the audio written when known
+        let duration = if let Some(written) = &samples_written {
+            Some(written.duration().seconds)
+        } else if let Ok(Some(session)) = self.get_session(&session_id) {
+            let now = chrono::Utc::now().timestamp();
+            let partial_duration = now - session.created_at;
+            if partial_duration > 0 {
+                Some(partial_duration)
             } else {
                 None
             }
+        } else {
+            None
         };
 
         if let Some(dur) = duration {
@@ -1697,6 +4311,7 @@ impl MeetingSessionManager {
         }
 
         log_ctx.log_state_transition("Recording", "Interrupted");
+        let interrupt_message = format!("Session interrupted due to {}", cause);
 
         // Update database with Interrupted status and partial duration
         if let Ok(conn) = self.get_connection() {
@@ -1706,7 +4321,7 @@ impl MeetingSessionManager {
                     params![
                         self.status_to_string(&MeetingStatus::Interrupted),
                         dur,
-                        "Session interrupted due to app shutdown",
+                        &interrupt_message,
                         &session_id
                     ],
                 )
@@ -1715,7 +4330,7 @@ impl MeetingSessionManager {
                     "UPDATE meeting_sessions SET status = ?1, error_message = ?2 WHERE id = ?3",
                     params![
                         self.status_to_string(&MeetingStatus::Interrupted),
-                        "Session interrupted due to app shutdown",
+                        &interrupt_message,
                         &session_id
                     ],
                 )
@@ -1743,28 +4358,105 @@ impl MeetingSessionManager {
         log_ctx.log_success_with_duration(
             total_time,
             &format!(
-                "App shutdown handled - session interrupted, duration={}s",
+                "Recording interrupted due to {}, duration={}s",
+                cause,
                 duration.unwrap_or(0)
             ),
         );
 
         log_meeting_event(
             &session_id,
-            "app_shutdown_interrupted",
+            event,
             &format!("duration={}s", duration.unwrap_or(0)),
         );
 
         true
     }
 
+    /// Queues transcription for interrupted sessions whose audio is still readable.
+    ///
+    /// Called on launch with the sessions found by `check_interrupted_sessions`
+    /// when auto-retry is enabled. Each session emits `meeting_processing` now
+    /// and `meeting_completed` / `meeting_failed` once transcribed; sessions
+    /// without usable audio stay Interrupted for a manual retry.
+    ///
+    /// # Returns
+    /// The IDs of the sessions that were queued
+    pub fn auto_retry_interrupted_sessions(&self, sessions: &[MeetingSession]) -> Vec<String> {
+        let mut queued = Vec::new();
+
+        for session in sessions_to_auto_retry(&self.meetings_dir, sessions) {
+            if let Err(e) = self.retry_transcription_for_session(&session.id) {
+                warn!("Failed to auto-retry interrupted session {}: {}", session.id, e);
+                continue;
+            }
+
+            if let Some(processing) = self.get_session(&session.id).ok().flatten() {
+                if let Err(e) = self.app_handle.emit("meeting_processing", &processing) {
+                    error!("Failed to emit meeting_processing event: {}", e);
+                }
+            }
+
+            self.enqueue_transcription(&session.id);
+            queued.push(session.id.clone());
+        }
+
+        if !queued.is_empty() {
+            info!("Auto-retrying transcription for {} interrupted session(s)", queued.len());
+        }
+        queued
+    }
+
+    /// Re-queues transcriptions that were pending when the app last closed.
+    ///
+    /// Sessions still marked Processing are picked up in the order of the saved
+    /// queue, followed by any the saved copy missed. Those whose audio can no
+    /// longer be read are marked Failed so they don't stay stuck in Processing.
+    ///
+    /// # Returns
+    /// The IDs of the sessions that were queued
+    pub fn resume_transcription_queue(&self) -> Result<Vec<String>> {
+        let saved = load_queue(&self.meetings_dir).session_ids();
+        let sessions: Vec<MeetingSession> = self
+            .list_sessions()?
+            .into_iter()
+            .filter(|session| session.status == MeetingStatus::Processing)
+            .collect();
+
+        let resumed: Vec<String> = sessions_to_resume(&self.meetings_dir, &saved, &sessions)
+            .into_iter()
+            .map(|session| session.id.clone())
+            .collect();
+
+        for session in sessions.iter().filter(|s| !resumed.contains(&s.id)) {
+            warn!(
+                "Session {} was queued for transcription but its audio is unreadable",
+                session.id
+            );
+            self.handle_transcription_failure(
+                &session.id,
+                "Transcription was interrupted and the audio could not be read",
+            );
+        }
+
+        for session_id in &resumed {
+            self.enqueue_transcription(session_id);
+        }
+
+        if !resumed.is_empty() {
+            info!("Resumed {} queued transcription(s) from the previous run", resumed.len());
+        }
+        Ok(resumed)
+    }
+
     /// Checks for interrupted sessions from previous app runs.
     ///
-    /// This method queries the database for any sessions in Recording or
-    /// Interrupted status (which indicate the app was closed during an
+    /// This method queries the database for any sessions in Recording, Paused,
+    /// or Interrupted status (which indicate the app was closed during an
     /// active recording) and returns them for potential recovery.
     ///
-    /// On startup, sessions found in Recording status are transitioned to
-    /// Interrupted status since they were not properly closed.
+    /// On startup, sessions found in Recording or Paused status are transitioned
+    /// to Interrupted status since they were not properly closed.
     ///
     /// # Returns
     /// * `Ok(Vec<MeetingSession>)` - Sessions that were interrupted
@@ -1774,27 +4466,28 @@ impl MeetingSessionManager {
 
         let conn = self.get_connection()?;
 
-        // First, transition any sessions in Recording status to Interrupted
+        // First, transition any sessions in Recording or Paused status to Interrupted
         // (they were interrupted by an unclean shutdown)
         let rows_updated = conn.execute(
-            "UPDATE meeting_sessions SET status = ?1, error_message = ?2 WHERE status = ?3",
+            "UPDATE meeting_sessions SET status = ?1, error_message = ?2 WHERE status IN (?3, ?4)",
             params![
                 self.status_to_string(&MeetingStatus::Interrupted),
                 "Session interrupted due to app shutdown (recovered on next launch)",
                 self.status_to_string(&MeetingStatus::Recording),
+                self.status_to_string(&MeetingStatus::Paused),
             ],
         )?;
 
         if rows_updated > 0 {
             info!(
-                "Transitioned {} sessions from Recording to Interrupted status",
+                "Transitioned {} sessions from Recording/Paused to Interrupted status",
                 rows_updated
             );
         }
 
         // Query for all interrupted sessions
         let mut stmt = conn.prepare(
-            "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id
+            "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, summary_path, template_id, archive_path, archive_format, category, sample_rate, notes, starred, system_audio_partial, status_note, transcript_removed
              FROM meeting_sessions WHERE status = ?1 ORDER BY created_at DESC",
         )?;
 
@@ -4,22 +4,137 @@
 //! which are completely separate from the existing Quick Dictation functionality.
 //!
 //! ## Module Structure
-//! - `models` - Data types: MeetingStatus, AudioSourceType, MeetingSession
+//! - `models` - Data types: MeetingStatus, AudioSourceType, MeetingSession, SessionLite, RecordingOptions
 //! - `wav_writer` - Thread-safe WAV file writer with timeout-based finalization
+//! - `audio_cache` - Cache of meeting audio converted to 16kHz mono for transcription
+//! - `audio_hash` - SHA-256 of session audio for duplicate and corruption checks
+//! - `archive` - 16-bit mono archive copies of meeting audio
+//! - `bundle` - Shareable JSON bundles of sessions for moving them to another instance
+//! - `corrections` - Re-applying custom-word correction to stored transcripts
+//! - `daily_note` - Appending completed meetings to a combined Markdown note per day
+//! - `detail` - Session plus transcript, summary, and stats in one bundle for the detail view
+//! - `direction` - Left-to-right / right-to-left direction of transcripts
+//! - `disk` - Free disk space and the recording time it leaves
+//! - `export` - Supported export formats and CSV export of session metadata
+//! - `files` - Atomic writes and the `latest_transcript.txt` mirror
+//! - `idempotency` - Returning the same session when a start request is retried with the same key
+//! - `input_check` - Failing early when a recording needs a microphone and none is present
+//! - `integrity` - Database and file consistency diagnostic across all sessions
+//! - `jobs` - Bounded worker pool running exports and hashing in the background
+//! - `keep_policy` - Choosing between the stored transcript and a retry's result
+//! - `level` - Input level of a recording in progress, for a VU meter
+//! - `live` - Transcribing chunks of a recording while it is still being captured
 //! - `db` - Database initialization, migrations, and CRUD operations
+//! - `paragraphs` - Breaking transcripts into paragraphs at sentence boundaries
+//! - `pre_roll` - Keeping the audio heard while a session is starting
+//! - `preview` - Resolving the configuration a recording start would use, without starting
+//! - `post_actions` - Running a template's actions after a session completes
+//! - `queue` - Bookkeeping for the one-at-a-time background transcription queue and its saved copy
+//! - `recovery` - Choosing interrupted or still-queued sessions to transcribe on launch
+//! - `repair` - Re-deriving a single session's status from its files
+//! - `reset` - Deleting all sessions and their files to start fresh
+//! - `resume` - Resuming a session when recording restarts right after a stop
+//! - `retention` - Removing audio/transcript after summarization for summary-only templates
+//! - `rollback` - Removing the leftovers of a recording start that failed part way
+//! - `search` - Full-text search across transcripts, kept in an FTS5 index
+//! - `silence` - Detection of empty or all-silent recordings at stop
+//! - `speech` - Share of a recording classified as speech by the VAD, and trimming the rest
+//! - `split` - Splitting a recording into several sessions at chosen times
+//! - `start_guard` - Ensuring only one recording start runs at a time
+//! - `stall` - Detection of input devices that stop delivering samples mid-recording
+//! - `stream` - Reading long recordings in bounded windows for transcription
+//! - `summary` - Summarizing transcripts with the configured LLM, within its context by truncating or map-reduce
+//! - `timeline` - Grouping sessions by calendar day
+//! - `title` - Rendering template titles, deriving titles from transcripts, and detecting generated titles
+//! - `toggle` - Single start/stop entry point for a recording hotkey
+//! - `transcript` - Transcription results with segments, and their sidecar file
 //! - `manager` - Core MeetingSessionManager implementation (recording, transcription, lifecycle)
 
 // Private internal modules (db is pub(crate) so tests can access it)
 pub(crate) mod db;
+mod archive;
+mod audio_cache;
+mod audio_hash;
+mod bundle;
+mod corrections;
+mod daily_note;
+mod detail;
+mod direction;
+mod disk;
+mod export;
+mod files;
+mod idempotency;
+mod input_check;
+mod integrity;
+mod jobs;
+mod keep_policy;
+mod level;
+mod live;
 mod manager;
 mod models;
+mod paragraphs;
+mod post_actions;
+mod pre_roll;
+mod preview;
+mod queue;
+mod recovery;
+mod repair;
+mod reset;
+mod resume;
+mod retention;
+mod rollback;
+mod search;
+mod silence;
+mod speech;
+mod split;
+mod stall;
+mod start_guard;
+mod stream;
+mod summary;
+mod timeline;
+mod title;
+mod toggle;
+mod transcript;
 mod wav_writer;
 
 // Re-export public types
-pub use models::{AudioSourceType, MeetingSession, MeetingStatus};
+pub use models::{
+    AudioSourceType, MeetingSession, MeetingStatus, RecordingOptions, SessionLite, SessionPatch,
+    SuspendSummary, MEETING_CATEGORIES,
+};
 
 // Re-export the manager
+pub use detail::{SessionDetail, SessionStats};
+pub use direction::TextDirection;
+pub use export::{list_export_formats, ExportFormat, ExportFormatInfo};
+pub use integrity::{IntegrityIssue, IntegrityReport};
+pub use jobs::{FileJobKind, FileJobState, FileJobStatus};
+pub use keep_policy::{KeptTranscript, RetryComparison, RetryKeepPolicy};
+pub use level::AudioLevel;
 pub use manager::MeetingSessionManager;
+pub use preview::ResolvedMeetingConfig;
+pub use queue::TranscriptionQueueSnapshot;
+pub use reset::ResetSummary;
+pub use search::MeetingSearchResult;
+pub use timeline::DayGroup;
+pub use toggle::ToggleAction;
+pub use transcript::{PartialTranscript, Segment, TranscriptionResult};
+
+pub(crate) use db::MEETING_DB_EXPECTED_VERSION;
+pub(crate) use files::write_atomic;
+pub(crate) use jobs::{DEFAULT_FILE_JOB_CONCURRENCY, MAX_FILE_JOB_CONCURRENCY};
+pub(crate) use live::{DEFAULT_LIVE_CHUNK_SECS, MAX_LIVE_CHUNK_SECS, MIN_LIVE_CHUNK_SECS};
+pub(crate) use paragraphs::{DEFAULT_PARAGRAPH_TARGET_CHARS, MIN_PARAGRAPH_TARGET_CHARS};
+pub(crate) use pre_roll::MAX_PRE_ROLL_MS;
+pub(crate) use preview::{
+    find_template, recording_options, require_template, resolve_audio_source,
+    resolve_meeting_config,
+};
+pub(crate) use queue::CancelOutcome;
+pub(crate) use resume::MAX_RESUME_GRACE_SECS;
+pub(crate) use speech::SPEECH_VAD_THRESHOLD;
+pub(crate) use summary::{DEFAULT_SUMMARY_TOKEN_BUDGET, MIN_SUMMARY_TOKEN_BUDGET};
+pub(crate) use title::render_title;
 
 // Re-export internal types needed by other modules (may not all be used yet)
 #[allow(unused_imports)]
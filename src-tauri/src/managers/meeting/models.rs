@@ -1,27 +1,40 @@
 //! Data models for meeting sessions.
 
-use crate::audio_toolkit::MixedAudioRecorder;
+use crate::audio_toolkit::{MixedAudioRecorder, SyncSettings};
+use crate::commands::audio::AudioDevice;
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use super::resume::RecentStop;
 use super::wav_writer::WavWriterHandle;
 
 /// Represents the lifecycle status of a meeting session.
 ///
 /// The state machine follows this flow:
 /// - Idle -> Recording (start meeting)
+/// - Recording -> Paused (pause meeting, audio is dropped until resumed)
+/// - Paused -> Recording (resume meeting)
 /// - Recording -> Processing (stop meeting, begin transcription)
+/// - Recording -> Recorded (stop meeting, transcribe later)
+/// - Paused -> Processing / Recorded (stop a paused meeting)
+/// - Recorded -> Processing (manual transcription)
 /// - Recording -> Interrupted (app closed during recording)
 /// - Processing -> Completed (transcription success)
 /// - Processing -> Failed (transcription failure)
 /// - Failed -> Processing (retry transcription)
 /// - Interrupted -> Processing (resume transcription on next launch)
-#[derive(Clone, Debug, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, Type, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum MeetingStatus {
     /// No active meeting session
     Idle,
     /// Meeting is currently being recorded
     Recording,
+    /// Recording is paused; the capture and WAV file stay open but no audio is written
+    Paused,
+    /// Recording stopped, waiting for transcription to be started manually
+    Recorded,
     /// Recording stopped, transcription in progress
     Processing,
     /// Meeting completed successfully with transcript
@@ -32,6 +45,35 @@ pub enum MeetingStatus {
     Interrupted,
 }
 
+impl MeetingStatus {
+    /// Every status, in lifecycle order.
+    pub const ALL: [MeetingStatus; 8] = [
+        MeetingStatus::Idle,
+        MeetingStatus::Recording,
+        MeetingStatus::Paused,
+        MeetingStatus::Recorded,
+        MeetingStatus::Processing,
+        MeetingStatus::Completed,
+        MeetingStatus::Failed,
+        MeetingStatus::Interrupted,
+    ];
+
+    /// Whether the session still holds an open capture, i.e. is recording or paused.
+    pub fn is_capturing(&self) -> bool {
+        matches!(self, MeetingStatus::Recording | MeetingStatus::Paused)
+    }
+
+    /// Status a session moves to when its recording stops: Processing if it is
+    /// transcribed right away, Recorded if transcription waits for a manual start.
+    pub fn after_stop(auto_transcribe: bool) -> Self {
+        if auto_transcribe {
+            MeetingStatus::Processing
+        } else {
+            MeetingStatus::Recorded
+        }
+    }
+}
+
 impl Default for MeetingStatus {
     fn default() -> Self {
         MeetingStatus::Idle
@@ -56,6 +98,101 @@ impl Default for AudioSourceType {
     }
 }
 
+/// Per-recording options that tune how audio is captured and mixed.
+///
+/// Typically derived from the meeting template the session is started from.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingOptions {
+    /// Balance mic and system audio by loudness when recording in Mixed mode
+    pub normalize_loudness: bool,
+    /// Leave the app's own sound output out of captured system audio
+    pub exclude_app_audio: bool,
+    /// How mic and system audio are lined up when recording in Mixed mode
+    pub sync: SyncSettings,
+    /// Transcribe the recording in chunks while it is captured, for live captions
+    pub live_captions: bool,
+    /// Name of the microphone to record from; `None` uses the default input
+    pub input_device: Option<String>,
+}
+
+/// The few session fields a UI polling for progress needs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct SessionLite {
+    pub id: String,
+    pub title: String,
+    pub status: MeetingStatus,
+    pub error_message: Option<String>,
+}
+
+/// Fields to change on a session in one `update_session_fields` call.
+///
+/// Absent fields are left as they are. An empty `category` or `notes` clears it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct SessionPatch {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub starred: Option<bool>,
+}
+
+impl SessionPatch {
+    /// Returns true if the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.notes.is_none()
+            && self.category.is_none()
+            && self.starred.is_none()
+    }
+
+    /// Checks each present field with the same rules as the single-field commands.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(title) = self.title.as_ref() {
+            if title.trim().is_empty() {
+                return Err("Title cannot be empty".to_string());
+            }
+        }
+        if let Some(category) = self.category.as_deref().filter(|c| !c.is_empty()) {
+            if !MEETING_CATEGORIES.contains(&category) {
+                return Err(format!(
+                    "Invalid category '{}' (expected one of: {})",
+                    category,
+                    MEETING_CATEGORIES.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What `suspend_meetings` stopped or cancelled.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Type)]
+pub struct SuspendSummary {
+    /// Session whose recording was stopped and marked Interrupted
+    pub interrupted_session: Option<String>,
+    /// Sessions whose pending transcription was cancelled (marked Failed, retryable)
+    pub cancelled_sessions: Vec<String>,
+    /// Session still being transcribed; it can't be aborted mid-run and finishes normally
+    pub running_session: Option<String>,
+}
+
+impl SuspendSummary {
+    /// Returns true if nothing was active when suspending.
+    pub fn is_noop(&self) -> bool {
+        self.interrupted_session.is_none()
+            && self.cancelled_sessions.is_empty()
+            && self.running_session.is_none()
+    }
+}
+
+/// Color labels a session can be categorized with.
+pub const MEETING_CATEGORIES: &[&str] = &[
+    "red", "orange", "yellow", "green", "blue", "purple", "gray",
+];
+
 /// Represents a meeting session with its metadata and file references.
 ///
 /// Each meeting session has a unique ID and is stored in a dedicated folder
@@ -86,7 +223,8 @@ pub struct MeetingSession {
     /// e.g., "{session-id}/transcript.txt"
     pub transcript_path: Option<String>,
 
-    /// Error message if the meeting failed
+    /// Error message if the meeting failed, or a note such as "No speech detected"
+    /// on sessions completed without transcription
     pub error_message: Option<String>,
 
     /// Audio source configuration for this meeting
@@ -99,6 +237,46 @@ pub struct MeetingSession {
     /// Template ID if this meeting was created from a template
     #[serde(default)]
     pub template_id: Option<String>,
+
+    /// Relative path to the compact archive copy of the audio, if one was made
+    /// e.g., "{session-id}/audio_archive.flac"
+    #[serde(default)]
+    pub archive_path: Option<String>,
+
+    /// Format of the archive copy (e.g., "flac_pcm16_mono")
+    #[serde(default)]
+    pub archive_format: Option<String>,
+
+    /// Color label from the fixed palette in `MEETING_CATEGORIES` (e.g., "blue")
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// Sample rate (Hz) the audio was recorded at, used to compute `duration`
+    #[serde(default)]
+    pub sample_rate: Option<u32>,
+
+    /// Free-form notes the user attached to the meeting
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    /// Whether the user starred the meeting
+    #[serde(default)]
+    pub starred: bool,
+
+    /// Whether system audio capture failed part way, so the rest of the
+    /// recording is microphone-only
+    #[serde(default)]
+    pub system_audio_partial: bool,
+
+    /// Informational note about how the session finished, such as
+    /// "No speech detected"; unlike `error_message` it does not mean failure
+    #[serde(default)]
+    pub status_note: Option<String>,
+
+    /// Whether summary-only retention deleted the transcript, so a Completed
+    /// session without one is expected rather than broken
+    #[serde(default)]
+    pub transcript_removed: bool,
 }
 
 impl MeetingSession {
@@ -119,6 +297,15 @@ impl MeetingSession {
             audio_source: AudioSourceType::default(),
             summary_path: None,
             template_id: None,
+            archive_path: None,
+            archive_format: None,
+            category: None,
+            sample_rate: None,
+            notes: None,
+            starred: false,
+            system_audio_partial: false,
+            status_note: None,
+            transcript_removed: false,
         }
     }
 
@@ -141,6 +328,15 @@ impl MeetingSession {
             audio_source,
             summary_path: None,
             template_id: None,
+            archive_path: None,
+            archive_format: None,
+            category: None,
+            sample_rate: None,
+            notes: None,
+            starred: false,
+            system_audio_partial: false,
+            status_note: None,
+            transcript_removed: false,
         }
     }
 
@@ -164,6 +360,15 @@ impl MeetingSession {
             audio_source,
             summary_path: None,
             template_id,
+            archive_path: None,
+            archive_format: None,
+            category: None,
+            sample_rate: None,
+            notes: None,
+            starred: false,
+            system_audio_partial: false,
+            status_note: None,
+            transcript_removed: false,
         }
     }
 }
@@ -175,6 +380,24 @@ pub(crate) struct MeetingManagerState {
     pub current_session: Option<MeetingSession>,
     pub mixed_recorder: Option<MixedAudioRecorder>,
     pub wav_writer: Option<WavWriterHandle>,
+    /// Last stopped session, kept while it can still be resumed
+    pub recent_stop: Option<RecentStop>,
+    /// Incremented on every start so watchdogs from an earlier capture can tell they are stale
+    pub recording_generation: u64,
+    /// Input device the current capture opened, if it records the microphone
+    pub active_device: Option<AudioDevice>,
+    /// Set while the current recording is paused; the sample callback drops audio then
+    pub capture_paused: Arc<AtomicBool>,
+}
+
+impl MeetingManagerState {
+    /// Returns the device being recorded, or `None` when no session is recording.
+    pub fn recording_device(&self) -> Option<AudioDevice> {
+        match &self.current_session {
+            Some(session) if session.status.is_capturing() => self.active_device.clone(),
+            _ => None,
+        }
+    }
 }
 
 impl Default for MeetingManagerState {
@@ -183,6 +406,10 @@ impl Default for MeetingManagerState {
             current_session: None,
             mixed_recorder: None,
             wav_writer: None,
+            recent_stop: None,
+            recording_generation: 0,
+            active_device: None,
+            capture_paused: Arc::new(AtomicBool::new(false)),
         }
     }
 }
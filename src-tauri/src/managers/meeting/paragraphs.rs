@@ -0,0 +1,85 @@
+//! Splitting a transcript into paragraphs for readability.
+//!
+//! Transcribers often return one long line. When a template enables it, the
+//! transcript is broken at sentence boundaries into paragraphs of roughly a
+//! target length before saving; the unformatted text is kept alongside.
+
+/// Unformatted transcript, saved next to `transcript.txt` when paragraphs are applied.
+pub(crate) const RAW_TRANSCRIPT_FILENAME: &str = "transcript.raw.txt";
+
+/// Shortest paragraph target a template may set, in characters.
+pub(crate) const MIN_PARAGRAPH_TARGET_CHARS: u32 = 80;
+
+/// Paragraph target used when a template doesn't set one, in characters.
+pub(crate) const DEFAULT_PARAGRAPH_TARGET_CHARS: u32 = 500;
+
+fn ends_sentence(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？')
+}
+
+fn closes_sentence(c: char) -> bool {
+    matches!(c, '"' | '\'' | ')' | ']' | '”' | '’' | '」')
+}
+
+/// Splits text into sentences, each trimmed and with inner whitespace collapsed.
+///
+/// A sentence ends at `.`, `!`, `?`, or `…` (plus any closing quotes or brackets)
+/// followed by whitespace, or at CJK full-width terminators.
+pub(crate) fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        current.push(c);
+        if !ends_sentence(c) {
+            continue;
+        }
+        while let Some(&next) = chars.peek() {
+            if ends_sentence(next) || closes_sentence(next) {
+                current.push(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let cjk = matches!(c, '。' | '！' | '？');
+        if cjk || chars.peek().map_or(true, |next| next.is_whitespace()) {
+            sentences.push(std::mem::take(&mut current));
+        }
+    }
+    sentences.push(current);
+
+    sentences
+        .into_iter()
+        .map(|s| s.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Groups sentences into paragraphs of about `target_chars` characters,
+/// separated by blank lines.
+///
+/// A paragraph is closed once it reaches the target, so no sentence is split.
+/// Existing line breaks in `text` are treated as paragraph breaks.
+pub(crate) fn format_paragraphs(text: &str, target_chars: usize) -> String {
+    let mut paragraphs: Vec<String> = Vec::new();
+
+    for block in text.lines().filter(|line| !line.trim().is_empty()) {
+        let mut paragraph = String::new();
+        for sentence in split_sentences(block) {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(&sentence);
+            if paragraph.chars().count() >= target_chars {
+                paragraphs.push(std::mem::take(&mut paragraph));
+            }
+        }
+        if !paragraph.is_empty() {
+            paragraphs.push(paragraph);
+        }
+    }
+
+    paragraphs.join("\n\n")
+}
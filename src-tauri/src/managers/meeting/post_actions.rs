@@ -0,0 +1,34 @@
+//! Running a template's post-completion actions.
+
+use anyhow::Result;
+use log::info;
+
+use crate::settings::PostAction;
+
+/// File the `ExportSrt` action writes into the session folder.
+pub(crate) const POST_ACTION_SRT_FILENAME: &str = "transcript.srt";
+
+/// Runs each action in order with `run`, collecting failures instead of stopping.
+///
+/// A failing action never prevents the ones after it from running; the caller
+/// decides how to report the failures.
+///
+/// # Returns
+/// The actions that failed, with their error messages
+pub(crate) fn run_post_actions<F>(
+    session_id: &str,
+    actions: &[PostAction],
+    mut run: F,
+) -> Vec<(PostAction, String)>
+where
+    F: FnMut(PostAction) -> Result<()>,
+{
+    let mut failures = Vec::new();
+    for &action in actions {
+        match run(action) {
+            Ok(()) => info!("Post action {:?} done for session {}", action, session_id),
+            Err(e) => failures.push((action, e.to_string())),
+        }
+    }
+    failures
+}
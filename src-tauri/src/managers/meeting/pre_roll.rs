@@ -0,0 +1,187 @@
+//! Keeping the audio heard while a session is starting.
+//!
+//! Opening the recorder takes a moment, and words spoken right after pressing
+//! record would be lost while it spins up. With `meeting_pre_roll_ms` set, the
+//! start opens the session's microphone first and keeps its last
+//! `meeting_pre_roll_ms` of audio in a ring buffer. When the recorder delivers
+//! its first samples, the buffered audio is written to the WAV ahead of them
+//! and the early input is closed. Nothing is kept open between meetings.
+//!
+//! Both inputs are open for a moment during the hand-off, so up to one device
+//! buffer around it can be heard twice rather than not at all.
+
+use anyhow::Result;
+use cpal::Device;
+use log::{debug, warn};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use super::wav_writer::WavSampleSink;
+use crate::audio_toolkit::AudioRecorder;
+
+/// Upper bound for the `meeting_pre_roll_ms` setting.
+pub(crate) const MAX_PRE_ROLL_MS: u32 = 2000;
+
+/// Number of interleaved samples that make up `pre_roll_ms` of audio.
+pub(crate) fn pre_roll_capacity(pre_roll_ms: u32, sample_rate: u32, channels: u16) -> usize {
+    (pre_roll_ms as u64 * sample_rate as u64 / 1000) as usize * channels as usize
+}
+
+/// Holds the newest `capacity` samples pushed into it.
+pub(crate) struct PreRollRing {
+    capacity: usize,
+    buffer: VecDeque<f32>,
+}
+
+impl PreRollRing {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buffer: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, samples: &[f32]) {
+        // Only the newest `capacity` samples can survive, so skip the rest up front
+        let keep = &samples[samples.len().saturating_sub(self.capacity)..];
+        let overflow = (self.buffer.len() + keep.len()).saturating_sub(self.capacity);
+        self.buffer.drain(..overflow);
+        self.buffer.extend(keep);
+    }
+
+    /// Empties the ring, returning its samples oldest first.
+    pub fn take(&mut self) -> Vec<f32> {
+        self.buffer.drain(..).collect()
+    }
+}
+
+/// Audio input that fills the pre-roll; it is closed when dropped.
+pub(crate) trait PreRollInput: Send {
+    /// Starts delivering samples in the recording's format to `on_samples`.
+    fn start(&mut self, on_samples: Box<dyn Fn(Vec<f32>) + Send + Sync>) -> Result<()>;
+}
+
+/// The session's microphone, opened for the pre-roll alone.
+pub(crate) struct DevicePreRoll {
+    device: Option<Device>,
+    recorder: Option<AudioRecorder>,
+}
+
+impl DevicePreRoll {
+    /// # Arguments
+    /// * `device` - Input the recorder will use; None for the default one
+    pub fn new(device: Option<Device>) -> Self {
+        Self {
+            device,
+            recorder: None,
+        }
+    }
+}
+
+impl PreRollInput for DevicePreRoll {
+    fn start(&mut self, on_samples: Box<dyn Fn(Vec<f32>) + Send + Sync>) -> Result<()> {
+        let mut recorder = AudioRecorder::new()
+            .map_err(|e| anyhow::anyhow!("Failed to create pre-roll recorder: {}", e))?
+            .without_sample_buffer()
+            .with_sample_callback(move |samples| on_samples(samples));
+        recorder
+            .open(self.device.take())
+            .map_err(|e| anyhow::anyhow!("Failed to open pre-roll input: {}", e))?;
+        // Stored before starting so a failed start still closes the device
+        let recorder = self.recorder.insert(recorder);
+        recorder
+            .start()
+            .map_err(|e| anyhow::anyhow!("Failed to start pre-roll capture: {}", e))?;
+        Ok(())
+    }
+}
+
+impl Drop for DevicePreRoll {
+    fn drop(&mut self) {
+        if let Some(mut recorder) = self.recorder.take() {
+            if let Err(e) = recorder.close() {
+                warn!("Failed to close pre-roll input: {}", e);
+            }
+        }
+    }
+}
+
+struct HandoffState {
+    live: bool,
+    ring: PreRollRing,
+}
+
+/// WAV sink for the recorder's samples that puts the pre-roll ahead of them.
+///
+/// The first samples from the recorder end the pre-roll: the ring is written
+/// out, the early input is closed, and everything after passes straight
+/// through. Writes happen under the state lock, so nothing from the pre-roll
+/// input can land after the recorder's audio.
+#[derive(Clone)]
+pub(crate) struct PreRollHandoff {
+    sink: WavSampleSink,
+    state: Arc<Mutex<HandoffState>>,
+    input: Arc<Mutex<Option<Box<dyn PreRollInput>>>>,
+}
+
+impl PreRollHandoff {
+    /// A sink without a pre-roll.
+    pub fn new(sink: WavSampleSink) -> Self {
+        Self {
+            sink,
+            state: Arc::new(Mutex::new(HandoffState {
+                live: true,
+                ring: PreRollRing::new(0),
+            })),
+            input: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Starts `input` filling a pre-roll of `capacity` samples.
+    ///
+    /// # Returns
+    /// An error, with `input` closed again, if it couldn't be started
+    pub fn with_input(
+        sink: WavSampleSink,
+        capacity: usize,
+        mut input: Box<dyn PreRollInput>,
+    ) -> Result<Self> {
+        let state = Arc::new(Mutex::new(HandoffState {
+            live: false,
+            ring: PreRollRing::new(capacity),
+        }));
+        let ring_state = state.clone();
+        input.start(Box::new(move |samples| {
+            let mut state = ring_state.lock().unwrap_or_else(|p| p.into_inner());
+            if !state.live {
+                state.ring.push(&samples);
+            }
+        }))?;
+
+        Ok(Self {
+            sink,
+            state,
+            input: Arc::new(Mutex::new(Some(input))),
+        })
+    }
+
+    pub fn write_samples(&self, samples: &[f32]) -> Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        if !state.live {
+            state.live = true;
+            let pre_roll = state.ring.take();
+            match self.sink.write_samples(&pre_roll) {
+                Ok(()) => debug!("Prepended {} pre-roll samples", pre_roll.len()),
+                Err(e) => warn!("Failed to write pre-roll audio: {}", e),
+            }
+
+            // Closing waits for the input's worker, so keep it off the audio thread
+            let input = self.input.lock().unwrap_or_else(|p| p.into_inner()).take();
+            if let Some(input) = input {
+                thread::spawn(move || drop(input));
+            }
+        }
+        self.sink.write_samples(samples)
+    }
+}
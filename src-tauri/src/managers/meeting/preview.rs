@@ -0,0 +1,154 @@
+//! Resolving what a recording start would use, without starting one.
+//!
+//! `start_meeting_session` and the "you're about to record with…" preview both
+//! go through these functions, so the preview shows exactly what a start with
+//! the same inputs would do.
+
+use serde::Serialize;
+use specta::Type;
+
+use super::disk::MEETING_WAV_SPEC;
+use super::models::{AudioSourceType, RecordingOptions};
+use super::summary::DEFAULT_SUMMARY_TOKEN_BUDGET;
+use crate::audio_toolkit::SyncSettings;
+use crate::commands::audio::AudioDevice;
+use crate::settings::{
+    AppSettings, MeetingTemplate, PostAction, PreprocessingStep, SummaryStrategy,
+};
+
+/// The fully resolved configuration of a recording that hasn't started yet.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ResolvedMeetingConfig {
+    /// Template the session would use, if any
+    pub template_id: Option<String>,
+    pub template_name: Option<String>,
+    pub audio_source: AudioSourceType,
+    /// Microphone that would be recorded; `None` for system-only or without a device
+    pub device: Option<AudioDevice>,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+    pub channels: u16,
+    /// Transcription language, "auto" to detect it
+    pub language: String,
+    pub translate_to_english: bool,
+    pub preprocessing: Vec<PreprocessingStep>,
+    pub normalize_loudness: bool,
+    pub exclude_app_audio: bool,
+    pub sync_offset_ms: i32,
+    pub auto_sync_offset: bool,
+    /// Seconds per chunk transcribed while recording; `None` without live captions
+    pub live_chunk_secs: Option<u32>,
+    /// Whether transcription starts as soon as the recording stops
+    pub auto_transcribe: bool,
+    /// Whether a summary is generated once the transcript is saved
+    pub generate_summary: bool,
+    /// Whether the template replaces the default summary prompt
+    pub custom_summary_prompt: bool,
+    pub summary_strategy: SummaryStrategy,
+    pub summary_token_budget: u32,
+}
+
+/// The template a start uses: the requested one, or else the active template.
+pub(crate) fn find_template(
+    settings: &AppSettings,
+    template_id: Option<&str>,
+) -> Option<MeetingTemplate> {
+    match template_id {
+        Some(id) => settings.meeting_templates.iter().find(|t| t.id == id),
+        None => settings.active_meeting_template(),
+    }
+    .cloned()
+}
+
+/// Looks up the template a start was explicitly asked to use.
+///
+/// Unlike `find_template`, a missing id is an error rather than a start
+/// without a template.
+pub(crate) fn require_template(
+    settings: &AppSettings,
+    template_id: &str,
+) -> Result<MeetingTemplate, String> {
+    find_template(settings, Some(template_id))
+        .ok_or_else(|| format!("Meeting template '{}' does not exist", template_id))
+}
+
+/// The audio source a start records: the requested one, then the template's,
+/// then the default.
+pub(crate) fn resolve_audio_source(
+    audio_source: Option<AudioSourceType>,
+    template: Option<&MeetingTemplate>,
+) -> AudioSourceType {
+    audio_source
+        .or_else(|| {
+            template.and_then(|t| match t.audio_source.as_str() {
+                "microphone_only" => Some(AudioSourceType::MicrophoneOnly),
+                "system_only" => Some(AudioSourceType::SystemOnly),
+                "mixed" => Some(AudioSourceType::Mixed),
+                _ => None,
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// The capture and mixing options taken from `template`.
+pub(crate) fn recording_options(template: Option<&MeetingTemplate>) -> RecordingOptions {
+    RecordingOptions {
+        normalize_loudness: template.map_or(false, |t| t.normalize_loudness),
+        exclude_app_audio: template.map_or(false, |t| t.exclude_app_audio),
+        live_captions: template.map_or(false, |t| t.live_captions),
+        input_device: None,
+        sync: template.map_or(SyncSettings::default(), |t| SyncSettings {
+            offset_ms: t.sync_offset_ms,
+            auto_calibrate: t.auto_sync_offset,
+        }),
+    }
+}
+
+/// Resolves everything a start with these inputs would record and process with.
+///
+/// # Arguments
+/// * `default_device` - The microphone `AudioRecorder::open(None)` would open
+pub(crate) fn resolve_meeting_config(
+    settings: &AppSettings,
+    audio_source: Option<AudioSourceType>,
+    template_id: Option<&str>,
+    default_device: Option<AudioDevice>,
+) -> ResolvedMeetingConfig {
+    let template = find_template(settings, template_id);
+    let template = template.as_ref();
+    let audio_source = resolve_audio_source(audio_source, template);
+    let options = recording_options(template);
+
+    let device = match audio_source {
+        AudioSourceType::SystemOnly => None,
+        AudioSourceType::MicrophoneOnly | AudioSourceType::Mixed => default_device,
+    };
+
+    ResolvedMeetingConfig {
+        template_id: template.map(|t| t.id.clone()),
+        template_name: template.map(|t| t.name.clone()),
+        audio_source,
+        device,
+        sample_rate: MEETING_WAV_SPEC.sample_rate,
+        bits_per_sample: MEETING_WAV_SPEC.bits_per_sample,
+        channels: MEETING_WAV_SPEC.channels,
+        language: settings.selected_language.clone(),
+        translate_to_english: settings.translate_to_english,
+        preprocessing: template.map_or_else(Vec::new, |t| t.preprocessing.clone()),
+        normalize_loudness: options.normalize_loudness,
+        exclude_app_audio: options.exclude_app_audio,
+        sync_offset_ms: options.sync.offset_ms,
+        auto_sync_offset: options.sync.auto_calibrate,
+        live_chunk_secs: options
+            .live_captions
+            .then_some(settings.meeting_live_chunk_secs),
+        auto_transcribe: settings.meeting_auto_transcribe,
+        generate_summary: template.map_or(false, |t| {
+            t.post_actions.contains(&PostAction::GenerateSummary)
+        }),
+        custom_summary_prompt: template.map_or(false, |t| t.summary_prompt_template.is_some()),
+        summary_strategy: template.map_or_else(SummaryStrategy::default, |t| t.summary_strategy),
+        summary_token_budget: template
+            .map_or(DEFAULT_SUMMARY_TOKEN_BUDGET, |t| t.summary_token_budget),
+    }
+}
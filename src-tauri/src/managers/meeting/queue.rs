@@ -0,0 +1,197 @@
+//! Bookkeeping for the background transcription queue.
+//!
+//! Meetings are transcribed one at a time. Sessions waiting for their turn sit in
+//! `pending`; the session currently being transcribed is tracked in `running`.
+//! The queue is mirrored to a small file so that work left over when the app
+//! closes can be picked up again on the next launch.
+
+use anyhow::Result;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+
+use super::files::write_atomic;
+
+/// File at the meetings root holding the queue as of its last change.
+pub(crate) const QUEUE_FILENAME: &str = "transcription_queue.json";
+
+/// Result of trying to remove a session from the transcription queue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CancelOutcome {
+    /// The session was waiting and has been removed from the queue
+    Removed,
+    /// The session is already being transcribed and can no longer be dequeued
+    Running,
+    /// The session is not in the queue at all
+    NotQueued,
+}
+
+/// Snapshot of the queue sent to the frontend with `meeting_queue_updated`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct TranscriptionQueueSnapshot {
+    /// Session currently being transcribed, if any
+    pub running: Option<String>,
+    /// Sessions waiting to be transcribed, in order
+    pub pending: Vec<String>,
+}
+
+/// FIFO of sessions waiting for transcription.
+///
+/// `draining` is set while a worker thread is consuming the queue, so that only
+/// one worker runs at a time.
+#[derive(Debug, Default)]
+pub(crate) struct TranscriptionQueue {
+    pending: VecDeque<String>,
+    running: Option<String>,
+    draining: bool,
+}
+
+impl TranscriptionQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a session to the back of the queue.
+    ///
+    /// Returns `true` if the caller should start a worker to drain the queue.
+    /// Sessions that are already queued or running are not added twice.
+    pub fn enqueue(&mut self, session_id: &str) -> bool {
+        let already_queued = self.running.as_deref() == Some(session_id)
+            || self.pending.iter().any(|id| id == session_id);
+        if !already_queued {
+            self.pending.push_back(session_id.to_string());
+        }
+
+        if self.draining {
+            false
+        } else {
+            self.draining = true;
+            true
+        }
+    }
+
+    /// Removes a session that has not started yet.
+    pub fn cancel(&mut self, session_id: &str) -> CancelOutcome {
+        if self.running.as_deref() == Some(session_id) {
+            return CancelOutcome::Running;
+        }
+
+        match self.pending.iter().position(|id| id == session_id) {
+            Some(index) => {
+                self.pending.remove(index);
+                CancelOutcome::Removed
+            }
+            None => CancelOutcome::NotQueued,
+        }
+    }
+
+    /// Removes every session that has not started yet, leaving the running one.
+    ///
+    /// # Returns
+    /// The removed sessions, in queue order
+    pub fn cancel_all_pending(&mut self) -> Vec<String> {
+        self.pending.drain(..).collect()
+    }
+
+    /// Marks the previously running job as finished and takes the next one.
+    ///
+    /// Returns `None` (and stops draining) once the queue is empty.
+    pub fn start_next(&mut self) -> Option<String> {
+        self.running = self.pending.pop_front();
+        if self.running.is_none() {
+            self.draining = false;
+        }
+        self.running.clone()
+    }
+
+    /// Forgets the running job and stops draining, for a worker that died mid-job.
+    ///
+    /// Waiting sessions stay queued; the next `enqueue` starts a worker for them.
+    pub fn abandon_running(&mut self) {
+        self.running = None;
+        self.draining = false;
+    }
+
+    /// Returns the session currently being transcribed, if any.
+    pub fn running(&self) -> Option<&str> {
+        self.running.as_deref()
+    }
+
+    /// Returns true if the session is waiting or running.
+    pub fn contains(&self, session_id: &str) -> bool {
+        self.running.as_deref() == Some(session_id)
+            || self.pending.iter().any(|id| id == session_id)
+    }
+
+    pub fn snapshot(&self) -> TranscriptionQueueSnapshot {
+        TranscriptionQueueSnapshot {
+            running: self.running.clone(),
+            pending: self.pending.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Held by the worker draining the queue. If the worker panics, dropping the
+/// guard clears `draining` so that later jobs still get a worker.
+pub(crate) struct DrainGuard<'a> {
+    queue: &'a Mutex<TranscriptionQueue>,
+}
+
+impl<'a> DrainGuard<'a> {
+    pub fn new(queue: &'a Mutex<TranscriptionQueue>) -> Self {
+        Self { queue }
+    }
+}
+
+impl Drop for DrainGuard<'_> {
+    fn drop(&mut self) {
+        // A normal exit already stopped draining in `start_next`, and a new
+        // worker may have started since
+        if thread::panicking() {
+            let mut queue = self.queue.lock().unwrap_or_else(|p| p.into_inner());
+            warn!(
+                "Transcription worker panicked while running {:?}",
+                queue.running()
+            );
+            queue.abandon_running();
+        }
+    }
+}
+
+impl TranscriptionQueueSnapshot {
+    /// Every session in the snapshot, running first, in the order they'd be transcribed.
+    pub fn session_ids(&self) -> Vec<String> {
+        self.running.iter().chain(&self.pending).cloned().collect()
+    }
+}
+
+/// Writes the queue to `meetings_dir`, replacing what was saved before.
+pub(crate) fn save_queue(meetings_dir: &Path, snapshot: &TranscriptionQueueSnapshot) -> Result<()> {
+    let json = serde_json::to_vec_pretty(snapshot)?;
+    write_atomic(&meetings_dir.join(QUEUE_FILENAME), &json)
+}
+
+/// Reads the queue saved by `save_queue`.
+///
+/// A missing file means nothing was queued. An unreadable one is logged and
+/// treated the same way; sessions stuck in Processing are still found through
+/// the database on launch.
+pub(crate) fn load_queue(meetings_dir: &Path) -> TranscriptionQueueSnapshot {
+    let path = meetings_dir.join(QUEUE_FILENAME);
+    let contents = match fs::read(&path) {
+        Ok(contents) => contents,
+        Err(_) => return TranscriptionQueueSnapshot::default(),
+    };
+    serde_json::from_slice(&contents).unwrap_or_else(|e| {
+        warn!(
+            "Ignoring unreadable transcription queue file {:?}: {}",
+            path, e
+        );
+        TranscriptionQueueSnapshot::default()
+    })
+}
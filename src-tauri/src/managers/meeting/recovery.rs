@@ -0,0 +1,63 @@
+//! Picking recovered sessions that can be transcribed automatically on launch.
+
+use hound::WavReader;
+use std::path::Path;
+
+use super::models::{MeetingSession, MeetingStatus};
+
+/// Returns true if `path` is a WAV file hound can open that holds at least one sample.
+///
+/// A recording cut off by a crash may have a header that was never finalized;
+/// such a file reports no samples and is left for the user to retry by hand.
+pub(crate) fn has_readable_audio(path: &Path) -> bool {
+    match WavReader::open(path) {
+        Ok(reader) => reader.spec().sample_rate > 0 && reader.duration() > 0,
+        Err(_) => false,
+    }
+}
+
+/// Selects the interrupted sessions whose audio is intact enough to transcribe.
+pub(crate) fn sessions_to_auto_retry<'a>(
+    meetings_dir: &Path,
+    sessions: &'a [MeetingSession],
+) -> Vec<&'a MeetingSession> {
+    sessions
+        .iter()
+        .filter(|session| session.status == MeetingStatus::Interrupted)
+        .filter(|session| {
+            session
+                .audio_path
+                .as_ref()
+                .map_or(false, |path| has_readable_audio(&meetings_dir.join(path)))
+        })
+        .collect()
+}
+
+/// Selects the sessions whose transcription should be picked up again on launch.
+///
+/// These are sessions still marked Processing, i.e. queued or being transcribed
+/// when the app closed, that have readable audio. Sessions listed in
+/// `saved_queue` come first in their saved order; any other Processing sessions
+/// follow, oldest first.
+pub(crate) fn sessions_to_resume<'a>(
+    meetings_dir: &Path,
+    saved_queue: &[String],
+    sessions: &'a [MeetingSession],
+) -> Vec<&'a MeetingSession> {
+    let mut resumable: Vec<&MeetingSession> = sessions
+        .iter()
+        .filter(|session| session.status == MeetingStatus::Processing)
+        .filter(|session| {
+            session
+                .audio_path
+                .as_ref()
+                .map_or(false, |path| has_readable_audio(&meetings_dir.join(path)))
+        })
+        .collect();
+
+    resumable.sort_by_key(|session| {
+        let saved_position = saved_queue.iter().position(|id| id == &session.id);
+        (saved_position.unwrap_or(usize::MAX), session.created_at)
+    });
+    resumable
+}
@@ -0,0 +1,175 @@
+//! Repairing sessions whose stored status or duration disagrees with the files on disk.
+
+use anyhow::Result;
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+
+use super::db::{
+    clear_session_error, get_session, list_sessions, set_session_status_note,
+    update_session_duration, update_session_status, update_session_status_with_error,
+};
+use super::models::{MeetingSession, MeetingStatus};
+use super::silence::NO_SPEECH_NOTE;
+use super::wav_writer::recorded_duration;
+
+/// Error recorded when the audio is present but transcription never produced a transcript.
+pub(crate) const MISSING_TRANSCRIPT_NOTE: &str =
+    "Transcript missing after processing; retry transcription";
+
+/// Error recorded when neither the audio nor the transcript can be found.
+pub(crate) const MISSING_FILES_NOTE: &str = "Audio and transcript files are missing";
+
+/// Status and error message consistent with a session's files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DerivedStatus {
+    pub status: MeetingStatus,
+    pub error_message: Option<String>,
+}
+
+fn file_exists(meetings_dir: &Path, rel_path: Option<&String>) -> bool {
+    rel_path.map_or(false, |path| meetings_dir.join(path).is_file())
+}
+
+/// Works out which status a session should have from the files that exist.
+///
+/// - A transcript on disk means Completed, without an error; so does a transcript
+///   removed by summary-only retention.
+/// - Audio still queued for transcription stays Processing.
+/// - Audio from a recording that never stopped cleanly is Interrupted.
+/// - Audio waiting for a manual transcription stays Recorded.
+/// - Any other audio without a transcript is Failed, so it can be retried.
+/// - Without audio or transcript the session is Failed.
+pub(crate) fn derive_status_from_files(
+    meetings_dir: &Path,
+    session: &MeetingSession,
+    transcription_queued: bool,
+) -> DerivedStatus {
+    let has_transcript = file_exists(meetings_dir, session.transcript_path.as_ref());
+    let has_audio = file_exists(meetings_dir, session.audio_path.as_ref())
+        || file_exists(meetings_dir, session.archive_path.as_ref());
+
+    if has_transcript || (session.transcript_removed && !transcription_queued) {
+        return DerivedStatus {
+            status: MeetingStatus::Completed,
+            error_message: None,
+        };
+    }
+
+    if !has_audio {
+        return DerivedStatus {
+            status: MeetingStatus::Failed,
+            error_message: Some(MISSING_FILES_NOTE.to_string()),
+        };
+    }
+
+    match session.status {
+        MeetingStatus::Processing if transcription_queued => DerivedStatus {
+            status: MeetingStatus::Processing,
+            error_message: session.error_message.clone(),
+        },
+        // Audio without a transcript is exactly what Recorded means
+        MeetingStatus::Recorded => DerivedStatus {
+            status: MeetingStatus::Recorded,
+            error_message: session.error_message.clone(),
+        },
+        MeetingStatus::Recording | MeetingStatus::Paused | MeetingStatus::Interrupted => {
+            DerivedStatus {
+                status: MeetingStatus::Interrupted,
+                error_message: session.error_message.clone(),
+            }
+        }
+        MeetingStatus::Failed if session.error_message.is_some() => DerivedStatus {
+            status: MeetingStatus::Failed,
+            error_message: session.error_message.clone(),
+        },
+        _ => DerivedStatus {
+            status: MeetingStatus::Failed,
+            error_message: Some(MISSING_TRANSCRIPT_NOTE.to_string()),
+        },
+    }
+}
+
+/// Re-derives a session's status from its files and stores it if it changed.
+///
+/// A "No speech detected" note that older versions stored as the error
+/// message is moved to the session's status note.
+///
+/// # Returns
+/// The session as stored after the repair
+pub(crate) fn repair_session_status(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    session: &MeetingSession,
+    transcription_queued: bool,
+) -> Result<MeetingSession> {
+    let derived = derive_status_from_files(meetings_dir, session, transcription_queued);
+
+    if session.error_message.as_deref() == Some(NO_SPEECH_NOTE) && session.status_note.is_none() {
+        set_session_status_note(db_path, &session.id, Some(NO_SPEECH_NOTE))?;
+    }
+
+    if derived.status != session.status || derived.error_message != session.error_message {
+        match derived.error_message.as_deref() {
+            Some(message) => {
+                update_session_status_with_error(db_path, &session.id, &derived.status, message)?
+            }
+            None => {
+                update_session_status(db_path, &session.id, &derived.status)?;
+                clear_session_error(db_path, &session.id)?;
+            }
+        }
+        info!(
+            "Repaired session {}: {:?} -> {:?}",
+            session.id, session.status, derived.status
+        );
+    }
+
+    get_session(db_path, &session.id)?
+        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session.id))
+}
+
+/// Recalculates the duration of every session with readable audio from the
+/// WAV's sample count and rate, storing it where the stored value differs.
+///
+/// Sessions without audio, and those in `skip_ids` (e.g. still recording),
+/// are left alone. Unreadable audio is logged and skipped.
+///
+/// # Returns
+/// The number of sessions whose duration was corrected
+pub(crate) fn recompute_session_durations(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    skip_ids: &[String],
+) -> Result<u32> {
+    let mut fixed = 0;
+    for session in list_sessions(db_path)? {
+        if skip_ids.contains(&session.id) {
+            continue;
+        }
+        let audio_path = match session.audio_path.as_ref() {
+            Some(audio_path) if meetings_dir.join(audio_path).is_file() => audio_path,
+            _ => continue,
+        };
+
+        let recorded = match recorded_duration(&meetings_dir.join(audio_path)) {
+            Ok(recorded) => recorded,
+            Err(e) => {
+                warn!("Skipping duration of session {}: {}", session.id, e);
+                continue;
+            }
+        };
+        if session.duration == Some(recorded.seconds)
+            && session.sample_rate == Some(recorded.sample_rate)
+        {
+            continue;
+        }
+
+        update_session_duration(db_path, &session.id, recorded.seconds, recorded.sample_rate)?;
+        info!(
+            "Corrected duration of session {}: {:?} -> {}s",
+            session.id, session.duration, recorded.seconds
+        );
+        fixed += 1;
+    }
+    Ok(fixed)
+}
@@ -0,0 +1,56 @@
+//! Wiping all meeting data to start fresh.
+
+use anyhow::Result;
+use log::info;
+use serde::Serialize;
+use specta::Type;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::db::get_connection;
+use super::export::session_storage_bytes;
+
+/// What `reset_all_meetings` removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Type)]
+pub struct ResetSummary {
+    /// Session rows deleted from the database
+    pub sessions_removed: usize,
+    /// Entries (session folders and loose files) deleted from the meetings directory
+    pub entries_removed: usize,
+    /// Disk space freed in the meetings directory, in bytes
+    pub bytes_freed: u64,
+}
+
+/// Deletes every session row and everything inside `meetings_dir`.
+///
+/// The database file and the (now empty) meetings directory are kept, so the
+/// manager can keep using them without re-initialising. Callers must make sure
+/// nothing is recording or transcribing.
+pub(crate) fn reset_meetings_data(db_path: &PathBuf, meetings_dir: &Path) -> Result<ResetSummary> {
+    let mut summary = ResetSummary::default();
+
+    let conn = get_connection(db_path)?;
+    summary.sessions_removed = conn.execute("DELETE FROM meeting_sessions", [])?;
+    conn.execute("DELETE FROM meeting_transcripts_fts", [])?;
+
+    if meetings_dir.exists() {
+        for entry in fs::read_dir(meetings_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                summary.bytes_freed +=
+                    session_storage_bytes(meetings_dir, &entry.file_name().to_string_lossy());
+                fs::remove_dir_all(&path)
+                    .map_err(|e| anyhow::anyhow!("Failed to remove {:?}: {}", path, e))?;
+            } else {
+                summary.bytes_freed += entry.metadata().map(|m| m.len()).unwrap_or(0);
+                fs::remove_file(&path)
+                    .map_err(|e| anyhow::anyhow!("Failed to remove {:?}: {}", path, e))?;
+            }
+            summary.entries_removed += 1;
+        }
+    }
+
+    info!("Reset meetings data: {:?}", summary);
+    Ok(summary)
+}
@@ -0,0 +1,70 @@
+//! Merging an accidental stop/start double-tap back into a single session.
+//!
+//! When a grace window is configured, a stopped recording is held for that long
+//! before it is handed to transcription. Starting again within the window resumes
+//! the same session and appends to its WAV instead of creating a new meeting.
+
+use anyhow::Result;
+use hound::{WavSpec, WavWriter};
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Upper bound for the configurable grace window.
+pub(crate) const MAX_RESUME_GRACE_SECS: u32 = 30;
+
+/// The most recently stopped session, remembered while it may still be resumed.
+#[derive(Clone, Debug)]
+pub(crate) struct RecentStop {
+    pub session_id: String,
+    pub stopped_at: Instant,
+}
+
+impl RecentStop {
+    pub fn new(session_id: &str, stopped_at: Instant) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            stopped_at,
+        }
+    }
+
+    /// Returns true if a start at `now` is close enough to this stop to resume it.
+    /// A zero grace window never resumes.
+    pub fn allows_resume(&self, grace: Duration, now: Instant) -> bool {
+        !grace.is_zero() && now.saturating_duration_since(self.stopped_at) <= grace
+    }
+}
+
+/// Returns the grace window for the configured number of seconds, clamped to the maximum.
+pub(crate) fn resume_grace(seconds: u32) -> Duration {
+    Duration::from_secs(seconds.min(MAX_RESUME_GRACE_SECS) as u64)
+}
+
+/// Opens a finalized meeting WAV so that new samples are written after the existing ones.
+///
+/// `spec` is the format new samples are written in; the existing audio must
+/// have the same rate and layout.
+pub(crate) fn open_wav_for_append(path: &Path, spec: WavSpec) -> Result<WavWriter<File>> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?} for append: {}", path, e))?;
+
+    let writer = WavWriter::new_append(file)
+        .map_err(|e| anyhow::anyhow!("Failed to append to audio file {:?}: {}", path, e))?;
+
+    // Samples in another format would be corrupted, and the stored duration wrong
+    let existing = writer.spec();
+    if existing != spec {
+        return Err(anyhow::anyhow!(
+            "Cannot append to audio file {:?}: unexpected format {}Hz, {} channel(s), {}bit",
+            path,
+            existing.sample_rate,
+            existing.channels,
+            existing.bits_per_sample
+        ));
+    }
+
+    Ok(writer)
+}
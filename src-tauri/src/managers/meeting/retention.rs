@@ -0,0 +1,91 @@
+//! Summary-only retention: dropping a session's audio and/or transcript once
+//! its summary has been saved, for templates that opt out of keeping them.
+
+use anyhow::Result;
+use log::{info, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::audio_cache::cache_path_for;
+use super::db::{clear_session_audio, clear_session_transcript};
+use super::models::MeetingSession;
+use super::paragraphs::RAW_TRANSCRIPT_FILENAME;
+use super::transcript::TRANSCRIPT_DETAILS_FILE;
+
+/// What was removed by [`apply_summary_retention`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RetentionOutcome {
+    pub removed_audio: bool,
+    pub removed_transcript: bool,
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(anyhow::anyhow!("Failed to remove {:?}: {}", path, e)),
+    }
+}
+
+/// Deletes the session's audio and/or transcript according to the template's
+/// retention flags and clears their paths in the database.
+///
+/// Only runs once a non-empty summary exists on disk, so nothing is deleted if
+/// summarization failed.
+pub(crate) fn apply_summary_retention(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    session: &MeetingSession,
+    retain_audio: bool,
+    retain_transcript: bool,
+) -> Result<RetentionOutcome> {
+    let mut outcome = RetentionOutcome::default();
+    if retain_audio && retain_transcript {
+        return Ok(outcome);
+    }
+
+    let summary_ok = session
+        .summary_path
+        .as_ref()
+        .and_then(|path| fs::metadata(meetings_dir.join(path)).ok())
+        .map_or(false, |meta| meta.is_file() && meta.len() > 0);
+    if !summary_ok {
+        return Err(anyhow::anyhow!(
+            "Refusing to remove files for session {}: no saved summary",
+            session.id
+        ));
+    }
+
+    if !retain_audio && (session.audio_path.is_some() || session.archive_path.is_some()) {
+        for rel_path in [&session.audio_path, &session.archive_path]
+            .into_iter()
+            .flatten()
+        {
+            let full_path = meetings_dir.join(rel_path);
+            remove_if_exists(&full_path)?;
+            if let Err(e) = remove_if_exists(&cache_path_for(&full_path)) {
+                warn!("{}", e);
+            }
+        }
+        clear_session_audio(db_path, &session.id)?;
+        outcome.removed_audio = true;
+    }
+
+    if !retain_transcript {
+        if let Some(rel_path) = session.transcript_path.as_ref() {
+            remove_if_exists(&meetings_dir.join(rel_path))?;
+            // The segments and unformatted copy repeat the transcript text, so they go too
+            let session_dir = meetings_dir.join(&session.id);
+            remove_if_exists(&session_dir.join(TRANSCRIPT_DETAILS_FILE))?;
+            remove_if_exists(&session_dir.join(RAW_TRANSCRIPT_FILENAME))?;
+            clear_session_transcript(db_path, &session.id)?;
+            outcome.removed_transcript = true;
+        }
+    }
+
+    info!(
+        "Applied summary-only retention to session {}: {:?}",
+        session.id, outcome
+    );
+    Ok(outcome)
+}
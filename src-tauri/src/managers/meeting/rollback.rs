@@ -0,0 +1,99 @@
+//! Undoing a recording start that fails part way through.
+//!
+//! By the time the recorder is opened, a new session already has a database
+//! row, a folder, and an open `audio.wav`. If opening or starting the recorder
+//! then fails, those would be left behind as an orphan session.
+
+use log::{debug, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::db::delete_session_record;
+use super::wav_writer::WavWriterHandle;
+
+/// How long a failed start waits to finalize its WAV before removing it.
+const ROLLBACK_FINALIZE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Held during a start; unless committed, dropping it finalizes the WAV and,
+/// for a new session, removes its folder and database row.
+///
+/// A resumed session already holds earlier audio, so only its WAV is finalized
+/// and its `on_abort` hook hands the session back to the stop it was taken from.
+pub(crate) struct StartRollback {
+    db_path: PathBuf,
+    meetings_dir: PathBuf,
+    session_id: String,
+    discard_session: bool,
+    wav: Option<WavWriterHandle>,
+    on_abort: Option<Box<dyn FnOnce() + Send>>,
+    committed: bool,
+}
+
+impl StartRollback {
+    pub fn new(db_path: &Path, meetings_dir: &Path, session_id: &str, is_resume: bool) -> Self {
+        Self {
+            db_path: db_path.to_path_buf(),
+            meetings_dir: meetings_dir.to_path_buf(),
+            session_id: session_id.to_string(),
+            discard_session: !is_resume,
+            wav: None,
+            on_abort: None,
+            committed: false,
+        }
+    }
+
+    /// Keeps the start's WAV writer, so a failed start finalizes it before
+    /// removing anything.
+    pub fn hold_wav(&mut self, wav: WavWriterHandle) {
+        self.wav = Some(wav);
+    }
+
+    /// Runs `f` if the start fails, once the WAV has been finalized.
+    pub fn on_abort(&mut self, f: impl FnOnce() + Send + 'static) {
+        self.on_abort = Some(Box::new(f));
+    }
+
+    /// Marks the start as successful and hands back the WAV writer.
+    pub fn commit(mut self) -> Option<WavWriterHandle> {
+        self.committed = true;
+        self.wav.take()
+    }
+}
+
+impl Drop for StartRollback {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+
+        if let Some(wav) = self.wav.take() {
+            if let Err(e) = wav.finalize_with_timeout(ROLLBACK_FINALIZE_TIMEOUT) {
+                warn!(
+                    "Failed to finalize audio of failed start {}: {}",
+                    self.session_id, e
+                );
+            }
+        }
+        if let Some(on_abort) = self.on_abort.take() {
+            on_abort();
+        }
+        if !self.discard_session {
+            return;
+        }
+
+        let session_dir = self.meetings_dir.join(&self.session_id);
+        if session_dir.exists() {
+            if let Err(e) = fs::remove_dir_all(&session_dir) {
+                warn!(
+                    "Failed to remove folder of failed start {:?}: {}",
+                    session_dir, e
+                );
+            }
+        }
+        if let Err(e) = delete_session_record(&self.db_path, &self.session_id) {
+            warn!("Failed to delete failed start {}: {}", self.session_id, e);
+        }
+        debug!("Rolled back failed start of session {}", self.session_id);
+    }
+}
@@ -0,0 +1,149 @@
+//! Full-text search across meeting transcripts.
+//!
+//! Transcript text is mirrored into the `meeting_transcripts_fts` FTS5 table,
+//! one row per session, whenever a transcript is written. Transcripts saved
+//! before the table existed are picked up by `reindex_transcripts`, which
+//! rebuilds the table from the `transcript.txt` files on disk.
+
+use anyhow::Result;
+use log::warn;
+use rusqlite::params;
+use serde::Serialize;
+use specta::Type;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::db::{get_connection, row_to_session};
+use super::models::MeetingSession;
+
+/// Most sessions a search returns.
+pub(crate) const SEARCH_RESULT_LIMIT: u32 = 50;
+
+/// A session whose transcript matches a search.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct MeetingSearchResult {
+    pub session: MeetingSession,
+    /// Excerpt of the transcript around the match, with matched words wrapped
+    /// in `**`
+    pub snippet: String,
+}
+
+/// Replaces the indexed transcript of a session with `text`.
+pub(crate) fn index_transcript(db_path: &PathBuf, session_id: &str, text: &str) -> Result<()> {
+    let mut conn = get_connection(db_path)?;
+    let tx = conn.transaction()?;
+    tx.execute(
+        "DELETE FROM meeting_transcripts_fts WHERE session_id = ?1",
+        params![session_id],
+    )?;
+    tx.execute(
+        "INSERT INTO meeting_transcripts_fts (session_id, content) VALUES (?1, ?2)",
+        params![session_id, text],
+    )?;
+    tx.commit()?;
+    Ok(())
+}
+
+/// Turns what the user typed into an FTS5 query matching sessions that contain
+/// every word, the last one as a prefix so results follow the typing.
+///
+/// Each word is quoted, so quotes, `*`, `-` and keywords like `OR` are searched
+/// for as text instead of being parsed as query syntax.
+///
+/// # Returns
+/// `None` if the query has no words
+pub(crate) fn to_match_query(query: &str) -> Option<String> {
+    let words: Vec<String> = query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(format!("{}*", words.join(" ")))
+}
+
+/// Finds the sessions whose transcript matches `query`, best match first.
+///
+/// # Returns
+/// Up to `limit` results; none for an empty query
+pub(crate) fn search_transcripts(
+    db_path: &PathBuf,
+    query: &str,
+    limit: u32,
+) -> Result<Vec<MeetingSearchResult>> {
+    let match_query = match to_match_query(query) {
+        Some(match_query) => match_query,
+        None => return Ok(Vec::new()),
+    };
+
+    let conn = get_connection(db_path)?;
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.title, s.created_at, s.duration, s.status, s.audio_path, s.transcript_path, s.audio_source, s.error_message, s.summary_path, s.template_id, s.archive_path, s.archive_format, s.category, s.sample_rate, s.notes, s.starred, s.system_audio_partial, s.status_note, s.transcript_removed,
+                snippet(meeting_transcripts_fts, 1, '**', '**', '…', 16)
+         FROM meeting_transcripts_fts
+         JOIN meeting_sessions s ON s.id = meeting_transcripts_fts.session_id
+         WHERE meeting_transcripts_fts MATCH ?1
+         ORDER BY rank
+         LIMIT ?2",
+    )?;
+    let results = stmt
+        .query_map(params![match_query, limit], |row| {
+            Ok(MeetingSearchResult {
+                session: row_to_session(row)?,
+                snippet: row.get(20)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(results)
+}
+
+/// Whether sessions have transcripts but none of them are indexed, as happens
+/// on the first launch after the index was added.
+pub(crate) fn needs_reindex(db_path: &PathBuf) -> Result<bool> {
+    let conn = get_connection(db_path)?;
+    let needed = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM meeting_sessions WHERE transcript_path IS NOT NULL)
+            AND NOT EXISTS(SELECT 1 FROM meeting_transcripts_fts)",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(needed)
+}
+
+/// Rebuilds the index from the transcript files of all sessions.
+///
+/// A transcript that can't be read is logged and left out.
+///
+/// # Returns
+/// How many transcripts were indexed
+pub(crate) fn reindex_transcripts(db_path: &PathBuf, meetings_dir: &Path) -> Result<u32> {
+    let mut conn = get_connection(db_path)?;
+    let transcripts: Vec<(String, String)> = {
+        let mut stmt = conn.prepare(
+            "SELECT id, transcript_path FROM meeting_sessions WHERE transcript_path IS NOT NULL",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    let tx = conn.transaction()?;
+    tx.execute("DELETE FROM meeting_transcripts_fts", [])?;
+    let mut indexed = 0;
+    for (session_id, rel_path) in transcripts {
+        let text = match fs::read_to_string(meetings_dir.join(&rel_path)) {
+            Ok(text) => text,
+            Err(e) => {
+                warn!("Not indexing transcript of session {}: {}", session_id, e);
+                continue;
+            }
+        };
+        tx.execute(
+            "INSERT INTO meeting_transcripts_fts (session_id, content) VALUES (?1, ?2)",
+            params![session_id, text],
+        )?;
+        indexed += 1;
+    }
+    tx.commit()?;
+    Ok(indexed)
+}
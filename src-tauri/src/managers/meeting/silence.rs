@@ -0,0 +1,153 @@
+//! Detection of recordings that contain nothing worth transcribing.
+//!
+//! A cheap RMS scan rules out empty and silent recordings; anything with
+//! signal is then checked with the VAD, so noise without speech is skipped too.
+
+use anyhow::Result;
+use hound::{SampleFormat, WavReader};
+use std::path::Path;
+
+use super::speech::SPEECH_FRAME_SAMPLES;
+use crate::audio_toolkit::VoiceActivityDetector;
+
+/// Note stored on sessions that were finished without running transcription.
+pub(crate) const NO_SPEECH_NOTE: &str = "No speech detected";
+
+/// Frame length used when scanning a recording for signal.
+const FRAME_MS: usize = 30;
+
+/// Frames with an RMS at or above this level (about -50 dBFS) count as signal.
+const SIGNAL_RMS_THRESHOLD: f32 = 0.003;
+
+/// Number of signal frames needed before a recording is considered non-silent,
+/// so a single click or pop does not trigger a transcription.
+const MIN_SIGNAL_FRAMES: usize = 3;
+
+/// Number of VAD speech frames needed before a recording is considered to
+/// contain speech, so a single misclassified frame does not trigger a transcription.
+const MIN_SPEECH_FRAMES: usize = 3;
+
+/// What a finished recording contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RecordingContent {
+    /// The WAV has no samples at all
+    Empty,
+    /// Every frame is below the signal threshold
+    Silent,
+    /// At least some frames carry signal (or, from `classify_speech`, speech)
+    Signal,
+}
+
+fn frame_rms(frame: &[f32]) -> f32 {
+    (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt()
+}
+
+/// Scans samples frame by frame, stopping as soon as enough signal is found.
+fn classify_samples(samples: impl Iterator<Item = f32>, frame_len: usize) -> RecordingContent {
+    let mut frame = Vec::with_capacity(frame_len);
+    let mut total_frames = 0;
+    let mut signal_frames = 0;
+
+    for sample in samples {
+        frame.push(sample);
+        if frame.len() == frame_len {
+            total_frames += 1;
+            if frame_rms(&frame) >= SIGNAL_RMS_THRESHOLD {
+                signal_frames += 1;
+                if signal_frames >= MIN_SIGNAL_FRAMES {
+                    return RecordingContent::Signal;
+                }
+            }
+            frame.clear();
+        }
+    }
+
+    if !frame.is_empty() {
+        total_frames += 1;
+        if frame_rms(&frame) >= SIGNAL_RMS_THRESHOLD {
+            signal_frames += 1;
+        }
+    }
+
+    if total_frames == 0 {
+        RecordingContent::Empty
+    } else if signal_frames > 0 && signal_frames >= MIN_SIGNAL_FRAMES.min(total_frames) {
+        // Very short recordings can't reach MIN_SIGNAL_FRAMES; require all their frames instead
+        RecordingContent::Signal
+    } else {
+        RecordingContent::Silent
+    }
+}
+
+/// Classifies the WAV at `wav_path` without decoding more of it than needed.
+///
+/// Normal recordings return after the first few frames; only silent recordings
+/// are read to the end.
+pub(crate) fn classify_recording(wav_path: &Path) -> Result<RecordingContent> {
+    let reader = WavReader::open(wav_path)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?}: {}", wav_path, e))?;
+
+    let spec = reader.spec();
+    if reader.len() == 0 {
+        return Ok(RecordingContent::Empty);
+    }
+
+    let frame_len =
+        (spec.sample_rate as usize * spec.channels.max(1) as usize * FRAME_MS / 1000).max(1);
+
+    let content = match (spec.sample_format, spec.bits_per_sample) {
+        (SampleFormat::Int, 8) => classify_samples(
+            reader
+                .into_samples::<i8>()
+                .filter_map(Result::ok)
+                .map(|s| s as f32 / i8::MAX as f32),
+            frame_len,
+        ),
+        (SampleFormat::Int, 16) => classify_samples(
+            reader
+                .into_samples::<i16>()
+                .filter_map(Result::ok)
+                .map(|s| s as f32 / i16::MAX as f32),
+            frame_len,
+        ),
+        (SampleFormat::Float, 32) => classify_samples(
+            reader.into_samples::<f32>().filter_map(Result::ok),
+            frame_len,
+        ),
+        (format, bits) => {
+            return Err(anyhow::anyhow!(
+                "Unsupported audio format: {:?} {}-bit",
+                format,
+                bits
+            ));
+        }
+    };
+
+    Ok(content)
+}
+
+/// Classifies 16 kHz mono `samples` by running `vad` over them, stopping as
+/// soon as enough speech is found.
+///
+/// Returns `Silent` when the audio has signal but no speech, e.g. only noise or music.
+pub(crate) fn classify_speech<V: VoiceActivityDetector + ?Sized>(
+    vad: &mut V,
+    samples: &[f32],
+) -> Result<RecordingContent> {
+    if samples.is_empty() {
+        return Ok(RecordingContent::Empty);
+    }
+
+    vad.reset();
+    let mut speech_frames = 0;
+    for frame in samples.chunks_exact(SPEECH_FRAME_SAMPLES) {
+        if vad.is_voice(frame)? {
+            speech_frames += 1;
+            if speech_frames >= MIN_SPEECH_FRAMES {
+                return Ok(RecordingContent::Signal);
+            }
+        }
+    }
+
+    Ok(RecordingContent::Silent)
+}
@@ -0,0 +1,96 @@
+//! Share of a recording that is speech, as a quick quality signal, and
+//! trimming the non-speech out of a recording before it is transcribed.
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::trim::{keep_regions, padded_regions};
+use crate::audio_toolkit::{TrimMap, VoiceActivityDetector};
+
+/// VAD frame length: 30 ms at 16 kHz.
+pub(crate) const SPEECH_FRAME_SAMPLES: usize = (WHISPER_SAMPLE_RATE as usize * 30) / 1000;
+
+/// Speech probability above which a frame counts as speech (same as dictation).
+pub(crate) const SPEECH_VAD_THRESHOLD: f32 = 0.3;
+
+/// Runs `vad` over 16 kHz mono `samples` and returns the fraction of frames
+/// classified as speech, from 0.0 to 1.0.
+///
+/// A trailing partial frame is ignored; audio shorter than one frame yields 0.0.
+pub(crate) fn speech_ratio<V: VoiceActivityDetector + ?Sized>(
+    vad: &mut V,
+    samples: &[f32],
+) -> Result<f32> {
+    vad.reset();
+
+    let mut frames = 0usize;
+    let mut speech_frames = 0usize;
+    for frame in samples.chunks_exact(SPEECH_FRAME_SAMPLES) {
+        frames += 1;
+        if vad.is_voice(frame)? {
+            speech_frames += 1;
+        }
+    }
+
+    if frames == 0 {
+        return Ok(0.0);
+    }
+    Ok(speech_frames as f32 / frames as f32)
+}
+
+/// Audio kept either side of speech when trimming, so words aren't clipped.
+pub(crate) const TRIM_PADDING_MS: u32 = 300;
+
+/// 16 kHz mono audio with its non-speech removed.
+pub(crate) struct TrimmedAudio {
+    pub samples: Vec<f32>,
+    pub map: TrimMap,
+}
+
+/// Keeps only the speech in 16 kHz mono `samples`, with `padding_ms` of audio
+/// either side of it. Regions closer together than twice the padding are
+/// merged, so short pauses inside a sentence stay in.
+///
+/// # Returns
+/// The kept audio in order; empty if the VAD found no speech
+pub(crate) fn trim_to_speech<V: VoiceActivityDetector + ?Sized>(
+    vad: &mut V,
+    samples: &[f32],
+    padding_ms: u32,
+) -> Result<TrimmedAudio> {
+    vad.reset();
+
+    let padding = (WHISPER_SAMPLE_RATE * padding_ms / 1000) as usize;
+    let mut speech_frames = Vec::new();
+    for (index, frame) in samples.chunks_exact(SPEECH_FRAME_SAMPLES).enumerate() {
+        if vad.is_voice(frame)? {
+            speech_frames.push(index * SPEECH_FRAME_SAMPLES..(index + 1) * SPEECH_FRAME_SAMPLES);
+        }
+    }
+
+    let regions = padded_regions(speech_frames, padding, samples.len());
+    let (samples, map) = keep_regions(samples, &regions);
+    Ok(TrimmedAudio { samples, map })
+}
+
+/// Speech ratios already computed, keyed by session and invalidated when the
+/// audio file's size changes (e.g. after a resumed recording appends to it).
+#[derive(Debug, Default)]
+pub(crate) struct SpeechRatioCache {
+    entries: HashMap<String, (u64, f32)>,
+}
+
+impl SpeechRatioCache {
+    pub fn get(&self, session_id: &str, audio_len: u64) -> Option<f32> {
+        self.entries
+            .get(session_id)
+            .filter(|(len, _)| *len == audio_len)
+            .map(|(_, ratio)| *ratio)
+    }
+
+    pub fn insert(&mut self, session_id: &str, audio_len: u64, ratio: f32) {
+        self.entries
+            .insert(session_id.to_string(), (audio_len, ratio));
+    }
+}
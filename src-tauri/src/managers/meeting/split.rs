@@ -0,0 +1,239 @@
+//! Splitting one recorded session into several at chosen timestamps.
+//!
+//! Each part becomes a session of its own with its slice of the audio. When the
+//! original has a timed transcript, its segments can be handed to the part they
+//! start in; parts without a transcript wait as Recorded to be transcribed.
+
+use anyhow::Result;
+use hound::{WavReader, WavSpec, WavWriter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+use super::audio_cache::{is_flac, read_audio};
+use super::db::{delete_session_record, insert_imported_session};
+use super::files::write_atomic;
+use super::models::{MeetingSession, MeetingStatus};
+use super::search::index_transcript;
+use super::transcript::{
+    read_transcript_details, write_transcript_details, Segment, TranscriptionResult,
+};
+use super::wav_writer::recorded_duration;
+
+/// Checks that split points are strictly ascending and fall inside the recording.
+pub(crate) fn validate_split_points(points_ms: &[u64], duration_ms: u64) -> Result<()> {
+    let (first, last) = match (points_ms.first(), points_ms.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return Err(anyhow::anyhow!("At least one split point is required")),
+    };
+    if points_ms.windows(2).any(|pair| pair[1] <= pair[0]) {
+        return Err(anyhow::anyhow!("Split points must be in ascending order"));
+    }
+    if first == 0 || last >= duration_ms {
+        return Err(anyhow::anyhow!(
+            "Split points must be inside the recording (between 0 and {}ms)",
+            duration_ms
+        ));
+    }
+    Ok(())
+}
+
+/// Length of a WAV file in milliseconds.
+fn wav_duration_ms(path: &Path) -> Result<u64> {
+    if is_flac(path) {
+        return Ok(read_audio(path)?.duration().as_millis() as u64);
+    }
+    let reader = WavReader::open(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?}: {}", path, e))?;
+    let sample_rate = reader.spec().sample_rate as u64;
+    if sample_rate == 0 {
+        return Err(anyhow::anyhow!(
+            "Audio file {:?} has a zero sample rate",
+            path
+        ));
+    }
+    Ok(reader.duration() as u64 * 1000 / sample_rate)
+}
+
+/// Copies `samples` into one file per part, starting the next part at each boundary.
+fn copy_parts<S, I>(samples: I, spec: WavSpec, boundaries: &[u64], dests: &[PathBuf]) -> Result<()>
+where
+    S: hound::Sample,
+    I: Iterator<Item = hound::Result<S>>,
+{
+    let mut part = 0;
+    let mut writer = WavWriter::create(&dests[0], spec)?;
+    for (index, sample) in samples.enumerate() {
+        while part < boundaries.len() && index as u64 >= boundaries[part] {
+            writer.finalize()?;
+            part += 1;
+            writer = WavWriter::create(&dests[part], spec)?;
+        }
+        writer.write_sample(sample?)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
+
+/// Split points as sample indices, so multi-channel frames are never cut in half.
+fn sample_boundaries(points_ms: &[u64], spec: WavSpec) -> Vec<u64> {
+    points_ms
+        .iter()
+        .map(|ms| ms * spec.sample_rate as u64 / 1000 * spec.channels as u64)
+        .collect()
+}
+
+/// Writes the audio of `source` between consecutive split points to `dests`,
+/// which holds one file more than there are points.
+pub(crate) fn split_wav(source: &Path, points_ms: &[u64], dests: &[PathBuf]) -> Result<()> {
+    // Archived recordings are FLAC; their parts are written as 16-bit WAV
+    if is_flac(source) {
+        let audio = read_audio(source)?;
+        let spec = WavSpec {
+            channels: audio.channels,
+            sample_rate: audio.sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let samples = audio
+            .samples
+            .iter()
+            .map(|sample| Ok((sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16));
+        return copy_parts(samples, spec, &sample_boundaries(points_ms, spec), dests);
+    }
+
+    let mut reader = WavReader::open(source)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?}: {}", source, e))?;
+    let spec = reader.spec();
+    let boundaries = sample_boundaries(points_ms, spec);
+
+    match spec.sample_format {
+        hound::SampleFormat::Float => copy_parts(reader.samples::<f32>(), spec, &boundaries, dests),
+        hound::SampleFormat::Int => copy_parts(reader.samples::<i32>(), spec, &boundaries, dests),
+    }
+}
+
+/// Hands each segment to the part it starts in, with times relative to that part.
+pub(crate) fn distribute_segments(segments: &[Segment], points_ms: &[u64]) -> Vec<Vec<Segment>> {
+    let mut parts = vec![Vec::new(); points_ms.len() + 1];
+    for segment in segments {
+        let start_ms = (segment.start.max(0.0) * 1000.0) as u64;
+        let part = points_ms
+            .iter()
+            .take_while(|&&point| point <= start_ms)
+            .count();
+        let offset = part
+            .checked_sub(1)
+            .map_or(0.0, |i| points_ms[i] as f32 / 1000.0);
+        parts[part].push(Segment {
+            start: segment.start - offset,
+            end: segment.end - offset,
+            text: segment.text.clone(),
+        });
+    }
+    parts
+}
+
+/// Creates one new session per part of `session`'s recording.
+///
+/// # Arguments
+/// * `points_ms` - Where to split, in milliseconds from the start
+/// * `distribute_transcript` - Give each part the transcript segments that
+///   start in it, if the original has a timed transcript
+///
+/// # Returns
+/// * `Ok(Vec<MeetingSession>)` - The new sessions, in recording order
+/// * `Err` - If the session has no audio, a split point is invalid, or writing
+///   fails; nothing is left behind in that case
+pub(crate) fn split_session_files(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    session: &MeetingSession,
+    points_ms: &[u64],
+    distribute_transcript: bool,
+) -> Result<Vec<MeetingSession>> {
+    let audio_path = session
+        .audio_path
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Session {} has no audio to split", session.id))?;
+    let source = meetings_dir.join(audio_path);
+    validate_split_points(points_ms, wav_duration_ms(&source)?)?;
+
+    let details = if distribute_transcript {
+        read_transcript_details(meetings_dir, &session.id)?
+            .filter(|details| !details.segments.is_empty())
+    } else {
+        None
+    };
+    let part_segments = details
+        .as_ref()
+        .map(|details| distribute_segments(&details.segments, points_ms));
+
+    let ids: Vec<String> = (0..=points_ms.len())
+        .map(|_| Uuid::new_v4().to_string())
+        .collect();
+    let result = (|| {
+        let mut dests = Vec::with_capacity(ids.len());
+        for id in &ids {
+            let dir = meetings_dir.join(id);
+            fs::create_dir_all(&dir)?;
+            dests.push(dir.join("audio.wav"));
+        }
+        split_wav(&source, points_ms, &dests)?;
+
+        let mut parts = Vec::with_capacity(ids.len());
+        for (index, (id, dest)) in ids.iter().zip(&dests).enumerate() {
+            let start_ms = index.checked_sub(1).map_or(0, |i| points_ms[i]);
+            let recorded = recorded_duration(dest)?;
+            let mut part = MeetingSession {
+                id: id.clone(),
+                title: format!("{} (part {})", session.title, index + 1),
+                created_at: session.created_at + (start_ms / 1000) as i64,
+                duration: Some(recorded.seconds),
+                status: MeetingStatus::Recorded,
+                audio_path: Some(format!("{}/audio.wav", id)),
+                transcript_path: None,
+                error_message: None,
+                summary_path: None,
+                archive_path: None,
+                archive_format: None,
+                sample_rate: Some(recorded.sample_rate),
+                ..session.clone()
+            };
+
+            if let (Some(details), Some(part_segments)) = (&details, &part_segments) {
+                let segments = part_segments[index].clone();
+                let text = segments
+                    .iter()
+                    .map(|segment| segment.text.trim())
+                    .filter(|text| !text.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let transcript_path = format!("{}/transcript.txt", id);
+                write_atomic(&meetings_dir.join(&transcript_path), text.as_bytes())?;
+                let result = TranscriptionResult {
+                    text,
+                    language: details.language.clone(),
+                    segments,
+                    ..Default::default()
+                };
+                write_transcript_details(meetings_dir, id, &result)?;
+                index_transcript(db_path, id, &result.text)?;
+                part.transcript_path = Some(transcript_path);
+                part.status = MeetingStatus::Completed;
+            }
+
+            insert_imported_session(db_path, &part)?;
+            parts.push(part);
+        }
+        Ok(parts)
+    })();
+
+    if result.is_err() {
+        for id in &ids {
+            let _ = fs::remove_dir_all(meetings_dir.join(id));
+            let _ = delete_session_record(db_path, id);
+        }
+    }
+    result
+}
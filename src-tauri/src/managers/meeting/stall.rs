@@ -0,0 +1,70 @@
+//! Detection of stalled audio input during meeting recording.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long the input may go without delivering samples before it is considered lost.
+pub(crate) const DEVICE_STALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the recording watchdog checks for a stall.
+pub(crate) const DEVICE_STALL_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Tracks when audio samples were last delivered so that a device that silently
+/// stops producing samples (e.g., an unplugged USB microphone) can be detected.
+///
+/// Times are milliseconds on a monotonic clock started when the detector is
+/// created, so wall-clock changes (NTP, sleep/resume adjustments) can neither
+/// fake a stall nor hide one. Cloning shares the underlying timestamp, so one
+/// clone can live in the sample callback while another is polled by the
+/// watchdog thread.
+#[derive(Clone)]
+pub(crate) struct StallDetector {
+    started: Instant,
+    last_sample_ms: Arc<AtomicU64>,
+    timeout: Duration,
+}
+
+impl StallDetector {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            started: Instant::now(),
+            last_sample_ms: Arc::new(AtomicU64::new(0)),
+            timeout,
+        }
+    }
+
+    /// Milliseconds elapsed since the detector was created.
+    fn now_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+
+    /// Records that samples were just received.
+    pub fn mark(&self) {
+        self.mark_at(self.now_ms());
+    }
+
+    /// Records that samples were received `timestamp_ms` after the detector was created.
+    pub fn mark_at(&self, timestamp_ms: u64) {
+        self.last_sample_ms.store(timestamp_ms, Ordering::Relaxed);
+    }
+
+    /// Returns how long it has been since samples were last received.
+    pub fn stalled_for(&self) -> Duration {
+        self.stalled_for_at(self.now_ms())
+    }
+
+    pub fn stalled_for_at(&self, now_ms: u64) -> Duration {
+        let last = self.last_sample_ms.load(Ordering::Relaxed);
+        Duration::from_millis(now_ms.saturating_sub(last))
+    }
+
+    /// Returns true if no samples have arrived within the timeout.
+    pub fn is_stalled(&self) -> bool {
+        self.is_stalled_at(self.now_ms())
+    }
+
+    pub fn is_stalled_at(&self, now_ms: u64) -> bool {
+        self.stalled_for_at(now_ms) > self.timeout
+    }
+}
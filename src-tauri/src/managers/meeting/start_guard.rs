@@ -0,0 +1,39 @@
+//! Serializing recording starts so concurrent requests cannot both proceed.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Flag held for the whole start sequence, from the status check until the
+/// session is stored as Recording.
+///
+/// Without it, two starts (e.g. a double-click) can both read an idle status
+/// before either records the new session, creating two sessions and recorders.
+/// Cloning shares the flag.
+#[derive(Clone, Default)]
+pub(crate) struct StartGuard {
+    starting: Arc<AtomicBool>,
+}
+
+/// Held while a start is in progress; releases the guard when dropped, including
+/// when the start fails part way through.
+pub(crate) struct StartPermit {
+    starting: Arc<AtomicBool>,
+}
+
+impl StartGuard {
+    /// Claims the guard, or returns `None` if another start is in progress.
+    pub fn try_begin(&self) -> Option<StartPermit> {
+        self.starting
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| StartPermit {
+                starting: Arc::clone(&self.starting),
+            })
+    }
+}
+
+impl Drop for StartPermit {
+    fn drop(&mut self) {
+        self.starting.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,230 @@
+//! Reading long recordings in bounded windows for transcription.
+//!
+//! Decoding a multi-hour WAV into one `Vec<f32>` costs hundreds of megabytes.
+//! `WavWindowReader` decodes, downmixes, and resamples the file a block at a
+//! time and hands out 16 kHz mono windows of a fixed length, so peak memory
+//! stays around one window regardless of the recording's length.
+
+use anyhow::Result;
+use hound::{SampleFormat, WavReader};
+use log::debug;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::time::Duration;
+
+use super::files::open_shared_read;
+use super::speech::SPEECH_FRAME_SAMPLES;
+use crate::audio_toolkit::audio::FrameResampler;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{validate_channel_count, AudioBuffer, VoiceActivityDetector};
+
+/// Recordings at least this long are transcribed window by window.
+pub(crate) const STREAMING_MIN_DURATION_SECS: u64 = 60 * 60;
+
+/// Length of each window handed to the transcriber.
+pub(crate) const STREAM_WINDOW_SECS: u32 = 30;
+
+/// How far back from the end of a window to look for a pause to cut at.
+const BOUNDARY_SEARCH_SECS: u32 = 3;
+
+/// Source frames decoded per read.
+const DECODE_BLOCK_FRAMES: usize = 4096;
+
+/// Returns true if the WAV at `path` is long enough to be worth streaming.
+pub(crate) fn should_stream(path: &Path) -> bool {
+    let reader = open_shared_read(path)
+        .map_err(hound::Error::IoError)
+        .and_then(|file| WavReader::new(BufReader::new(file)));
+    match reader {
+        Ok(reader) => {
+            let spec = reader.spec();
+            spec.sample_rate > 0
+                && reader.duration() as u64 / spec.sample_rate as u64 >= STREAMING_MIN_DURATION_SECS
+        }
+        Err(_) => false,
+    }
+}
+
+/// Yields a WAV file as consecutive 16 kHz mono windows.
+///
+/// Windows are at most `window_samples` long. With a VAD attached, each window
+/// ends at the last pause in its final few seconds when there is one, so words
+/// aren't split between two transcriber calls.
+pub(crate) struct WavWindowReader<R: Read> {
+    reader: WavReader<R>,
+    channels: u16,
+    sample_rate: u32,
+    resampler: FrameResampler,
+    window_samples: usize,
+    vad: Option<Box<dyn VoiceActivityDetector>>,
+    /// Converted samples not yet handed out
+    pending: Vec<f32>,
+    finished: bool,
+}
+
+impl<R: Read> WavWindowReader<R> {
+    pub fn new(source: R, window_secs: u32) -> Result<Self> {
+        let reader = WavReader::new(source)
+            .map_err(|e| anyhow::anyhow!("Failed to read WAV header: {}", e))?;
+        let spec = reader.spec();
+        validate_channel_count(spec.channels)
+            .map_err(|e| anyhow::anyhow!("Cannot read audio: {}", e))?;
+        match (spec.sample_format, spec.bits_per_sample) {
+            (SampleFormat::Int, 8) | (SampleFormat::Int, 16) | (SampleFormat::Float, 32) => {}
+            (format, bits) => {
+                return Err(anyhow::anyhow!(
+                    "Unsupported audio format: {:?} {}-bit",
+                    format,
+                    bits
+                ));
+            }
+        }
+
+        // Frames of one VAD frame each, so windows can be cut on frame boundaries
+        let resampler = FrameResampler::new(
+            spec.sample_rate as usize,
+            WHISPER_SAMPLE_RATE as usize,
+            Duration::from_millis(30),
+        );
+        let window_samples = (window_secs.max(1) * WHISPER_SAMPLE_RATE) as usize;
+
+        Ok(Self {
+            reader,
+            channels: spec.channels,
+            sample_rate: spec.sample_rate,
+            resampler,
+            window_samples,
+            vad: None,
+            pending: Vec::with_capacity(window_samples),
+            finished: false,
+        })
+    }
+
+    /// Cuts windows at pauses detected by `vad` instead of at a fixed length.
+    pub fn with_vad(mut self, vad: Box<dyn VoiceActivityDetector>) -> Self {
+        self.vad = Some(vad);
+        self
+    }
+
+    /// Returns the next window, or `None` once the file is exhausted.
+    pub fn next_window(&mut self) -> Result<Option<Vec<f32>>> {
+        while self.pending.len() < self.window_samples && !self.finished {
+            self.read_block()?;
+        }
+
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+
+        let cut = if self.pending.len() <= self.window_samples {
+            self.pending.len()
+        } else {
+            self.boundary_cut()?
+        };
+        Ok(Some(self.pending.drain(..cut).collect()))
+    }
+
+    /// Decodes one block of the file into `pending`, flushing the resampler at the end.
+    fn read_block(&mut self) -> Result<()> {
+        let max_samples = DECODE_BLOCK_FRAMES * self.channels as usize;
+        let mut block = Vec::with_capacity(max_samples);
+        let mut truncated = false;
+
+        match self.reader.spec().bits_per_sample {
+            8 => {
+                for sample in self.reader.samples::<i8>().take(max_samples) {
+                    match sample {
+                        Ok(s) => block.push(s as f32 / i8::MAX as f32),
+                        Err(_) => {
+                            truncated = true;
+                            break;
+                        }
+                    }
+                }
+            }
+            16 => {
+                for sample in self.reader.samples::<i16>().take(max_samples) {
+                    match sample {
+                        Ok(s) => block.push(s as f32 / i16::MAX as f32),
+                        Err(_) => {
+                            truncated = true;
+                            break;
+                        }
+                    }
+                }
+            }
+            _ => {
+                for sample in self.reader.samples::<f32>().take(max_samples) {
+                    match sample {
+                        Ok(s) => block.push(s),
+                        Err(_) => {
+                            truncated = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // A short block means the data ran out
+        let at_end = truncated || block.len() < max_samples;
+
+        let pending = &mut self.pending;
+        if !block.is_empty() {
+            let mono = AudioBuffer::new(block, self.sample_rate, self.channels).to_mono();
+            self.resampler
+                .push(&mono.samples, |frame| pending.extend_from_slice(frame));
+        }
+
+        if at_end {
+            // A recording cut off by a crash ends early; keep what was read
+            if truncated {
+                debug!("WAV data ended early, transcribing what was read");
+            }
+            self.resampler
+                .finish(|frame| pending.extend_from_slice(frame));
+            self.finished = true;
+        }
+        Ok(())
+    }
+
+    /// Picks where to end the next window: just after the last pause within the
+    /// final few seconds of a full window, or at the full length if there is none.
+    fn boundary_cut(&mut self) -> Result<usize> {
+        let vad = match self.vad.as_mut() {
+            Some(vad) => vad,
+            None => return Ok(self.window_samples),
+        };
+
+        let search_samples = (BOUNDARY_SEARCH_SECS * WHISPER_SAMPLE_RATE) as usize;
+        let search_start = self.window_samples.saturating_sub(search_samples)
+            / SPEECH_FRAME_SAMPLES
+            * SPEECH_FRAME_SAMPLES;
+
+        vad.reset();
+        let mut cut = None;
+        let region = &self.pending[search_start..self.window_samples];
+        for (index, frame) in region.chunks_exact(SPEECH_FRAME_SAMPLES).enumerate() {
+            if !vad.is_voice(frame)? {
+                cut = Some(search_start + (index + 1) * SPEECH_FRAME_SAMPLES);
+            }
+        }
+        Ok(cut.unwrap_or(self.window_samples))
+    }
+}
+
+impl<R: Read> Iterator for WavWindowReader<R> {
+    type Item = Result<Vec<f32>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_window() {
+            Ok(window) => window.map(Ok),
+            Err(e) => {
+                // Stop after the first error rather than retrying a broken file
+                self.finished = true;
+                self.pending.clear();
+                Some(Err(e))
+            }
+        }
+    }
+}
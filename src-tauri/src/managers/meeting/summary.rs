@@ -0,0 +1,377 @@
+//! Fitting long transcripts into the LLM's context window for summarizing.
+//!
+//! Token counts are estimated from character counts. When the summary prompt
+//! would exceed a template's token budget, the transcript is either truncated
+//! with a notice, or summarized in chunks whose summaries are then summarized
+//! together (map-reduce).
+
+use log::{debug, info};
+use std::future::Future;
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+
+use crate::settings::{AppSettings, MeetingTemplate, PostProcessProvider, SummaryStrategy};
+
+/// Largest transcript, in bytes, that is sent for summarizing.
+const MAX_SUMMARY_TRANSCRIPT_BYTES: u64 = 1024 * 1024;
+
+/// Token budget used when a template doesn't set one.
+pub(crate) const DEFAULT_SUMMARY_TOKEN_BUDGET: u32 = 8000;
+
+/// Smallest token budget a template may set.
+pub(crate) const MIN_SUMMARY_TOKEN_BUDGET: u32 = 1000;
+
+/// Rough number of characters per token for English-like text.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Map-reduce rounds before falling back to truncation, in case the partial
+/// summaries don't get any shorter.
+const MAX_REDUCE_ROUNDS: usize = 3;
+
+/// Appended to a transcript that was cut to fit the budget.
+const TRUNCATION_NOTICE: &str =
+    "\n\n[Transcript truncated to fit the model's context; later parts are not included.]";
+
+/// Builds the default summary prompt for meetings without a custom template.
+fn build_default_summary_prompt(transcript: &str) -> String {
+    format!(
+        r#"Please summarize this meeting transcript concisely. Structure your response with:
+
+## Key Points
+- Main topics and discussions
+
+## Action Items
+- Tasks assigned with owners (if mentioned)
+
+## Decisions Made
+- Important decisions reached
+
+## Next Steps
+- Follow-up actions needed
+
+Transcript:
+{}
+
+Provide a clear, professional summary in markdown format."#,
+        transcript
+    )
+}
+
+/// Builds the summary prompt for `transcript`: the template's custom prompt
+/// with its `{}` placeholder replaced by the transcript, or the default prompt.
+pub(crate) fn build_summary_prompt(custom_prompt: Option<&str>, transcript: &str) -> String {
+    match custom_prompt {
+        Some(custom_prompt) => custom_prompt.replace("{}", transcript),
+        None => build_default_summary_prompt(transcript),
+    }
+}
+
+/// Something that answers one summary prompt, e.g. the configured LLM provider.
+pub(crate) trait SummaryBackend {
+    async fn complete(&self, prompt: String) -> Result<String, String>;
+}
+
+/// Estimates how many tokens `text` uses.
+pub(crate) fn estimate_tokens(text: &str) -> usize {
+    (text.chars().count() + CHARS_PER_TOKEN - 1) / CHARS_PER_TOKEN
+}
+
+/// Prompt asking for a summary of one part of a longer transcript.
+fn build_chunk_prompt(chunk: &str, part: usize, parts: usize) -> String {
+    format!(
+        "This is part {} of {} of a meeting transcript. Summarize this part, keeping \
+         topics, decisions, action items with owners, and open questions. Reply with \
+         the summary only.\n\nTranscript part:\n{}",
+        part, parts, chunk
+    )
+}
+
+/// Cuts `text` to at most `max_chars` characters, preferring a word boundary.
+fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    let end = match text.char_indices().nth(max_chars) {
+        Some((index, _)) => index,
+        None => return text,
+    };
+    match text[..end].rfind(char::is_whitespace) {
+        Some(space) if space > 0 => &text[..space],
+        _ => &text[..end],
+    }
+}
+
+/// Splits `text` into chunks of at most `max_chars` characters at whitespace.
+///
+/// A single word longer than `max_chars` becomes its own chunk.
+fn split_into_chunks(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_chars = 0;
+
+    for word in text.split_whitespace() {
+        let word_chars = word.chars().count();
+        if current_chars > 0 && current_chars + 1 + word_chars > max_chars {
+            chunks.push(std::mem::take(&mut current));
+            current_chars = 0;
+        }
+        if current_chars > 0 {
+            current.push(' ');
+            current_chars += 1;
+        }
+        current.push_str(word);
+        current_chars += word_chars;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Summarizes `transcript` with at most `budget_tokens` tokens per LLM request.
+///
+/// # Arguments
+/// * `build_prompt` - Turns transcript text into the template's summary prompt
+/// * `strategy` - What to do when the full prompt is over budget
+/// * `complete` - Sends one prompt to the LLM and returns its reply
+///
+/// # Returns
+/// The reply to the final summary prompt; with map-reduce, the chunk summaries
+/// are combined into that one prompt rather than returned separately.
+pub(crate) async fn summarize_within_budget<P, C, F>(
+    transcript: &str,
+    build_prompt: P,
+    strategy: SummaryStrategy,
+    budget_tokens: u32,
+    mut complete: C,
+) -> Result<String, String>
+where
+    P: Fn(&str) -> String,
+    C: FnMut(String) -> F,
+    F: Future<Output = Result<String, String>>,
+{
+    let budget = budget_tokens.max(MIN_SUMMARY_TOKEN_BUDGET) as usize;
+    // A few extra tokens leave room for the part numbers in chunk prompts
+    let chunk_overhead = estimate_tokens(&build_chunk_prompt("", 0, 0)) + 4;
+    let overhead = estimate_tokens(&build_prompt("")).max(chunk_overhead);
+    let available_chars = budget.saturating_sub(overhead).max(1) * CHARS_PER_TOKEN;
+
+    let mut text = transcript.to_string();
+    let mut rounds = 0;
+    loop {
+        let prompt = build_prompt(&text);
+        if estimate_tokens(&prompt) <= budget {
+            return complete(prompt).await;
+        }
+
+        if strategy == SummaryStrategy::Truncate || rounds == MAX_REDUCE_ROUNDS {
+            let room = available_chars.saturating_sub(TRUNCATION_NOTICE.chars().count());
+            let truncated = format!("{}{}", truncate_chars(&text, room), TRUNCATION_NOTICE);
+            info!(
+                "Summary prompt over budget ({} tokens), truncating transcript",
+                estimate_tokens(&prompt)
+            );
+            return complete(build_prompt(&truncated)).await;
+        }
+
+        let chunks = split_into_chunks(&text, available_chars);
+        info!(
+            "Summary prompt over budget ({} tokens), summarizing {} parts first",
+            estimate_tokens(&prompt),
+            chunks.len()
+        );
+        let mut partials = Vec::with_capacity(chunks.len());
+        for (index, chunk) in chunks.iter().enumerate() {
+            let partial = complete(build_chunk_prompt(chunk, index + 1, chunks.len())).await?;
+            partials.push(partial.trim().to_string());
+        }
+        text = partials.join("\n\n");
+        rounds += 1;
+    }
+}
+
+/// Summarizes a transcript with the template's prompt, strategy, and token
+/// budget, or the defaults when there is no template.
+pub(crate) async fn summarize_transcript<B: SummaryBackend>(
+    transcript: &str,
+    template: Option<&MeetingTemplate>,
+    backend: &B,
+) -> Result<String, String> {
+    let custom_prompt = template.and_then(|t| t.summary_prompt_template.as_deref());
+    let (strategy, budget) = template.map_or(
+        (SummaryStrategy::default(), DEFAULT_SUMMARY_TOKEN_BUDGET),
+        |t| (t.summary_strategy, t.summary_token_budget),
+    );
+    summarize_within_budget(
+        transcript,
+        |text| build_summary_prompt(custom_prompt, text),
+        strategy,
+        budget,
+        |prompt| backend.complete(prompt),
+    )
+    .await
+}
+
+/// The configured post-processing provider, answering summary prompts.
+struct LlmSummaryBackend<'a> {
+    provider: &'a PostProcessProvider,
+    api_key: String,
+    model: &'a str,
+}
+
+impl SummaryBackend for LlmSummaryBackend<'_> {
+    async fn complete(&self, prompt: String) -> Result<String, String> {
+        crate::llm_client::send_chat_completion(
+            self.provider,
+            self.api_key.clone(),
+            self.model,
+            prompt,
+        )
+        .await
+        .map_err(|e| format!("LLM API call failed: {}", e))?
+        .ok_or_else(|| "LLM returned empty response".to_string())
+    }
+}
+
+/// Reads a transcript to summarize, refusing empty files and files over
+/// `MAX_SUMMARY_TRANSCRIPT_BYTES`.
+pub(crate) fn read_transcript_for_summary(path: &Path) -> Result<String, String> {
+    if !path.exists() {
+        return Err("Transcript file not found".to_string());
+    }
+
+    // Check file size before reading to prevent OOM
+    let metadata =
+        std::fs::metadata(path).map_err(|e| format!("Failed to get transcript metadata: {}", e))?;
+    if metadata.len() > MAX_SUMMARY_TRANSCRIPT_BYTES {
+        return Err(format!(
+            "Transcript too large ({} bytes). Maximum allowed: {} bytes",
+            metadata.len(),
+            MAX_SUMMARY_TRANSCRIPT_BYTES
+        ));
+    }
+
+    let transcript =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read transcript: {}", e))?;
+    if transcript.trim().is_empty() {
+        return Err("Transcript is empty".to_string());
+    }
+    Ok(transcript)
+}
+
+/// Summarizes a transcript with the active post-processing provider and model
+/// from `settings`.
+///
+/// Local Ollama/LM Studio providers are started first if needed, and missing
+/// Ollama models are pulled; progress is reported as `meeting_summary_status`.
+pub(crate) async fn summarize_with_provider(
+    app: &AppHandle,
+    settings: &AppSettings,
+    transcript: &str,
+    template: Option<&MeetingTemplate>,
+) -> Result<String, String> {
+    let provider = settings
+        .active_post_process_provider()
+        .cloned()
+        .ok_or_else(|| {
+            "No LLM provider configured. Please set up a provider in Settings.".to_string()
+        })?;
+
+    let model = settings
+        .post_process_models
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+
+    // Fall back to provider's default model if none configured
+    let model = if model.trim().is_empty() {
+        provider.default_model.clone().unwrap_or_default()
+    } else {
+        model
+    };
+
+    if model.trim().is_empty() {
+        return Err(format!(
+            "No model configured for provider '{}'. Please configure in Settings.",
+            provider.label
+        ));
+    }
+
+    let api_key = settings
+        .post_process_api_keys
+        .get(&provider.id)
+        .cloned()
+        .unwrap_or_default();
+
+    // Validate API key is set — but only if the provider requires one
+    if provider.requires_api_key && api_key.trim().is_empty() {
+        return Err(format!(
+            "No API key configured for provider '{}'. Please set your API key in Settings.",
+            provider.label
+        ));
+    }
+
+    if let Some(template) = template.filter(|t| t.summary_prompt_template.is_some()) {
+        debug!(
+            "Using template-specific summary prompt for template '{}'",
+            template.name
+        );
+    }
+
+    debug!(
+        "Generating summary with provider '{}' (model: {})",
+        provider.id, model
+    );
+
+    // Auto-setup for Ollama: start server + pull model if needed
+    if provider.id == "ollama" || provider.id == "lmstudio" {
+        let status = crate::ollama::check_ollama_status().await;
+        match status.status {
+            crate::ollama::OllamaStatus::NotInstalled => {
+                return Err(format!(
+                    "Ollama is not installed. Please download from: {}",
+                    crate::ollama::get_ollama_install_url()
+                ));
+            }
+            crate::ollama::OllamaStatus::Installed => {
+                // Auto-start
+                info!("Ollama not running, starting automatically...");
+                let _ = app.emit("meeting_summary_status", "Starting Ollama server...");
+                crate::ollama::start_ollama().await.map_err(|e| {
+                    format!(
+                        "Failed to auto-start Ollama: {}. Please start it manually.",
+                        e
+                    )
+                })?;
+            }
+            crate::ollama::OllamaStatus::Running => {
+                debug!("Ollama is already running");
+            }
+        }
+
+        // Check if the model is available, if not — auto-pull
+        if provider.id == "ollama" {
+            let models = crate::ollama::check_ollama_status().await;
+            let model_available = models
+                .models
+                .iter()
+                .any(|m| m.name == model || m.name.starts_with(&format!("{}:", model)));
+
+            if !model_available {
+                info!("Model '{}' not found locally, pulling...", model);
+                let _ = app.emit(
+                    "meeting_summary_status",
+                    &format!("Downloading model {}...", model),
+                );
+                crate::ollama::pull_ollama_model(app.clone(), model.clone())
+                    .await
+                    .map_err(|e| format!("Failed to download model '{}': {}", model, e))?;
+            }
+        }
+    }
+
+    // Call LLM API, in several requests if the transcript is over budget
+    let backend = LlmSummaryBackend {
+        provider: &provider,
+        api_key,
+        model: &model,
+    };
+    summarize_transcript(transcript, template, &backend).await
+}
@@ -159,6 +159,8 @@ mod tests {
             match status {
                 MeetingStatus::Idle => "idle".to_string(),
                 MeetingStatus::Recording => "recording".to_string(),
+                MeetingStatus::Paused => "paused".to_string(),
+                MeetingStatus::Recorded => "recorded".to_string(),
                 MeetingStatus::Processing => "processing".to_string(),
                 MeetingStatus::Completed => "completed".to_string(),
                 MeetingStatus::Failed => "failed".to_string(),
@@ -170,6 +172,8 @@ mod tests {
             match s {
                 "idle" => MeetingStatus::Idle,
                 "recording" => MeetingStatus::Recording,
+                "paused" => MeetingStatus::Paused,
+                "recorded" => MeetingStatus::Recorded,
                 "processing" => MeetingStatus::Processing,
                 "completed" => MeetingStatus::Completed,
                 "failed" => MeetingStatus::Failed,
@@ -195,6 +199,15 @@ mod tests {
                 audio_source: self.string_to_audio_source(&audio_source_str),
                 summary_path: row.get("summary_path").unwrap_or(None),
                 template_id: row.get("template_id").unwrap_or(None),
+                archive_path: row.get("archive_path").unwrap_or(None),
+                archive_format: row.get("archive_format").unwrap_or(None),
+                category: row.get("category").unwrap_or(None),
+                sample_rate: row.get("sample_rate").unwrap_or(None),
+                notes: row.get("notes").unwrap_or(None),
+                starred: row.get("starred").unwrap_or(false),
+                system_audio_partial: row.get("system_audio_partial").unwrap_or(false),
+                status_note: row.get("status_note").unwrap_or(None),
+                transcript_removed: row.get("transcript_removed").unwrap_or(false),
             })
         }
 
@@ -244,7 +257,7 @@ mod tests {
             let conn = self.get_connection()?;
             let session = conn
                 .query_row(
-                    "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source
+                    "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, sample_rate
                      FROM meeting_sessions WHERE id = ?1",
                     params![session_id],
                     |row| self.row_to_session(row),
@@ -271,7 +284,7 @@ mod tests {
         fn list_sessions(&self) -> Result<Vec<MeetingSession>> {
             let conn = self.get_connection()?;
             let mut stmt = conn.prepare(
-                "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source
+                "SELECT id, title, created_at, duration, status, audio_path, transcript_path, error_message, audio_source, sample_rate
                  FROM meeting_sessions ORDER BY created_at DESC",
             )?;
 
@@ -296,6 +309,12 @@ mod tests {
                 (MeetingStatus::Recording, MeetingStatus::Processing) => Ok(()),
                 (MeetingStatus::Recording, MeetingStatus::Failed) => Ok(()), // Mic disconnect
                 (MeetingStatus::Recording, MeetingStatus::Interrupted) => Ok(()), // App shutdown
+                (MeetingStatus::Recording, MeetingStatus::Paused) => Ok(()),
+                (MeetingStatus::Paused, MeetingStatus::Recording) => Ok(()), // Resume capture
+                (MeetingStatus::Paused, MeetingStatus::Processing) => Ok(()),
+                (MeetingStatus::Paused, MeetingStatus::Recorded) => Ok(()),
+                (MeetingStatus::Paused, MeetingStatus::Failed) => Ok(()),
+                (MeetingStatus::Paused, MeetingStatus::Interrupted) => Ok(()),
                 (MeetingStatus::Processing, MeetingStatus::Completed) => Ok(()),
                 (MeetingStatus::Processing, MeetingStatus::Failed) => Ok(()),
                 (MeetingStatus::Failed, MeetingStatus::Processing) => Ok(()),
@@ -773,4 +792,4206 @@ mod tests {
             "Final state should be valid, not corrupted"
         );
     }
+
+    #[test]
+    fn test_stall_detector_flags_dried_up_sample_stream() {
+        use crate::managers::meeting::stall::StallDetector;
+        use std::time::Duration;
+
+        let detector = StallDetector::new(Duration::from_secs(5));
+        // A fresh detector counts from its creation, not from some earlier sample
+        assert!(!detector.is_stalled());
+        assert!(detector.stalled_for() < Duration::from_secs(1));
+        assert!(!detector.is_stalled_at(5_000));
+
+        // Samples flowing steadily every 100ms, in ms since the detector was created
+        let mut now = 0u64;
+        for _ in 0..20 {
+            detector.mark_at(now);
+            now += 100;
+            assert!(!detector.is_stalled_at(now));
+        }
+
+        // Stream dries up: still healthy just before the timeout
+        let last = now - 100;
+        assert!(!detector.is_stalled_at(last + 5_000));
+
+        // ...and flagged as stalled once the timeout elapses
+        assert!(detector.is_stalled_at(last + 5_001));
+        assert_eq!(
+            detector.stalled_for_at(last + 6_000),
+            Duration::from_secs(6)
+        );
+
+        // Samples resuming clears the stall
+        detector.mark_at(last + 6_000);
+        assert!(!detector.is_stalled_at(last + 6_100));
+    }
+
+    #[test]
+    fn test_db_version_matches_expected_after_init() {
+        use crate::managers::meeting::db::{get_db_version, MEETING_DB_EXPECTED_VERSION};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("test_meetings_version.db");
+
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let version = get_db_version(&db_path).expect("Failed to read version");
+        assert_eq!(version, MEETING_DB_EXPECTED_VERSION);
+    }
+
+    #[test]
+    fn test_cancel_second_queued_transcription() {
+        use crate::managers::meeting::queue::{CancelOutcome, TranscriptionQueue};
+
+        let mut queue = TranscriptionQueue::new();
+
+        // First enqueue asks the caller to start a worker, the second does not
+        assert!(queue.enqueue("session-1"));
+        assert!(!queue.enqueue("session-2"));
+
+        // Worker picks up the first session
+        assert_eq!(queue.start_next().as_deref(), Some("session-1"));
+
+        // The running job cannot be dequeued, but the waiting one can
+        assert_eq!(queue.cancel("session-1"), CancelOutcome::Running);
+        assert_eq!(queue.cancel("session-2"), CancelOutcome::Removed);
+        assert_eq!(queue.cancel("session-2"), CancelOutcome::NotQueued);
+
+        let snapshot = queue.snapshot();
+        assert_eq!(snapshot.running.as_deref(), Some("session-1"));
+        assert!(snapshot.pending.is_empty());
+
+        // Queue drains without ever running the cancelled session
+        assert_eq!(queue.start_next(), None);
+        assert!(!queue.contains("session-2"));
+
+        // Once drained, the next enqueue starts a new worker
+        assert!(queue.enqueue("session-3"));
+    }
+
+    #[test]
+    fn test_second_load_reuses_converted_audio_cache() {
+        use crate::managers::meeting::audio_cache::{cache_path_for, load_16k_samples};
+        use hound::{WavSpec, WavWriter};
+
+        fn write_wav(path: &std::path::Path, sample_rate: u32, len: usize) {
+            let spec = WavSpec {
+                channels: 1,
+                sample_rate,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            };
+            let mut writer = WavWriter::create(path, spec).unwrap();
+            for i in 0..len {
+                writer.write_sample(((i % 100) as i16) * 100).unwrap();
+            }
+            writer.finalize().unwrap();
+        }
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+        write_wav(&wav_path, 48000, 4800);
+
+        // First load converts 48kHz -> 16kHz and writes the cache
+        let first = load_16k_samples(&wav_path).expect("First load should succeed");
+        assert!(!first.from_cache);
+        assert_eq!(first.audio.len(), 1600);
+        assert!(cache_path_for(&wav_path).exists());
+
+        // Second load skips the conversion step
+        let second = load_16k_samples(&wav_path).expect("Second load should succeed");
+        assert!(second.from_cache);
+        assert_eq!(second.audio, first.audio);
+
+        // Changing the source WAV invalidates the cache
+        write_wav(&wav_path, 48000, 9600);
+        let third = load_16k_samples(&wav_path).expect("Third load should succeed");
+        assert!(!third.from_cache);
+        assert_eq!(third.audio.len(), 3200);
+    }
+
+    #[test]
+    fn test_latest_transcript_mirror_replaced_atomically() {
+        use crate::managers::meeting::files::{update_latest_transcript, LATEST_TRANSCRIPT_FILENAME};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path();
+
+        let path = update_latest_transcript(meetings_dir, "first meeting")
+            .expect("First mirror should succeed");
+        assert_eq!(path, meetings_dir.join(LATEST_TRANSCRIPT_FILENAME));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first meeting");
+
+        // A later completed session replaces the mirror
+        update_latest_transcript(meetings_dir, "second meeting")
+            .expect("Second mirror should succeed");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second meeting");
+
+        // Only the mirror remains; the temp file was renamed into place
+        let entries: Vec<String> = fs::read_dir(meetings_dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(entries, vec![LATEST_TRANSCRIPT_FILENAME.to_string()]);
+    }
+
+    #[test]
+    fn test_auto_title_only_replaces_generated_titles() {
+        use crate::managers::meeting::title::{auto_title_for, is_generated_title};
+
+        let transcript = "Let's review the Q3 roadmap. First item is hiring.";
+
+        // Default manager title gets the snippet
+        assert_eq!(
+            auto_title_for("Meeting - January 15, 2025 3:30 PM", None, transcript).as_deref(),
+            Some("Let's review the Q3 roadmap")
+        );
+
+        // Interpolated template title gets the snippet
+        assert!(is_generated_title("Standup - 2025-01-15", Some("Standup - {date}")));
+        assert_eq!(
+            auto_title_for("Standup - 2025-01-15", Some("Standup - {date}"), transcript).as_deref(),
+            Some("Let's review the Q3 roadmap")
+        );
+
+        // User-renamed sessions are left untouched
+        assert_eq!(auto_title_for("Roadmap sync with Alex", None, transcript), None);
+        assert_eq!(
+            auto_title_for("Standup - moved to Friday", Some("Standup - {date}"), transcript),
+            None
+        );
+
+        // Long first sentences are cut at a word boundary
+        let long = "word ".repeat(40);
+        let title = auto_title_for("Meeting - March 3, 2025 10:05 AM", None, &long).unwrap();
+        assert!(title.ends_with('…'));
+        assert!(title.chars().count() <= 60);
+    }
+
+    #[test]
+    fn test_get_session_by_audio_path() {
+        use crate::managers::meeting::db::{
+            get_session_by_audio_path, insert_session, update_session_audio,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("test_meetings_audio_path.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session = MeetingSession::new(
+            Uuid::new_v4().to_string(),
+            "Audio Lookup".to_string(),
+            1705340400,
+        );
+        insert_session(&db_path, &session).expect("Failed to insert session");
+
+        let stored_path = format!("{}/audio.wav", session.id);
+        update_session_audio(&db_path, &session.id, &stored_path, 60, &MeetingStatus::Completed)
+            .expect("Failed to set audio path");
+
+        // Stored relative form
+        let found = get_session_by_audio_path(&db_path, &stored_path)
+            .expect("Lookup should succeed")
+            .expect("Session should be found");
+        assert_eq!(found.id, session.id);
+
+        // Windows-style separators resolve to the same session
+        let windows_path = format!("{}\\audio.wav", session.id);
+        let found = get_session_by_audio_path(&db_path, &windows_path)
+            .expect("Lookup should succeed")
+            .expect("Session should be found");
+        assert_eq!(found.id, session.id);
+
+        // Unknown paths return None
+        assert!(get_session_by_audio_path(&db_path, "missing/audio.wav")
+            .expect("Lookup should succeed")
+            .is_none());
+    }
+
+    #[test]
+    fn test_archival_writes_compact_copy_and_updates_stored_path() {
+        use crate::managers::meeting::archive::{
+            archive_session_audio, ARCHIVE_FILENAME, ARCHIVE_FORMAT,
+        };
+        use crate::managers::meeting::audio_cache::read_audio;
+        use crate::managers::meeting::db::{get_session, insert_session, update_session_audio};
+        use hound::{WavSpec, WavWriter};
+        use std::time::Duration;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session = MeetingSession::new(
+            Uuid::new_v4().to_string(),
+            "Archive Me".to_string(),
+            1705340400,
+        );
+        insert_session(&db_path, &session).expect("Failed to insert session");
+        fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+
+        // One second of 16kHz stereo float audio
+        let audio_rel = format!("{}/audio.wav", session.id);
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 16000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = WavWriter::create(meetings_dir.join(&audio_rel), spec).unwrap();
+        for i in 0..16000 {
+            let sample = ((i % 50) as f32 - 25.0) / 100.0;
+            writer.write_sample(sample).unwrap();
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+        update_session_audio(&db_path, &session.id, &audio_rel, 1, &MeetingStatus::Completed)
+            .unwrap();
+
+        let original_size = fs::metadata(meetings_dir.join(&audio_rel)).unwrap().len();
+
+        let archive_rel =
+            archive_session_audio(&db_path, &meetings_dir, &session.id, &audio_rel, true)
+                .expect("Archival should succeed");
+        assert_eq!(archive_rel, format!("{}/{}", session.id, ARCHIVE_FILENAME));
+
+        // The copy keeps the sample rate and stored duration, is 16-bit mono FLAC,
+        // and is much smaller than the original
+        let archive_full = meetings_dir.join(&archive_rel);
+        let info = claxon::FlacReader::open(&archive_full)
+            .unwrap()
+            .streaminfo();
+        assert_eq!(info.channels, 1);
+        assert_eq!(info.bits_per_sample, 16);
+        let archived = read_audio(&archive_full).unwrap();
+        assert_eq!(archived.sample_rate, 16000);
+        assert_eq!(archived.samples.len(), 16000);
+        assert_eq!(archived.duration(), Duration::from_secs(1));
+        assert!(fs::metadata(&archive_full).unwrap().len() * 4 < original_size);
+
+        // Original was replaced and the stored paths point at the archive
+        assert!(!meetings_dir.join(&audio_rel).exists());
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(stored.audio_path.as_deref(), Some(archive_rel.as_str()));
+        assert_eq!(stored.archive_path.as_deref(), Some(archive_rel.as_str()));
+        assert_eq!(stored.archive_format.as_deref(), Some(ARCHIVE_FORMAT));
+    }
+
+    fn write_test_wav(path: &std::path::Path, samples: &[i16]) {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for sample in samples {
+            writer.write_sample(*sample).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn test_zero_sample_recording_is_empty() {
+        use crate::managers::meeting::silence::{classify_recording, RecordingContent};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+        write_test_wav(&wav_path, &[]);
+
+        assert_eq!(classify_recording(&wav_path).unwrap(), RecordingContent::Empty);
+    }
+
+    #[test]
+    fn test_all_silence_recording_is_silent() {
+        use crate::managers::meeting::silence::{classify_recording, RecordingContent};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+
+        // Two seconds of digital silence with faint dither and a single click
+        let mut silent: Vec<i16> = (0..32000).map(|i| if i % 2 == 0 { 3 } else { -3 }).collect();
+        silent[16000] = i16::MAX;
+        let silent_path = temp_dir.path().join("silent.wav");
+        write_test_wav(&silent_path, &silent);
+        assert_eq!(
+            classify_recording(&silent_path).unwrap(),
+            RecordingContent::Silent
+        );
+
+        // Same length with half a second of a clearly audible tone
+        let mut speech = silent.clone();
+        for (i, sample) in speech[8000..16000].iter_mut().enumerate() {
+            *sample = if (i / 20) % 2 == 0 { 4000 } else { -4000 };
+        }
+        let speech_path = temp_dir.path().join("speech.wav");
+        write_test_wav(&speech_path, &speech);
+        assert_eq!(
+            classify_recording(&speech_path).unwrap(),
+            RecordingContent::Signal
+        );
+    }
+
+    #[test]
+    fn test_export_csv_quotes_titles_with_commas() {
+        use crate::managers::meeting::export::{write_sessions_csv, CSV_HEADER};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        fs::write(meetings_dir.join("session-1/transcript.txt"), "hello").unwrap();
+
+        let mut session = MeetingSession::new(
+            "session-1".to_string(),
+            "Sync, \"Q3\" planning".to_string(),
+            1705340400,
+        );
+        session.duration = Some(90);
+        session.sample_rate = Some(48000);
+        session.status = MeetingStatus::Completed;
+        session.transcript_path = Some("session-1/transcript.txt".to_string());
+
+        let dest = temp_dir.path().join("meetings.csv");
+        let count = write_sessions_csv(&meetings_dir, &[session], &dest).unwrap();
+        assert_eq!(count, 1);
+
+        let csv = fs::read_to_string(&dest).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert_eq!(
+            lines[1],
+            "session-1,\"Sync, \"\"Q3\"\" planning\",2024-01-15T17:40:00Z,90,48000,completed,false,true,5"
+        );
+        assert_eq!(lines[2], "");
+    }
+
+    #[test]
+    fn test_rapid_stop_start_resumes_and_appends_audio() {
+        use crate::managers::meeting::disk::MEETING_WAV_SPEC;
+        use crate::managers::meeting::resume::{open_wav_for_append, resume_grace, RecentStop};
+        use std::time::{Duration, Instant};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+
+        // First segment: one second recorded before the accidental stop
+        let first: Vec<i16> = (0..16000).map(|i| (i % 100) as i16).collect();
+        write_test_wav(&wav_path, &first);
+
+        let stopped_at = Instant::now();
+        let stop = RecentStop::new("session-1", stopped_at);
+        let grace = resume_grace(3);
+
+        // Start one second later falls inside the window; later starts and a disabled window don't
+        assert!(stop.allows_resume(grace, stopped_at + Duration::from_secs(1)));
+        assert!(!stop.allows_resume(grace, stopped_at + Duration::from_secs(4)));
+        assert!(!stop.allows_resume(resume_grace(0), stopped_at));
+        assert_eq!(resume_grace(600), Duration::from_secs(30));
+
+        // Resumed capture appends to the same file
+        let mut writer =
+            open_wav_for_append(&wav_path, MEETING_WAV_SPEC).expect("Failed to reopen WAV");
+        for _ in 0..8000 {
+            writer.write_sample(1000i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let mut reader = hound::WavReader::open(&wav_path).unwrap();
+        assert_eq!(reader.len(), 24000);
+        let samples: Vec<i16> = reader.samples::<i16>().map(|s| s.unwrap()).collect();
+        assert_eq!(&samples[..16000], first.as_slice());
+        assert!(samples[16000..].iter().all(|s| *s == 1000));
+
+        // Audio recorded at another rate isn't appended to
+        let other_rate = hound::WavSpec {
+            sample_rate: 48000,
+            ..MEETING_WAV_SPEC
+        };
+        assert!(open_wav_for_append(&wav_path, other_rate).is_err());
+    }
+
+    #[test]
+    fn test_set_and_filter_sessions_by_category() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, list_sessions_by_category, update_session_category,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let ids: Vec<String> = (0..3)
+            .map(|i| {
+                let session = MeetingSession::new(
+                    Uuid::new_v4().to_string(),
+                    format!("Meeting {}", i),
+                    1705340400 + i,
+                );
+                insert_session(&db_path, &session).unwrap();
+                session.id
+            })
+            .collect();
+
+        update_session_category(&db_path, &ids[0], Some("blue")).unwrap();
+        update_session_category(&db_path, &ids[2], Some("blue")).unwrap();
+        update_session_category(&db_path, &ids[1], Some("red")).unwrap();
+
+        let blue = list_sessions_by_category(&db_path, "blue").unwrap();
+        let blue_ids: Vec<&str> = blue.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(blue_ids, vec![ids[2].as_str(), ids[0].as_str()]);
+        assert!(blue.iter().all(|s| s.category.as_deref() == Some("blue")));
+
+        // Clearing removes the session from the filter
+        update_session_category(&db_path, &ids[1], None).unwrap();
+        assert!(list_sessions_by_category(&db_path, "red").unwrap().is_empty());
+        assert_eq!(get_session(&db_path, &ids[1]).unwrap().unwrap().category, None);
+
+        assert!(update_session_category(&db_path, "missing", Some("green")).is_err());
+        assert!(MEETING_CATEGORIES.contains(&"blue"));
+        assert!(!MEETING_CATEGORIES.contains(&"chartreuse"));
+    }
+
+    #[test]
+    fn test_transcript_direction_for_rtl_and_ltr_languages() {
+        use crate::managers::meeting::direction::{direction_for_language, transcript_direction};
+
+        assert_eq!(direction_for_language("he"), Some(TextDirection::Rtl));
+        assert_eq!(direction_for_language("ar-EG"), Some(TextDirection::Rtl));
+        assert_eq!(direction_for_language("en"), Some(TextDirection::Ltr));
+        assert_eq!(direction_for_language("zh-Hans"), Some(TextDirection::Ltr));
+        assert_eq!(direction_for_language("auto"), None);
+
+        // Auto-detected language falls back to the transcript's script
+        assert_eq!(
+            transcript_direction("auto", Some("  12:00 שלום לכולם")),
+            Some(TextDirection::Rtl)
+        );
+        assert_eq!(
+            transcript_direction("auto", Some("Hello everyone")),
+            Some(TextDirection::Ltr)
+        );
+        assert_eq!(transcript_direction("auto", None), None);
+        assert!(transcript_direction("fa", None).unwrap().is_rtl());
+    }
+
+    #[test]
+    fn test_session_detail_includes_existing_parts_only() {
+        use crate::managers::meeting::detail::build_session_detail;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        fs::write(meetings_dir.join("session-1/transcript.txt"), "Hello there everyone").unwrap();
+        write_test_wav(&meetings_dir.join("session-1/audio.wav"), &[0i16; 1600]);
+
+        let mut session = MeetingSession::new(
+            "session-1".to_string(),
+            "Standup".to_string(),
+            1705340400,
+        );
+        session.status = MeetingStatus::Completed;
+        session.audio_path = Some("session-1/audio.wav".to_string());
+        session.transcript_path = Some("session-1/transcript.txt".to_string());
+        // Summary was recorded but the file has since gone missing
+        session.summary_path = Some("session-1/summary.md".to_string());
+
+        let detail = build_session_detail(&meetings_dir, session, "auto");
+
+        assert_eq!(detail.session.id, "session-1");
+        assert_eq!(detail.transcript.as_deref(), Some("Hello there everyone"));
+        assert_eq!(detail.summary, None);
+        assert!(detail.audio_path.unwrap().ends_with("audio.wav"));
+        assert_eq!(detail.text_direction, Some(TextDirection::Ltr));
+        assert!(!detail.is_rtl);
+        assert_eq!(detail.stats.word_count, 3);
+        let wav_bytes = fs::metadata(meetings_dir.join("session-1/audio.wav")).unwrap().len();
+        assert_eq!(detail.stats.audio_bytes, Some(wav_bytes));
+        assert_eq!(detail.stats.storage_bytes, wav_bytes + 20);
+        assert!(detail.segments.is_empty());
+    }
+
+    #[test]
+    fn test_session_detail_includes_stored_segments() {
+        use crate::managers::meeting::detail::build_session_detail;
+        use crate::managers::meeting::transcript::{write_transcript_details, Segment};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        let segments = vec![
+            Segment {
+                start: 0.0,
+                end: 1.5,
+                text: "Hello".to_string(),
+            },
+            Segment {
+                start: 1.5,
+                end: 3.0,
+                text: "there".to_string(),
+            },
+        ];
+        let details = TranscriptionResult {
+            text: "Hello there".to_string(),
+            segments: segments.clone(),
+            ..Default::default()
+        };
+        write_transcript_details(&meetings_dir, "session-1", &details).unwrap();
+
+        let session =
+            MeetingSession::new("session-1".to_string(), "Standup".to_string(), 1705340400);
+        let detail = build_session_detail(&meetings_dir, session, "auto");
+
+        assert_eq!(detail.segments, segments);
+    }
+
+    #[test]
+    fn test_recorded_duration_uses_actual_sample_rate() {
+        use crate::managers::meeting::wav_writer::recorded_duration;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+
+        // Three seconds of stereo audio at 48 kHz
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&wav_path, spec).unwrap();
+        for _ in 0..(3 * 48000 * 2) {
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let recorded = recorded_duration(&wav_path).unwrap();
+        assert_eq!(recorded.sample_rate, 48000);
+        // Assuming 16 kHz mono would have reported 18 seconds
+        assert_eq!(recorded.seconds, 3);
+    }
+
+    #[test]
+    fn test_clear_session_error_keeps_failed_status() {
+        use crate::managers::meeting::db::{
+            clear_session_error, get_session, insert_session, update_session_status_with_error,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session = MeetingSession::new(
+            Uuid::new_v4().to_string(),
+            "Broken Meeting".to_string(),
+            1705340400,
+        );
+        insert_session(&db_path, &session).unwrap();
+        update_session_status_with_error(
+            &db_path,
+            &session.id,
+            &MeetingStatus::Failed,
+            "Transcription failed: model not loaded",
+        )
+        .unwrap();
+
+        clear_session_error(&db_path, &session.id).unwrap();
+
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(stored.error_message, None);
+        assert_eq!(stored.status, MeetingStatus::Failed);
+
+        assert!(clear_session_error(&db_path, "missing").is_err());
+    }
+
+    #[test]
+    fn test_summary_only_retention_removes_audio_and_transcript() {
+        use crate::managers::meeting::audio_cache::cache_path_for;
+        use crate::managers::meeting::db::{
+            get_session, insert_session, update_session_audio, update_session_summary_path,
+            update_session_transcript,
+        };
+        use crate::managers::meeting::integrity::{check_integrity, sessions_missing_transcript};
+        use crate::managers::meeting::repair::repair_session_status;
+        use crate::managers::meeting::retention::apply_summary_retention;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session = MeetingSession::new(
+            Uuid::new_v4().to_string(),
+            "Summary Only".to_string(),
+            1705340400,
+        );
+        insert_session(&db_path, &session).unwrap();
+
+        let session_dir = meetings_dir.join(&session.id);
+        fs::create_dir_all(&session_dir).unwrap();
+        let audio_rel = format!("{}/audio.wav", session.id);
+        let transcript_rel = format!("{}/transcript.txt", session.id);
+        let summary_rel = format!("{}/summary.md", session.id);
+        write_test_wav(&meetings_dir.join(&audio_rel), &[0i16; 160]);
+        fs::write(cache_path_for(&meetings_dir.join(&audio_rel)), b"cache").unwrap();
+        fs::write(meetings_dir.join(&transcript_rel), "hello world").unwrap();
+
+        update_session_audio(&db_path, &session.id, &audio_rel, 1, &MeetingStatus::Completed)
+            .unwrap();
+        update_session_transcript(&db_path, &session.id, &transcript_rel, &MeetingStatus::Completed)
+            .unwrap();
+
+        // Without a saved summary nothing may be deleted
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert!(apply_summary_retention(&db_path, &meetings_dir, &stored, false, false).is_err());
+        assert!(meetings_dir.join(&audio_rel).exists());
+        assert!(meetings_dir.join(&transcript_rel).exists());
+
+        fs::write(meetings_dir.join(&summary_rel), "## Summary\n- shipped").unwrap();
+        update_session_summary_path(&db_path, &session.id, &summary_rel).unwrap();
+
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        let outcome =
+            apply_summary_retention(&db_path, &meetings_dir, &stored, false, false).unwrap();
+        assert!(outcome.removed_audio);
+        assert!(outcome.removed_transcript);
+
+        assert!(!meetings_dir.join(&audio_rel).exists());
+        assert!(!cache_path_for(&meetings_dir.join(&audio_rel)).exists());
+        assert!(!meetings_dir.join(&transcript_rel).exists());
+        assert!(meetings_dir.join(&summary_rel).exists());
+
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(stored.audio_path, None);
+        assert_eq!(stored.transcript_path, None);
+        assert_eq!(stored.summary_path, Some(summary_rel));
+        assert!(stored.transcript_removed);
+
+        // A Completed session without its transcript is expected here, not broken
+        let report = check_integrity(&db_path, &meetings_dir, &[]).unwrap();
+        assert!(report.is_healthy(), "{:?}", report.issues);
+        assert!(sessions_missing_transcript(&db_path, &meetings_dir)
+            .unwrap()
+            .is_empty());
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, false).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Completed);
+        assert_eq!(repaired.error_message, None);
+    }
+
+    #[test]
+    fn test_group_sessions_by_day_straddling_midnight() {
+        use crate::managers::meeting::timeline::group_sessions_by_day;
+        use chrono::FixedOffset;
+
+        // UTC+2: 2024-03-09 21:30 UTC is 23:30 local, 22:30 UTC is 00:30 local on the 10th
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        let before_midnight = 1710019800; // 2024-03-09T21:30:00Z
+        let after_midnight = 1710023400; // 2024-03-09T22:30:00Z
+        let next_evening = 1710100800; // 2024-03-10T20:00:00Z
+
+        let session = |title: &str, created_at: i64| {
+            MeetingSession::new(Uuid::new_v4().to_string(), title.to_string(), created_at)
+        };
+        let sessions = vec![
+            session("Late", before_midnight),
+            session("Evening", next_evening),
+            session("Early", after_midnight),
+        ];
+
+        let groups = group_sessions_by_day(sessions, &tz);
+        assert_eq!(groups.len(), 2);
+
+        assert_eq!(groups[0].date, "2024-03-10");
+        let titles: Vec<&str> = groups[0]
+            .sessions
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Evening", "Early"]);
+
+        assert_eq!(groups[1].date, "2024-03-09");
+        assert_eq!(groups[1].sessions.len(), 1);
+        assert_eq!(groups[1].sessions[0].title, "Late");
+
+        // In UTC both early sessions fall on the 9th
+        let all_sessions = groups.into_iter().flat_map(|g| g.sessions).collect();
+        let utc_groups = group_sessions_by_day(all_sessions, &chrono::Utc);
+        assert_eq!(utc_groups.len(), 2);
+        assert_eq!(utc_groups[1].date, "2024-03-09");
+        assert_eq!(utc_groups[1].sessions.len(), 2);
+    }
+
+    #[test]
+    fn test_interrupted_atomic_write_keeps_previous_file() {
+        use crate::managers::meeting::files::{
+            commit_temp_file, temp_path_for, write_atomic, write_temp_file,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let transcript_path = temp_dir.path().join("transcript.txt");
+
+        write_atomic(&transcript_path, b"first complete transcript").unwrap();
+        assert_eq!(
+            temp_path_for(&transcript_path).unwrap(),
+            temp_dir.path().join("transcript.txt.tmp")
+        );
+
+        // Simulate a crash after the temp file is written but before the rename
+        let tmp_path = write_temp_file(&transcript_path, b"second transcript, never committed")
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&transcript_path).unwrap(),
+            "first complete transcript"
+        );
+
+        // The next successful write replaces the stale temp file and the target
+        write_atomic(&transcript_path, b"third complete transcript").unwrap();
+        assert_eq!(
+            fs::read_to_string(&transcript_path).unwrap(),
+            "third complete transcript"
+        );
+        assert!(!tmp_path.exists());
+
+        // A committed temp file fully replaces the target
+        let tmp_path = write_temp_file(&transcript_path, b"fourth").unwrap();
+        commit_temp_file(&tmp_path, &transcript_path).unwrap();
+        assert_eq!(fs::read_to_string(&transcript_path).unwrap(), "fourth");
+        assert!(!tmp_path.exists());
+    }
+
+    #[test]
+    fn test_remaining_recording_minutes_for_free_space() {
+        use crate::managers::meeting::disk::{
+            bytes_per_second, parse_df_available, remaining_recording_minutes, MEETING_WAV_SPEC,
+        };
+
+        // 16kHz mono 16-bit: 32,000 bytes/s, 1,920,000 bytes/min
+        assert_eq!(bytes_per_second(&MEETING_WAV_SPEC), 32_000);
+        assert_eq!(remaining_recording_minutes(76_800_000, &MEETING_WAV_SPEC), 40);
+        assert_eq!(remaining_recording_minutes(1_919_999, &MEETING_WAV_SPEC), 0);
+
+        let stereo_48k = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 24,
+            sample_format: hound::SampleFormat::Int,
+        };
+        // 288,000 bytes/s -> 17,280,000 bytes/min
+        assert_eq!(remaining_recording_minutes(172_800_000, &stereo_48k), 10);
+
+        let df_output = "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+                         /dev/disk1s1 488245288 400000000 75000 85% /\n";
+        assert_eq!(parse_df_available(df_output), Some(75_000 * 1024));
+        assert_eq!(parse_df_available("garbage"), None);
+    }
+
+    #[test]
+    fn test_repair_session_status_from_file_presence() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, update_session_audio, update_session_status,
+            update_session_status_with_error, update_session_transcript,
+        };
+        use crate::managers::meeting::repair::{
+            repair_session_status, MISSING_FILES_NOTE, MISSING_TRANSCRIPT_NOTE,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let new_session = |title: &str| {
+            let session =
+                MeetingSession::new(Uuid::new_v4().to_string(), title.to_string(), 1705340400);
+            insert_session(&db_path, &session).unwrap();
+            fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+            session
+        };
+
+        // Stuck in Processing with audio but nothing queued -> Failed, retryable
+        let stuck = new_session("Stuck");
+        let audio_rel = format!("{}/audio.wav", stuck.id);
+        write_test_wav(&meetings_dir.join(&audio_rel), &[0i16; 160]);
+        update_session_audio(&db_path, &stuck.id, &audio_rel, 1, &MeetingStatus::Processing)
+            .unwrap();
+        let stored = get_session(&db_path, &stuck.id).unwrap().unwrap();
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, false).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Failed);
+        assert_eq!(repaired.error_message.as_deref(), Some(MISSING_TRANSCRIPT_NOTE));
+
+        // Still queued for transcription -> left in Processing
+        update_session_status(&db_path, &stuck.id, &MeetingStatus::Processing).unwrap();
+        let stored = get_session(&db_path, &stuck.id).unwrap().unwrap();
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, true).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Processing);
+
+        // Failed but the transcript exists -> Completed with the error cleared
+        let transcribed = new_session("Transcribed");
+        let transcript_rel = format!("{}/transcript.txt", transcribed.id);
+        fs::write(meetings_dir.join(&transcript_rel), "hello").unwrap();
+        update_session_transcript(&db_path, &transcribed.id, &transcript_rel, &MeetingStatus::Failed)
+            .unwrap();
+        update_session_status_with_error(
+            &db_path,
+            &transcribed.id,
+            &MeetingStatus::Failed,
+            "Transcription timed out",
+        )
+        .unwrap();
+        let stored = get_session(&db_path, &transcribed.id).unwrap().unwrap();
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, false).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Completed);
+        assert_eq!(repaired.error_message, None);
+
+        // Completed but both files are gone -> Failed
+        fs::remove_file(meetings_dir.join(&transcript_rel)).unwrap();
+        let stored = get_session(&db_path, &transcribed.id).unwrap().unwrap();
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, false).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Failed);
+        assert_eq!(repaired.error_message.as_deref(), Some(MISSING_FILES_NOTE));
+    }
+
+    #[test]
+    fn test_suspend_cancels_pending_and_keeps_running_transcription() {
+        use crate::managers::meeting::queue::TranscriptionQueue;
+
+        // Idle: nothing to cancel, and repeating is harmless
+        let mut queue = TranscriptionQueue::new();
+        assert!(queue.cancel_all_pending().is_empty());
+        assert!(queue.cancel_all_pending().is_empty());
+        assert_eq!(queue.running(), None);
+        assert!(SuspendSummary::default().is_noop());
+
+        // Active: the running session stays, everything pending is removed in order
+        queue.enqueue("session-1");
+        queue.enqueue("session-2");
+        queue.enqueue("session-3");
+        assert_eq!(queue.start_next().as_deref(), Some("session-1"));
+
+        assert_eq!(queue.cancel_all_pending(), vec!["session-2", "session-3"]);
+        assert_eq!(queue.running(), Some("session-1"));
+        assert!(queue.contains("session-1"));
+        assert!(!queue.contains("session-2"));
+
+        let summary = SuspendSummary {
+            interrupted_session: Some("recording".to_string()),
+            cancelled_sessions: vec!["session-2".to_string(), "session-3".to_string()],
+            running_session: queue.running().map(str::to_string),
+        };
+        assert!(!summary.is_noop());
+
+        // The worker finishes the running job and then finds the queue empty
+        assert_eq!(queue.start_next(), None);
+        assert!(queue.cancel_all_pending().is_empty());
+    }
+
+    #[test]
+    fn test_four_channel_wav_is_downmixed_to_mono() {
+        use crate::managers::meeting::audio_cache::convert_wav_to_16k;
+        use hound::{WavSpec, WavWriter};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("quad.wav");
+        let spec = WavSpec {
+            channels: 4,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let frames = 1600;
+        let mut writer = WavWriter::create(&wav_path, spec).unwrap();
+        for _ in 0..frames {
+            // Only the front-left channel carries signal
+            for channel in 0..4 {
+                writer.write_sample(if channel == 0 { 16000i16 } else { 0 }).unwrap();
+            }
+        }
+        writer.finalize().unwrap();
+
+        let mono = convert_wav_to_16k(&wav_path).expect("Quad WAV should convert");
+        assert!(mono.is_mono_16k());
+        assert_eq!(mono.len(), frames);
+        let expected = 16000.0 / i16::MAX as f32 / 4.0;
+        assert!(mono.samples.iter().all(|s| (s - expected).abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_speech_ratio_of_half_speech_signal() {
+        use crate::audio_toolkit::vad::VadFrame;
+        use crate::audio_toolkit::VoiceActivityDetector;
+        use crate::managers::meeting::speech::{speech_ratio, SPEECH_FRAME_SAMPLES};
+
+        /// Treats any frame with noticeable energy as speech
+        struct EnergyVad;
+
+        impl VoiceActivityDetector for EnergyVad {
+            fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+                let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32;
+                Ok(if energy > 0.001 {
+                    VadFrame::Speech(frame)
+                } else {
+                    VadFrame::Noise
+                })
+            }
+        }
+
+        // Two seconds of 440 Hz tone followed by two seconds of silence
+        let tone: Vec<f32> = (0..32000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin())
+            .collect();
+        let mut samples = tone;
+        samples.extend(std::iter::repeat(0.0).take(32000));
+
+        let ratio = speech_ratio(&mut EnergyVad, &samples).unwrap();
+        assert!((ratio - 0.5).abs() < 0.02, "ratio was {}", ratio);
+
+        let too_short = vec![0.5; SPEECH_FRAME_SAMPLES - 1];
+        assert_eq!(speech_ratio(&mut EnergyVad, &too_short).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_concurrent_starts_create_one_session() {
+        use crate::managers::meeting::start_guard::StartGuard;
+        use std::sync::{Arc, Barrier, Mutex};
+        use std::thread;
+        use std::time::Duration;
+
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).unwrap();
+
+        let guard = StartGuard::default();
+        let current_session: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let barrier = Arc::new(Barrier::new(2));
+
+        // Mirrors the start sequence: status check, session creation, then state update
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let guard = guard.clone();
+                let current_session = Arc::clone(&current_session);
+                let barrier = Arc::clone(&barrier);
+                let db_path = db_path.clone();
+                thread::spawn(move || -> bool {
+                    barrier.wait();
+                    let Some(_permit) = guard.try_begin() else {
+                        return false;
+                    };
+                    if current_session.lock().unwrap().is_some() {
+                        return false;
+                    }
+                    // Widen the window between the status check and the state update
+                    thread::sleep(Duration::from_millis(50));
+                    let id = Uuid::new_v4().to_string();
+                    let conn = Connection::open(&db_path).unwrap();
+                    conn.execute(
+                        "INSERT INTO meeting_sessions (id, title, created_at, status, audio_source)
+                         VALUES (?1, 'Meeting', 0, 'recording', 'microphone_only')",
+                        params![id],
+                    )
+                    .unwrap();
+                    *current_session.lock().unwrap() = Some(id);
+                    true
+                })
+            })
+            .collect();
+
+        let started = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|started| *started)
+            .count();
+        assert_eq!(started, 1);
+
+        let conn = Connection::open(&db_path).unwrap();
+        let sessions: i64 = conn
+            .query_row("SELECT COUNT(*) FROM meeting_sessions", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(sessions, 1);
+
+        // The permit is released once the start finishes
+        assert!(guard.try_begin().is_some());
+    }
+
+    #[test]
+    fn test_listed_export_formats_are_all_exportable() {
+        use crate::managers::meeting::export::write_sessions_export;
+        use crate::managers::meeting::transcript::write_transcript_details;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        fs::create_dir_all(&meetings_dir).unwrap();
+        let session = MeetingSession::new("session-1".to_string(), "Standup".to_string(), 0);
+        // Formats that need timed segments can only export sessions that have them
+        let details = TranscriptionResult {
+            text: "Hello".to_string(),
+            segments: vec![Segment {
+                start: 0.0,
+                end: 1.0,
+                text: "Hello".to_string(),
+            }],
+            ..Default::default()
+        };
+        write_transcript_details(&meetings_dir, &session.id, &details).unwrap();
+
+        let formats = list_export_formats();
+        let listed: Vec<ExportFormat> = formats.iter().map(|info| info.format).collect();
+        assert_eq!(listed, vec![ExportFormat::Csv, ExportFormat::Srt]);
+        assert_eq!(listed, ExportFormat::ALL);
+
+        for info in formats {
+            assert!(!info.display_name.is_empty());
+            assert!(!info.extension.is_empty() && !info.extension.starts_with('.'));
+            assert_eq!(info.requires_segments, info.format.requires_segments());
+
+            // Every listed format must be handled by the export itself
+            let dest = temp_dir.path().join(format!("export.{}", info.extension));
+            let count =
+                write_sessions_export(&meetings_dir, &[session.clone()], info.format, &dest)
+                    .unwrap_or_else(|e| panic!("{:?} export failed: {}", info.format, e));
+            assert_eq!(count, 1);
+            assert!(dest.is_file());
+        }
+    }
+
+    #[test]
+    fn test_session_lite_reflects_status_changes() {
+        use crate::managers::meeting::db::{
+            get_session_lite, insert_session, update_session_status, update_session_status_with_error,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session = MeetingSession::new(
+            Uuid::new_v4().to_string(),
+            "Weekly Sync".to_string(),
+            1705340400,
+        );
+        insert_session(&db_path, &session).unwrap();
+
+        update_session_status(&db_path, &session.id, &MeetingStatus::Processing).unwrap();
+        let lite = get_session_lite(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(
+            lite,
+            SessionLite {
+                id: session.id.clone(),
+                title: "Weekly Sync".to_string(),
+                status: MeetingStatus::Processing,
+                error_message: None,
+            }
+        );
+
+        update_session_status_with_error(
+            &db_path,
+            &session.id,
+            &MeetingStatus::Failed,
+            "Transcription failed",
+        )
+        .unwrap();
+        let lite = get_session_lite(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(lite.status, MeetingStatus::Failed);
+        assert_eq!(lite.error_message.as_deref(), Some("Transcription failed"));
+
+        assert!(get_session_lite(&db_path, "missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_auto_retry_transcribes_interrupted_session_with_valid_audio() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, update_session_status, update_session_transcript,
+        };
+        use crate::managers::meeting::recovery::sessions_to_auto_retry;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let mut sessions = Vec::new();
+        for (id, audio) in [
+            ("valid", Some(vec![1000i16; 16000])),
+            ("empty", Some(Vec::new())),
+            ("missing", None),
+        ] {
+            let mut session = MeetingSession::new(id.to_string(), id.to_string(), 1705340400);
+            session.status = MeetingStatus::Interrupted;
+            session.audio_path = Some(format!("{}/audio.wav", id));
+            fs::create_dir_all(meetings_dir.join(id)).unwrap();
+            match audio {
+                Some(samples) if samples.is_empty() => {
+                    // Crashed before the header was ever written
+                    fs::write(meetings_dir.join(format!("{}/audio.wav", id)), b"").unwrap()
+                }
+                Some(samples) => {
+                    write_test_wav(&meetings_dir.join(format!("{}/audio.wav", id)), &samples)
+                }
+                None => {}
+            }
+            insert_session(&db_path, &session).unwrap();
+            sessions.push(session);
+        }
+
+        let retry: Vec<&str> = sessions_to_auto_retry(&meetings_dir, &sessions)
+            .into_iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        assert_eq!(retry, vec!["valid"]);
+
+        // The retry goes through the normal pipeline: Processing, then Completed
+        update_session_status(&db_path, "valid", &MeetingStatus::Processing).unwrap();
+        fs::write(meetings_dir.join("valid/transcript.txt"), "recovered text").unwrap();
+        update_session_transcript(
+            &db_path,
+            "valid",
+            "valid/transcript.txt",
+            &MeetingStatus::Completed,
+        )
+        .unwrap();
+
+        let stored = get_session(&db_path, "valid").unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Completed);
+        let skipped = get_session(&db_path, "empty").unwrap().unwrap();
+        assert_eq!(skipped.status, MeetingStatus::Interrupted);
+    }
+
+    #[test]
+    fn test_transcription_segments_are_stored_with_transcript() {
+        use crate::audio_toolkit::AudioBuffer;
+        use crate::managers::meeting::transcript::{
+            run_transcription, write_transcript_details, Segment, Transcriber,
+            TranscriptionResult, TRANSCRIPT_DETAILS_FILE,
+        };
+
+        struct StubTranscriber;
+
+        impl Transcriber for StubTranscriber {
+            fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+                assert_eq!(samples.len(), 32000);
+                Ok(TranscriptionResult {
+                    text: "hello there general kenobi".to_string(),
+                    language: Some("en".to_string()),
+                    segments: vec![
+                        Segment {
+                            start: 0.0,
+                            end: 0.8,
+                            text: "hello there".to_string(),
+                        },
+                        Segment {
+                            start: 0.8,
+                            end: 2.0,
+                            text: "general kenobi".to_string(),
+                        },
+                    ],
+                    rtf: 0.0,
+                })
+            }
+        }
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+
+        let audio = AudioBuffer::mono_16k(vec![0.0; 32000]);
+        let result = run_transcription(&StubTranscriber, audio).unwrap();
+        assert_eq!(result.segments.len(), 2);
+        assert!(result.rtf >= 0.0);
+
+        let rel_path = write_transcript_details(&meetings_dir, "session-1", &result).unwrap();
+        assert_eq!(rel_path, format!("session-1/{}", TRANSCRIPT_DETAILS_FILE));
+
+        let stored: TranscriptionResult =
+            serde_json::from_slice(&fs::read(meetings_dir.join(&rel_path)).unwrap()).unwrap();
+        assert_eq!(stored.language.as_deref(), Some("en"));
+        assert_eq!(stored.segments, result.segments);
+        // The text lives in transcript.txt, not the details file
+        assert!(stored.text.is_empty());
+    }
+
+    #[test]
+    fn test_reset_meetings_data_leaves_empty_working_database() {
+        use crate::managers::meeting::db::{insert_session, list_sessions};
+        use crate::managers::meeting::reset::reset_meetings_data;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        for i in 0..3 {
+            let session = MeetingSession::new(
+                Uuid::new_v4().to_string(),
+                format!("Meeting {}", i),
+                1705340400 + i,
+            );
+            fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+            fs::write(meetings_dir.join(&session.id).join("transcript.txt"), "hello").unwrap();
+            insert_session(&db_path, &session).unwrap();
+        }
+        fs::write(meetings_dir.join("latest_transcript.txt"), "hello").unwrap();
+
+        let summary = reset_meetings_data(&db_path, &meetings_dir).unwrap();
+        assert_eq!(summary.sessions_removed, 3);
+        assert_eq!(summary.entries_removed, 4);
+        assert_eq!(summary.bytes_freed, 20);
+
+        assert!(list_sessions(&db_path).unwrap().is_empty());
+        assert!(meetings_dir.is_dir());
+        assert_eq!(fs::read_dir(&meetings_dir).unwrap().count(), 0);
+
+        // The database keeps working after the reset
+        let session = MeetingSession::new(Uuid::new_v4().to_string(), "Fresh".to_string(), 0);
+        insert_session(&db_path, &session).unwrap();
+        assert_eq!(list_sessions(&db_path).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_integrity_check_reports_each_problem() {
+        use crate::managers::meeting::db::{
+            insert_session, update_session_audio, update_session_transcript,
+        };
+        use crate::managers::meeting::integrity::{check_integrity, IntegrityIssue};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let add = |id: &str| {
+            let session = MeetingSession::new(id.to_string(), id.to_string(), 1705340400);
+            insert_session(&db_path, &session).unwrap();
+            fs::create_dir_all(meetings_dir.join(id)).unwrap();
+        };
+
+        // Healthy: valid audio and transcript
+        add("healthy");
+        write_test_wav(&meetings_dir.join("healthy/audio.wav"), &[0i16; 1600]);
+        fs::write(meetings_dir.join("healthy/transcript.txt"), "hi").unwrap();
+        update_session_audio(
+            &db_path,
+            "healthy",
+            "healthy/audio.wav",
+            1,
+            &MeetingStatus::Processing,
+        )
+        .unwrap();
+        update_session_transcript(
+            &db_path,
+            "healthy",
+            "healthy/transcript.txt",
+            &MeetingStatus::Completed,
+        )
+        .unwrap();
+
+        // Missing transcript file
+        add("missing");
+        update_session_transcript(
+            &db_path,
+            "missing",
+            "missing/transcript.txt",
+            &MeetingStatus::Completed,
+        )
+        .unwrap();
+
+        // Corrupt audio
+        add("corrupt");
+        fs::write(meetings_dir.join("corrupt/audio.wav"), b"not a wav file").unwrap();
+        update_session_audio(
+            &db_path,
+            "corrupt",
+            "corrupt/audio.wav",
+            1,
+            &MeetingStatus::Failed,
+        )
+        .unwrap();
+
+        // Stuck in Processing with nothing queued
+        add("stuck");
+        write_test_wav(&meetings_dir.join("stuck/audio.wav"), &[0i16; 1600]);
+        update_session_audio(
+            &db_path,
+            "stuck",
+            "stuck/audio.wav",
+            1,
+            &MeetingStatus::Processing,
+        )
+        .unwrap();
+
+        // Queued for transcription, so Processing is expected
+        add("queued");
+        write_test_wav(&meetings_dir.join("queued/audio.wav"), &[0i16; 1600]);
+        update_session_audio(
+            &db_path,
+            "queued",
+            "queued/audio.wav",
+            1,
+            &MeetingStatus::Processing,
+        )
+        .unwrap();
+
+        // Folder without a row
+        fs::create_dir_all(meetings_dir.join("orphan")).unwrap();
+
+        let report = check_integrity(&db_path, &meetings_dir, &["queued".to_string()]).unwrap();
+        assert!(report.database_ok);
+        assert_eq!(report.sessions_checked, 5);
+        assert!(!report.is_healthy());
+
+        let issues = &report.issues;
+        assert_eq!(issues.len(), 4, "unexpected issues: {:?}", issues);
+        assert!(issues.contains(&IntegrityIssue::MissingTranscript {
+            session_id: "missing".to_string(),
+            path: "missing/transcript.txt".to_string(),
+        }));
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            IntegrityIssue::UnreadableAudio { session_id, .. } if session_id == "corrupt"
+        )));
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            IntegrityIssue::ImpossibleState { session_id, .. } if session_id == "stuck"
+        )));
+        assert!(issues.contains(&IntegrityIssue::OrphanFolder {
+            folder: "orphan".to_string()
+        }));
+    }
+
+    #[test]
+    fn test_format_paragraphs_splits_long_line_at_sentence_boundaries() {
+        use crate::managers::meeting::paragraphs::{format_paragraphs, split_sentences};
+
+        let text = "We reviewed the launch plan. Marketing needs the final copy by Friday! \
+                    Who owns the press release? Dana will draft it. \
+                    The budget was approved without changes. Next meeting is on Tuesday.";
+        assert!(!text.contains('\n'));
+
+        let formatted = format_paragraphs(text, 80);
+        let paragraphs: Vec<&str> = formatted.split("\n\n").collect();
+        assert!(
+            paragraphs.len() > 1,
+            "expected several paragraphs, got {:?}",
+            paragraphs
+        );
+
+        let sentences = split_sentences(text);
+        for paragraph in &paragraphs {
+            assert!(paragraph.ends_with(['.', '!', '?']), "{:?}", paragraph);
+            // Each paragraph is made of whole sentences
+            let rebuilt = split_sentences(paragraph);
+            assert!(
+                rebuilt.iter().all(|s| sentences.contains(s)),
+                "{:?}",
+                paragraph
+            );
+        }
+        assert_eq!(paragraphs.join(" "), sentences.join(" "));
+    }
+
+    #[test]
+    fn test_saved_queue_is_resumed_and_cleared_on_startup() {
+        use crate::managers::meeting::db::{insert_session, list_sessions};
+        use crate::managers::meeting::queue::{
+            load_queue, save_queue, TranscriptionQueue, TranscriptionQueueSnapshot,
+        };
+        use crate::managers::meeting::recovery::sessions_to_resume;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        // "b" was running and "a" waiting when the app closed; "c" was queued but
+        // missed by the saved copy, and "gone" lost its audio
+        for (id, created_at, has_audio) in [
+            ("a", 100, true),
+            ("b", 200, true),
+            ("c", 50, true),
+            ("gone", 10, false),
+        ] {
+            let mut session = MeetingSession::new(id.to_string(), id.to_string(), created_at);
+            session.status = MeetingStatus::Processing;
+            session.audio_path = Some(format!("{}/audio.wav", id));
+            fs::create_dir_all(meetings_dir.join(id)).unwrap();
+            if has_audio {
+                write_test_wav(&meetings_dir.join(format!("{}/audio.wav", id)), &[500i16; 1600]);
+            }
+            insert_session(&db_path, &session).unwrap();
+        }
+        let mut done = MeetingSession::new("done".to_string(), "done".to_string(), 5);
+        done.status = MeetingStatus::Completed;
+        insert_session(&db_path, &done).unwrap();
+
+        save_queue(
+            &meetings_dir,
+            &TranscriptionQueueSnapshot {
+                running: Some("b".to_string()),
+                pending: vec!["a".to_string()],
+            },
+        )
+        .unwrap();
+
+        // Startup: load the saved queue and re-enqueue what can be transcribed
+        let saved = load_queue(&meetings_dir).session_ids();
+        let sessions = list_sessions(&db_path).unwrap();
+        let resumed: Vec<&str> = sessions_to_resume(&meetings_dir, &saved, &sessions)
+            .into_iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        assert_eq!(resumed, vec!["b", "a", "c"]);
+
+        let mut queue = TranscriptionQueue::new();
+        for id in &resumed {
+            queue.enqueue(id);
+        }
+        save_queue(&meetings_dir, &queue.snapshot()).unwrap();
+        assert_eq!(load_queue(&meetings_dir).session_ids(), vec!["b", "a", "c"]);
+
+        // Drain it, saving after each step as the worker does
+        let mut processed = Vec::new();
+        while let Some(id) = queue.start_next() {
+            save_queue(&meetings_dir, &queue.snapshot()).unwrap();
+            assert_eq!(
+                load_queue(&meetings_dir).running.as_deref(),
+                Some(id.as_str())
+            );
+            processed.push(id);
+        }
+        save_queue(&meetings_dir, &queue.snapshot()).unwrap();
+        assert_eq!(processed, vec!["b", "a", "c"]);
+        assert!(load_queue(&meetings_dir).session_ids().is_empty());
+
+        // A corrupt file is treated as an empty queue
+        fs::write(meetings_dir.join("transcription_queue.json"), b"{not json").unwrap();
+        assert_eq!(
+            load_queue(&meetings_dir),
+            TranscriptionQueueSnapshot::default()
+        );
+    }
+
+    #[test]
+    fn test_streaming_transcription_reads_large_wav_in_bounded_windows() {
+        use crate::audio_toolkit::vad::VadFrame;
+        use crate::audio_toolkit::VoiceActivityDetector;
+        use crate::managers::meeting::stream::WavWindowReader;
+        use crate::managers::meeting::transcript::{
+            run_windowed_transcription, Segment, Transcriber, TranscriptionResult,
+        };
+        use std::cell::{Cell, RefCell};
+        use std::io::{BufReader, Read};
+        use std::rc::Rc;
+
+        struct CountingReader<R> {
+            inner: R,
+            bytes_read: Rc<Cell<u64>>,
+        }
+
+        impl<R: Read> Read for CountingReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = self.inner.read(buf)?;
+                self.bytes_read.set(self.bytes_read.get() + n as u64);
+                Ok(n)
+            }
+        }
+
+        struct EnergyVad;
+
+        impl VoiceActivityDetector for EnergyVad {
+            fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+                let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32;
+                Ok(if energy > 1e-6 {
+                    VadFrame::Speech(frame)
+                } else {
+                    VadFrame::Noise
+                })
+            }
+        }
+
+        /// Records the window sizes it sees and how much of the file had been read by then
+        struct RecordingTranscriber {
+            bytes_read: Rc<Cell<u64>>,
+            calls: RefCell<Vec<(usize, u64)>>,
+        }
+
+        impl Transcriber for RecordingTranscriber {
+            fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+                let mut calls = self.calls.borrow_mut();
+                calls.push((samples.len(), self.bytes_read.get()));
+                Ok(TranscriptionResult {
+                    text: format!("w{}", calls.len() - 1),
+                    segments: vec![Segment {
+                        start: 0.0,
+                        end: samples.len() as f32 / 16000.0,
+                        text: "window".to_string(),
+                    }],
+                    ..Default::default()
+                })
+            }
+        }
+
+        // 90 s of "speech" with a 0.5 s pause every 3 s
+        let samples: Vec<i16> = (0..90 * 16000)
+            .map(|i| {
+                if i % 48000 >= 40000 {
+                    0
+                } else {
+                    ((i as f32 * 440.0 * 2.0 * std::f32::consts::PI / 16000.0).sin() * 8000.0)
+                        as i16
+                }
+            })
+            .collect();
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("long.wav");
+        write_test_wav(&wav_path, &samples);
+        let file_len = fs::metadata(&wav_path).unwrap().len();
+
+        let bytes_read = Rc::new(Cell::new(0));
+        let source = BufReader::new(CountingReader {
+            inner: fs::File::open(&wav_path).unwrap(),
+            bytes_read: bytes_read.clone(),
+        });
+        let window_secs = 10;
+        let windows = WavWindowReader::new(source, window_secs)
+            .unwrap()
+            .with_vad(Box::new(EnergyVad));
+        let transcriber = RecordingTranscriber {
+            bytes_read: bytes_read.clone(),
+            calls: RefCell::new(Vec::new()),
+        };
+
+        let result = run_windowed_transcription(&transcriber, windows, None, None).unwrap();
+        let calls = transcriber.calls.into_inner();
+        let window_samples = window_secs as usize * 16000;
+
+        assert!(
+            calls.len() >= 9,
+            "expected many windows, got {}",
+            calls.len()
+        );
+        assert!(
+            calls[0].1 < file_len / 4,
+            "first window read {} bytes",
+            calls[0].1
+        );
+
+        let mut consumed = 0usize;
+        for (index, (len, read_at_call)) in calls.iter().enumerate() {
+            assert!(*len <= window_samples);
+            consumed += len;
+            // Never more than about one window read ahead of what was transcribed
+            let read_ahead = read_at_call.saturating_sub(consumed as u64 * 2);
+            assert!(
+                read_ahead <= window_samples as u64 * 2,
+                "window {} read ahead {}",
+                index,
+                read_ahead
+            );
+
+            // Windows are cut inside a pause rather than mid-word
+            let segment = &result.segments[index];
+            assert!((segment.start - (consumed - len) as f32 / 16000.0).abs() < 1e-3);
+            if index + 1 < calls.len() {
+                let end = consumed - 1;
+                assert_eq!(samples[end], 0, "window {} ends mid-speech", index);
+            }
+        }
+        assert!(consumed >= samples.len());
+
+        let expected: Vec<String> = (0..calls.len()).map(|i| format!("w{}", i)).collect();
+        assert_eq!(result.text, expected.join(" "));
+    }
+
+    #[test]
+    fn test_session_patch_updates_fields_atomically() {
+        use crate::managers::meeting::db::{
+            get_connection, get_session, insert_session, update_session_fields,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session = MeetingSession::new("s1".to_string(), "Original".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+
+        let patch = SessionPatch {
+            title: Some("Quarterly planning".to_string()),
+            category: Some("blue".to_string()),
+            ..Default::default()
+        };
+        patch.validate().unwrap();
+        update_session_fields(&db_path, "s1", &patch).unwrap();
+
+        let stored = get_session(&db_path, "s1").unwrap().unwrap();
+        assert_eq!(stored.title, "Quarterly planning");
+        assert_eq!(stored.category.as_deref(), Some("blue"));
+        assert_eq!(stored.notes, None);
+        assert!(!stored.starred);
+
+        // If any field fails to save, none of the patch is kept
+        get_connection(&db_path)
+            .unwrap()
+            .execute_batch(
+                "CREATE TRIGGER reject_red BEFORE UPDATE OF category ON meeting_sessions
+                 WHEN NEW.category = 'red' BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
+        let failing = SessionPatch {
+            title: Some("Should not stick".to_string()),
+            notes: Some("Follow up with finance".to_string()),
+            category: Some("red".to_string()),
+            starred: Some(true),
+        };
+        assert!(update_session_fields(&db_path, "s1", &failing).is_err());
+
+        let stored = get_session(&db_path, "s1").unwrap().unwrap();
+        assert_eq!(stored.title, "Quarterly planning");
+        assert_eq!(stored.category.as_deref(), Some("blue"));
+        assert_eq!(stored.notes, None);
+        assert!(!stored.starred);
+
+        // Invalid fields are rejected before anything is written
+        let invalid = SessionPatch {
+            title: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+        assert!(update_session_fields(&db_path, "missing", &patch).is_err());
+    }
+
+    #[test]
+    fn test_wav_writer_handle_header_counts_exact_samples() {
+        use crate::managers::meeting::wav_writer::WavWriterHandle;
+        use std::time::Duration;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("audio.wav");
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let writer = hound::WavWriter::create(&path, spec).unwrap();
+        let handle = WavWriterHandle::new(writer);
+
+        // Two callback threads write 25 chunks of 480 samples each
+        let threads: Vec<_> = (0..2)
+            .map(|_| {
+                let sink = handle.sink();
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        sink.write_samples(&[0.25; 480]).unwrap();
+                    }
+                    sink
+                })
+            })
+            .collect();
+        let sinks: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+
+        handle
+            .finalize_with_timeout(Duration::from_secs(5))
+            .unwrap();
+
+        // Late writes from a callback that outlives the recording are dropped
+        sinks[0].write_samples(&[0.5; 480]).unwrap();
+
+        let reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.len(), 2 * 25 * 480);
+        assert_eq!(reader.duration(), 2 * 25 * 480);
+        let expected = (0.25 * i16::MAX as f32) as i16;
+        assert!(reader
+            .into_samples::<i16>()
+            .all(|sample| sample.unwrap() == expected));
+    }
+
+    #[test]
+    fn test_status_counts_include_every_status() {
+        use crate::managers::meeting::db::{get_status_counts, insert_session};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let statuses = [
+            MeetingStatus::Completed,
+            MeetingStatus::Completed,
+            MeetingStatus::Completed,
+            MeetingStatus::Failed,
+            MeetingStatus::Processing,
+            MeetingStatus::Processing,
+        ];
+        for (index, status) in statuses.iter().enumerate() {
+            let mut session =
+                MeetingSession::new(format!("s{}", index), "Meeting".to_string(), 1705340400);
+            session.status = status.clone();
+            insert_session(&db_path, &session).unwrap();
+        }
+
+        let counts = get_status_counts(&db_path).unwrap();
+        assert_eq!(counts.len(), MeetingStatus::ALL.len());
+        assert_eq!(counts[&MeetingStatus::Completed], 3);
+        assert_eq!(counts[&MeetingStatus::Processing], 2);
+        assert_eq!(counts[&MeetingStatus::Failed], 1);
+        assert_eq!(counts[&MeetingStatus::Recording], 0);
+        assert_eq!(counts[&MeetingStatus::Interrupted], 0);
+        assert_eq!(counts[&MeetingStatus::Idle], 0);
+    }
+
+    #[test]
+    fn test_over_budget_transcript_is_summarized_with_map_reduce() {
+        use crate::managers::meeting::summary::{estimate_tokens, summarize_within_budget};
+        use crate::settings::SummaryStrategy;
+        use std::cell::RefCell;
+
+        let build_prompt = |text: &str| format!("Summarize this meeting:\n{}", text);
+        let transcript = "We agreed to ship the release on Friday. ".repeat(1000);
+        assert!(estimate_tokens(&build_prompt(&transcript)) > 2000);
+
+        let prompts = RefCell::new(Vec::new());
+        let summary = tauri::async_runtime::block_on(summarize_within_budget(
+            &transcript,
+            build_prompt,
+            SummaryStrategy::MapReduce,
+            2000,
+            |prompt: String| {
+                let reply = if prompt.starts_with("This is part") {
+                    "Release ships Friday.".to_string()
+                } else {
+                    "Final summary".to_string()
+                };
+                prompts.borrow_mut().push(prompt);
+                async move { Ok(reply) }
+            },
+        ))
+        .unwrap();
+
+        assert_eq!(summary, "Final summary");
+        let prompts = prompts.into_inner();
+        let (parts, finals): (Vec<_>, Vec<_>) =
+            prompts.iter().partition(|p| p.starts_with("This is part"));
+        assert!(parts.len() > 1);
+        assert_eq!(finals.len(), 1);
+        // Every request fits the budget, and the last one combines the part summaries
+        assert!(prompts.iter().all(|p| estimate_tokens(p) <= 2000));
+        assert_eq!(prompts.last(), finals.first().copied());
+        assert!(finals[0].contains("Release ships Friday."));
+    }
+
+    #[test]
+    fn test_error_log_keeps_only_most_recent_entries() {
+        use crate::managers::meeting_logger::{MeetingErrorLog, MeetingLogContext};
+        use std::sync::Arc;
+
+        let error_log = Arc::new(MeetingErrorLog::new(3));
+        let log_ctx =
+            MeetingLogContext::new("s1", "stop_recording").with_error_log(error_log.clone());
+        for i in 0..5 {
+            log_ctx.log_error(format!("error {}", i));
+        }
+        log_ctx.log_warning("disk almost full");
+        // Contexts without an error log don't record anything
+        MeetingLogContext::new("s2", "start_recording").log_error("not kept");
+
+        let recent = error_log.recent(10);
+        let messages: Vec<&str> = recent.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["disk almost full", "error 4", "error 3"]);
+        assert_eq!(recent[0].level, "warning");
+        assert_eq!(recent[1].level, "error");
+        assert_eq!(recent[1].session_id, "s1");
+        assert_eq!(recent[1].operation, "stop_recording");
+
+        assert_eq!(error_log.recent(1).len(), 1);
+        assert_eq!(error_log.recent(1)[0].message, "disk almost full");
+    }
+
+    #[test]
+    fn test_srt_export_streams_sequential_cues() {
+        use crate::managers::meeting::export::{
+            segments_to_srt, srt_timestamp, write_sessions_export, write_srt_to_path,
+        };
+        use crate::managers::meeting::transcript::{
+            read_transcript_details, write_transcript_details, Segment, TranscriptionResult,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+
+        let mut segments: Vec<Segment> = (0..1500)
+            .map(|i| Segment {
+                start: i as f32 * 2.0,
+                end: i as f32 * 2.0 + 1.5,
+                text: format!("Line {}", i),
+            })
+            .collect();
+        // Empty segments don't produce a cue or a gap in the numbering
+        segments.insert(
+            10,
+            Segment {
+                start: 20.0,
+                end: 20.5,
+                text: "  ".to_string(),
+            },
+        );
+        let result = TranscriptionResult {
+            segments,
+            ..Default::default()
+        };
+        write_transcript_details(&meetings_dir, "session-1", &result).unwrap();
+
+        let stored = read_transcript_details(&meetings_dir, "session-1")
+            .unwrap()
+            .unwrap();
+        let dest = temp_dir.path().join("export.srt");
+        assert_eq!(write_srt_to_path(&stored.segments, &dest).unwrap(), 1500);
+
+        let srt = fs::read_to_string(&dest).unwrap();
+        let cues: Vec<&str> = srt.split("\n\n").filter(|cue| !cue.is_empty()).collect();
+        assert_eq!(cues.len(), 1500);
+        for (index, cue) in cues.iter().enumerate() {
+            let lines: Vec<&str> = cue.lines().collect();
+            assert_eq!(lines[0], (index + 1).to_string());
+            assert_eq!(lines[2], format!("Line {}", index));
+        }
+        assert_eq!(
+            cues[1499].lines().nth(1),
+            Some("00:49:58,000 --> 00:49:59,500")
+        );
+
+        // The in-memory export produces the same document
+        assert_eq!(segments_to_srt(&stored.segments), srt);
+        assert_eq!(srt_timestamp(3723.25), "01:02:03,250");
+
+        // The file is written through a temp file that doesn't outlive the export
+        assert!(!temp_dir.path().join("export.srt.tmp").exists());
+
+        // As a sessions export, SRT covers exactly one session
+        let session = MeetingSession::new("session-1".to_string(), "Marathon".to_string(), 0);
+        let dest = temp_dir.path().join("session.srt");
+        let sessions = [session.clone()];
+        assert_eq!(
+            write_sessions_export(&meetings_dir, &sessions, ExportFormat::Srt, &dest).unwrap(),
+            1
+        );
+        assert_eq!(fs::read_to_string(&dest).unwrap(), srt);
+        let two = [session.clone(), session];
+        assert!(write_sessions_export(&meetings_dir, &two, ExportFormat::Srt, &dest).is_err());
+    }
+
+    #[test]
+    fn test_start_with_same_idempotency_key_creates_one_session() {
+        use crate::managers::meeting::idempotency::{start_once, IdempotencyKeys};
+        use std::cell::RefCell;
+        use std::sync::Mutex;
+        use std::time::{Duration, Instant};
+
+        let keys = Mutex::new(IdempotencyKeys::new(Duration::from_secs(60)));
+        let sessions: RefCell<Vec<MeetingSession>> = RefCell::new(Vec::new());
+        let existing = |id: &str| sessions.borrow().iter().find(|s| s.id == id).cloned();
+        let start = || -> Result<MeetingSession, String> {
+            let id = format!("session-{}", sessions.borrow().len() + 1);
+            let session = MeetingSession::new(id, "Meeting".to_string(), 1705340400);
+            sessions.borrow_mut().push(session.clone());
+            Ok(session)
+        };
+
+        let first = start_once(&keys, Some("click-1"), existing, start).unwrap();
+        let retry = start_once(&keys, Some("click-1"), existing, start).unwrap();
+        assert_eq!(first.id, retry.id);
+        assert_eq!(sessions.borrow().len(), 1);
+
+        // A different key, or no key, starts a new session
+        let other = start_once(&keys, Some("click-2"), existing, start).unwrap();
+        assert_ne!(other.id, first.id);
+        start_once(&keys, None, existing, start).unwrap();
+        assert_eq!(sessions.borrow().len(), 3);
+
+        // Keys expire after the window
+        let mut expiring = IdempotencyKeys::new(Duration::from_secs(60));
+        let now = Instant::now();
+        expiring.remember("click-1", "session-1", now);
+        assert_eq!(
+            expiring.lookup("click-1", now).as_deref(),
+            Some("session-1")
+        );
+        assert_eq!(
+            expiring.lookup("click-1", now + Duration::from_secs(61)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_audio_hash_is_stable_and_cached() {
+        use crate::managers::meeting::audio_hash::hash_file;
+        use crate::managers::meeting::db::{
+            get_audio_hash, insert_session, set_audio_hash, update_session_audio,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("known.bin");
+        fs::write(&path, b"abc").unwrap();
+        assert_eq!(
+            hash_file(&path).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        // Larger than one read chunk, hashed the same every time
+        let wav_path = temp_dir.path().join("audio.wav");
+        let samples: Vec<i16> = (0..100_000).map(|i| (i % 2000) as i16).collect();
+        write_test_wav(&wav_path, &samples);
+        let hash = hash_file(&wav_path).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash_file(&wav_path).unwrap(), hash);
+
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+        let session = MeetingSession::new("s1".to_string(), "Meeting".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+        assert_eq!(get_audio_hash(&db_path, "s1").unwrap(), None);
+
+        set_audio_hash(&db_path, "s1", &hash).unwrap();
+        assert_eq!(get_audio_hash(&db_path, "s1").unwrap(), Some(hash));
+
+        // New audio invalidates the cached hash
+        update_session_audio(
+            &db_path,
+            "s1",
+            "s1/audio.wav",
+            6,
+            &MeetingStatus::Processing,
+        )
+        .unwrap();
+        assert_eq!(get_audio_hash(&db_path, "s1").unwrap(), None);
+        assert!(get_audio_hash(&db_path, "missing").is_err());
+    }
+
+    #[test]
+    fn test_failing_post_action_does_not_abort_the_others() {
+        use crate::managers::meeting::post_actions::run_post_actions;
+        use crate::settings::PostAction;
+
+        let mut ran = Vec::new();
+        let failures = run_post_actions(
+            "session-1",
+            &[PostAction::GenerateSummary, PostAction::ExportSrt],
+            |action| {
+                ran.push(action);
+                match action {
+                    PostAction::GenerateSummary => {
+                        Err(anyhow::anyhow!("No LLM provider configured"))
+                    }
+                    _ => Ok(()),
+                }
+            },
+        );
+
+        assert_eq!(
+            ran,
+            vec![PostAction::GenerateSummary, PostAction::ExportSrt]
+        );
+        assert_eq!(
+            failures,
+            vec![(
+                PostAction::GenerateSummary,
+                "No LLM provider configured".to_string()
+            )]
+        );
+
+        // Both succeeding reports no failures
+        let failures = run_post_actions(
+            "session-1",
+            &[PostAction::ExportSrt, PostAction::CopyTranscriptPath],
+            |_| Ok(()),
+        );
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn test_active_recording_device_is_cleared_after_stop() {
+        use crate::commands::audio::AudioDevice;
+
+        let device = AudioDevice {
+            index: "0".to_string(),
+            name: "Built-in Microphone".to_string(),
+            is_default: true,
+        };
+        let mut session = MeetingSession::new("session-1".to_string(), "Standup".to_string(), 0);
+        session.status = MeetingStatus::Recording;
+
+        let mut state = MeetingManagerState::default();
+        assert!(state.recording_device().is_none());
+
+        state.current_session = Some(session.clone());
+        state.active_device = Some(device);
+        let reported = state.recording_device().expect("device while recording");
+        assert_eq!(reported.name, "Built-in Microphone");
+
+        // Once the session leaves Recording, no device is reported even before
+        // the stop path clears it
+        session.status = MeetingStatus::Processing;
+        state.current_session = Some(session);
+        assert!(state.recording_device().is_none());
+
+        state.active_device = None;
+        state.current_session = None;
+        assert!(state.recording_device().is_none());
+    }
+
+    #[test]
+    fn test_live_captions_emit_one_partial_per_chunk() {
+        use crate::managers::meeting::transcript::{
+            forward_partials, run_windowed_transcription, PartialTranscript, Transcriber,
+            TranscriptionResult,
+        };
+        use std::cell::Cell;
+
+        /// Returns "chunk N" for every window, and nothing for silent ones
+        struct StubTranscriber {
+            calls: Cell<usize>,
+        }
+
+        impl Transcriber for StubTranscriber {
+            fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+                let call = self.calls.get();
+                self.calls.set(call + 1);
+                let text = if samples.iter().all(|s| *s == 0.0) {
+                    String::new()
+                } else {
+                    format!(" chunk {} ", call)
+                };
+                Ok(TranscriptionResult::text_only(text))
+            }
+        }
+
+        let windows = vec![
+            Ok(vec![0.1; 1600]),
+            Ok(vec![0.0; 1600]),
+            Ok(vec![0.2; 1600]),
+            Ok(vec![0.3; 800]),
+        ];
+        let transcriber = StubTranscriber {
+            calls: Cell::new(0),
+        };
+        let mut events: Vec<PartialTranscript> = Vec::new();
+        let mut on_partial = forward_partials("session-1", |partial| events.push(partial));
+
+        let result =
+            run_windowed_transcription(&transcriber, windows, None, Some(&mut on_partial)).unwrap();
+        drop(on_partial);
+
+        assert_eq!(transcriber.calls.get(), 4);
+        let expected: Vec<PartialTranscript> = [(0, "chunk 0"), (2, "chunk 2"), (3, "chunk 3")]
+            .iter()
+            .map(|(chunk, text)| PartialTranscript {
+                session_id: "session-1".to_string(),
+                chunk: *chunk,
+                text: text.to_string(),
+                offset_secs: None,
+            })
+            .collect();
+        assert_eq!(events, expected);
+        // The final text is unaffected by the callback
+        assert_eq!(result.text, "chunk 0 chunk 2 chunk 3");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_transcription_reads_audio_held_open_by_playback() {
+        use crate::managers::meeting::audio_cache::convert_wav_to_16k;
+        use crate::managers::meeting::files::open_shared_read;
+        use std::os::windows::fs::OpenOptionsExt;
+        use windows::Win32::Storage::FileSystem::FILE_SHARE_READ;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+        let samples: Vec<i16> = (0..16000).map(|i| (i % 100) as i16 * 100).collect();
+        write_test_wav(&wav_path, &samples);
+
+        // A player that only lets others read while it has the file open
+        let _playback = fs::OpenOptions::new()
+            .read(true)
+            .share_mode(FILE_SHARE_READ.0)
+            .open(&wav_path)
+            .unwrap();
+
+        // Two readers at once alongside the player, as with export during transcription
+        let first = open_shared_read(&wav_path).unwrap();
+        let second = open_shared_read(&wav_path).unwrap();
+        drop((first, second));
+
+        let audio = convert_wav_to_16k(&wav_path).unwrap();
+        assert_eq!(audio.len(), samples.len());
+    }
+
+    #[test]
+    fn test_completed_sessions_missing_transcript_are_listed() {
+        use crate::managers::meeting::db::{insert_session, update_session_transcript};
+        use crate::managers::meeting::integrity::sessions_missing_transcript;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        let meetings_dir = temp_dir.path().join("meetings");
+        init_meeting_database(&db_path).unwrap();
+
+        let completed = |id: &str, transcript_path: Option<&str>| {
+            let mut session = MeetingSession::new(id.to_string(), id.to_string(), 0);
+            session.status = MeetingStatus::Completed;
+            insert_session(&db_path, &session).unwrap();
+            if let Some(path) = transcript_path {
+                update_session_transcript(&db_path, id, path, &MeetingStatus::Completed).unwrap();
+            }
+        };
+
+        // Healthy: the transcript file exists
+        fs::create_dir_all(meetings_dir.join("healthy")).unwrap();
+        fs::write(meetings_dir.join("healthy/transcript.txt"), "hello").unwrap();
+        completed("healthy", Some("healthy/transcript.txt"));
+        // Path recorded but the file was deleted
+        completed("deleted", Some("deleted/transcript.txt"));
+        // No path at all
+        completed("no-path", None);
+        // Not Completed, so a missing transcript is expected
+        let mut failed = MeetingSession::new("failed".to_string(), "failed".to_string(), 0);
+        failed.status = MeetingStatus::Failed;
+        insert_session(&db_path, &failed).unwrap();
+
+        let mut ids: Vec<String> = sessions_missing_transcript(&db_path, &meetings_dir)
+            .unwrap()
+            .into_iter()
+            .map(|session| session.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["deleted".to_string(), "no-path".to_string()]);
+    }
+
+    #[test]
+    fn test_audio_byte_range_matches_file_contents() {
+        use crate::managers::meeting::files::{read_file_range, MAX_FILE_RANGE_BYTES};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+        let samples: Vec<i16> = (0..4000).map(|i| (i * 7) as i16).collect();
+        write_test_wav(&wav_path, &samples);
+        let contents = fs::read(&wav_path).unwrap();
+        let size = contents.len() as u64;
+
+        // The header plus the first samples
+        assert_eq!(read_file_range(&wav_path, 0, 64).unwrap(), &contents[..64]);
+        // A range in the middle
+        assert_eq!(
+            read_file_range(&wav_path, 1000, 500).unwrap(),
+            &contents[1000..1500]
+        );
+        // Up to the last byte
+        assert_eq!(
+            read_file_range(&wav_path, size - 10, 10).unwrap(),
+            &contents[contents.len() - 10..]
+        );
+        assert!(read_file_range(&wav_path, size, 0).unwrap().is_empty());
+
+        // Past the end, overflowing, or too large at once
+        assert!(read_file_range(&wav_path, size - 10, 11).is_err());
+        assert!(read_file_range(&wav_path, u64::MAX, 2).is_err());
+        assert!(read_file_range(&wav_path, 0, MAX_FILE_RANGE_BYTES + 1).is_err());
+    }
+
+    #[test]
+    fn test_daily_note_collects_same_day_sessions_in_order() {
+        use crate::managers::meeting::daily_note::{append_to_daily_note, DAILY_NOTES_DIR};
+        use chrono::Utc;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+
+        // 2024-03-05 09:30 and 14:00 UTC
+        let standup = MeetingSession::new("a".to_string(), "Standup".to_string(), 1709631000);
+        let review = MeetingSession::new("b".to_string(), "Design review".to_string(), 1709647200);
+
+        let first =
+            append_to_daily_note(&meetings_dir, &standup, "Shipped the fix.\n", &Utc).unwrap();
+        let second =
+            append_to_daily_note(&meetings_dir, &review, "Agreed on the layout.", &Utc).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(
+            first,
+            meetings_dir.join(DAILY_NOTES_DIR).join("2024-03-05.md")
+        );
+        assert_eq!(
+            fs::read_to_string(&first).unwrap(),
+            "## Standup (09:30)\n\nShipped the fix.\n\n\
+             ## Design review (14:00)\n\nAgreed on the layout.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_input_device_is_reported_unless_system_only() {
+        use crate::audio_toolkit::NoInputDevice;
+        use crate::managers::meeting::input_check::check_input_device;
+
+        let no_devices = || Ok::<Vec<String>, String>(Vec::new());
+        let one_device = || Ok::<Vec<String>, String>(vec!["Built-in Microphone".to_string()]);
+
+        for source in [AudioSourceType::MicrophoneOnly, AudioSourceType::Mixed] {
+            let err = check_input_device(&source, no_devices).unwrap_err();
+            assert_eq!(err, NoInputDevice);
+            assert!(err.to_string().contains("No microphone was found"));
+            assert!(check_input_device(&source, one_device).is_ok());
+        }
+
+        // System-only recordings never look for a microphone
+        let listed = std::cell::Cell::new(false);
+        let result = check_input_device(&AudioSourceType::SystemOnly, || {
+            listed.set(true);
+            Ok::<Vec<String>, String>(Vec::new())
+        });
+        assert!(result.is_ok());
+        assert!(!listed.get());
+
+        // A failed lookup leaves it to opening the device to report the problem
+        let failed = || Err::<Vec<String>, String>("host unavailable".to_string());
+        assert!(check_input_device(&AudioSourceType::MicrophoneOnly, failed).is_ok());
+    }
+
+    #[test]
+    fn test_stop_with_auto_transcribe_moves_to_processing() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, list_sessions_with_status, update_session_audio,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session =
+            MeetingSession::new(Uuid::new_v4().to_string(), "Auto".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+        let audio_rel = format!("{}/audio.wav", session.id);
+        write_test_wav(&meetings_dir.join(&audio_rel), &[0i16; 160]);
+
+        let stopped = MeetingStatus::after_stop(true);
+        assert_eq!(stopped, MeetingStatus::Processing);
+        update_session_audio(&db_path, &session.id, &audio_rel, 1, &stopped).unwrap();
+
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Processing);
+        assert!(
+            list_sessions_with_status(&db_path, &MeetingStatus::Recorded)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_stop_without_auto_transcribe_waits_in_recorded() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, list_sessions_with_status, update_session_audio,
+            update_session_status,
+        };
+        use crate::managers::meeting::repair::repair_session_status;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session =
+            MeetingSession::new(Uuid::new_v4().to_string(), "Manual".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+        let audio_rel = format!("{}/audio.wav", session.id);
+        write_test_wav(&meetings_dir.join(&audio_rel), &[0i16; 160]);
+
+        let stopped = MeetingStatus::after_stop(false);
+        assert_eq!(stopped, MeetingStatus::Recorded);
+        update_session_audio(&db_path, &session.id, &audio_rel, 1, &stopped).unwrap();
+
+        // Stored and listed as waiting for transcription
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Recorded);
+        let waiting = list_sessions_with_status(&db_path, &MeetingStatus::Recorded).unwrap();
+        assert_eq!(waiting.len(), 1);
+        assert_eq!(waiting[0].id, session.id);
+
+        // A restart doesn't mistake the missing transcript for a failure
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, false).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Recorded);
+        assert_eq!(repaired.error_message, None);
+
+        // Starting the transcription manually moves it on to Processing
+        update_session_status(&db_path, &session.id, &MeetingStatus::Processing).unwrap();
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Processing);
+    }
+
+    #[test]
+    fn test_play_position_is_stored_per_session() {
+        use crate::managers::meeting::db::{
+            get_play_position, get_session, insert_session, set_play_position,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session =
+            MeetingSession::new(Uuid::new_v4().to_string(), "Long".to_string(), 1705340400);
+        let other =
+            MeetingSession::new(Uuid::new_v4().to_string(), "Other".to_string(), 1705340500);
+        insert_session(&db_path, &session).unwrap();
+        insert_session(&db_path, &other).unwrap();
+
+        // Defaults to the start
+        assert_eq!(get_play_position(&db_path, &session.id).unwrap(), 0);
+
+        set_play_position(&db_path, &session.id, 30_000).unwrap();
+        set_play_position(&db_path, &session.id, 754_250).unwrap();
+        assert_eq!(get_play_position(&db_path, &session.id).unwrap(), 754_250);
+        assert_eq!(get_play_position(&db_path, &other.id).unwrap(), 0);
+
+        // Survives reloading the session and reopening the database
+        let reloaded = get_session(&db_path, &session.id).unwrap().unwrap();
+        init_meeting_database(&db_path).expect("Failed to reopen database");
+        assert_eq!(get_play_position(&db_path, &reloaded.id).unwrap(), 754_250);
+
+        assert!(set_play_position(&db_path, "missing", 1).is_err());
+        assert!(get_play_position(&db_path, "missing").is_err());
+    }
+
+    #[test]
+    fn test_reapply_custom_words_rewrites_only_changed_transcripts() {
+        use crate::managers::meeting::corrections::reapply_custom_words;
+        use crate::managers::meeting::db::{insert_session, update_session_transcript};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let mut write_session = |title: &str, transcript: &str| {
+            let session =
+                MeetingSession::new(Uuid::new_v4().to_string(), title.to_string(), 1705340400);
+            insert_session(&db_path, &session).unwrap();
+            let rel_path = format!("{}/transcript.txt", session.id);
+            fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+            fs::write(meetings_dir.join(&rel_path), transcript).unwrap();
+            update_session_transcript(&db_path, &session.id, &rel_path, &MeetingStatus::Completed)
+                .unwrap();
+            (session.id, meetings_dir.join(rel_path))
+        };
+        let (infra_id, infra_path) = write_session(
+            "Infra",
+            "We moved the cluster to kubernetis.\n\nRollout  went fine.",
+        );
+        let (_, budget_path) = write_session("Budget", "Budget review for next quarter.");
+        let budget_before = fs::read(&budget_path).unwrap();
+
+        let custom_words = vec!["Kubernetes".to_string()];
+        let changed = reapply_custom_words(&db_path, &meetings_dir, &custom_words, 0.18).unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].id, infra_id);
+
+        // Line breaks and untouched lines keep their layout
+        assert_eq!(
+            fs::read_to_string(&infra_path).unwrap(),
+            "We moved the cluster to Kubernetes.\n\nRollout  went fine."
+        );
+        assert_eq!(fs::read(&budget_path).unwrap(), budget_before);
+
+        // Running again finds nothing left to change
+        let changed = reapply_custom_words(&db_path, &meetings_dir, &custom_words, 0.18).unwrap();
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn test_preview_reflects_template_overrides() {
+        use crate::commands::audio::AudioDevice;
+        use crate::managers::meeting::preview::resolve_meeting_config;
+        use crate::settings::{get_default_settings, PostAction, PreprocessingStep};
+
+        let mut settings = get_default_settings();
+        settings.selected_language = "de".to_string();
+        let template = settings
+            .meeting_templates
+            .iter_mut()
+            .find(|t| t.id == "template_team_standup")
+            .unwrap();
+        template.audio_source = "system_only".to_string();
+        template.preprocessing = vec![PreprocessingStep {
+            name: "normalize".to_string(),
+            params: Default::default(),
+        }];
+        template.sync_offset_ms = 120;
+        template.post_actions = vec![PostAction::GenerateSummary];
+        template.summary_token_budget = 4000;
+
+        let microphone = AudioDevice {
+            index: "0".to_string(),
+            name: "Built-in Microphone".to_string(),
+            is_default: true,
+        };
+
+        // Without a template, the defaults apply and the microphone is recorded
+        let plain = resolve_meeting_config(&settings, None, None, Some(microphone.clone()));
+        assert_eq!(plain.template_id, None);
+        assert_eq!(plain.audio_source, AudioSourceType::MicrophoneOnly);
+        assert_eq!(plain.device.map(|d| d.name), Some(microphone.name.clone()));
+        assert_eq!(
+            (plain.sample_rate, plain.bits_per_sample, plain.channels),
+            (16000, 16, 1)
+        );
+        assert_eq!(plain.language, "de");
+        assert!(plain.preprocessing.is_empty());
+        assert!(!plain.generate_summary);
+
+        // The template's source, chain, offset, and summary settings show up
+        let preview = resolve_meeting_config(
+            &settings,
+            None,
+            Some("template_team_standup"),
+            Some(microphone.clone()),
+        );
+        assert_eq!(
+            preview.template_id.as_deref(),
+            Some("template_team_standup")
+        );
+        assert_eq!(preview.audio_source, AudioSourceType::SystemOnly);
+        assert!(preview.device.is_none());
+        assert_eq!(preview.preprocessing.len(), 1);
+        assert_eq!(preview.preprocessing[0].name, "normalize");
+        assert_eq!(preview.sync_offset_ms, 120);
+        assert!(preview.generate_summary);
+        assert!(preview.custom_summary_prompt);
+        assert_eq!(preview.summary_token_budget, 4000);
+
+        // The active template is used when none is named
+        settings.active_meeting_template_id = Some("template_team_standup".to_string());
+        let active = resolve_meeting_config(&settings, None, None, Some(microphone));
+        assert_eq!(active.template_id.as_deref(), Some("template_team_standup"));
+    }
+
+    #[test]
+    fn test_failed_start_leaves_no_session_behind() {
+        use crate::managers::meeting::db::{get_session, insert_session};
+        use crate::managers::meeting::disk::MEETING_WAV_SPEC;
+        use crate::managers::meeting::rollback::StartRollback;
+        use crate::managers::meeting::wav_writer::WavWriterHandle;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+        // Resumes whose start failed, with the audio length their stop got back
+        let restored = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        // Mirrors start_recording: row and folder, then the WAV, then the recorder
+        let start = |session_id: &str, is_resume: bool, recorder_fails: bool| -> Result<()> {
+            let mut rollback = StartRollback::new(&db_path, &meetings_dir, session_id, is_resume);
+            let audio_path = meetings_dir.join(session_id).join("audio.wav");
+            if is_resume {
+                let restored = restored.clone();
+                let (id, path) = (session_id.to_string(), audio_path.clone());
+                rollback.on_abort(move || {
+                    let frames = hound::WavReader::open(&path)
+                        .map(|r| r.duration())
+                        .unwrap_or(0);
+                    restored.lock().unwrap().push((id, frames));
+                });
+            }
+            let writer = hound::WavWriter::create(&audio_path, MEETING_WAV_SPEC)?;
+            let wav = WavWriterHandle::new(writer);
+            wav.sink().write_samples(&[0.25; 160])?;
+            rollback.hold_wav(wav);
+
+            if recorder_fails {
+                return Err(anyhow::anyhow!("Failed to start audio capture: no device"));
+            }
+            let wav = rollback.commit().unwrap();
+            wav.finalize_with_timeout(std::time::Duration::from_secs(1))
+        };
+        let new_session = |title: &str| {
+            let session =
+                MeetingSession::new(Uuid::new_v4().to_string(), title.to_string(), 1705340400);
+            insert_session(&db_path, &session).unwrap();
+            fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+            session.id
+        };
+
+        // The recorder fails: no folder, audio, or row is left
+        let failed = new_session("Failed");
+        assert!(start(&failed, false, true).is_err());
+        assert!(!meetings_dir.join(&failed).exists());
+        assert!(get_session(&db_path, &failed).unwrap().is_none());
+
+        // A successful start keeps everything
+        let started = new_session("Started");
+        start(&started, false, false).unwrap();
+        assert!(meetings_dir.join(&started).join("audio.wav").is_file());
+        assert!(get_session(&db_path, &started).unwrap().is_some());
+
+        // A failed resume keeps the earlier session, with its audio finalized
+        let resumed = new_session("Resumed");
+        assert!(start(&resumed, true, true).is_err());
+        assert!(get_session(&db_path, &resumed).unwrap().is_some());
+        let reader = hound::WavReader::open(meetings_dir.join(&resumed).join("audio.wav")).unwrap();
+        assert_eq!(reader.duration(), 160);
+        // ...and its stop gets it back only after the WAV was finalized
+        assert_eq!(*restored.lock().unwrap(), vec![(resumed.clone(), 160)]);
+
+        // A resume that starts keeps the stop consumed
+        let kept = new_session("Kept");
+        start(&kept, true, false).unwrap();
+        assert_eq!(restored.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_export_job_runs_in_background_and_reports_completion() {
+        use crate::managers::meeting::export::{write_sessions_export, ExportFormat};
+        use crate::managers::meeting::jobs::{FileJobKind, FileJobPool, FileJobState};
+        use std::sync::{Arc, Mutex};
+        use std::time::{Duration, Instant};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        fs::create_dir_all(&meetings_dir).unwrap();
+        let sessions = vec![
+            MeetingSession::new("session-1".to_string(), "Standup".to_string(), 1705340400),
+            MeetingSession::new("session-2".to_string(), "Review".to_string(), 1705344000),
+        ];
+        let dest = temp_dir.path().join("meetings.csv");
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let recorded = updates.clone();
+        let pool = FileJobPool::new(2, move |status| {
+            recorded.lock().unwrap().push(status.state);
+        });
+
+        let job_dest = dest.clone();
+        let job_id = pool.submit(FileJobKind::Export, move || {
+            write_sessions_export(&meetings_dir, &sessions, ExportFormat::Csv, &job_dest)
+                .map(|count| count.to_string())
+        });
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let status = loop {
+            let status = pool.status(&job_id).expect("job should be known");
+            if status.state == FileJobState::Completed || status.state == FileJobState::Failed {
+                break status;
+            }
+            assert!(Instant::now() < deadline, "export job did not finish");
+            std::thread::sleep(Duration::from_millis(10));
+        };
+
+        assert_eq!(status.kind, FileJobKind::Export);
+        assert_eq!(status.state, FileJobState::Completed);
+        assert_eq!(status.result.as_deref(), Some("2"));
+        assert_eq!(status.error, None);
+        assert!(dest.exists());
+        // The completion event is sent right after the status is updated
+        while updates.lock().unwrap().len() < 3 {
+            assert!(Instant::now() < deadline, "completion event not sent");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(
+            *updates.lock().unwrap(),
+            vec![
+                FileJobState::Queued,
+                FileJobState::Running,
+                FileJobState::Completed
+            ]
+        );
+
+        let failing = pool.submit(FileJobKind::AudioHash, || Err(anyhow::anyhow!("no audio")));
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while pool.status(&failing).unwrap().state != FileJobState::Failed {
+            assert!(Instant::now() < deadline, "failing job did not finish");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(
+            pool.status(&failing).unwrap().error.as_deref(),
+            Some("no audio")
+        );
+        assert!(pool.status("unknown").is_none());
+    }
+
+    #[test]
+    fn test_keep_longer_retry_preserves_longer_original() {
+        use crate::managers::meeting::keep_policy::{
+            apply_keep_policy, load_stored_transcript, KeptTranscript, RetryKeepPolicy,
+        };
+        use crate::managers::meeting::transcript::write_transcript_details;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        let original = "we agreed to ship the release on friday after the review";
+        fs::write(meetings_dir.join("session-1/transcript.txt"), original).unwrap();
+        let stored = TranscriptionResult {
+            language: Some("en".to_string()),
+            segments: vec![Segment {
+                start: 0.0,
+                end: 3.0,
+                text: original.to_string(),
+            }],
+            ..Default::default()
+        };
+        write_transcript_details(&meetings_dir, "session-1", &stored).unwrap();
+
+        let previous =
+            load_stored_transcript(&meetings_dir, "session-1", Some("session-1/transcript.txt"))
+                .unwrap()
+                .expect("stored transcript should load");
+        assert_eq!(previous.text, original);
+        assert_eq!(previous.segments.len(), 1);
+
+        let retried = TranscriptionResult::text_only("ship on friday");
+        let (chosen, comparison) = apply_keep_policy(
+            "session-1",
+            RetryKeepPolicy::KeepLonger,
+            previous.clone(),
+            retried.clone(),
+        );
+        assert_eq!(chosen.text, original);
+        assert_eq!(chosen.segments, previous.segments);
+        assert_eq!(comparison.kept, KeptTranscript::Previous);
+        assert_eq!(comparison.previous_text, original);
+        assert_eq!(comparison.new_text, "ship on friday");
+
+        // Overwrite keeps the new one regardless of length
+        let (chosen, comparison) =
+            apply_keep_policy("session-1", RetryKeepPolicy::Overwrite, previous, retried);
+        assert_eq!(chosen.text, "ship on friday");
+        assert_eq!(comparison.kept, KeptTranscript::New);
+    }
+
+    #[test]
+    fn test_session_bundle_round_trips_into_fresh_database() {
+        use crate::managers::meeting::bundle::{
+            export_sessions_bundle, import_sessions_bundle, SESSION_BUNDLE_SCHEMA_VERSION,
+        };
+        use crate::managers::meeting::db::{
+            get_session, insert_session, list_sessions, update_session_category,
+            update_session_summary_path, update_session_transcript,
+        };
+
+        let source_dir = tempdir().expect("Failed to create temp dir");
+        let source_db = source_dir.path().join("meetings.db");
+        let source_meetings = source_dir.path().join("meetings");
+        init_meeting_database(&source_db).unwrap();
+
+        let standup =
+            MeetingSession::new("session-1".to_string(), "Standup".to_string(), 1705340400);
+        insert_session(&source_db, &standup).unwrap();
+        update_session_category(&source_db, "session-1", Some("blue")).unwrap();
+        fs::create_dir_all(source_meetings.join("session-1")).unwrap();
+        fs::write(
+            source_meetings.join("session-1/transcript.txt"),
+            "yesterday I fixed the build",
+        )
+        .unwrap();
+        fs::write(source_meetings.join("session-1/summary.md"), "# Summary").unwrap();
+        update_session_transcript(
+            &source_db,
+            "session-1",
+            "session-1/transcript.txt",
+            &MeetingStatus::Completed,
+        )
+        .unwrap();
+        update_session_summary_path(&source_db, "session-1", "session-1/summary.md").unwrap();
+
+        let mut failed =
+            MeetingSession::new("session-2".to_string(), "Broken".to_string(), 1705344000);
+        failed.status = MeetingStatus::Interrupted;
+        insert_session(&source_db, &failed).unwrap();
+
+        let json = export_sessions_bundle(&source_db, &source_meetings, None).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], SESSION_BUNDLE_SCHEMA_VERSION);
+
+        let dest_dir = tempdir().expect("Failed to create temp dir");
+        let dest_db = dest_dir.path().join("meetings.db");
+        let dest_meetings = dest_dir.path().join("meetings");
+        init_meeting_database(&dest_db).unwrap();
+
+        let imported = import_sessions_bundle(&dest_db, &dest_meetings, &json).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert_eq!(list_sessions(&dest_db).unwrap().len(), 2);
+
+        let standup = imported.iter().find(|s| s.title == "Standup").unwrap();
+        assert_ne!(standup.id, "session-1");
+        let stored = get_session(&dest_db, &standup.id).unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Completed);
+        assert_eq!(stored.created_at, 1705340400);
+        assert_eq!(stored.category.as_deref(), Some("blue"));
+        assert_eq!(
+            fs::read_to_string(dest_meetings.join(stored.transcript_path.unwrap())).unwrap(),
+            "yesterday I fixed the build"
+        );
+        assert_eq!(
+            fs::read_to_string(dest_meetings.join(stored.summary_path.unwrap())).unwrap(),
+            "# Summary"
+        );
+
+        // Without audio, an unfinished session can only come back as Failed
+        let broken = imported.iter().find(|s| s.title == "Broken").unwrap();
+        let stored = get_session(&dest_db, &broken.id).unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Failed);
+        assert!(stored.transcript_path.is_none());
+
+        // Bundles from a newer version are refused rather than misread
+        let mut newer = value;
+        newer["schema_version"] = serde_json::json!(SESSION_BUNDLE_SCHEMA_VERSION + 1);
+        let err = import_sessions_bundle(&dest_db, &dest_meetings, &newer.to_string())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("newer version"), "{}", err);
+        assert_eq!(list_sessions(&dest_db).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_split_session_at_one_point_gives_two_sessions_with_matching_durations() {
+        use crate::managers::meeting::db::{get_session, insert_session};
+        use crate::managers::meeting::split::split_session_files;
+
+        let dir = tempdir().expect("Failed to create temp dir");
+        let db_path = dir.path().join("meetings.db");
+        let meetings_dir = dir.path().join("meetings");
+        init_meeting_database(&db_path).unwrap();
+
+        // Three seconds at 16kHz
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        write_test_wav(
+            &meetings_dir.join("session-1/audio.wav"),
+            &vec![1000i16; 48000],
+        );
+        let mut session =
+            MeetingSession::new("session-1".to_string(), "Planning".to_string(), 1705340400);
+        session.status = MeetingStatus::Recorded;
+        session.audio_path = Some("session-1/audio.wav".to_string());
+        insert_session(&db_path, &session).unwrap();
+
+        assert!(split_session_files(&db_path, &meetings_dir, &session, &[3000], false).is_err());
+        assert!(
+            split_session_files(&db_path, &meetings_dir, &session, &[2000, 1000], false).is_err()
+        );
+
+        let parts = split_session_files(&db_path, &meetings_dir, &session, &[1000], false).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].duration, Some(1));
+        assert_eq!(parts[1].duration, Some(2));
+        assert_eq!(parts[0].title, "Planning (part 1)");
+        assert_eq!(parts[1].created_at, 1705340401);
+
+        for part in &parts {
+            let stored = get_session(&db_path, &part.id).unwrap().unwrap();
+            assert_eq!(stored.status, MeetingStatus::Recorded);
+            assert_eq!(stored.duration, part.duration);
+        }
+        let first =
+            hound::WavReader::open(meetings_dir.join(parts[0].audio_path.as_ref().unwrap()))
+                .unwrap();
+        assert_eq!(first.duration(), 16000);
+        let second =
+            hound::WavReader::open(meetings_dir.join(parts[1].audio_path.as_ref().unwrap()))
+                .unwrap();
+        assert_eq!(second.duration(), 32000);
+
+        // The original is left alone
+        assert!(get_session(&db_path, "session-1").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_split_session_reads_archived_flac_audio() {
+        use crate::managers::meeting::archive::{write_archive, ARCHIVE_FILENAME};
+        use crate::managers::meeting::db::insert_session;
+        use crate::managers::meeting::split::split_session_files;
+
+        let dir = tempdir().expect("Failed to create temp dir");
+        let db_path = dir.path().join("meetings.db");
+        let meetings_dir = dir.path().join("meetings");
+        init_meeting_database(&db_path).unwrap();
+
+        // Three seconds at 16kHz, archived in place of the original
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        let original = meetings_dir.join("session-1/audio.wav");
+        write_test_wav(&original, &vec![1000i16; 48000]);
+        let archive_rel = format!("session-1/{}", ARCHIVE_FILENAME);
+        write_archive(&original, &meetings_dir.join(&archive_rel)).unwrap();
+        fs::remove_file(&original).unwrap();
+
+        let mut session =
+            MeetingSession::new("session-1".to_string(), "Planning".to_string(), 1705340400);
+        session.status = MeetingStatus::Completed;
+        session.audio_path = Some(archive_rel);
+        insert_session(&db_path, &session).unwrap();
+
+        assert!(split_session_files(&db_path, &meetings_dir, &session, &[3000], false).is_err());
+        let parts = split_session_files(&db_path, &meetings_dir, &session, &[1000], false).unwrap();
+        assert_eq!(parts[0].duration, Some(1));
+        assert_eq!(parts[1].duration, Some(2));
+
+        // Parts are written as WAV with the archived samples
+        let mut second =
+            hound::WavReader::open(meetings_dir.join(parts[1].audio_path.as_ref().unwrap()))
+                .unwrap();
+        assert_eq!(second.duration(), 32000);
+        assert!(second
+            .samples::<i16>()
+            .all(|sample| sample.unwrap() == 1000));
+    }
+
+    #[test]
+    fn test_pre_roll_samples_appear_at_start_of_wav() {
+        use crate::managers::meeting::pre_roll::{pre_roll_capacity, PreRollHandoff, PreRollInput};
+        use std::sync::{mpsc, Arc, Mutex};
+        use std::time::Duration;
+
+        type Callback = Box<dyn Fn(Vec<f32>) + Send + Sync>;
+
+        /// Hands its callback to the test, and reports when it is closed
+        struct StubInput {
+            callback: Arc<Mutex<Option<Callback>>>,
+            closed: mpsc::Sender<()>,
+            fail: bool,
+        }
+
+        impl PreRollInput for StubInput {
+            fn start(&mut self, on_samples: Callback) -> Result<()> {
+                if self.fail {
+                    return Err(anyhow::anyhow!("device busy"));
+                }
+                *self.callback.lock().unwrap() = Some(on_samples);
+                Ok(())
+            }
+        }
+
+        impl Drop for StubInput {
+            fn drop(&mut self) {
+                let _ = self.closed.send(());
+            }
+        }
+
+        let dir = tempdir().expect("Failed to create temp dir");
+        let path = dir.path().join("audio.wav");
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let handle = WavWriterHandle::new(hound::WavWriter::create(&path, spec).unwrap());
+
+        // 250ms of 16kHz mono audio
+        assert_eq!(pre_roll_capacity(250, 16000, 1), 4000);
+
+        // An input that can't start is closed again, and the start goes on without it
+        let (closed_tx, closed_rx) = mpsc::channel();
+        let failing = StubInput {
+            callback: Arc::new(Mutex::new(None)),
+            closed: closed_tx,
+            fail: true,
+        };
+        assert!(PreRollHandoff::with_input(handle.sink(), 4, Box::new(failing)).is_err());
+        closed_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+
+        let callback = Arc::new(Mutex::new(None));
+        let (closed_tx, closed_rx) = mpsc::channel();
+        let input = StubInput {
+            callback: callback.clone(),
+            closed: closed_tx,
+            fail: false,
+        };
+        let handoff = PreRollHandoff::with_input(handle.sink(), 4, Box::new(input)).unwrap();
+        let pre_roll =
+            |samples: &[f32]| (callback.lock().unwrap().as_ref().unwrap())(samples.to_vec());
+
+        // Heard while the recorder starts: only the newest four samples are kept
+        pre_roll(&[0.1, 0.2]);
+        pre_roll(&[0.3, 0.4, 0.5]);
+
+        // The recorder's first samples follow the pre-roll and close the early input
+        handoff.write_samples(&[0.6, 0.7]).unwrap();
+        closed_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+
+        // Anything the early input still delivers is dropped
+        pre_roll(&[0.9]);
+        handoff.write_samples(&[0.8]).unwrap();
+        handle
+            .finalize_with_timeout(Duration::from_secs(1))
+            .unwrap();
+
+        let samples: Vec<i16> = hound::WavReader::open(&path)
+            .unwrap()
+            .samples::<i16>()
+            .map(|s| s.unwrap())
+            .collect();
+        let expected: Vec<i16> = [0.2f32, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]
+            .iter()
+            .map(|s| (s * i16::MAX as f32) as i16)
+            .collect();
+        assert_eq!(samples, expected);
+    }
+
+    #[test]
+    fn test_recompute_durations_corrects_duration_that_disagrees_with_wav() {
+        use crate::managers::meeting::db::{get_session, insert_session, update_session_duration};
+        use crate::managers::meeting::repair::recompute_session_durations;
+
+        let dir = tempdir().expect("Failed to create temp dir");
+        let db_path = dir.path().join("meetings.db");
+        let meetings_dir = dir.path().join("meetings");
+        init_meeting_database(&db_path).unwrap();
+
+        // Two seconds of audio stored as ten
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        write_test_wav(
+            &meetings_dir.join("session-1/audio.wav"),
+            &vec![0i16; 32000],
+        );
+        let mut wrong =
+            MeetingSession::new("session-1".to_string(), "Wrong".to_string(), 1705340400);
+        wrong.audio_path = Some("session-1/audio.wav".to_string());
+        insert_session(&db_path, &wrong).unwrap();
+        update_session_duration(&db_path, "session-1", 10, 16000).unwrap();
+
+        // Already correct
+        fs::create_dir_all(meetings_dir.join("session-2")).unwrap();
+        write_test_wav(
+            &meetings_dir.join("session-2/audio.wav"),
+            &vec![0i16; 16000],
+        );
+        let mut right =
+            MeetingSession::new("session-2".to_string(), "Right".to_string(), 1705344000);
+        right.audio_path = Some("session-2/audio.wav".to_string());
+        insert_session(&db_path, &right).unwrap();
+        update_session_duration(&db_path, "session-2", 1, 16000).unwrap();
+
+        // No audio
+        let imported =
+            MeetingSession::new("session-3".to_string(), "Imported".to_string(), 1705347600);
+        insert_session(&db_path, &imported).unwrap();
+
+        let fixed = recompute_session_durations(&db_path, &meetings_dir, &[]).unwrap();
+        assert_eq!(fixed, 1);
+        assert_eq!(
+            get_session(&db_path, "session-1")
+                .unwrap()
+                .unwrap()
+                .duration,
+            Some(2)
+        );
+        assert_eq!(
+            get_session(&db_path, "session-2")
+                .unwrap()
+                .unwrap()
+                .duration,
+            Some(1)
+        );
+        assert_eq!(
+            get_session(&db_path, "session-3")
+                .unwrap()
+                .unwrap()
+                .duration,
+            None
+        );
+
+        // Nothing left to fix
+        assert_eq!(
+            recompute_session_durations(&db_path, &meetings_dir, &[]).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_toggle_starts_then_stops_recording() {
+        use crate::managers::meeting::toggle::RecordingToggle;
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let toggle = RecordingToggle::default();
+        let recording = AtomicBool::new(false);
+        let toggle_once = || {
+            toggle.toggle(
+                || recording.load(Ordering::SeqCst),
+                || -> Result<()> {
+                    recording.store(true, Ordering::SeqCst);
+                    Ok(())
+                },
+                || {
+                    recording.store(false, Ordering::SeqCst);
+                    Ok(())
+                },
+            )
+        };
+
+        assert_eq!(toggle_once().unwrap(), ToggleAction::Started);
+        assert!(recording.load(Ordering::SeqCst));
+        assert_eq!(toggle_once().unwrap(), ToggleAction::Stopped);
+        assert!(!recording.load(Ordering::SeqCst));
+
+        // A failed start reports the error and leaves nothing recording
+        let failed: Result<ToggleAction> =
+            toggle.toggle(|| false, || Err(anyhow::anyhow!("no device")), || Ok(()));
+        assert!(failed.is_err());
+
+        // Two toggles at once start and stop rather than both starting
+        let toggle = Arc::new(RecordingToggle::default());
+        let recording = Arc::new(AtomicBool::new(false));
+        let starts = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let (toggle, recording, starts) = (
+                    Arc::clone(&toggle),
+                    Arc::clone(&recording),
+                    Arc::clone(&starts),
+                );
+                std::thread::spawn(move || {
+                    toggle.toggle(
+                        || recording.load(Ordering::SeqCst),
+                        || -> Result<()> {
+                            starts.fetch_add(1, Ordering::SeqCst);
+                            std::thread::sleep(std::time::Duration::from_millis(20));
+                            recording.store(true, Ordering::SeqCst);
+                            Ok(())
+                        },
+                        || {
+                            recording.store(false, Ordering::SeqCst);
+                            Ok(())
+                        },
+                    )
+                })
+            })
+            .collect();
+        let mut actions: Vec<ToggleAction> = handles
+            .into_iter()
+            .map(|h| h.join().unwrap().unwrap())
+            .collect();
+        actions.sort_by_key(|action| *action == ToggleAction::Stopped);
+        assert_eq!(actions, vec![ToggleAction::Started, ToggleAction::Stopped]);
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_wav_writer_handle_round_trips_known_samples() {
+        use crate::managers::meeting::disk::MEETING_WAV_SPEC;
+        use std::time::Duration;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("audio.wav");
+        let writer = hound::WavWriter::create(&path, MEETING_WAV_SPEC).unwrap();
+        let handle = WavWriterHandle::new(writer);
+
+        // A ramp, written in uneven chunks as the callback delivers them
+        let samples: Vec<f32> = (0..1000).map(|i| i as f32 / 1000.0 - 0.5).collect();
+        let sink = handle.sink();
+        for chunk in samples.chunks(333) {
+            sink.write_samples(chunk).unwrap();
+        }
+        handle
+            .finalize_with_timeout(Duration::from_secs(1))
+            .unwrap();
+
+        let reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec(), MEETING_WAV_SPEC);
+        assert_eq!(reader.len(), 1000);
+        let read: Vec<i16> = reader.into_samples::<i16>().map(|s| s.unwrap()).collect();
+        let expected: Vec<i16> = samples
+            .iter()
+            .map(|s| (s * i16::MAX as f32) as i16)
+            .collect();
+        assert_eq!(read, expected);
+    }
+
+    #[test]
+    fn test_48khz_recording_reaches_transcriber_as_16khz() {
+        use crate::managers::meeting::audio_cache::load_16k_samples;
+        use crate::managers::meeting::stream::WavWindowReader;
+        use crate::managers::meeting::transcript::{
+            run_transcription, run_windowed_transcription, Transcriber,
+        };
+        use std::cell::RefCell;
+        use std::io::BufReader;
+
+        /// Records how many samples each call received
+        struct CountingTranscriber {
+            calls: RefCell<Vec<usize>>,
+        }
+
+        impl Transcriber for CountingTranscriber {
+            fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+                self.calls.borrow_mut().push(samples.len());
+                Ok(TranscriptionResult::default())
+            }
+        }
+
+        // Three seconds of stereo at 48kHz, as a USB mic's native format would give
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&wav_path, spec).unwrap();
+        for i in 0..3 * 48000 {
+            let sample =
+                ((i as f32 * 440.0 * 2.0 * std::f32::consts::PI / 48000.0).sin() * 8000.0) as i16;
+            writer.write_sample(sample).unwrap();
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        // Whole-file path
+        let transcriber = CountingTranscriber {
+            calls: RefCell::new(Vec::new()),
+        };
+        let audio = load_16k_samples(&wav_path).unwrap().audio;
+        assert_eq!(audio.sample_rate, 16000);
+        assert_eq!(audio.channels, 1);
+        run_transcription(&transcriber, audio).unwrap();
+        assert_eq!(transcriber.calls.into_inner(), vec![3 * 16000]);
+
+        // Windowed path used for long recordings
+        let transcriber = CountingTranscriber {
+            calls: RefCell::new(Vec::new()),
+        };
+        let source = BufReader::new(fs::File::open(&wav_path).unwrap());
+        let windows = WavWindowReader::new(source, 1).unwrap();
+        run_windowed_transcription(&transcriber, windows, None, None).unwrap();
+        let total: usize = transcriber.calls.into_inner().iter().sum();
+        assert!(
+            (total as i64 - 3 * 16000).abs() <= 480,
+            "expected about 48000 samples, got {}",
+            total
+        );
+    }
+
+    #[test]
+    fn test_pause_and_resume_transitions() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let manager = TestMeetingManager::new(temp_dir.path());
+
+        let allowed = [
+            (MeetingStatus::Recording, MeetingStatus::Paused),
+            (MeetingStatus::Paused, MeetingStatus::Recording),
+            (MeetingStatus::Paused, MeetingStatus::Processing),
+            (MeetingStatus::Paused, MeetingStatus::Interrupted),
+        ];
+        for (from, to) in &allowed {
+            assert!(
+                manager.validate_state_transition(from, to).is_ok(),
+                "{:?} -> {:?} should be valid",
+                from,
+                to
+            );
+        }
+
+        let rejected = [
+            (MeetingStatus::Idle, MeetingStatus::Paused),
+            (MeetingStatus::Paused, MeetingStatus::Paused),
+            (MeetingStatus::Processing, MeetingStatus::Paused),
+            (MeetingStatus::Paused, MeetingStatus::Completed),
+        ];
+        for (from, to) in &rejected {
+            assert!(
+                manager.validate_state_transition(from, to).is_err(),
+                "{:?} -> {:?} should be invalid",
+                from,
+                to
+            );
+        }
+
+        // A paused session still holds its capture, and its status survives the database
+        assert!(MeetingStatus::Paused.is_capturing());
+        assert!(!MeetingStatus::Recorded.is_capturing());
+        let session = manager.create_session().expect("Failed to create session");
+        manager
+            .update_session_status(&session.id, MeetingStatus::Paused)
+            .expect("Failed to set to Paused");
+        let stored = manager
+            .get_session(&session.id)
+            .expect("Failed to get session")
+            .expect("Session not found");
+        assert_eq!(stored.status, MeetingStatus::Paused);
+    }
+
+    #[test]
+    fn test_duration_from_samples_written_rounds_to_nearest_second() {
+        use crate::managers::meeting::disk::MEETING_WAV_SPEC;
+        use crate::managers::meeting::wav_writer::recorded_duration;
+        use std::time::Duration;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+
+        // Just under and exactly at the half second past 2s, at 16 kHz mono
+        for (samples, expected) in [(16000 * 2 + 7999, 2), (16000 * 2 + 8000, 3)] {
+            let path = temp_dir.path().join(format!("{}.wav", samples));
+            let writer = hound::WavWriter::create(&path, MEETING_WAV_SPEC).unwrap();
+            let handle = WavWriterHandle::new(writer);
+            let written = handle.samples_written();
+
+            let sink = handle.sink();
+            let audio = vec![0.0f32; samples];
+            for chunk in audio.chunks(1024) {
+                sink.write_samples(chunk).unwrap();
+            }
+            handle
+                .finalize_with_timeout(Duration::from_secs(1))
+                .unwrap();
+
+            assert_eq!(written.count(), samples as u64);
+            let duration = written.duration();
+            assert_eq!(duration.seconds, expected, "{} samples", samples);
+            assert_eq!(duration.sample_rate, MEETING_WAV_SPEC.sample_rate);
+            // The count agrees with what the file header says
+            assert_eq!(recorded_duration(&path).unwrap(), duration);
+        }
+    }
+
+    #[test]
+    fn test_audio_source_migration_defaults_existing_rows_to_microphone_only() {
+        use crate::managers::meeting::db::{get_session, MEETING_DB_EXPECTED_VERSION};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("test_meetings_v1.db");
+
+        // A database written before the audio_source column existed
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE meeting_sessions (
+                    id TEXT PRIMARY KEY,
+                    title TEXT NOT NULL,
+                    created_at INTEGER NOT NULL,
+                    duration INTEGER,
+                    status TEXT NOT NULL DEFAULT 'idle',
+                    audio_path TEXT,
+                    transcript_path TEXT,
+                    error_message TEXT
+                );
+                INSERT INTO meeting_sessions (id, title, created_at, duration, status, audio_path)
+                VALUES ('old-session', 'Old meeting', 1705340400, 60, 'completed', 'old-session/audio.wav');
+                PRAGMA user_version = 1;",
+            )
+            .unwrap();
+        }
+
+        init_meeting_database(&db_path).expect("Failed to migrate database");
+
+        let conn = Connection::open(&db_path).unwrap();
+        let version: i32 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, MEETING_DB_EXPECTED_VERSION);
+
+        let session = get_session(&db_path, "old-session").unwrap().unwrap();
+        assert_eq!(session.audio_source, AudioSourceType::MicrophoneOnly);
+        assert_eq!(session.title, "Old meeting");
+        assert_eq!(session.status, MeetingStatus::Completed);
+    }
+
+    #[test]
+    fn test_session_started_from_template_carries_its_title_and_source() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, update_session_template_id,
+        };
+        use crate::managers::meeting::preview::{require_template, resolve_audio_source};
+        use crate::managers::meeting::title::render_title;
+        use crate::settings::get_default_settings;
+
+        let mut settings = get_default_settings();
+        let mut template = settings.meeting_templates[0].clone();
+        template.id = "template_design_review".to_string();
+        template.title_template = "Design review".to_string();
+        template.audio_source = "mixed".to_string();
+        settings.meeting_templates.push(template);
+
+        let template = require_template(&settings, "template_design_review").unwrap();
+        let source = resolve_audio_source(None, Some(&template));
+        let title = render_title(&template, chrono::Local::now(), Some(1));
+        assert_eq!(source, AudioSourceType::Mixed);
+        assert_eq!(title, "Design review");
+
+        // The session is stored with the template's source, title, and id
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).unwrap();
+        let mut session = MeetingSession::new("s1".to_string(), title, 1705340400);
+        session.audio_source = source;
+        insert_session(&db_path, &session).unwrap();
+        update_session_template_id(&db_path, "s1", &template.id).unwrap();
+
+        let stored = get_session(&db_path, "s1").unwrap().unwrap();
+        assert_eq!(stored.title, "Design review");
+        assert_eq!(stored.audio_source, AudioSourceType::Mixed);
+        assert_eq!(
+            stored.template_id.as_deref(),
+            Some("template_design_review")
+        );
+
+        // An unknown template is an error naming it, not a start without one
+        let error = require_template(&settings, "template_missing").unwrap_err();
+        assert!(error.contains("template_missing"), "{}", error);
+    }
+
+    #[test]
+    fn test_start_without_template_id_falls_back_to_active_template() {
+        use crate::managers::meeting::preview::{
+            find_template, recording_options, resolve_audio_source, resolve_meeting_config,
+        };
+        use crate::settings::get_default_settings;
+
+        let mut settings = get_default_settings();
+        let mut template = settings.meeting_templates[0].clone();
+        template.id = "custom_interview".to_string();
+        template.audio_source = "mixed".to_string();
+        template.normalize_loudness = true;
+        settings.meeting_templates.push(template);
+
+        // Nothing active: a plain start uses no template
+        assert!(find_template(&settings, None).is_none());
+        assert_eq!(
+            resolve_audio_source(None, find_template(&settings, None).as_ref()),
+            AudioSourceType::MicrophoneOnly
+        );
+
+        // With an active template, a start without an id picks it up
+        let active = settings
+            .set_active_meeting_template(Some("custom_interview".to_string()))
+            .unwrap();
+        assert_eq!(active.map(|t| t.id), Some("custom_interview".to_string()));
+        let template = find_template(&settings, None).expect("Active template should be used");
+        assert_eq!(template.id, "custom_interview");
+        assert_eq!(
+            resolve_audio_source(None, Some(&template)),
+            AudioSourceType::Mixed
+        );
+        assert!(recording_options(Some(&template)).normalize_loudness);
+        let preview = resolve_meeting_config(&settings, None, None, None);
+        assert_eq!(preview.template_id.as_deref(), Some("custom_interview"));
+
+        // An explicit id still wins over the active template
+        let standup = find_template(&settings, Some("template_team_standup")).unwrap();
+        assert_eq!(standup.id, "template_team_standup");
+
+        // Clearing the active template goes back to plain starts
+        assert!(settings
+            .set_active_meeting_template(None)
+            .unwrap()
+            .is_none());
+        assert!(find_template(&settings, None).is_none());
+    }
+
+    #[test]
+    fn test_active_template_id_is_cleared_once_its_template_is_deleted() {
+        use crate::managers::meeting::preview::find_template;
+        use crate::settings::get_default_settings;
+
+        let mut settings = get_default_settings();
+        let mut template = settings.meeting_templates[0].clone();
+        template.id = "custom_retro".to_string();
+        settings.meeting_templates.push(template);
+        settings
+            .set_active_meeting_template(Some("custom_retro".to_string()))
+            .unwrap();
+
+        // Activating an unknown template fails and keeps the current one
+        let error = settings
+            .set_active_meeting_template(Some("custom_missing".to_string()))
+            .unwrap_err();
+        assert!(error.contains("custom_missing"), "{}", error);
+        assert_eq!(
+            settings.active_meeting_template_id.as_deref(),
+            Some("custom_retro")
+        );
+
+        // A template that still exists is left active
+        assert_eq!(settings.clear_missing_active_template(), None);
+        assert_eq!(
+            settings.active_meeting_template_id.as_deref(),
+            Some("custom_retro")
+        );
+
+        // Once the template is gone, the stale id is cleared, and only once
+        settings
+            .meeting_templates
+            .retain(|t| t.id != "custom_retro");
+        assert!(find_template(&settings, None).is_none());
+        assert_eq!(
+            settings.clear_missing_active_template().as_deref(),
+            Some("custom_retro")
+        );
+        assert_eq!(settings.active_meeting_template_id, None);
+        assert_eq!(settings.clear_missing_active_template(), None);
+    }
+
+    #[test]
+    fn test_render_title_expands_each_placeholder() {
+        use crate::managers::meeting::title::{is_generated_title, render_title};
+        use crate::settings::get_default_settings;
+        use chrono::{Local, TimeZone};
+
+        let mut template = get_default_settings().meeting_templates[0].clone();
+        // A Wednesday
+        let now = Local.with_ymd_and_hms(2025, 1, 15, 9, 5, 0).unwrap();
+        let render =
+            |template: &crate::settings::MeetingTemplate| render_title(template, now, Some(7));
+
+        template.title_template = "Standup - {date}".to_string();
+        assert_eq!(render(&template), "Standup - 2025-01-15");
+        template.title_template = "Sync at {time}".to_string();
+        assert_eq!(render(&template), "Sync at 09:05");
+        template.title_template = "{weekday} review".to_string();
+        assert_eq!(render(&template), "Wednesday review");
+        template.title_template = "Retro #{count}".to_string();
+        assert_eq!(render(&template), "Retro #7");
+
+        // Without placeholders the title is used as written
+        template.title_template = "Board meeting".to_string();
+        assert_eq!(render(&template), "Board meeting");
+
+        // Unknown placeholders are kept rather than failing
+        template.title_template = "{weekday} {team} sync".to_string();
+        assert_eq!(render(&template), "Wednesday {team} sync");
+
+        // Rendered titles still count as generated, so auto-titling may replace them
+        template.title_template = "{weekday} retro #{count} ({date} {time})".to_string();
+        let title = render(&template);
+        assert_eq!(title, "Wednesday retro #7 (2025-01-15 09:05)");
+        assert!(is_generated_title(&title, Some(&template.title_template)));
+        assert!(!is_generated_title(
+            "Wednesday retro #seven (2025-01-15 09:05)",
+            Some(&template.title_template)
+        ));
+    }
+
+    #[test]
+    fn test_template_summary_prompt_receives_the_transcript() {
+        use crate::managers::meeting::summary::{summarize_transcript, SummaryBackend};
+        use crate::settings::get_default_settings;
+        use std::cell::RefCell;
+
+        /// Records the prompts it is sent instead of calling an LLM
+        struct StubBackend {
+            prompts: RefCell<Vec<String>>,
+        }
+
+        impl SummaryBackend for StubBackend {
+            async fn complete(&self, prompt: String) -> Result<String, String> {
+                self.prompts.borrow_mut().push(prompt);
+                Ok("- Ship on Friday".to_string())
+            }
+        }
+
+        let backend = StubBackend {
+            prompts: RefCell::new(Vec::new()),
+        };
+        let transcript = "We agreed to ship the release on Friday.";
+        let mut template = get_default_settings().meeting_templates[0].clone();
+        template.summary_prompt_template =
+            Some("List the decisions in this standup:\n{}\nUse bullet points.".to_string());
+
+        let summary = tauri::async_runtime::block_on(summarize_transcript(
+            transcript,
+            Some(&template),
+            &backend,
+        ))
+        .unwrap();
+        assert_eq!(summary, "- Ship on Friday");
+        assert_eq!(
+            backend.prompts.take(),
+            vec![
+                "List the decisions in this standup:\nWe agreed to ship the release on Friday.\nUse bullet points."
+                    .to_string()
+            ]
+        );
+
+        // Without a custom prompt the default one still carries the transcript
+        tauri::async_runtime::block_on(summarize_transcript(transcript, None, &backend)).unwrap();
+        let prompts = backend.prompts.take();
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains(transcript));
+        assert!(!prompts[0].contains("{}"));
+    }
+
+    #[test]
+    fn test_new_transcript_is_saved_with_custom_words_applied() {
+        use crate::managers::meeting::db::{get_session, insert_session};
+        use crate::managers::meeting::transcript::{
+            read_transcript_details, save_transcript, Segment, TranscriptionResult,
+        };
+        use crate::settings::get_default_settings;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).unwrap();
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+        let session = MeetingSession::new("session-1".to_string(), "Sync".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+
+        let raw = TranscriptionResult {
+            text: "We moved the cluster to kubernetis.\nRollout went fine.".to_string(),
+            segments: vec![Segment {
+                start: 0.0,
+                end: 2.5,
+                text: "We moved the cluster to kubernetis.".to_string(),
+            }],
+            ..Default::default()
+        };
+        let mut settings = get_default_settings();
+        settings.custom_words = vec!["Kubernetes".to_string()];
+        settings.word_correction_threshold = 0.18;
+        assert!(settings.meeting_apply_custom_words);
+
+        let rel_path =
+            save_transcript(&db_path, &meetings_dir, "session-1", &raw, &settings).unwrap();
+        let transcript_path = meetings_dir.join(&rel_path);
+        assert_eq!(
+            fs::read_to_string(&transcript_path).unwrap(),
+            "We moved the cluster to Kubernetes.\nRollout went fine."
+        );
+        let details = read_transcript_details(&meetings_dir, "session-1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            details.segments[0].text,
+            "We moved the cluster to Kubernetes."
+        );
+        assert_eq!(details.segments[0].end, 2.5);
+        let stored = get_session(&db_path, "session-1").unwrap().unwrap();
+        assert_eq!(stored.status, MeetingStatus::Completed);
+        assert_eq!(stored.transcript_path.as_deref(), Some(rel_path.as_str()));
+
+        // With the setting off the transcript stays verbatim
+        settings.meeting_apply_custom_words = false;
+        save_transcript(&db_path, &meetings_dir, "session-1", &raw, &settings).unwrap();
+        assert_eq!(fs::read_to_string(&transcript_path).unwrap(), raw.text);
+        let details = read_transcript_details(&meetings_dir, "session-1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.segments, raw.segments);
+    }
+
+    #[test]
+    fn test_live_captions_get_custom_words_applied() {
+        use crate::managers::meeting::corrections::CustomWords;
+        use crate::managers::meeting::live::{run_live_worker, LiveChunker};
+        use crate::managers::meeting::transcript::{Transcriber, TranscriptionResult};
+        use crate::settings::get_default_settings;
+
+        struct StubTranscriber;
+
+        impl Transcriber for StubTranscriber {
+            fn transcribe_detailed(&self, _samples: Vec<f32>) -> Result<TranscriptionResult> {
+                Ok(TranscriptionResult::text_only("deploy to kubernetis"))
+            }
+        }
+
+        let mut settings = get_default_settings();
+        settings.custom_words = vec!["Kubernetes".to_string()];
+        settings.word_correction_threshold = 0.18;
+
+        let caption = |settings: &crate::settings::AppSettings| {
+            let (mut chunker, chunks) = LiveChunker::new(1, 16000, 1);
+            chunker.push(&[0.1; 16000]);
+            drop(chunker);
+            let custom_words = CustomWords::from_settings(settings);
+            let mut texts = Vec::new();
+            run_live_worker(
+                &StubTranscriber,
+                "session-1",
+                custom_words.as_ref(),
+                chunks,
+                |partial| texts.push(partial.text),
+            );
+            texts
+        };
+
+        assert_eq!(caption(&settings), vec!["deploy to Kubernetes"]);
+        settings.meeting_apply_custom_words = false;
+        assert_eq!(caption(&settings), vec!["deploy to kubernetis"]);
+    }
+
+    #[test]
+    fn test_saved_transcript_json_deserializes_into_segments() {
+        use crate::managers::meeting::transcript::{
+            write_transcript_details, Segment, TranscriptionResult, TRANSCRIPT_DETAILS_FILE,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path();
+        fs::create_dir_all(meetings_dir.join("session-1")).unwrap();
+
+        let segments = vec![
+            Segment {
+                start: 0.0,
+                end: 1.5,
+                text: "Good morning everyone.".to_string(),
+            },
+            Segment {
+                start: 1.5,
+                end: 4.25,
+                text: "Let's start with the roadmap.".to_string(),
+            },
+        ];
+        let result = TranscriptionResult {
+            text: "Good morning everyone. Let's start with the roadmap.".to_string(),
+            language: Some("en".to_string()),
+            segments: segments.clone(),
+            ..Default::default()
+        };
+        let rel_path = write_transcript_details(meetings_dir, "session-1", &result).unwrap();
+        assert_eq!(rel_path, format!("session-1/{}", TRANSCRIPT_DETAILS_FILE));
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&fs::read(meetings_dir.join(&rel_path)).unwrap()).unwrap();
+        let saved: Vec<Segment> = serde_json::from_value(json["segments"].clone()).unwrap();
+        assert_eq!(saved, segments);
+    }
+
+    #[test]
+    fn test_live_chunks_are_transcribed_with_their_offsets_without_blocking() {
+        use crate::managers::meeting::live::{run_live_worker, LiveChunker};
+        use crate::managers::meeting::transcript::{
+            PartialTranscript, Transcriber, TranscriptionResult,
+        };
+
+        /// Names each chunk by its first sample, and returns nothing for silence
+        struct StubTranscriber;
+
+        impl Transcriber for StubTranscriber {
+            fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+                let text = match samples.first() {
+                    Some(first) if *first != 0.0 => format!(" starts at {} ", first),
+                    _ => String::new(),
+                };
+                Ok(TranscriptionResult::text_only(text))
+            }
+        }
+
+        // 2-second chunks of 16 kHz mono, fed in pieces that don't line up with them
+        let (mut chunker, chunks) = LiveChunker::new(2, 16000, 1);
+        let samples: Vec<f32> = (0..32000 * 3 + 500)
+            .map(|i| if i < 32000 { 0.0 } else { (i / 32000) as f32 })
+            .collect();
+        for piece in samples.chunks(5000) {
+            chunker.push(piece);
+        }
+        // Nothing consumed the queue, so the third chunk was skipped rather than waited on
+        drop(chunker);
+
+        let mut events: Vec<PartialTranscript> = Vec::new();
+        run_live_worker(&StubTranscriber, "session-1", None, chunks, |partial| {
+            events.push(partial)
+        });
+
+        // The silent first chunk gives no caption; the partial fourth was never sent
+        assert_eq!(
+            events,
+            vec![PartialTranscript {
+                session_id: "session-1".to_string(),
+                chunk: 1,
+                text: "starts at 1".to_string(),
+                offset_secs: Some(2.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_vad_trim_drops_silence_around_speech() {
+        use crate::audio_toolkit::vad::VadFrame;
+        use crate::audio_toolkit::VoiceActivityDetector;
+        use crate::managers::meeting::speech::{trim_to_speech, TRIM_PADDING_MS};
+
+        /// Treats any frame with noticeable energy as speech
+        struct EnergyVad;
+
+        impl VoiceActivityDetector for EnergyVad {
+            fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+                let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32;
+                Ok(if energy > 0.001 {
+                    VadFrame::Speech(frame)
+                } else {
+                    VadFrame::Noise
+                })
+            }
+        }
+
+        // Ten seconds of silence, a second of 220 Hz tone, then ten more of silence
+        let silence = vec![0.0f32; 160_000];
+        let tone: Vec<f32> = (0..16000)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16000.0).sin())
+            .collect();
+        let samples: Vec<f32> = [&silence[..], &tone[..], &silence[..]].concat();
+
+        let trimmed = trim_to_speech(&mut EnergyVad, &samples, TRIM_PADDING_MS).unwrap();
+
+        // The tone and its padding survive; the twenty seconds of silence don't
+        assert!(trimmed.samples.len() >= tone.len());
+        assert!(
+            trimmed.samples.len() < samples.len() / 10,
+            "trimmed to {} of {} samples",
+            trimmed.samples.len(),
+            samples.len()
+        );
+        // Times in the trimmed audio map back to where the tone was recorded
+        let padding_secs = TRIM_PADDING_MS as f32 / 1000.0;
+        assert!((trimmed.map.original_secs(0.0) - (10.0 - padding_secs)).abs() < 0.05);
+        assert!((trimmed.map.original_secs(padding_secs + 0.5) - 10.5).abs() < 0.05);
+
+        // All silence leaves nothing to transcribe
+        let empty = trim_to_speech(&mut EnergyVad, &silence, TRIM_PADDING_MS).unwrap();
+        assert!(empty.samples.is_empty());
+    }
+
+    #[test]
+    fn test_audio_level_is_batch_rms_reported_once_per_interval() {
+        use crate::managers::meeting::level::{rms_level, LevelMeter, LEVEL_EMIT_INTERVAL};
+        use std::time::{Duration, Instant};
+
+        assert_eq!(rms_level(&[]), 0.0);
+        assert_eq!(rms_level(&[0.0; 480]), 0.0);
+        assert!((rms_level(&[0.5, -0.5, 0.5, -0.5]) - 0.5).abs() < 1e-6);
+        // A full-scale sine has an RMS of 1/sqrt(2)
+        let sine: Vec<f32> = (0..16000)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 16000.0).sin())
+            .collect();
+        assert!((rms_level(&sine) - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3);
+        // Clipped input never reports above full scale
+        assert_eq!(rms_level(&[1.5, -1.5]), 1.0);
+
+        let start = Instant::now();
+        let mut meter = LevelMeter::new(LEVEL_EMIT_INTERVAL);
+        assert_eq!(meter.observe(&[0.25, -0.25], start), Some(0.25));
+        // Batches inside the interval are folded into the next report, keeping the loudest
+        assert_eq!(
+            meter.observe(&[0.75, -0.75], start + Duration::from_millis(30)),
+            None
+        );
+        assert_eq!(
+            meter.observe(&[0.5, -0.5], start + Duration::from_millis(60)),
+            None
+        );
+        assert_eq!(
+            meter.observe(&[0.0, 0.0], start + Duration::from_millis(100)),
+            Some(0.75)
+        );
+    }
+
+    #[test]
+    fn test_requested_input_device_falls_back_to_default_when_missing() {
+        use crate::managers::meeting::input_check::select_input_device;
+
+        let devices = || vec!["MacBook Pro Microphone", "USB Headset", "Studio Mic"];
+
+        assert_eq!(
+            select_input_device(devices(), Some("USB Headset"), |name| *name),
+            Some("USB Headset")
+        );
+        // An unplugged device records from the default instead of failing
+        assert_eq!(
+            select_input_device(devices(), Some("Bluetooth Earbuds"), |name| *name),
+            None
+        );
+        assert_eq!(select_input_device(devices(), None, |name| *name), None);
+    }
+
+    #[test]
+    fn test_transcript_search_finds_only_the_matching_session() {
+        use crate::managers::meeting::db::{insert_session, update_session_transcript};
+        use crate::managers::meeting::search::{
+            index_transcript, needs_reindex, reindex_transcripts, search_transcripts,
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().join("meetings");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let transcripts = [
+            (
+                "Planning",
+                "We agreed to cut the travel budget for next quarter.",
+            ),
+            ("Standup", "Everyone is on track with their tickets."),
+        ];
+        let mut ids = Vec::new();
+        for (title, text) in transcripts {
+            let session = MeetingSession::new(Uuid::new_v4().to_string(), title.to_string(), 0);
+            let rel_path = format!("{}/transcript.txt", session.id);
+            fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+            fs::write(meetings_dir.join(&rel_path), text).unwrap();
+            insert_session(&db_path, &session).unwrap();
+            update_session_transcript(&db_path, &session.id, &rel_path, &MeetingStatus::Completed)
+                .unwrap();
+            ids.push(session.id);
+        }
+
+        // Transcripts written before the index existed are picked up once
+        assert!(needs_reindex(&db_path).unwrap());
+        assert_eq!(reindex_transcripts(&db_path, &meetings_dir).unwrap(), 2);
+        assert!(!needs_reindex(&db_path).unwrap());
+
+        let results = search_transcripts(&db_path, "budget", 50).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].session.id, ids[0]);
+        assert_eq!(results[0].session.title, "Planning");
+        assert!(results[0].snippet.contains("**budget**"));
+
+        // The last word matches as a prefix, and query syntax is searched as text
+        assert_eq!(
+            search_transcripts(&db_path, "tick", 50).unwrap()[0]
+                .session
+                .id,
+            ids[1]
+        );
+        assert!(search_transcripts(&db_path, "\"budget OR", 50)
+            .unwrap()
+            .is_empty());
+        assert!(search_transcripts(&db_path, "   ", 50).unwrap().is_empty());
+
+        // Re-indexing a session replaces its old text
+        index_transcript(&db_path, &ids[0], "Nothing about money.").unwrap();
+        assert!(search_transcripts(&db_path, "budget", 50)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_panicking_worker_releases_the_queue() {
+        use crate::managers::meeting::queue::{DrainGuard, TranscriptionQueue};
+        use std::sync::Mutex;
+
+        let queue = Mutex::new(TranscriptionQueue::new());
+        assert!(queue.lock().unwrap().enqueue("session-1"));
+        assert!(!queue.lock().unwrap().enqueue("session-2"));
+
+        let result = std::panic::catch_unwind(|| {
+            let _guard = DrainGuard::new(&queue);
+            let next = queue.lock().unwrap().start_next();
+            assert_eq!(next.as_deref(), Some("session-1"));
+            panic!("engine crashed");
+        });
+        assert!(result.is_err());
+
+        // The crashed job is gone, the waiting one isn't, and a new worker is needed
+        let mut queue = queue.lock().unwrap_or_else(|p| p.into_inner());
+        assert_eq!(queue.running(), None);
+        assert!(queue.contains("session-2"));
+        assert!(queue.enqueue("session-3"));
+    }
+
+    #[test]
+    fn test_converted_audio_cache_is_16_bit_and_removable() {
+        use crate::managers::meeting::audio_cache::{
+            cache_path_for, load_16k_samples, remove_converted_audio,
+        };
+        use hound::{WavSpec, WavWriter};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("audio.wav");
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = WavWriter::create(&wav_path, spec).unwrap();
+        for i in 0..16000 {
+            writer.write_sample((i as f32 * 0.01).sin() * 0.5).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let converted = load_16k_samples(&wav_path).unwrap();
+        let cache_len = fs::metadata(cache_path_for(&wav_path)).unwrap().len();
+        let wav_len = fs::metadata(&wav_path).unwrap().len();
+        // Two bytes per sample, so half the size of the f32 recording
+        assert!(cache_len < 16000 * 2 + 64);
+        assert!(cache_len < wav_len / 2 + 64);
+        assert_eq!(load_16k_samples(&wav_path).unwrap().audio, converted.audio);
+
+        remove_converted_audio(temp_dir.path()).unwrap();
+        assert!(!cache_path_for(&wav_path).exists());
+        // Nothing to remove is fine too
+        remove_converted_audio(temp_dir.path()).unwrap();
+    }
+
+    #[test]
+    fn test_archive_of_16_bit_recording_is_smaller_and_lossless() {
+        use crate::managers::meeting::archive::{verify_archive, write_archive, ARCHIVE_FILENAME};
+        use crate::managers::meeting::audio_cache::read_audio;
+        use hound::{WavSpec, WavWriter};
+        use std::time::Duration;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let source = temp_dir.path().join("audio.wav");
+        let archive = temp_dir.path().join(ARCHIVE_FILENAME);
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        // Two seconds of a quiet tone, the kind of signal a meeting recording is mostly made of
+        let original: Vec<i16> = (0..32000)
+            .map(|i| ((i as f32 * 0.07).sin() * 3000.0 + (i as f32 * 0.011).sin() * 1000.0) as i16)
+            .collect();
+        let mut writer = WavWriter::create(&source, spec).unwrap();
+        for &sample in &original {
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let frames = write_archive(&source, &archive).unwrap();
+        assert_eq!(frames, 32000);
+        verify_archive(&archive, frames).unwrap();
+        // A frame count that doesn't match means the archive can't stand in for the original
+        assert!(verify_archive(&archive, frames + 1).is_err());
+
+        // The archive is a real compression of the 16-bit source, not a same-size copy
+        let source_size = fs::metadata(&source).unwrap().len();
+        let archive_size = fs::metadata(&archive).unwrap().len();
+        assert!(
+            archive_size * 2 < source_size,
+            "archive is {} bytes, source {} bytes",
+            archive_size,
+            source_size
+        );
+
+        // ...and still decodes to every original sample and the stored duration
+        let decoded = read_audio(&archive).unwrap();
+        assert_eq!(decoded.channels, 1);
+        assert_eq!(decoded.duration(), Duration::from_secs(2));
+        let archived: Vec<i16> = decoded
+            .samples
+            .iter()
+            .map(|sample| (sample * i16::MAX as f32).round() as i16)
+            .collect();
+        assert_eq!(archived, original);
+
+        // A truncated archive fails verification rather than replacing the original
+        let bytes = fs::read(&archive).unwrap();
+        fs::write(&archive, &bytes[..bytes.len() / 2]).unwrap();
+        assert!(verify_archive(&archive, frames).is_err());
+    }
+
+    #[test]
+    fn test_classify_speech_skips_noise_without_speech() {
+        use crate::audio_toolkit::vad::VadFrame;
+        use crate::audio_toolkit::VoiceActivityDetector;
+        use crate::managers::meeting::silence::{classify_speech, RecordingContent};
+        use crate::managers::meeting::speech::SPEECH_FRAME_SAMPLES;
+
+        /// Reports speech only for frames whose first sample is positive
+        struct MarkerVad;
+
+        impl VoiceActivityDetector for MarkerVad {
+            fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+                Ok(if frame[0] > 0.0 {
+                    VadFrame::Speech(frame)
+                } else {
+                    VadFrame::Noise
+                })
+            }
+        }
+
+        // Loud noise the VAD doesn't hear as speech
+        let noise = vec![-0.5f32; SPEECH_FRAME_SAMPLES * 50];
+        assert_eq!(
+            classify_speech(&mut MarkerVad, &noise).unwrap(),
+            RecordingContent::Silent
+        );
+
+        // A single speech frame isn't enough
+        let mut blip = noise.clone();
+        blip[SPEECH_FRAME_SAMPLES * 10] = 0.5;
+        assert_eq!(
+            classify_speech(&mut MarkerVad, &blip).unwrap(),
+            RecordingContent::Silent
+        );
+
+        // A few speech frames are
+        let mut speech = noise.clone();
+        for frame in 10..13 {
+            speech[SPEECH_FRAME_SAMPLES * frame] = 0.5;
+        }
+        assert_eq!(
+            classify_speech(&mut MarkerVad, &speech).unwrap(),
+            RecordingContent::Signal
+        );
+
+        assert_eq!(
+            classify_speech(&mut MarkerVad, &[]).unwrap(),
+            RecordingContent::Empty
+        );
+    }
+
+    #[test]
+    fn test_no_speech_note_is_not_an_error() {
+        use crate::managers::meeting::db::{
+            get_session, insert_session, update_session_status_with_error,
+        };
+        use crate::managers::meeting::repair::repair_session_status;
+        use crate::managers::meeting::silence::NO_SPEECH_NOTE;
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let meetings_dir = temp_dir.path().to_path_buf();
+        let db_path = meetings_dir.join("meetings.db");
+        init_meeting_database(&db_path).expect("Failed to initialize database");
+
+        let session =
+            MeetingSession::new(Uuid::new_v4().to_string(), "Quiet".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+        let transcript_rel = format!("{}/transcript.txt", session.id);
+        fs::create_dir_all(meetings_dir.join(&session.id)).unwrap();
+        fs::write(meetings_dir.join(&transcript_rel), "").unwrap();
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute(
+            "UPDATE meeting_sessions SET transcript_path = ?1 WHERE id = ?2",
+            params![transcript_rel, session.id],
+        )
+        .unwrap();
+
+        // Older versions stored the note as the error message
+        update_session_status_with_error(
+            &db_path,
+            &session.id,
+            &MeetingStatus::Completed,
+            NO_SPEECH_NOTE,
+        )
+        .unwrap();
+        let stored = get_session(&db_path, &session.id).unwrap().unwrap();
+
+        let repaired = repair_session_status(&db_path, &meetings_dir, &stored, false).unwrap();
+        assert_eq!(repaired.status, MeetingStatus::Completed);
+        assert_eq!(repaired.error_message, None);
+        assert_eq!(repaired.status_note.as_deref(), Some(NO_SPEECH_NOTE));
+    }
+
+    #[test]
+    fn test_streamed_windows_are_trimmed_with_recording_times() {
+        use crate::audio_toolkit::vad::VadFrame;
+        use crate::audio_toolkit::VoiceActivityDetector;
+        use crate::managers::meeting::speech::TRIM_PADDING_MS;
+        use crate::managers::meeting::stream::WavWindowReader;
+        use crate::managers::meeting::transcript::{run_windowed_transcription, Transcriber};
+        use std::cell::RefCell;
+        use std::io::BufReader;
+
+        /// Treats any frame with noticeable energy as speech
+        struct EnergyVad;
+
+        impl VoiceActivityDetector for EnergyVad {
+            fn push_frame<'a>(&'a mut self, frame: &'a [f32]) -> Result<VadFrame<'a>> {
+                let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32;
+                Ok(if energy > 0.001 {
+                    VadFrame::Speech(frame)
+                } else {
+                    VadFrame::Noise
+                })
+            }
+        }
+
+        /// Answers every window with one segment spanning all of it
+        struct SpanTranscriber {
+            calls: RefCell<Vec<usize>>,
+        }
+
+        impl Transcriber for SpanTranscriber {
+            fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+                self.calls.borrow_mut().push(samples.len());
+                let secs = samples.len() as f32 / 16000.0;
+                Ok(TranscriptionResult {
+                    text: "speech".to_string(),
+                    segments: vec![Segment {
+                        start: 0.0,
+                        end: secs,
+                        text: "speech".to_string(),
+                    }],
+                    ..TranscriptionResult::default()
+                })
+            }
+        }
+
+        // 30 s read in 10 s windows, the way recordings of an hour or more are
+        // streamed: a silent window, then tones at 10-12 s and 25-26 s
+        let tone = |i: usize| {
+            ((i as f32 * 440.0 * 2.0 * std::f32::consts::PI / 16000.0).sin() * 8000.0) as i16
+        };
+        let samples: Vec<i16> = (0..30 * 16000)
+            .map(|i| match i {
+                160_000..=191_999 | 400_000..=415_999 => tone(i),
+                _ => 0,
+            })
+            .collect();
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let wav_path = temp_dir.path().join("long.wav");
+        write_test_wav(&wav_path, &samples);
+
+        let transcriber = SpanTranscriber {
+            calls: RefCell::new(Vec::new()),
+        };
+        let source = BufReader::new(fs::File::open(&wav_path).unwrap());
+        let windows = WavWindowReader::new(source, 10).unwrap();
+        let mut vad = EnergyVad;
+        let result =
+            run_windowed_transcription(&transcriber, windows, Some(&mut vad), None).unwrap();
+
+        // The silent window is never transcribed, the others only around their tone
+        let calls = transcriber.calls.into_inner();
+        assert_eq!(calls.len(), 2, "calls: {:?}", calls);
+        assert!(
+            calls.iter().all(|&len| len < 4 * 16000),
+            "calls: {:?}",
+            calls
+        );
+
+        // Segment times refer to the recording, not the trimmed windows; the
+        // first tone starts a window, so it has no padding before it
+        let padding = TRIM_PADDING_MS as f32 / 1000.0;
+        let expected = [(10.0, 12.0 + padding), (25.0 - padding, 26.0 + padding)];
+        assert_eq!(result.segments.len(), 2);
+        for (segment, (start, end)) in result.segments.iter().zip(expected) {
+            assert!((segment.start - start).abs() < 0.05, "{:?}", segment);
+            assert!((segment.end - end).abs() < 0.05, "{:?}", segment);
+        }
+        assert_eq!(result.text, "speech speech");
+    }
+
+    #[test]
+    fn test_template_numbers_only_increase() {
+        use crate::managers::meeting::db::{
+            delete_session_record, insert_session, next_template_number, update_session_template_id,
+        };
+        use crate::managers::meeting::title::{is_generated_title, render_title};
+        use crate::settings::get_default_settings;
+        use chrono::{Local, TimeZone};
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("meetings.db");
+        init_meeting_database(&db_path).unwrap();
+
+        assert_eq!(next_template_number(&db_path, "standup").unwrap(), 1);
+        let session = MeetingSession::new("s1".to_string(), "Standup".to_string(), 1705340400);
+        insert_session(&db_path, &session).unwrap();
+        update_session_template_id(&db_path, "s1", "standup").unwrap();
+        assert_eq!(next_template_number(&db_path, "standup").unwrap(), 2);
+
+        // Deleting a session doesn't hand its number out again
+        delete_session_record(&db_path, "s1").unwrap();
+        assert_eq!(next_template_number(&db_path, "standup").unwrap(), 3);
+        // Each template counts on its own
+        assert_eq!(next_template_number(&db_path, "retro").unwrap(), 1);
+
+        // Without a number the placeholder is left out
+        let mut template = get_default_settings().meeting_templates[0].clone();
+        template.title_template = "Standup {count} ({date})".to_string();
+        let now = Local.with_ymd_and_hms(2025, 1, 15, 9, 5, 0).unwrap();
+        let title = render_title(&template, now, None);
+        assert_eq!(title, "Standup (2025-01-15)");
+        assert!(is_generated_title(&title, Some(&template.title_template)));
+    }
 }
@@ -0,0 +1,48 @@
+//! Grouping sessions by calendar day for the timeline view.
+
+use chrono::{DateTime, TimeZone};
+use serde::Serialize;
+use specta::Type;
+
+use super::models::MeetingSession;
+
+/// Sessions that started on the same calendar day.
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct DayGroup {
+    /// Calendar date in the user's timezone, as "YYYY-MM-DD"
+    pub date: String,
+    /// Sessions of that day, newest first
+    pub sessions: Vec<MeetingSession>,
+}
+
+/// Buckets sessions by the calendar date of `created_at` in `tz`.
+///
+/// Groups are returned newest day first and sessions within a group newest first,
+/// regardless of the input order.
+pub(crate) fn group_sessions_by_day<Tz: TimeZone>(
+    mut sessions: Vec<MeetingSession>,
+    tz: &Tz,
+) -> Vec<DayGroup> {
+    sessions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let mut groups: Vec<DayGroup> = Vec::new();
+    for session in sessions {
+        let date = DateTime::from_timestamp(session.created_at, 0)
+            .map(|utc| {
+                utc.with_timezone(tz)
+                    .date_naive()
+                    .format("%Y-%m-%d")
+                    .to_string()
+            })
+            .unwrap_or_default();
+
+        match groups.last_mut() {
+            Some(group) if group.date == date => group.sessions.push(session),
+            _ => groups.push(DayGroup {
+                date,
+                sessions: vec![session],
+            }),
+        }
+    }
+    groups
+}
@@ -0,0 +1,222 @@
+//! Helpers for rendering meeting titles from templates and deriving them from transcripts.
+
+use chrono::{DateTime, Local};
+
+use crate::settings::MeetingTemplate;
+
+/// Maximum length (in characters) of a title derived from the transcript.
+pub(crate) const MAX_SNIPPET_TITLE_CHARS: usize = 60;
+
+const MONTHS: &[&str] = &[
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: &[&str] = &[
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Expands the placeholders in a template's `title_template`.
+///
+/// Supported placeholders:
+/// - `{date}` - The date in YYYY-MM-DD format
+/// - `{time}` - The time in HH:MM format
+/// - `{weekday}` - The English name of the day, e.g. "Monday"
+/// - `{count}` - `count`, the number of this meeting among the template's
+///   sessions; dropped when there is no number
+///
+/// Anything else in braces is left as written.
+pub(crate) fn render_title(
+    template: &MeetingTemplate,
+    now: DateTime<Local>,
+    count: Option<usize>,
+) -> String {
+    let title_template = match count {
+        Some(count) => template
+            .title_template
+            .replace("{count}", &count.to_string()),
+        None => without_count(&template.title_template),
+    };
+    title_template
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{time}", &now.format("%H:%M").to_string())
+        .replace("{weekday}", &now.format("%A").to_string())
+}
+
+/// Drops the `{count}` placeholder from a title template, along with the
+/// extra space it leaves behind.
+fn without_count(title_template: &str) -> String {
+    title_template
+        .replace("{count}", "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn all_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Matches the manager's default title, e.g. "Meeting - January 15, 2025 3:30 PM".
+fn is_default_title(title: &str) -> bool {
+    if let Some(rest) = title.strip_prefix("Meeting - ") {
+        let parts: Vec<&str> = rest.split_whitespace().collect();
+        if let [month, day, year, time, meridiem] = parts.as_slice() {
+            let day_ok = day
+                .strip_suffix(',')
+                .map_or(false, |d| all_digits(d) && d.len() <= 2);
+            let time_ok = time
+                .split_once(':')
+                .map_or(false, |(h, m)| all_digits(h) && h.len() <= 2 && all_digits(m) && m.len() == 2);
+            return MONTHS.contains(month)
+                && day_ok
+                && all_digits(year)
+                && year.len() == 4
+                && time_ok
+                && (*meridiem == "AM" || *meridiem == "PM");
+        }
+        return false;
+    }
+
+    // Fallback format used when the timestamp can't be converted
+    title
+        .strip_prefix("Meeting ")
+        .map_or(false, |ts| all_digits(ts.trim_start_matches('-')))
+}
+
+/// Matches a title produced by `render_title` from a template's `title_template`.
+fn matches_title_template(title: &str, template: &str) -> bool {
+    let mut remaining = title;
+    let mut pattern = template;
+
+    while !pattern.is_empty() {
+        if let Some(rest) = pattern.strip_prefix("{weekday}") {
+            match WEEKDAYS.iter().find_map(|day| remaining.strip_prefix(day)) {
+                Some(after) => remaining = after,
+                None => return false,
+            }
+            pattern = rest;
+            continue;
+        }
+        if let Some(rest) = pattern.strip_prefix("{count}") {
+            let after = remaining.trim_start_matches(|c: char| c.is_ascii_digit());
+            if after.len() == remaining.len() {
+                return false;
+            }
+            remaining = after;
+            pattern = rest;
+            continue;
+        }
+
+        let (placeholder_len, shape): (usize, &[u8]) = if pattern.starts_with("{date}") {
+            (6, b"dddd-dd-dd")
+        } else if pattern.starts_with("{time}") {
+            (6, b"dd:dd")
+        } else {
+            // Literal character must match exactly
+            let ch = pattern.chars().next().unwrap();
+            if !remaining.starts_with(ch) {
+                return false;
+            }
+            pattern = &pattern[ch.len_utf8()..];
+            remaining = &remaining[ch.len_utf8()..];
+            continue;
+        };
+
+        let candidate = remaining.as_bytes();
+        if candidate.len() < shape.len() {
+            return false;
+        }
+        let shape_ok = shape.iter().zip(candidate).all(|(s, c)| match s {
+            b'd' => c.is_ascii_digit(),
+            other => other == c,
+        });
+        if !shape_ok {
+            return false;
+        }
+        pattern = &pattern[placeholder_len..];
+        remaining = &remaining[shape.len()..];
+    }
+
+    remaining.is_empty()
+}
+
+/// Returns true if `title` still looks auto-generated (either the default
+/// timestamp title or the interpolated template title), i.e. not user-edited.
+pub(crate) fn is_generated_title(title: &str, title_template: Option<&str>) -> bool {
+    is_default_title(title)
+        || title_template.map_or(false, |t| {
+            matches_title_template(title, t) || matches_title_template(title, &without_count(t))
+        })
+}
+
+/// Builds a short title from the first sentence of a transcript.
+///
+/// Returns `None` if the transcript has no usable text.
+pub(crate) fn title_from_transcript(transcript: &str) -> Option<String> {
+    let text = transcript.split_whitespace().collect::<Vec<_>>().join(" ");
+    let first_sentence = text
+        .split_inclusive(['.', '!', '?'])
+        .next()?
+        .trim()
+        .trim_end_matches(['.', '!', '?'])
+        .trim();
+
+    if first_sentence.is_empty() {
+        return None;
+    }
+
+    if first_sentence.chars().count() <= MAX_SNIPPET_TITLE_CHARS {
+        return Some(first_sentence.to_string());
+    }
+
+    // Cut at the last word boundary that fits, leaving room for the ellipsis
+    let mut snippet = String::new();
+    for word in first_sentence.split(' ') {
+        let needed = if snippet.is_empty() { 0 } else { 1 } + word.chars().count();
+        if snippet.chars().count() + needed > MAX_SNIPPET_TITLE_CHARS - 1 {
+            break;
+        }
+        if !snippet.is_empty() {
+            snippet.push(' ');
+        }
+        snippet.push_str(word);
+    }
+    if snippet.is_empty() {
+        snippet = first_sentence
+            .chars()
+            .take(MAX_SNIPPET_TITLE_CHARS - 1)
+            .collect();
+    }
+    snippet.push('…');
+    Some(snippet)
+}
+
+/// Returns the new title for a session whose transcript just completed, or
+/// `None` if the current title was edited by the user or no snippet is available.
+pub(crate) fn auto_title_for(
+    current_title: &str,
+    title_template: Option<&str>,
+    transcript: &str,
+) -> Option<String> {
+    if !is_generated_title(current_title, title_template) {
+        return None;
+    }
+    title_from_transcript(transcript)
+}
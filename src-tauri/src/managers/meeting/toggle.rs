@@ -0,0 +1,47 @@
+//! Single start/stop entry point for a global recording hotkey.
+//!
+//! Pressing the hotkey twice in quick succession sends two toggles. Each one
+//! decides and acts while holding a lock, so the second sees the state the
+//! first left behind instead of both deciding to start.
+
+use serde::Serialize;
+use specta::Type;
+use std::sync::{Arc, Mutex};
+
+/// What a toggle did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ToggleAction {
+    Started,
+    Stopped,
+}
+
+/// Serializes toggles so deciding and acting happen as one step. Cloning
+/// shares the lock.
+#[derive(Clone, Default)]
+pub(crate) struct RecordingToggle {
+    lock: Arc<Mutex<()>>,
+}
+
+impl RecordingToggle {
+    /// Stops the recording if `is_recording` says one is active, otherwise starts one.
+    ///
+    /// The start and stop guards still apply inside `start` and `stop`; a
+    /// start or stop requested some other way can fail this toggle, but two
+    /// toggles can't both start or both stop.
+    pub fn toggle<E>(
+        &self,
+        is_recording: impl FnOnce() -> bool,
+        start: impl FnOnce() -> Result<(), E>,
+        stop: impl FnOnce() -> Result<(), E>,
+    ) -> Result<ToggleAction, E> {
+        let _held = self.lock.lock().unwrap_or_else(|p| p.into_inner());
+        if is_recording() {
+            stop()?;
+            Ok(ToggleAction::Stopped)
+        } else {
+            start()?;
+            Ok(ToggleAction::Started)
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Transcription results with timing data, and how they are saved to the session folder.
+
+use anyhow::Result;
+use log::{debug, error, info, warn};
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use super::audio_cache::remove_converted_audio;
+use super::corrections::CustomWords;
+use super::db::{get_connection, status_to_string};
+use super::files::{update_latest_transcript, write_atomic};
+use super::models::MeetingStatus;
+use super::search::index_transcript;
+use super::speech::{trim_to_speech, TRIM_PADDING_MS};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::{AudioBuffer, TrimMap, VoiceActivityDetector};
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::AppSettings;
+
+/// File next to `transcript.txt` holding the language, segments, and metrics.
+pub(crate) const TRANSCRIPT_DETAILS_FILE: &str = "transcript.json";
+
+/// A timed piece of a transcript, in seconds from the start of the recording.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct Segment {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+}
+
+/// Everything a transcription run produced for a session.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionResult {
+    /// Full transcript text
+    pub text: String,
+    /// Language the transcript is in, when known
+    pub language: Option<String>,
+    /// Timed segments, empty if the engine didn't provide any
+    pub segments: Vec<Segment>,
+    /// Real-time factor: processing time divided by audio duration
+    pub rtf: f64,
+}
+
+impl TranscriptionResult {
+    /// A result with just text, for transcripts produced without an engine run.
+    pub fn text_only(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Interim text for one chunk of a transcription still in progress, or of a
+/// recording still being captured, sent to the frontend as a
+/// `meeting_partial_transcript` event for live captions.
+#[derive(Clone, Debug, PartialEq, Serialize, Type)]
+pub struct PartialTranscript {
+    pub session_id: String,
+    /// Zero-based position of the chunk in the recording
+    pub chunk: u32,
+    pub text: String,
+    /// Seconds from the start of the recording to the start of the chunk; only
+    /// known for chunks transcribed while recording
+    pub offset_secs: Option<f32>,
+}
+
+/// Called with each chunk's index and text as soon as the chunk is transcribed.
+pub(crate) type PartialCallback<'a> = &'a mut dyn FnMut(usize, &str);
+
+/// Turns the chunk callback into `PartialTranscript`s for `session_id`, passed to `emit`.
+pub(crate) fn forward_partials<'a>(
+    session_id: &'a str,
+    mut emit: impl FnMut(PartialTranscript) + 'a,
+) -> impl FnMut(usize, &str) + 'a {
+    move |chunk, text| {
+        emit(PartialTranscript {
+            session_id: session_id.to_string(),
+            chunk: chunk as u32,
+            text: text.to_string(),
+            offset_secs: None,
+        })
+    }
+}
+
+/// Something that turns 16 kHz mono samples into a transcription.
+///
+/// `rtf` in the returned result is filled in by `run_transcription`.
+pub(crate) trait Transcriber {
+    fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult>;
+}
+
+impl Transcriber for TranscriptionManager {
+    fn transcribe_detailed(&self, samples: Vec<f32>) -> Result<TranscriptionResult> {
+        let result = TranscriptionManager::transcribe_detailed(self, samples)?;
+        Ok(TranscriptionResult {
+            text: result.text,
+            language: result.language,
+            segments: result
+                .segments
+                .into_iter()
+                .map(|segment| Segment {
+                    start: segment.start,
+                    end: segment.end,
+                    text: segment.text.trim().to_string(),
+                })
+                .collect(),
+            rtf: 0.0,
+        })
+    }
+}
+
+/// Transcribes 16 kHz mono `audio` and records how long it took relative to its length.
+pub(crate) fn run_transcription<T: Transcriber + ?Sized>(
+    transcriber: &T,
+    audio: AudioBuffer,
+) -> Result<TranscriptionResult> {
+    let audio_secs = audio.duration().as_secs_f64();
+    let started = Instant::now();
+    let mut result = transcriber.transcribe_detailed(audio.into_samples())?;
+    result.rtf = if audio_secs > 0.0 {
+        started.elapsed().as_secs_f64() / audio_secs
+    } else {
+        0.0
+    };
+    Ok(result)
+}
+
+/// Transcribes 16 kHz mono audio delivered as consecutive windows, one
+/// transcriber call per window.
+///
+/// Segment times are shifted by each window's offset so they refer to the
+/// whole recording, and the window texts are joined with spaces. The language
+/// is the first one any window reports.
+///
+/// `trim_vad`, when given, drops the non-speech from each window before it is
+/// transcribed, like `trim_to_speech` does for a whole recording; windows
+/// without speech aren't transcribed at all.
+///
+/// `on_partial`, when given, receives each window's text as soon as it is
+/// transcribed; windows without text are skipped.
+pub(crate) fn run_windowed_transcription<T, I>(
+    transcriber: &T,
+    windows: I,
+    mut trim_vad: Option<&mut dyn VoiceActivityDetector>,
+    mut on_partial: Option<PartialCallback>,
+) -> Result<TranscriptionResult>
+where
+    T: Transcriber + ?Sized,
+    I: IntoIterator<Item = Result<Vec<f32>>>,
+{
+    let started = Instant::now();
+    let mut result = TranscriptionResult::default();
+    let mut texts: Vec<String> = Vec::new();
+    let mut total_samples = 0usize;
+
+    for (index, window) in windows.into_iter().enumerate() {
+        let window = window?;
+        let offset = total_samples as f32 / WHISPER_SAMPLE_RATE as f32;
+        total_samples += window.len();
+
+        let (window, trim_map) = match trim_vad.as_mut() {
+            Some(vad) => match trim_to_speech(&mut **vad, &window, TRIM_PADDING_MS) {
+                Ok(trimmed) if trimmed.samples.is_empty() => continue,
+                Ok(trimmed) => (trimmed.samples, trimmed.map),
+                Err(e) => {
+                    warn!("Transcribing window {} untrimmed, VAD failed: {}", index, e);
+                    (window, TrimMap::default())
+                }
+            },
+            None => (window, TrimMap::default()),
+        };
+
+        let part = transcriber.transcribe_detailed(window)?;
+        let text = part.text.trim();
+        if !text.is_empty() {
+            if let Some(on_partial) = on_partial.as_mut() {
+                on_partial(index, text);
+            }
+            texts.push(text.to_string());
+        }
+        if result.language.is_none() {
+            result.language = part.language;
+        }
+        result
+            .segments
+            .extend(part.segments.into_iter().map(|segment| Segment {
+                start: trim_map.original_secs(segment.start) + offset,
+                end: trim_map.original_secs(segment.end) + offset,
+                text: segment.text,
+            }));
+    }
+
+    result.text = texts.join(" ");
+    let audio_secs = total_samples as f64 / WHISPER_SAMPLE_RATE as f64;
+    result.rtf = if audio_secs > 0.0 {
+        started.elapsed().as_secs_f64() / audio_secs
+    } else {
+        0.0
+    };
+    Ok(result)
+}
+
+/// Writes the language, segments, and metrics of `result` to the session folder.
+///
+/// # Returns
+/// The path of the details file relative to `meetings_dir`
+pub(crate) fn write_transcript_details(
+    meetings_dir: &Path,
+    session_id: &str,
+    result: &TranscriptionResult,
+) -> Result<String> {
+    let rel_path = format!("{}/{}", session_id, TRANSCRIPT_DETAILS_FILE);
+    let details = TranscriptionResult {
+        text: String::new(),
+        ..result.clone()
+    };
+    let json = serde_json::to_vec_pretty(&details)?;
+    write_atomic(&meetings_dir.join(&rel_path), &json)?;
+    Ok(rel_path)
+}
+
+/// Stores a finished transcription and marks its session Completed in the database.
+///
+/// This function:
+/// 1. Applies the custom words, unless `meeting_apply_custom_words` is off
+/// 2. Writes the transcript file to the session's folder
+/// 3. Stores the language, segments, and metrics next to it, when there are any
+/// 4. Mirrors it to the latest-transcript file, if enabled
+/// 5. Stores the transcript path and Completed status, and indexes it for search
+///
+/// # Returns
+/// The path of the transcript relative to `meetings_dir`
+pub(crate) fn save_transcript(
+    db_path: &PathBuf,
+    meetings_dir: &Path,
+    session_id: &str,
+    transcription: &TranscriptionResult,
+    settings: &AppSettings,
+) -> Result<String> {
+    let corrected;
+    let transcription = match CustomWords::from_settings(settings) {
+        Some(custom_words) => {
+            corrected = custom_words.correct(transcription);
+            &corrected
+        }
+        None => transcription,
+    };
+
+    let transcript_text = transcription.text.as_str();
+    debug!(
+        "Saving transcript for session {}: {} bytes",
+        session_id,
+        transcript_text.len()
+    );
+
+    // Create transcript file path: {session-id}/transcript.txt
+    let transcript_filename = format!("{}/transcript.txt", session_id);
+    let transcript_path = meetings_dir.join(&transcript_filename);
+
+    // Write transcript atomically so a crash never leaves a half-written file
+    write_atomic(&transcript_path, transcript_text.as_bytes()).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to write transcript file {:?}: {}",
+            transcript_path,
+            e
+        )
+    })?;
+
+    info!(
+        "Saved transcript to {:?} for session {}",
+        transcript_path, session_id
+    );
+
+    // Text-only transcripts (e.g. for silent recordings) have nothing more to store
+    if !transcription.segments.is_empty() || transcription.language.is_some() {
+        let details_path = write_transcript_details(meetings_dir, session_id, transcription)?;
+        debug!(
+            "Saved {} segment(s) to {} (rtf {:.2})",
+            transcription.segments.len(),
+            details_path,
+            transcription.rtf
+        );
+    }
+
+    // Optionally mirror to a fixed path so external tools can watch it
+    if settings.mirror_latest_transcript {
+        match update_latest_transcript(meetings_dir, transcript_text) {
+            Ok(path) => debug!("Mirrored transcript to {:?}", path),
+            Err(e) => error!("Failed to update latest transcript mirror: {}", e),
+        }
+    }
+
+    // Update database with transcript path and Completed status
+    let conn = get_connection(db_path)?;
+    conn.execute(
+        "UPDATE meeting_sessions SET transcript_path = ?1, status = ?2, error_message = NULL, status_note = NULL, transcript_removed = 0 WHERE id = ?3",
+        params![
+            transcript_filename,
+            status_to_string(&MeetingStatus::Completed),
+            session_id
+        ],
+    )?;
+
+    // Only transcribing reads the converted audio, so a finished session doesn't need it
+    if let Err(e) = remove_converted_audio(&meetings_dir.join(session_id)) {
+        warn!(
+            "Failed to remove converted audio of session {}: {}",
+            session_id, e
+        );
+    }
+
+    // Search is a convenience; a failed index update doesn't fail the transcript
+    if let Err(e) = index_transcript(db_path, session_id, transcript_text) {
+        warn!(
+            "Failed to index transcript of session {} for search: {}",
+            session_id, e
+        );
+    }
+
+    Ok(transcript_filename)
+}
+
+/// Reads the details file written by `write_transcript_details`.
+///
+/// # Returns
+/// * `Ok(None)` - If the session has no details file (e.g. transcribed before
+///   details were stored)
+pub(crate) fn read_transcript_details(
+    meetings_dir: &Path,
+    session_id: &str,
+) -> Result<Option<TranscriptionResult>> {
+    let path = meetings_dir.join(session_id).join(TRANSCRIPT_DETAILS_FILE);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let json = std::fs::read(&path)?;
+    Ok(Some(serde_json::from_slice(&json)?))
+}
@@ -1,10 +1,11 @@
 //! Thread-safe WAV file writer with timeout-based finalization.
 
 use anyhow::Result;
-use hound::WavWriter;
+use hound::{WavReader, WavWriter};
 use log::{debug, error, info};
 use std::fs::File;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -14,23 +15,67 @@ use std::time::{Duration, Instant};
 /// This struct solves the race condition where `Arc::try_unwrap` fails because
 /// the audio callback thread still holds a reference to the WAV writer.
 ///
+/// The handle is the only owner that can finalize the file. The audio callback
+/// gets a [`WavSampleSink`] from [`WavWriterHandle::sink`], which can append
+/// samples but never finalize, so there is exactly one finalize path and one
+/// header written.
+///
 /// Key features:
 /// - Uses `AtomicBool` to signal when finalization starts
-/// - Callback checks `closed` flag before writing samples
+/// - Sinks check the `closed` flag before writing samples
 /// - `finalize_with_timeout` retries with exponential backoff
 pub(crate) struct WavWriterHandle {
     inner: Arc<Mutex<Option<WavWriter<File>>>>,
     closed: Arc<AtomicBool>,
+    written: SamplesWritten,
 }
 
-impl WavWriterHandle {
-    pub fn new(writer: WavWriter<File>) -> Self {
+/// Write-only side of a [`WavWriterHandle`], moved into the sample callback.
+#[derive(Clone)]
+pub(crate) struct WavSampleSink {
+    inner: Arc<Mutex<Option<WavWriter<File>>>>,
+    closed: Arc<AtomicBool>,
+    written: SamplesWritten,
+}
+
+/// Running count of the samples in a recording's file, readable after the
+/// handle has been finalized.
+#[derive(Clone, Debug)]
+pub(crate) struct SamplesWritten {
+    count: Arc<AtomicU64>,
+    sample_rate: u32,
+    channels: u16,
+}
+
+impl SamplesWritten {
+    pub fn new(initial: u64, sample_rate: u32, channels: u16) -> Self {
         Self {
-            inner: Arc::new(Mutex::new(Some(writer))),
-            closed: Arc::new(AtomicBool::new(false)),
+            count: Arc::new(AtomicU64::new(initial)),
+            sample_rate,
+            channels,
         }
     }
 
+    fn add(&self, samples: usize) {
+        self.count.fetch_add(samples as u64, Ordering::Relaxed);
+    }
+
+    /// Number of interleaved samples written so far.
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Length of the audio written so far, independent of wall-clock time.
+    pub fn duration(&self) -> RecordedDuration {
+        let frames = self.count() / self.channels.max(1) as u64;
+        RecordedDuration {
+            seconds: rounded_seconds(frames, self.sample_rate),
+            sample_rate: self.sample_rate,
+        }
+    }
+}
+
+impl WavSampleSink {
     pub fn write_samples(&self, samples: &[f32]) -> Result<()> {
         // Check if closed - skip writes after finalize starts
         if self.closed.load(Ordering::Relaxed) {
@@ -45,6 +90,7 @@ impl WavWriterHandle {
                         .write_sample(sample_i16)
                         .map_err(|e| anyhow::anyhow!("Failed to write sample: {}", e))?;
                 }
+                self.written.add(samples.len());
                 writer
                     .flush()
                     .map_err(|e| anyhow::anyhow!("Failed to flush WAV writer: {}", e))?;
@@ -52,8 +98,38 @@ impl WavWriterHandle {
         }
         Ok(())
     }
+}
 
-    pub fn finalize_with_timeout(&self, timeout: Duration) -> Result<()> {
+impl WavWriterHandle {
+    pub fn new(writer: WavWriter<File>) -> Self {
+        // A writer reopened for appending already holds the earlier samples
+        let spec = writer.spec();
+        let written = SamplesWritten::new(writer.len() as u64, spec.sample_rate, spec.channels);
+        Self {
+            inner: Arc::new(Mutex::new(Some(writer))),
+            closed: Arc::new(AtomicBool::new(false)),
+            written,
+        }
+    }
+
+    /// Returns a sink for the audio callback to append samples through.
+    pub fn sink(&self) -> WavSampleSink {
+        WavSampleSink {
+            inner: Arc::clone(&self.inner),
+            closed: Arc::clone(&self.closed),
+            written: self.written.clone(),
+        }
+    }
+
+    /// Returns the counter of samples written through this handle's sinks.
+    pub fn samples_written(&self) -> SamplesWritten {
+        self.written.clone()
+    }
+
+    /// Stops all sinks and finalizes the file, writing its header once.
+    ///
+    /// Consumes the handle, so a recording can only be finalized one way.
+    pub fn finalize_with_timeout(self, timeout: Duration) -> Result<()> {
         let timer = Instant::now();
         let mut retry_count = 0;
 
@@ -119,11 +195,36 @@ impl WavWriterHandle {
     }
 }
 
-impl Clone for WavWriterHandle {
-    fn clone(&self) -> Self {
-        Self {
-            inner: Arc::clone(&self.inner),
-            closed: Arc::clone(&self.closed),
-        }
+/// Length of a finalized recording, derived from its own sample rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RecordedDuration {
+    /// Duration in whole seconds (rounded to nearest)
+    pub seconds: i64,
+    /// Sample rate the file was recorded at, in Hz
+    pub sample_rate: u32,
+}
+
+/// Reads the duration of a finalized WAV file from its header as
+/// `sample_count / sample_rate`, rather than assuming a fixed rate.
+pub(crate) fn recorded_duration(path: &Path) -> Result<RecordedDuration> {
+    let reader = WavReader::open(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open audio file {:?}: {}", path, e))?;
+    let sample_rate = reader.spec().sample_rate;
+    if sample_rate == 0 {
+        return Err(anyhow::anyhow!("Audio file {:?} has a zero sample rate", path));
+    }
+
+    // duration() is in frames, i.e. samples per channel
+    Ok(RecordedDuration {
+        seconds: rounded_seconds(reader.duration() as u64, sample_rate),
+        sample_rate,
+    })
+}
+
+/// `frames / sample_rate` rounded to the nearest second; 0 for a zero rate.
+fn rounded_seconds(frames: u64, sample_rate: u32) -> i64 {
+    if sample_rate == 0 {
+        return 0;
     }
+    (frames as f64 / sample_rate as f64).round() as i64
 }
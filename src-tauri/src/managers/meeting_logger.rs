@@ -3,12 +3,64 @@
 ///! This module provides helpers for logging meeting-related events with consistent
 ///! structure and context, making it easier to debug and analyze meeting issues.
 use log::{debug, error, info, warn};
+use serde::Serialize;
+use specta::Type;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Number of recent errors and warnings kept by the manager's `MeetingErrorLog`
+pub const MEETING_ERROR_LOG_CAPACITY: usize = 200;
+
+/// A meeting error or warning kept in memory for the support panel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Type)]
+pub struct ErrorEntry {
+    /// Unix timestamp (seconds) when the entry was logged
+    pub timestamp: i64,
+    /// "error" or "warning"
+    pub level: String,
+    pub session_id: String,
+    pub operation: String,
+    pub message: String,
+}
+
+/// Bounded in-memory buffer of the most recent meeting errors and warnings
+#[derive(Debug)]
+pub struct MeetingErrorLog {
+    capacity: usize,
+    entries: Mutex<VecDeque<ErrorEntry>>,
+}
+
+impl MeetingErrorLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Adds an entry, dropping the oldest one when the buffer is full
+    pub fn push(&self, entry: ErrorEntry) {
+        let mut entries = self.entries.lock().unwrap_or_else(|p| p.into_inner());
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Returns up to `n` of the most recent entries, newest first
+    pub fn recent(&self, n: usize) -> Vec<ErrorEntry> {
+        let entries = self.entries.lock().unwrap_or_else(|p| p.into_inner());
+        entries.iter().rev().take(n).cloned().collect()
+    }
+}
 
 /// Log context for meeting operations
 #[derive(Debug, Clone)]
 pub struct MeetingLogContext {
     pub session_id: String,
     pub operation: String,
+    /// Where errors and warnings are also recorded, if anywhere
+    error_log: Option<Arc<MeetingErrorLog>>,
 }
 
 impl MeetingLogContext {
@@ -16,6 +68,25 @@ impl MeetingLogContext {
         Self {
             session_id: session_id.into(),
             operation: operation.into(),
+            error_log: None,
+        }
+    }
+
+    /// Also records this context's errors and warnings in `error_log`
+    pub fn with_error_log(mut self, error_log: Arc<MeetingErrorLog>) -> Self {
+        self.error_log = Some(error_log);
+        self
+    }
+
+    fn record(&self, level: &str, message: &str) {
+        if let Some(error_log) = self.error_log.as_ref() {
+            error_log.push(ErrorEntry {
+                timestamp: chrono::Utc::now().timestamp(),
+                level: level.to_string(),
+                session_id: self.session_id.clone(),
+                operation: self.operation.clone(),
+                message: message.to_string(),
+            });
         }
     }
 
@@ -57,6 +128,7 @@ impl MeetingLogContext {
             self.operation,
             error.as_ref()
         );
+        self.record("error", error.as_ref());
     }
 
     /// Log warning
@@ -67,6 +139,7 @@ impl MeetingLogContext {
             self.operation,
             warning.as_ref()
         );
+        self.record("warning", warning.as_ref());
     }
 
     /// Log debug info
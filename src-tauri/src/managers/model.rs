@@ -35,6 +35,18 @@ pub struct ModelInfo {
     pub engine_type: EngineType,
     pub accuracy_score: f32, // 0.0 to 1.0, higher is more accurate
     pub speed_score: f32,    // 0.0 to 1.0, higher is faster
+    /// ISO 639-1 codes the model can transcribe; `["auto"]` for multilingual models with language detection
+    pub languages: Vec<String>,
+}
+
+/// Languages supported by Parakeet V3 (25 European languages)
+const PARAKEET_V3_LANGUAGES: &[&str] = &[
+    "bg", "hr", "cs", "da", "nl", "en", "et", "fi", "fr", "de", "el", "hu", "it", "lv", "lt", "mt",
+    "pl", "pt", "ro", "sk", "sl", "es", "sv", "ru", "uk",
+];
+
+fn language_list(codes: &[&str]) -> Vec<String> {
+    codes.iter().map(|c| c.to_string()).collect()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -83,6 +95,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.60,
                 speed_score: 0.85,
+                languages: language_list(&["auto"]),
             },
         );
 
@@ -103,6 +116,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.75,
                 speed_score: 0.60,
+                languages: language_list(&["auto"]),
             },
         );
 
@@ -122,6 +136,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.80,
                 speed_score: 0.40,
+                languages: language_list(&["auto"]),
             },
         );
 
@@ -141,6 +156,7 @@ impl ModelManager {
                 engine_type: EngineType::Whisper,
                 accuracy_score: 0.85,
                 speed_score: 0.30,
+                languages: language_list(&["auto"]),
             },
         );
 
@@ -161,6 +177,7 @@ impl ModelManager {
                 engine_type: EngineType::Parakeet,
                 accuracy_score: 0.85,
                 speed_score: 0.85,
+                languages: language_list(&["en"]),
             },
         );
 
@@ -180,6 +197,7 @@ impl ModelManager {
                 engine_type: EngineType::Parakeet,
                 accuracy_score: 0.80,
                 speed_score: 0.85,
+                languages: language_list(PARAKEET_V3_LANGUAGES),
             },
         );
 
@@ -206,6 +224,18 @@ impl ModelManager {
         models.values().cloned().collect()
     }
 
+    /// Returns only the models that are downloaded and ready to use, sorted by name.
+    pub fn get_installed_models(&self) -> Vec<ModelInfo> {
+        let models = self.available_models.lock().unwrap_or_else(|p| p.into_inner());
+        let mut installed: Vec<ModelInfo> = models
+            .values()
+            .filter(|m| m.is_downloaded)
+            .cloned()
+            .collect();
+        installed.sort_by(|a, b| a.name.cmp(&b.name));
+        installed
+    }
+
     pub fn get_model_info(&self, model_id: &str) -> Option<ModelInfo> {
         let models = self.available_models.lock().unwrap_or_else(|p| p.into_inner());
         models.get(model_id).cloned()
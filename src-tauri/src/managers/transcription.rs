@@ -16,9 +16,22 @@ use transcribe_rs::{
         },
         whisper::{WhisperEngine, WhisperInferenceParams},
     },
-    TranscriptionEngine,
+    TranscriptionEngine, TranscriptionSegment,
 };
 
+/// Transcription text along with the timing data the engine produced.
+#[derive(Clone, Debug, Default)]
+pub struct DetailedTranscription {
+    /// Final text, with custom-word correction applied
+    pub text: String,
+    /// Language the text came out in: English when translating, otherwise the
+    /// language Whisper was told to use. None when the engine picked the
+    /// language itself, since it doesn't report what it detected.
+    pub language: Option<String>,
+    /// Timed segments as returned by the engine, before word correction
+    pub segments: Vec<TranscriptionSegment>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ModelStateEvent {
     pub event_type: String,
@@ -315,7 +328,28 @@ impl TranscriptionManager {
         current_model.clone()
     }
 
+    /// Transcribes `audio` to text with the custom words applied.
     pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
+        let text = self.transcribe_detailed(audio)?.text;
+
+        // Apply word correction if custom words are configured
+        let settings = get_settings(&self.app_handle);
+        if settings.custom_words.is_empty() {
+            return Ok(text);
+        }
+        let corrected = apply_custom_words(
+            &text,
+            &settings.custom_words,
+            settings.word_correction_threshold,
+        );
+        Ok(corrected.trim().to_string())
+    }
+
+    /// Transcribes `audio` and also returns the engine's segments and language.
+    ///
+    /// The text is returned as the engine produced it; custom words are left
+    /// to the caller, since meetings can opt out of them.
+    pub fn transcribe_detailed(&self, audio: Vec<f32>) -> Result<DetailedTranscription> {
         // Update last activity timestamp
         self.last_activity.store(
             SystemTime::now()
@@ -332,7 +366,7 @@ impl TranscriptionManager {
         if audio.is_empty() {
             debug!("Empty audio vector");
             self.maybe_unload_immediately("empty audio");
-            return Ok(String::new());
+            return Ok(DetailedTranscription::default());
         }
 
         // Check if model is loaded, if not try to load it
@@ -353,6 +387,7 @@ impl TranscriptionManager {
         let settings = get_settings(&self.app_handle);
 
         // Perform transcription with the appropriate engine
+        let mut language = None;
         let result = {
             let mut engine_guard = self.engine.lock().unwrap_or_else(|p| p.into_inner());
             let engine = engine_guard.as_mut().ok_or_else(|| {
@@ -378,6 +413,12 @@ impl TranscriptionManager {
                         Some(normalized)
                     };
 
+                    // A translation is English whatever was spoken
+                    language = if settings.translate_to_english {
+                        Some("en".to_string())
+                    } else {
+                        whisper_language.clone()
+                    };
                     let params = WhisperInferenceParams {
                         language: whisper_language,
                         translate: settings.translate_to_english,
@@ -415,17 +456,6 @@ impl TranscriptionManager {
             }
         };
 
-        // Apply word correction if custom words are configured
-        let corrected_result = if !settings.custom_words.is_empty() {
-            apply_custom_words(
-                &result.text,
-                &settings.custom_words,
-                settings.word_correction_threshold,
-            )
-        } else {
-            result.text
-        };
-
         let et = std::time::Instant::now();
         let translation_note = if settings.translate_to_english {
             " (translated)"
@@ -438,7 +468,7 @@ impl TranscriptionManager {
             translation_note
         );
 
-        let final_result = corrected_result.trim().to_string();
+        let final_result = result.text.trim().to_string();
 
         if final_result.is_empty() {
             info!("Transcription result is empty");
@@ -448,7 +478,11 @@ impl TranscriptionManager {
 
         self.maybe_unload_immediately("transcription");
 
-        Ok(final_result)
+        Ok(DetailedTranscription {
+            text: final_result,
+            language,
+            segments: result.segments.unwrap_or_default(),
+        })
     }
 }
 
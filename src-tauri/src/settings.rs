@@ -96,16 +96,105 @@ pub struct LLMPrompt {
 pub struct MeetingTemplate {
     pub id: String,
     pub name: String,
+    /// Translation key for a built-in template's name; `name` is the English fallback.
+    /// User-created templates have no key and are always shown by `name`.
+    #[serde(default)]
+    pub name_key: Option<String>,
     pub icon: String,
     pub title_template: String,
     pub audio_source: String, // Serialized AudioSourceType: "microphone_only", "system_only", "mixed"
     pub prompt_id: Option<String>,
     #[serde(default)]
     pub summary_prompt_template: Option<String>, // Custom prompt template for AI summaries
+    /// Balance mic and system audio by loudness when mixing instead of a plain average
+    #[serde(default)]
+    pub normalize_loudness: bool,
+    /// Leave sounds played by this app out of captured system audio. Off by
+    /// default, in which case notification sounds or previews end up in the recording.
+    #[serde(default)]
+    pub exclude_app_audio: bool,
+    /// Ordered audio preprocessing steps applied before transcription
+    #[serde(default)]
+    pub preprocessing: Vec<PreprocessingStep>,
+    /// Replace the generated title with the transcript's first sentence once transcribed
+    #[serde(default)]
+    pub auto_title_from_transcript: bool,
+    /// Mark recordings with no speech as Failed instead of Completed with an empty transcript
+    #[serde(default)]
+    pub fail_on_no_speech: bool,
+    /// Keep the audio after a summary is generated (false deletes it once summarized)
+    #[serde(default = "default_retain")]
+    pub retain_audio: bool,
+    /// Keep the transcript after a summary is generated (false deletes it once summarized)
+    #[serde(default = "default_retain")]
+    pub retain_transcript: bool,
+    /// Break the transcript into paragraphs at sentence boundaries before saving
+    #[serde(default)]
+    pub format_paragraphs: bool,
+    /// Approximate paragraph length in characters when `format_paragraphs` is on
+    #[serde(default = "default_paragraph_target_chars")]
+    pub paragraph_target_chars: u32,
+    /// How to summarize a transcript too long for `summary_token_budget`
+    #[serde(default)]
+    pub summary_strategy: SummaryStrategy,
+    /// Estimated token limit for a single summary request to the LLM
+    #[serde(default = "default_summary_token_budget")]
+    pub summary_token_budget: u32,
+    /// Actions run in order once a session using this template is Completed
+    #[serde(default)]
+    pub post_actions: Vec<PostAction>,
+    /// Emit each chunk's text as it is transcribed, for live captions; also
+    /// transcribes the recording in chunks while it is captured
+    #[serde(default)]
+    pub live_captions: bool,
+    /// How much later the microphone picks up a sound than system audio
+    /// delivers it, in milliseconds; applied when mixing to avoid echo
+    #[serde(default)]
+    pub sync_offset_ms: i32,
+    /// Estimate `sync_offset_ms` from the start of each Mixed recording instead
+    #[serde(default)]
+    pub auto_sync_offset: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
 
+/// How a template summarizes a transcript that doesn't fit its token budget.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStrategy {
+    /// Cut the transcript to fit and note that it was shortened
+    #[default]
+    Truncate,
+    /// Summarize the transcript in parts, then summarize the part summaries
+    MapReduce,
+}
+
+/// Something done automatically after a session's transcript is saved.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PostAction {
+    /// Write `transcript.srt` into the session folder
+    ExportSrt,
+    /// Generate an AI summary with the template's summary prompt
+    GenerateSummary,
+    /// Add the transcript to the Quick Dictation history
+    AddToHistory,
+    /// Copy the transcript file's path to the clipboard
+    CopyTranscriptPath,
+    /// Append the summary, or the transcript if there is none, to `daily/{date}.md`
+    AppendToDailyNote,
+}
+
+/// One step of a template's audio preprocessing chain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct PreprocessingStep {
+    /// Step name: "normalize", "noise_gate", or "trim_silence"
+    pub name: String,
+    /// Step parameters; missing parameters use the step's defaults
+    #[serde(default)]
+    pub params: HashMap<String, f32>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct PostProcessProvider {
     pub id: String,
@@ -119,6 +208,18 @@ pub struct PostProcessProvider {
     pub default_model: Option<String>,
 }
 
+fn default_retain() -> bool {
+    true
+}
+
+fn default_paragraph_target_chars() -> u32 {
+    crate::managers::meeting::DEFAULT_PARAGRAPH_TARGET_CHARS
+}
+
+fn default_summary_token_budget() -> u32 {
+    crate::managers::meeting::DEFAULT_SUMMARY_TOKEN_BUDGET
+}
+
 fn default_requires_api_key() -> bool {
     true
 }
@@ -171,6 +272,24 @@ pub enum RecordingRetentionPeriod {
     Months3,
 }
 
+/// What to do with a meeting's WAV after it has been transcribed.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingAudioArchival {
+    /// Keep only the original 16 kHz WAV
+    Off,
+    /// Write a 16-bit mono archive copy alongside the original
+    KeepOriginal,
+    /// Write a 16-bit mono archive copy and delete the original once it checks out
+    ReplaceOriginal,
+}
+
+impl Default for MeetingAudioArchival {
+    fn default() -> Self {
+        MeetingAudioArchival::Off
+    }
+}
+
 impl Default for ModelUnloadTimeout {
     fn default() -> Self {
         ModelUnloadTimeout::Never
@@ -315,6 +434,73 @@ pub struct AppSettings {
     pub app_language: String,
     #[serde(default = "default_meeting_templates")]
     pub meeting_templates: Vec<MeetingTemplate>,
+    /// Template armed for the next meeting when none is passed explicitly
+    #[serde(default)]
+    pub active_meeting_template_id: Option<String>,
+    /// Mirror each completed meeting transcript to `meetings/latest_transcript.txt`
+    #[serde(default)]
+    pub mirror_latest_transcript: bool,
+    /// Whether to store a compact archive copy of meeting audio after transcription
+    #[serde(default)]
+    pub meeting_audio_archival: MeetingAudioArchival,
+    /// Seconds after stopping during which starting again resumes the same meeting (0 = off)
+    #[serde(default)]
+    pub meeting_resume_grace_secs: u32,
+    /// Frames per chunk delivered to the meeting sample callback (0 = device's native period)
+    #[serde(default)]
+    pub meeting_sample_chunk_frames: u32,
+    /// Milliseconds of audio kept from the microphone opened while a meeting's
+    /// recorder starts up, placed ahead of the recording (0 = off)
+    #[serde(default)]
+    pub meeting_pre_roll_ms: u32,
+    /// Transcribe sessions interrupted by a crash on the next launch if their audio is intact
+    #[serde(default)]
+    pub meeting_auto_retry_interrupted: bool,
+    /// Start transcribing as soon as a meeting stops; when off, sessions wait as
+    /// Recorded until transcribed manually
+    #[serde(default = "default_meeting_auto_transcribe")]
+    pub meeting_auto_transcribe: bool,
+    /// Summarize each meeting with the configured LLM once its transcript is saved
+    #[serde(default)]
+    pub meeting_auto_summarize: bool,
+    /// Exports and hashing jobs that may run at the same time
+    #[serde(default = "default_meeting_file_job_concurrency")]
+    pub meeting_file_job_concurrency: u32,
+    /// Apply `custom_words` to meeting transcripts and live captions; off keeps them verbatim
+    #[serde(default = "default_meeting_apply_custom_words")]
+    pub meeting_apply_custom_words: bool,
+    /// Seconds of audio per chunk when a template's live captions transcribe a
+    /// meeting while it is recording
+    #[serde(default = "default_meeting_live_chunk_secs")]
+    pub meeting_live_chunk_secs: u32,
+    /// Drop the non-speech parts of a recording before transcribing it; the
+    /// saved audio is left as recorded
+    #[serde(default)]
+    pub meeting_vad_trim: bool,
+    /// Speech probability (0-1) above which the VAD keeps a frame when
+    /// trimming; lower keeps more quiet speech, and more noise
+    #[serde(default = "default_meeting_vad_trim_threshold")]
+    pub meeting_vad_trim_threshold: f32,
+}
+
+fn default_meeting_auto_transcribe() -> bool {
+    true
+}
+
+fn default_meeting_apply_custom_words() -> bool {
+    true
+}
+
+fn default_meeting_live_chunk_secs() -> u32 {
+    crate::managers::meeting::DEFAULT_LIVE_CHUNK_SECS
+}
+
+fn default_meeting_vad_trim_threshold() -> f32 {
+    crate::managers::meeting::SPEECH_VAD_THRESHOLD
+}
+
+fn default_meeting_file_job_concurrency() -> u32 {
+    crate::managers::meeting::DEFAULT_FILE_JOB_CONCURRENCY
 }
 
 fn default_model() -> String {
@@ -395,6 +581,7 @@ fn default_meeting_templates() -> Vec<MeetingTemplate> {
         MeetingTemplate {
             id: "template_1on1".to_string(),
             name: "1:1 Meeting".to_string(),
+            name_key: Some("oneOnOne".to_string()),
             icon: "Users".to_string(),
             title_template: "1:1 - {date}".to_string(),
             audio_source: "microphone_only".to_string(),
@@ -422,12 +609,28 @@ Transcript:
 
 Provide a clear, concise summary focusing on actionable items and personal development points."#.to_string()
             ),
+            normalize_loudness: false,
+            exclude_app_audio: false,
+            preprocessing: Vec::new(),
+            auto_title_from_transcript: false,
+            fail_on_no_speech: false,
+            retain_audio: true,
+            retain_transcript: true,
+            format_paragraphs: false,
+            paragraph_target_chars: default_paragraph_target_chars(),
+            summary_strategy: SummaryStrategy::default(),
+            summary_token_budget: default_summary_token_budget(),
+            post_actions: Vec::new(),
+            live_captions: false,
+            sync_offset_ms: 0,
+            auto_sync_offset: false,
             created_at: 0,
             updated_at: 0,
         },
         MeetingTemplate {
             id: "template_team_standup".to_string(),
             name: "Team Standup".to_string(),
+            name_key: Some("teamStandup".to_string()),
             icon: "Coffee".to_string(),
             title_template: "Standup - {date}".to_string(),
             audio_source: "mixed".to_string(),
@@ -456,12 +659,28 @@ Transcript:
 
 Keep it brief and action-oriented, focusing on momentum and blockers."#.to_string()
             ),
+            normalize_loudness: false,
+            exclude_app_audio: false,
+            preprocessing: Vec::new(),
+            auto_title_from_transcript: false,
+            fail_on_no_speech: false,
+            retain_audio: true,
+            retain_transcript: true,
+            format_paragraphs: false,
+            paragraph_target_chars: default_paragraph_target_chars(),
+            summary_strategy: SummaryStrategy::default(),
+            summary_token_budget: default_summary_token_budget(),
+            post_actions: Vec::new(),
+            live_captions: false,
+            sync_offset_ms: 0,
+            auto_sync_offset: false,
             created_at: 0,
             updated_at: 0,
         },
         MeetingTemplate {
             id: "template_interview".to_string(),
             name: "Interview".to_string(),
+            name_key: Some("interview".to_string()),
             icon: "Mic".to_string(),
             title_template: "Interview - {date}".to_string(),
             audio_source: "microphone_only".to_string(),
@@ -498,6 +717,21 @@ Transcript:
 
 Provide an objective, balanced assessment suitable for hiring decisions."#.to_string()
             ),
+            normalize_loudness: false,
+            exclude_app_audio: false,
+            preprocessing: Vec::new(),
+            auto_title_from_transcript: false,
+            fail_on_no_speech: false,
+            retain_audio: true,
+            retain_transcript: true,
+            format_paragraphs: false,
+            paragraph_target_chars: default_paragraph_target_chars(),
+            summary_strategy: SummaryStrategy::default(),
+            summary_token_budget: default_summary_token_budget(),
+            post_actions: Vec::new(),
+            live_captions: false,
+            sync_offset_ms: 0,
+            auto_sync_offset: false,
             created_at: 0,
             updated_at: 0,
         },
@@ -627,6 +861,23 @@ fn default_post_process_prompts() -> Vec<LLMPrompt> {
     }]
 }
 
+/// Gives stored built-in templates the `name_key` added after they were saved,
+/// as long as the user hasn't renamed them.
+fn ensure_template_name_keys(settings: &mut AppSettings) -> bool {
+    let mut changed = false;
+    for default in default_meeting_templates() {
+        if let Some(template) = settings
+            .meeting_templates
+            .iter_mut()
+            .find(|t| t.id == default.id && t.name_key.is_none() && t.name == default.name)
+        {
+            template.name_key = default.name_key;
+            changed = true;
+        }
+    }
+    changed
+}
+
 fn ensure_post_process_defaults(settings: &mut AppSettings) -> bool {
     let mut changed = false;
     for provider in default_post_process_providers() {
@@ -737,6 +988,20 @@ pub fn get_default_settings() -> AppSettings {
         append_trailing_space: false,
         app_language: default_app_language(),
         meeting_templates: default_meeting_templates(),
+        active_meeting_template_id: None,
+        mirror_latest_transcript: false,
+        meeting_audio_archival: MeetingAudioArchival::Off,
+        meeting_resume_grace_secs: 0,
+        meeting_sample_chunk_frames: 0,
+        meeting_pre_roll_ms: 0,
+        meeting_auto_retry_interrupted: false,
+        meeting_auto_transcribe: true,
+        meeting_auto_summarize: false,
+        meeting_file_job_concurrency: default_meeting_file_job_concurrency(),
+        meeting_apply_custom_words: true,
+        meeting_live_chunk_secs: default_meeting_live_chunk_secs(),
+        meeting_vad_trim: false,
+        meeting_vad_trim_threshold: default_meeting_vad_trim_threshold(),
     }
 }
 
@@ -747,6 +1012,43 @@ impl AppSettings {
             .find(|provider| provider.id == self.post_process_provider_id)
     }
 
+    /// Returns the active meeting template, if one is set and still exists.
+    pub fn active_meeting_template(&self) -> Option<&MeetingTemplate> {
+        let active_id = self.active_meeting_template_id.as_ref()?;
+        self.meeting_templates.iter().find(|t| &t.id == active_id)
+    }
+
+    /// Makes the template with `id` the active one, or clears it with `None`.
+    ///
+    /// An unknown id is an error and leaves the active template as it was.
+    pub fn set_active_meeting_template(
+        &mut self,
+        id: Option<String>,
+    ) -> Result<Option<MeetingTemplate>, String> {
+        let template = match &id {
+            Some(tid) => Some(
+                self.meeting_templates
+                    .iter()
+                    .find(|t| &t.id == tid)
+                    .cloned()
+                    .ok_or_else(|| format!("Template with id '{}' not found", tid))?,
+            ),
+            None => None,
+        };
+        self.active_meeting_template_id = id;
+        Ok(template)
+    }
+
+    /// Clears the active template id if it points at a template that no longer
+    /// exists, returning the cleared id.
+    pub fn clear_missing_active_template(&mut self) -> Option<String> {
+        if self.active_meeting_template_id.is_some() && self.active_meeting_template().is_none() {
+            self.active_meeting_template_id.take()
+        } else {
+            None
+        }
+    }
+
     pub fn post_process_provider(&self, provider_id: &str) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
@@ -807,7 +1109,8 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
         default_settings
     };
 
-    if ensure_post_process_defaults(&mut settings) {
+    let post_process_changed = ensure_post_process_defaults(&mut settings);
+    if ensure_template_name_keys(&mut settings) || post_process_changed {
         store.set("settings", serde_json::to_value(&settings).unwrap());
     }
 
@@ -831,7 +1134,8 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
         default_settings
     };
 
-    if ensure_post_process_defaults(&mut settings) {
+    let post_process_changed = ensure_post_process_defaults(&mut settings);
+    if ensure_template_name_keys(&mut settings) || post_process_changed {
         store.set("settings", serde_json::to_value(&settings).unwrap());
     }
 
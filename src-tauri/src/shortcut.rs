@@ -8,10 +8,11 @@ use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
 use crate::actions::ACTION_MAP;
 use crate::managers::audio::AudioRecordingManager;
+use crate::managers::meeting::MeetingSessionManager;
 use crate::settings::ShortcutBinding;
 use crate::settings::{
-    self, get_settings, ClipboardHandling, LLMPrompt, OverlayPosition, PasteMethod, SoundTheme,
-    APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
+    self, get_settings, ClipboardHandling, LLMPrompt, MeetingAudioArchival, OverlayPosition,
+    PasteMethod, SoundTheme, APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::tray;
 use crate::ManagedToggleState;
@@ -612,6 +613,62 @@ pub fn change_append_trailing_space_setting(app: AppHandle, enabled: bool) -> Re
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_mirror_latest_transcript_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.mirror_latest_transcript = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_audio_archival_setting(app: AppHandle, archival: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let parsed = match archival.as_str() {
+        "off" => MeetingAudioArchival::Off,
+        "keep_original" => MeetingAudioArchival::KeepOriginal,
+        "replace_original" => MeetingAudioArchival::ReplaceOriginal,
+        other => {
+            warn!("Invalid meeting audio archival '{}', defaulting to off", other);
+            MeetingAudioArchival::Off
+        }
+    };
+    settings.meeting_audio_archival = parsed;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_auto_retry_interrupted_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_auto_retry_interrupted = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_resume_grace_setting(app: AppHandle, seconds: u32) -> Result<(), String> {
+    let max = crate::managers::meeting::MAX_RESUME_GRACE_SECS;
+    if seconds > max {
+        return Err(format!("Resume grace window must be at most {} seconds", max));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_resume_grace_secs = seconds;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_app_language_setting(app: AppHandle, language: String) -> Result<(), String> {
@@ -834,3 +891,143 @@ pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<
 
     Ok(())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_sample_chunk_frames_setting(
+    app: AppHandle,
+    frames: u32,
+) -> Result<(), String> {
+    let max = crate::audio_toolkit::MAX_SAMPLE_CHUNK_FRAMES as u32;
+    if frames > max {
+        return Err(format!("Sample chunk size must be at most {} frames", max));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_sample_chunk_frames = frames;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_pre_roll_ms_setting(app: AppHandle, pre_roll_ms: u32) -> Result<(), String> {
+    let max = crate::managers::meeting::MAX_PRE_ROLL_MS;
+    if pre_roll_ms > max {
+        return Err(format!("Pre-roll must be at most {}ms", max));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_pre_roll_ms = pre_roll_ms;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_auto_transcribe_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_auto_transcribe = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_auto_summarize_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_auto_summarize = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_apply_custom_words_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_apply_custom_words = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_live_chunk_secs_setting(
+    app: AppHandle,
+    chunk_secs: u32,
+) -> Result<(), String> {
+    let min = crate::managers::meeting::MIN_LIVE_CHUNK_SECS;
+    let max = crate::managers::meeting::MAX_LIVE_CHUNK_SECS;
+    if !(min..=max).contains(&chunk_secs) {
+        return Err(format!(
+            "Live transcription chunks must be between {} and {} seconds",
+            min, max
+        ));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_live_chunk_secs = chunk_secs;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_vad_trim_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_vad_trim = enabled;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_vad_trim_threshold_setting(
+    app: AppHandle,
+    threshold: f32,
+) -> Result<(), String> {
+    if !(0.0..1.0).contains(&threshold) {
+        return Err("VAD threshold must be at least 0 and below 1".to_string());
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_vad_trim_threshold = threshold;
+    settings::write_settings(&app, settings);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_meeting_file_job_concurrency_setting(
+    app: AppHandle,
+    workers: u32,
+) -> Result<(), String> {
+    let max = crate::managers::meeting::MAX_FILE_JOB_CONCURRENCY;
+    if workers == 0 || workers > max {
+        return Err(format!(
+            "File job concurrency must be between 1 and {}",
+            max
+        ));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.meeting_file_job_concurrency = workers;
+    settings::write_settings(&app, settings);
+
+    if let Some(manager) = app.try_state::<Arc<MeetingSessionManager>>() {
+        manager.set_file_job_concurrency(workers);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Built-in meeting template name internationalization
+//!
+//! The names are generated at compile time by build.rs from the
+//! "builtinTemplates" section of the frontend locale files
+//! (src/i18n/locales/*/translation.json). Built-in templates store a stable
+//! `name_key` into that section; user templates have no key and keep their
+//! literal names.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::settings::MeetingTemplate;
+
+// Include the auto-generated TEMPLATE_NAMES static
+include!(concat!(env!("OUT_DIR"), "/template_translations.rs"));
+
+/// Returns the language used for template names: the language code of
+/// `locale` (e.g. "fr-CA" -> "fr") if it has translations, otherwise "en".
+pub fn template_language(locale: &str) -> &'static str {
+    let lang = locale.split(['-', '_']).next().unwrap_or("en");
+    TEMPLATE_NAMES
+        .get_key_value(lang)
+        .map(|(lang, _)| *lang)
+        .unwrap_or("en")
+}
+
+/// Looks up a built-in template name, falling back to English when the
+/// locale lacks the key.
+pub fn localized_template_name(name_key: &str, locale: &str) -> Option<&'static str> {
+    let lang = template_language(locale);
+    TEMPLATE_NAMES
+        .get(lang)
+        .and_then(|names| names.get(name_key))
+        .or_else(|| {
+            TEMPLATE_NAMES
+                .get("en")
+                .and_then(|names| names.get(name_key))
+        })
+        .copied()
+}
+
+/// Returns the name to show for `template` in `locale`.
+pub fn template_display_name(template: &MeetingTemplate, locale: &str) -> String {
+    template
+        .name_key
+        .as_deref()
+        .and_then(|key| localized_template_name(key, locale))
+        .map(str::to_string)
+        .unwrap_or_else(|| template.name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_template_name_resolves_per_locale() {
+        assert_eq!(
+            localized_template_name("teamStandup", "en"),
+            Some("Team Standup")
+        );
+        assert_eq!(
+            localized_template_name("interview", "fr-FR"),
+            Some("Entretien")
+        );
+        assert_eq!(
+            localized_template_name("oneOnOne", "de"),
+            Some("1:1-Gespräch")
+        );
+
+        // Unknown locales fall back to English, unknown keys to nothing
+        assert_eq!(template_language("xx"), "en");
+        assert_eq!(
+            localized_template_name("interview", "xx"),
+            Some("Interview")
+        );
+        assert_eq!(localized_template_name("missing", "fr"), None);
+    }
+}